@@ -174,6 +174,32 @@ macro_rules! ec_bench {
             });
         }
 
+        // Small MSMs sum their buckets in XYZZ coordinates, larger ones in
+        // affine coordinates with batched inversions.
+        #[bench]
+        fn bench_g1_msm_64(b: &mut ::test::Bencher) {
+            bench_g1_msm(b, 64);
+        }
+
+        #[bench]
+        fn bench_g1_msm_1024(b: &mut ::test::Bencher) {
+            bench_g1_msm(b, 1024);
+        }
+
+        fn bench_g1_msm(b: &mut ::test::Bencher, size: usize) {
+            use algebra::{msm::VariableBaseMSM, ProjectiveCurve};
+
+            let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+            let v: Vec<_> = (0..size).map(|_| G1::rand(&mut rng)).collect();
+            let bases = G1::batch_normalization_into_affine(&v);
+            let scalars: Vec<_> = (0..size)
+                .map(|_| Fr::rand(&mut rng).into_repr())
+                .collect();
+
+            b.iter(|| VariableBaseMSM::multi_scalar_mul(&bases, &scalars));
+        }
+
         #[bench]
         fn bench_g2_rand(b: &mut ::test::Bencher) {
             let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -0,0 +1,373 @@
+extern crate proc_macro;
+
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{One, ToPrimitive, Zero};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Error, Lit, Meta};
+
+/// Derives `FftParameters`, `FpParameters` and the matching `FpNParameters`
+/// marker trait from a decimal modulus and a multiplicative generator.
+///
+/// The generator must be a quadratic non-residue, and may be negative. An
+/// optional `small_subgroup_base` additionally sets the mixed-radix FFT
+/// parameters, using the largest power of the base that divides `MODULUS - 1`,
+/// and the order of the generator must then be divisible by every prime
+/// factor of the base.
+///
+/// ```ignore
+/// use algebra_core::fields::{Fp256, FpParameters};
+///
+/// #[derive(FpParameters)]
+/// #[modulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
+/// #[generator = "7"]
+/// pub struct FrParameters;
+///
+/// pub type Fr = Fp256<FrParameters>;
+/// ```
+#[proc_macro_derive(FpParameters, attributes(modulus, generator, small_subgroup_base))]
+pub fn derive_fp_parameters(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(impl_fp_parameters(&ast).unwrap_or_else(|e| e.to_compile_error()))
+}
+
+fn impl_fp_parameters(ast: &DeriveInput) -> Result<TokenStream, Error> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let mut modulus = None;
+    let mut generator = None;
+    let mut small_subgroup_base = None;
+    for attr in &ast.attrs {
+        let slot = if attr.path.is_ident("modulus") {
+            &mut modulus
+        } else if attr.path.is_ident("generator") {
+            &mut generator
+        } else if attr.path.is_ident("small_subgroup_base") {
+            &mut small_subgroup_base
+        } else {
+            continue;
+        };
+        *slot = Some(parse_integer_attribute(attr)?);
+    }
+    let missing = |attr| {
+        Error::new(
+            Span::call_site(),
+            format!("missing `#[{} = \"...\"]`", attr),
+        )
+    };
+    let (modulus_span, modulus) = modulus.ok_or_else(|| missing("modulus"))?;
+    let (generator_span, generator) = generator.ok_or_else(|| missing("generator"))?;
+
+    let params = FieldParameters::new(modulus, generator).map_err(|(is_modulus, msg)| {
+        Error::new(
+            if is_modulus {
+                modulus_span
+            } else {
+                generator_span
+            },
+            msg,
+        )
+    })?;
+    let num_limbs = params.num_limbs;
+    let (bigint, marker) = match num_limbs {
+        4 => (quote!(BigInteger256), quote!(Fp256Parameters)),
+        5 => (quote!(BigInteger320), quote!(Fp320Parameters)),
+        6 => (quote!(BigInteger384), quote!(Fp384Parameters)),
+        12 => (quote!(BigInteger768), quote!(Fp768Parameters)),
+        13 => (quote!(BigInteger832), quote!(Fp832Parameters)),
+        _ => {
+            return Err(Error::new(
+                modulus_span,
+                format!(
+                    "a {}-bit modulus needs {} limbs, but no matching prime field is available",
+                    params.modulus_bits, num_limbs
+                ),
+            ))
+        },
+    };
+    let bigint = quote!(algebra_core::biginteger::#bigint);
+    let to_bigint = |n: &BigUint| {
+        let limbs = to_limbs(n, num_limbs);
+        quote!(#bigint([#(#limbs,)*]))
+    };
+
+    let small_subgroup = match small_subgroup_base {
+        Some((span, base)) => {
+            let (base, adicity, root) =
+                params
+                    .small_subgroup(&base)
+                    .map_err(|(is_generator, msg)| {
+                        Error::new(if is_generator { generator_span } else { span }, msg)
+                    })?;
+            let root = to_bigint(&root);
+            quote! {
+                const SMALL_SUBGROUP_BASE: Option<u32> = Some(#base);
+                const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(#adicity);
+                const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Self::BigInt> = Some(#root);
+            }
+        },
+        None => quote!(),
+    };
+
+    let two_adicity = params.two_adicity;
+    let two_adic_root_of_unity = to_bigint(&params.two_adic_root_of_unity);
+    let modulus = to_bigint(&params.modulus);
+    let modulus_bits = params.modulus_bits;
+    let repr_shave_bits = 64 * num_limbs as u32 - modulus_bits;
+    let r = to_bigint(&params.r);
+    let r2 = to_bigint(&params.r2);
+    let inv = params.inv;
+    let generator = to_bigint(&params.generator);
+    let modulus_minus_one_div_two = to_bigint(&params.modulus_minus_one_div_two);
+    let t = to_bigint(&params.t);
+    let t_minus_one_div_two = to_bigint(&params.t_minus_one_div_two);
+
+    Ok(quote! {
+        impl #impl_generics algebra_core::fields::#marker for #name #ty_generics #where_clause {}
+
+        impl #impl_generics algebra_core::fields::FftParameters for #name #ty_generics #where_clause {
+            type BigInt = #bigint;
+
+            const TWO_ADICITY: u32 = #two_adicity;
+
+            #[rustfmt::skip]
+            const TWO_ADIC_ROOT_OF_UNITY: Self::BigInt = #two_adic_root_of_unity;
+
+            #small_subgroup
+        }
+
+        impl #impl_generics algebra_core::fields::FpParameters for #name #ty_generics #where_clause {
+            #[rustfmt::skip]
+            const MODULUS: Self::BigInt = #modulus;
+
+            const MODULUS_BITS: u32 = #modulus_bits;
+
+            const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+            const REPR_SHAVE_BITS: u32 = #repr_shave_bits;
+
+            #[rustfmt::skip]
+            const R: Self::BigInt = #r;
+
+            #[rustfmt::skip]
+            const R2: Self::BigInt = #r2;
+
+            const INV: u64 = #inv;
+
+            #[rustfmt::skip]
+            const GENERATOR: Self::BigInt = #generator;
+
+            #[rustfmt::skip]
+            const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt = #modulus_minus_one_div_two;
+
+            #[rustfmt::skip]
+            const T: Self::BigInt = #t;
+
+            #[rustfmt::skip]
+            const T_MINUS_ONE_DIV_TWO: Self::BigInt = #t_minus_one_div_two;
+        }
+    })
+}
+
+/// Parses `#[name = "<decimal integer>"]`, allowing a leading minus sign and
+/// `_` separators.
+fn parse_integer_attribute(attr: &syn::Attribute) -> Result<(Span, BigInt), Error> {
+    let value = match attr.parse_meta()? {
+        Meta::NameValue(nv) => match nv.lit {
+            Lit::Str(s) => s,
+            lit => return Err(Error::new(lit.span(), "expected a string literal")),
+        },
+        meta => return Err(Error::new(meta.span(), "expected `#[name = \"...\"]`")),
+    };
+    let digits: String = value.value().chars().filter(|c| *c != '_').collect();
+    let (sign, digits) = match digits.trim().strip_prefix('-') {
+        Some(rest) => (Sign::Minus, rest.to_owned()),
+        None => (Sign::Plus, digits.trim().to_owned()),
+    };
+    let magnitude = BigUint::parse_bytes(digits.as_bytes(), 10)
+        .ok_or_else(|| Error::new(value.span(), "expected a decimal integer"))?;
+    Ok((value.span(), BigInt::from_biguint(sign, magnitude)))
+}
+
+/// Returns the little-endian 64-bit limbs of `n`, padded to `num_limbs`.
+fn to_limbs(n: &BigUint, num_limbs: usize) -> Vec<u64> {
+    let mut limbs = n.to_u64_digits();
+    limbs.resize(num_limbs, 0);
+    limbs
+}
+
+/// The constants of a Montgomery-form prime field. Elements that are stored
+/// as field elements (`GENERATOR` and the roots of unity) are already
+/// multiplied by `R`.
+struct FieldParameters {
+    modulus: BigUint,
+    modulus_bits: u32,
+    num_limbs: usize,
+    r: BigUint,
+    r2: BigUint,
+    inv: u64,
+    /// The generator in canonical (non-Montgomery) form.
+    raw_generator: BigUint,
+    generator: BigUint,
+    two_adicity: u32,
+    two_adic_root_of_unity: BigUint,
+    modulus_minus_one_div_two: BigUint,
+    t: BigUint,
+    t_minus_one_div_two: BigUint,
+}
+
+impl FieldParameters {
+    /// Computes all constants, returning an error message tagged with
+    /// whether the modulus (`true`) or the generator (`false`) is at fault.
+    fn new(modulus: BigInt, generator: BigInt) -> Result<Self, (bool, String)> {
+        let modulus = match modulus.to_biguint() {
+            Some(m) if m > BigUint::from(2u32) => m,
+            _ => return Err((true, "the modulus must be an odd prime".to_owned())),
+        };
+        if !is_probable_prime(&modulus) {
+            return Err((true, "the modulus must be an odd prime".to_owned()));
+        }
+        let modulus_bits = modulus.bits() as u32;
+        let num_limbs = (modulus_bits as usize).div_ceil(64);
+
+        let r = (BigUint::one() << (64 * num_limbs)) % &modulus;
+        let r2 = (&r * &r) % &modulus;
+
+        // Newton iteration for MODULUS^{-1} mod 2^64.
+        let m0 = modulus.to_u64_digits()[0];
+        let mut inv = 1u64;
+        for _ in 0..63 {
+            inv = inv.wrapping_mul(inv);
+            inv = inv.wrapping_mul(m0);
+        }
+        let inv = inv.wrapping_neg();
+
+        let signed_modulus = BigInt::from(modulus.clone());
+        let raw_generator = (((generator % &signed_modulus) + &signed_modulus) % &signed_modulus)
+            .to_biguint()
+            .unwrap();
+
+        let modulus_minus_one = &modulus - 1u32;
+        let modulus_minus_one_div_two = &modulus_minus_one >> 1;
+        if raw_generator.modpow(&modulus_minus_one_div_two, &modulus) != modulus_minus_one {
+            return Err((
+                false,
+                "the generator must be a quadratic non-residue".to_owned(),
+            ));
+        }
+
+        let two_adicity = modulus_minus_one.trailing_zeros().unwrap() as u32;
+        let t = &modulus_minus_one >> two_adicity;
+        let t_minus_one_div_two = (&t - 1u32) >> 1;
+        let two_adic_root_of_unity = raw_generator.modpow(&t, &modulus) * &r % &modulus;
+        let generator = &raw_generator * &r % &modulus;
+
+        Ok(Self {
+            modulus,
+            modulus_bits,
+            num_limbs,
+            r,
+            r2,
+            inv,
+            raw_generator,
+            generator,
+            two_adicity,
+            two_adic_root_of_unity,
+            modulus_minus_one_div_two,
+            t,
+            t_minus_one_div_two,
+        })
+    }
+
+    /// Returns `(base, adicity, root)`, where `root` is a primitive
+    /// `2^TWO_ADICITY * base^adicity`-th root of unity in Montgomery form. As
+    /// in `new`, errors are tagged with whether the generator (`true`) or the
+    /// base (`false`) is at fault.
+    fn small_subgroup(&self, base: &BigInt) -> Result<(u32, u32, BigUint), (bool, String)> {
+        let base = base.to_u32().filter(|b| *b > 2).ok_or_else(|| {
+            (
+                false,
+                "the small subgroup base must fit in a u32 and exceed 2".to_owned(),
+            )
+        })?;
+        let mut quotient = self.t.clone();
+        let mut adicity = 0u32;
+        while (&quotient % base).is_zero() {
+            quotient /= base;
+            adicity += 1;
+        }
+        if adicity == 0 {
+            return Err((false, format!("{} does not divide MODULUS - 1", base)));
+        }
+        let modulus_minus_one = &self.modulus - 1u32;
+        for q in prime_factors(base) {
+            if self
+                .raw_generator
+                .modpow(&(&modulus_minus_one / q), &self.modulus)
+                .is_one()
+            {
+                return Err((
+                    true,
+                    format!(
+                        "the order of the generator must be divisible by {}, a factor of the small subgroup base",
+                        q
+                    ),
+                ));
+            }
+        }
+        let root = self.raw_generator.modpow(&quotient, &self.modulus) * &self.r % &self.modulus;
+        Ok((base, adicity, root))
+    }
+}
+
+/// Returns the prime factors of `n`, by trial division.
+fn prime_factors(mut n: u32) -> Vec<u32> {
+    let mut factors = Vec::new();
+    let mut q = 2;
+    while q * q <= n {
+        if n.is_multiple_of(q) {
+            factors.push(q);
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Miller-Rabin with the first twelve primes as witnesses, which is
+/// deterministic below 2^78 and a strong sanity check above that.
+fn is_probable_prime(n: &BigUint) -> bool {
+    const WITNESSES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    for w in WITNESSES.iter() {
+        let w = BigUint::from(*w);
+        if *n == w {
+            return true;
+        }
+        if (n % &w).is_zero() {
+            return false;
+        }
+    }
+    let n_minus_one = n - 1u32;
+    let s = n_minus_one.trailing_zeros().unwrap();
+    let d = &n_minus_one >> s;
+    'witness: for w in WITNESSES.iter() {
+        let mut x = BigUint::from(*w).modpow(&d, n);
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
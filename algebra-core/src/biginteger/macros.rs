@@ -198,6 +198,145 @@ macro_rules! bigint_impl {
 
                 res
             }
+
+            #[inline]
+            fn mul_wide(&self, other: &Self) -> (Self, Self) {
+                let mut r = [0u64; 2 * $num_limbs];
+                arithmetic::mul(&self.0, &other.0, &mut r);
+                let mut lo = Self::default();
+                let mut hi = Self::default();
+                lo.0.copy_from_slice(&r[..$num_limbs]);
+                hi.0.copy_from_slice(&r[$num_limbs..]);
+                (lo, hi)
+            }
+
+            #[inline]
+            fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+                let mut q = Self::default();
+                let mut r = Self::default();
+                arithmetic::div_rem(&self.0, &divisor.0, &mut q.0, &mut r.0);
+                (q, r)
+            }
+
+            #[inline]
+            fn mul_mod(&self, other: &Self, modulus: &Self) -> Self {
+                let mut product = [0u64; 2 * $num_limbs];
+                arithmetic::mul(&self.0, &other.0, &mut product);
+                let mut q = [0u64; 2 * $num_limbs];
+                let mut r = Self::default();
+                arithmetic::div_rem(&product, &modulus.0, &mut q, &mut r.0);
+                r
+            }
+
+            fn pow_mod<S: AsRef<[u64]>>(&self, exp: S, modulus: &Self) -> Self {
+                let base = self.div_rem(modulus).1;
+                let mut res = Self::from(1).div_rem(modulus).1;
+                for bit in BitIteratorBE::without_leading_zeros(exp) {
+                    res = res.mul_mod(&res, modulus);
+                    if bit {
+                        res = res.mul_mod(&base, modulus);
+                    }
+                }
+                res
+            }
+
+            fn gcd(&self, other: &Self) -> Self {
+                let mut a = *self;
+                let mut b = *other;
+                while !b.is_zero() {
+                    let r = a.div_rem(&b).1;
+                    a = b;
+                    b = r;
+                }
+                a
+            }
+
+            fn inverse_mod(&self, modulus: &Self) -> Option<Self> {
+                if modulus.is_zero() {
+                    return None;
+                }
+                // Extended Euclid, keeping only the coefficients of `self`,
+                // reduced modulo `modulus`.
+                let mut r0 = *modulus;
+                let mut r1 = self.div_rem(modulus).1;
+                let mut t0 = Self::default();
+                let mut t1 = Self::from(1);
+                while !r1.is_zero() {
+                    let (q, r2) = r0.div_rem(&r1);
+                    let qt1 = q.mul_mod(&t1, modulus);
+                    let mut t2 = t0;
+                    if t2.sub_noborrow(&qt1) {
+                        t2.add_nocarry(modulus);
+                    }
+                    r0 = r1;
+                    r1 = r2;
+                    t0 = t1;
+                    t1 = t2;
+                }
+                if r0 == Self::from(1) {
+                    Some(t0)
+                } else {
+                    None
+                }
+            }
+
+            fn from_str_radix(s: &str, radix: u32) -> Option<Self> {
+                if s.is_empty() || radix < 2 || radix > 36 {
+                    return None;
+                }
+                let mut res = Self::default();
+                for c in s.chars() {
+                    let mut carry = u64::from(c.to_digit(radix)?);
+                    for limb in &mut res.0 {
+                        *limb = arithmetic::mac_with_carry(0, *limb, u64::from(radix), &mut carry);
+                    }
+                    if carry != 0 {
+                        return None;
+                    }
+                }
+                Some(res)
+            }
+
+            fn to_str_radix(&self, radix: u32) -> String {
+                assert!(radix >= 2 && radix <= 36, "radix must be between 2 and 36");
+                let mut digits = Vec::new();
+                let mut n = *self;
+                loop {
+                    let d = arithmetic::div_rem_limb(&mut n.0, u64::from(radix));
+                    digits.push(core::char::from_digit(d as u32, radix).unwrap());
+                    if n.is_zero() {
+                        break;
+                    }
+                }
+                digits.into_iter().rev().collect()
+            }
+        }
+
+        /// Parses a decimal number, or a hexadecimal one if it starts with
+        /// `0x`.
+        impl FromStr for $name {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let res = if s.starts_with("0x") || s.starts_with("0X") {
+                    Self::from_str_radix(&s[2..], 16)
+                } else {
+                    Self::from_str_radix(s, 10)
+                };
+                res.ok_or(())
+            }
+        }
+
+        impl LowerHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad_integral(true, "0x", &self.to_str_radix(16))
+            }
+        }
+
+        impl UpperHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad_integral(true, "0x", &self.to_str_radix(16).to_uppercase())
+            }
         }
 
         impl ToBytes for $name {
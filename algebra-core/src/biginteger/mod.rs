@@ -2,10 +2,13 @@ use crate::{
     bytes::{FromBytes, ToBytes},
     fields::BitIteratorBE,
     io::{Read, Result as IoResult, Write},
-    CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize, SerializationError,
+    CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize, SerializationError, String,
     UniformRand, Vec,
 };
-use core::fmt::{Debug, Display};
+use core::{
+    fmt::{Debug, Display, LowerHex, UpperHex},
+    str::FromStr,
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
@@ -64,6 +67,9 @@ pub trait BigInteger:
     + Debug
     + Default
     + Display
+    + LowerHex
+    + UpperHex
+    + FromStr<Err = ()>
     + Eq
     + Ord
     + Send
@@ -123,8 +129,41 @@ pub trait BigInteger:
     fn to_bits(&self) -> Vec<bool>;
 
     /// Returns a vector for wnaf.
+    ///
+    /// This runs in time that depends on the value of `self`, so it must not
+    /// be used on secret values such as private keys.
     fn find_wnaf(&self) -> Vec<i64>;
 
+    /// Multiplies this number by `other`, returning the low and high halves
+    /// of the double-width product.
+    fn mul_wide(&self, other: &Self) -> (Self, Self);
+
+    /// Divides this number by `divisor`, returning the quotient and the
+    /// remainder. Panics if `divisor` is zero.
+    fn div_rem(&self, divisor: &Self) -> (Self, Self);
+
+    /// Computes `self * other mod modulus`. Panics if `modulus` is zero.
+    fn mul_mod(&self, other: &Self, modulus: &Self) -> Self;
+
+    /// Computes `self^exp mod modulus`. Panics if `modulus` is zero.
+    fn pow_mod<S: AsRef<[u64]>>(&self, exp: S, modulus: &Self) -> Self;
+
+    /// Returns the greatest common divisor of this number and `other`.
+    fn gcd(&self, other: &Self) -> Self;
+
+    /// Returns the inverse of this number modulo `modulus`, or `None` if they
+    /// are not coprime or `modulus` is zero.
+    fn inverse_mod(&self, modulus: &Self) -> Option<Self>;
+
+    /// Parses a number written in the given radix, which must be between 2
+    /// and 36. Returns `None` if the string is empty, has invalid digits, or
+    /// the number does not fit.
+    fn from_str_radix(s: &str, radix: u32) -> Option<Self>;
+
+    /// Writes this number in the given radix, which must be between 2 and 36,
+    /// using lowercase letters and no leading zeros.
+    fn to_str_radix(&self, radix: u32) -> String;
+
     /// Writes this `BigInteger` as a big endian integer. Always writes
     /// `(num_bits` / 8) bytes.
     fn write_le<W: Write>(&self, writer: &mut W) -> IoResult<()> {
@@ -141,6 +180,9 @@ pub trait BigInteger:
 
 pub mod arithmetic {
     use crate::Vec;
+
+    /// Returns a vector for wnaf. As with `BigInteger::find_wnaf`, this must
+    /// not be used on secret values.
     pub fn find_wnaf(num: &[u64]) -> Vec<i64> {
         let is_zero = |num: &[u64]| num.iter().all(|x| *x == 0u64);
         let is_odd = |num: &[u64]| num[0] & 1 == 1;
@@ -194,6 +236,129 @@ pub mod arithmetic {
         res
     }
 
+    /// Computes `a * b` into `out`, which must have room for
+    /// `a.len() + b.len()` limbs.
+    pub fn mul(a: &[u64], b: &[u64], out: &mut [u64]) {
+        for limb in out.iter_mut() {
+            *limb = 0;
+        }
+        for (i, a) in a.iter().enumerate() {
+            let mut carry = 0;
+            for (j, b) in b.iter().enumerate() {
+                out[i + j] = mac_with_carry(out[i + j], *a, *b, &mut carry);
+            }
+            out[i + b.len()] = carry;
+        }
+    }
+
+    /// Divides `num` in place by the nonzero limb `den`, returning the
+    /// remainder.
+    pub fn div_rem_limb(num: &mut [u64], den: u64) -> u64 {
+        let mut rem = 0u128;
+        for limb in num.iter_mut().rev() {
+            let cur = (rem << 64) | u128::from(*limb);
+            *limb = (cur / u128::from(den)) as u64;
+            rem = cur % u128::from(den);
+        }
+        rem as u64
+    }
+
+    /// Divides `num` by `den` with Knuth's algorithm D, writing the quotient
+    /// to `quot` and the remainder to `rem`. `quot` must have as many limbs
+    /// as `num`, and `rem` as many as `den`.
+    ///
+    /// Panics if `den` is zero.
+    pub fn div_rem(num: &[u64], den: &[u64], quot: &mut [u64], rem: &mut [u64]) {
+        let significant = |x: &[u64]| x.len() - x.iter().rev().take_while(|l| **l == 0).count();
+        let n = significant(den);
+        let len = significant(num);
+        assert!(n > 0, "division by zero");
+
+        for limb in quot.iter_mut().chain(rem.iter_mut()) {
+            *limb = 0;
+        }
+        if len < n || (len == n && num[..n].iter().rev().le(den[..n].iter().rev())) {
+            if num[..n] == den[..n] {
+                quot[0] = 1;
+            } else {
+                rem[..len].copy_from_slice(&num[..len]);
+            }
+            return;
+        }
+        if n == 1 {
+            quot[..len].copy_from_slice(&num[..len]);
+            rem[0] = div_rem_limb(&mut quot[..len], den[0]);
+            return;
+        }
+
+        // Normalize so that the top limb of the divisor has its top bit set.
+        let shift = den[n - 1].leading_zeros();
+        let shl = |x: &[u64], out: &mut [u64]| {
+            let mut carry = 0;
+            for (o, x) in out.iter_mut().zip(x) {
+                *o = (x << shift) | carry;
+                carry = if shift == 0 { 0 } else { x >> (64 - shift) };
+            }
+            if out.len() > x.len() {
+                out[x.len()] = carry;
+            }
+        };
+        let mut v = vec![0u64; n];
+        shl(&den[..n], &mut v);
+        let mut u = vec![0u64; len + 1];
+        shl(&num[..len], &mut u);
+
+        let b = 1u128 << 64;
+        for j in (0..=len - n).rev() {
+            // Estimate the quotient limb from the top two limbs of the
+            // dividend, which overshoots by at most two.
+            let top = (u128::from(u[j + n]) << 64) | u128::from(u[j + n - 1]);
+            let mut qhat = top / u128::from(v[n - 1]);
+            let mut rhat = top % u128::from(v[n - 1]);
+            while qhat >= b
+                || qhat * u128::from(v[n - 2]) > ((rhat << 64) | u128::from(u[j + n - 2]))
+            {
+                qhat -= 1;
+                rhat += u128::from(v[n - 1]);
+                if rhat >= b {
+                    break;
+                }
+            }
+
+            // u[j..=j + n] -= qhat * v
+            let mut borrow = 0i128;
+            let mut carry = 0u128;
+            for i in 0..n {
+                let p = qhat * u128::from(v[i]) + carry;
+                carry = p >> 64;
+                let t = i128::from(u[i + j]) - borrow - i128::from(p as u64);
+                u[i + j] = t as u64;
+                borrow = if t < 0 { 1 } else { 0 };
+            }
+            let t = i128::from(u[j + n]) - borrow - carry as i128;
+            u[j + n] = t as u64;
+
+            // The estimate was one too large, so add back the divisor.
+            if t < 0 {
+                qhat -= 1;
+                let mut carry = 0;
+                for i in 0..n {
+                    u[i + j] = adc(u[i + j], v[i], &mut carry);
+                }
+                u[j + n] = u[j + n].wrapping_add(carry);
+            }
+            quot[j] = qhat as u64;
+        }
+
+        for i in 0..n {
+            rem[i] = if shift == 0 {
+                u[i]
+            } else {
+                (u[i] >> shift) | (u[i + 1] << (64 - shift))
+            };
+        }
+    }
+
     /// Calculate a + b + carry, returning the sum and modifying the
     /// carry value.
     #[inline(always)]
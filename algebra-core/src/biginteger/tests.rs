@@ -58,11 +58,127 @@ fn biginteger_bytes_test<B: BigInteger>() {
     assert_eq!(x, y);
 }
 
+fn biginteger_division_test<B: BigInteger>(a: B, b: B) {
+    // a = q * b + r, with r < b
+    let check = |a: B, b: B| {
+        let (q, r) = a.div_rem(&b);
+        assert!(r < b);
+        let (mut lo, hi) = q.mul_wide(&b);
+        assert!(hi.is_zero());
+        assert!(!lo.add_nocarry(&r));
+        assert_eq!(lo, a);
+    };
+    check(a, b);
+    check(b, a);
+    check(a, B::from(7));
+    let mut short_b = b;
+    short_b.divn(64 * (B::NUM_LIMBS as u32 / 2) + 5);
+    check(a, short_b);
+    check(a, a);
+    check(B::default(), b);
+
+    // (a * b) / b = a
+    let (lo, hi) = a.mul_wide(&b);
+    assert_eq!(
+        B::from(7).mul_wide(&B::from(6)),
+        (B::from(42), B::default())
+    );
+    if hi.is_zero() {
+        assert_eq!(lo.div_rem(&b), (a, B::default()));
+    }
+
+    // A case where the estimated quotient limb has to be corrected after the
+    // subtraction.
+    if B::NUM_LIMBS >= 4 {
+        let mut u = B::default();
+        u.as_mut()[2] = 1 << 63;
+        u.as_mut()[3] = (1 << 63) - 1;
+        let mut v = B::default();
+        v.as_mut()[0] = 1;
+        v.as_mut()[2] = 1 << 63;
+        let mut r = B::default();
+        r.as_mut()[..3].copy_from_slice(&[2, u64::MAX, (1 << 63) - 1]);
+        assert_eq!(u.div_rem(&v), (B::from(u64::MAX - 1), r));
+    }
+}
+
+fn biginteger_modular_test<B: BigInteger>(a: B, b: B) {
+    // The Mersenne prime 2^127 - 1, or 2^61 - 1 if it does not fit.
+    let mut p = B::from(u64::MAX);
+    if B::NUM_LIMBS >= 2 {
+        p.as_mut()[1] = u64::MAX >> 1;
+    } else {
+        p.as_mut()[0] >>= 3;
+    }
+    let mut p_minus_one = p;
+    p_minus_one.sub_noborrow(&B::from(1));
+
+    let a_mod_p = a.div_rem(&p).1;
+    let b_mod_p = b.div_rem(&p).1;
+    assert_eq!(a.mul_mod(&b, &p), a_mod_p.mul_mod(&b_mod_p, &p));
+    assert_eq!(a.mul_mod(&b, &p), b.mul_mod(&a, &p));
+    assert_eq!(a.mul_mod(&B::from(1), &p), a_mod_p);
+
+    // Fermat's little theorem
+    assert_eq!(a.pow_mod(&p_minus_one, &p), B::from(1));
+    assert_eq!(a.pow_mod(&[0u64], &p), B::from(1));
+    assert_eq!(a.pow_mod(&[3u64], &p), a.mul_mod(&a, &p).mul_mod(&a, &p));
+    assert_eq!(a.pow_mod(&[5u64], &B::from(1)), B::default());
+    assert_eq!(B::from(3).pow_mod(&[4u64], &B::from(100)), B::from(81));
+
+    let a_inv = a.inverse_mod(&p).unwrap();
+    assert_eq!(a.mul_mod(&a_inv, &p), B::from(1));
+    assert_eq!(p.inverse_mod(&p), None);
+    assert_eq!(B::from(2).inverse_mod(&B::from(4)), None);
+    assert_eq!(B::from(3).inverse_mod(&B::from(10)), Some(B::from(7)));
+    assert_eq!(a.inverse_mod(&B::default()), None);
+
+    assert_eq!(B::from(12).gcd(&B::from(18)), B::from(6));
+    assert_eq!(a.gcd(&B::default()), a);
+    assert_eq!(p.gcd(&a_mod_p), B::from(1));
+    let g = a.gcd(&b);
+    assert!(a.div_rem(&g).1.is_zero());
+    assert!(b.div_rem(&g).1.is_zero());
+}
+
+fn biginteger_string_test<B: BigInteger>(a: B) {
+    for radix in &[2, 10, 16, 36] {
+        assert_eq!(B::from_str_radix(&a.to_str_radix(*radix), *radix), Some(a));
+    }
+    assert_eq!(B::default().to_str_radix(10), "0");
+    assert_eq!(B::from(255).to_str_radix(16), "ff");
+    assert_eq!(format!("{:x}", a), a.to_str_radix(16));
+    assert_eq!(format!("{:#X}", B::from(255)), "0xFF");
+
+    assert_eq!(B::from_str("18446744073709551615"), Ok(B::from(u64::MAX)));
+    assert_eq!(B::from_str("0xffffffffffffffff"), Ok(B::from(u64::MAX)));
+    assert_eq!(B::from_str("0x"), Err(()));
+    assert_eq!(B::from_str("12a"), Err(()));
+    assert_eq!(B::from_str("-1"), Err(()));
+    assert_eq!(B::from_str_radix("1", 37), None);
+
+    // One more than the largest value overflows.
+    let mut too_large = String::from("1");
+    too_large.push_str(&"0".repeat(16 * B::NUM_LIMBS));
+    assert_eq!(B::from_str_radix(&too_large, 16), None);
+    assert_eq!(B::from_str_radix(&too_large[1..], 16), Some(B::default()));
+    if B::NUM_LIMBS >= 2 {
+        let mut two_to_64 = B::default();
+        two_to_64.as_mut()[1] = 1;
+        assert_eq!(B::from_str("18446744073709551616"), Ok(two_to_64));
+    } else {
+        assert_eq!(B::from_str("18446744073709551616"), Err(()));
+    }
+}
+
 fn test_biginteger<B: BigInteger>(zero: B) {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
     let a: B = UniformRand::rand(&mut rng);
     let b: B = UniformRand::rand(&mut rng);
     biginteger_arithmetic_test(a, b, zero);
+    biginteger_division_test(a, b);
+    biginteger_modular_test(a, b);
+    biginteger_string_test(a);
     biginteger_bytes_test::<B>();
     biginteger_bits_test::<B>();
 }
@@ -85,6 +201,12 @@ fn test_biginteger256() {
     test_biginteger(B::new([0u64; 4]));
 }
 
+#[test]
+fn test_biginteger320() {
+    use crate::biginteger::BigInteger320 as B;
+    test_biginteger(B::new([0u64; 5]));
+}
+
 #[test]
 fn test_biginteger384() {
     use crate::biginteger::BigInteger384 as B;
@@ -0,0 +1,246 @@
+use crate::{
+    biginteger::{arithmetic::mac_with_carry, BigInteger},
+    curves::{
+        models::{short_weierstrass_jacobian::GroupProjective, ModelParameters},
+        ProjectiveCurve,
+    },
+    fields::PrimeField,
+    Vec,
+};
+use num_traits::Zero;
+
+/// Parameters for scalar multiplication with the method of Gallant, Lambert and
+/// Vanstone, on curves with an efficiently computable endomorphism `phi` that
+/// acts on the prime order subgroup as multiplication by `lambda`.
+///
+/// A scalar `k` is split as `k = k1 + k2 * lambda mod r` with `k1` and `k2` of
+/// about half the length of `r`, by rounding `(k, 0)` to a nearby vector of the
+/// lattice `L = {(a, b) : a + b * lambda = 0 mod r}`. Then `k * p` is computed as
+/// `k1 * p + k2 * phi(p)`, which needs half as many doublings.
+///
+/// Since `phi` only acts as `lambda` on the prime order subgroup, the method
+/// must not be used for points outside of it.
+///
+/// See `GLSParameters` for the four-dimensional split with `psi` on the twists
+/// of pairing-friendly curves.
+///
+/// `G` is the projective point type that `phi` is evaluated on, which is that
+/// of the short Weierstrass model unless the curve is given in another model.
+pub struct GLVParameters<P: ModelParameters, G = GroupProjective<P>> {
+    /// The eigenvalue of `phi` on the prime order subgroup.
+    pub lambda: P::ScalarField,
+    /// A reduced basis `[(a1, b1), (a2, b2)]` of `L`, with entries reduced
+    /// modulo `r`.
+    pub basis: [(P::ScalarField, P::ScalarField); 2],
+    /// The constants `round(2^n * b2 / d)` and `round(-2^n * b1 / d)`, where
+    /// `n` is the bit size of `P::ScalarField::BigInt` and `d = a1 * b2 - a2 * b1`
+    /// is `r` or `-r`. The signs of the basis vectors must be chosen so that
+    /// both are non-negative.
+    pub rounding: [<P::ScalarField as PrimeField>::BigInt; 2],
+    /// Evaluates `phi`.
+    pub endomorphism: fn(&G) -> G,
+}
+
+impl<P: ModelParameters, G> GLVParameters<P, G> {
+    /// Splits `k` as `k1 + k2 * lambda`. Both halves are returned as a flag
+    /// that is set if the half is negative, and its absolute value.
+    pub fn decompose(
+        &self,
+        k: &P::ScalarField,
+    ) -> [(bool, <P::ScalarField as PrimeField>::BigInt); 2] {
+        let k_repr = k.into_repr();
+        // The coordinates of `(k, 0)` in the basis, rounded to integers. They
+        // are at most the rounding constants, and hence smaller than `r`.
+        let c1 = P::ScalarField::from_repr(mul_high(&k_repr, &self.rounding[0])).unwrap();
+        let c2 = P::ScalarField::from_repr(mul_high(&k_repr, &self.rounding[1])).unwrap();
+
+        let [(a1, b1), (a2, b2)] = self.basis;
+        let k1 = *k - &(c1 * &a1) - &(c2 * &a2);
+        let k2 = -(c1 * &b1) - &(c2 * &b2);
+        [signed(k1), signed(k2)]
+    }
+}
+
+impl<P: ModelParameters, G: ProjectiveCurve<ScalarField = P::ScalarField>> GLVParameters<P, G> {
+    /// Computes `k * p` as `k1 * p + k2 * phi(p)`. `p` must be in the prime
+    /// order subgroup.
+    pub fn mul(&self, p: &G, k: &P::ScalarField) -> G {
+        let [(neg1, k1), (neg2, k2)] = self.decompose(k);
+        let p1 = if neg1 { -*p } else { *p };
+        let p2 = (self.endomorphism)(p);
+        let p2 = if neg2 { -p2 } else { p2 };
+        mul_bits_joint(&p1, k1, &p2, k2)
+    }
+}
+
+/// Parameters for scalar multiplication with the four-dimensional method of
+/// Galbraith, Lin and Scott, on the twists of pairing-friendly curves with
+/// embedding degree 12, where the untwist-Frobenius-twist endomorphism `psi`
+/// acts on the prime order subgroup as multiplication by `lambda`, a root of
+/// `X^4 - X^2 + 1` modulo `r`.
+///
+/// A scalar `k` is split as `k = k0 + k1 * lambda + k2 * lambda^2 + k3 * lambda^3
+/// mod r` with all four parts of about a quarter of the length of `r`, by
+/// rounding `(k, 0, 0, 0)` to a nearby vector of the lattice
+/// `L = {a : a0 + a1 * lambda + a2 * lambda^2 + a3 * lambda^3 = 0 mod r}`.
+/// Then `k * p` is computed as `k0 * p + k1 * psi(p) + k2 * psi^2(p) +
+/// k3 * psi^3(p)`, which needs a quarter as many doublings.
+///
+/// As with `GLVParameters`, the method must not be used for points outside of
+/// the prime order subgroup.
+pub struct GLSParameters<P: ModelParameters, G = GroupProjective<P>> {
+    /// The eigenvalue of `psi` on the prime order subgroup.
+    pub lambda: P::ScalarField,
+    /// A reduced basis of `L`, with entries reduced modulo `r`.
+    pub basis: [[P::ScalarField; 4]; 4],
+    /// The constants `round(2^n * c_j)`, where `n` is the bit size of
+    /// `P::ScalarField::BigInt` and `(c_1, c_2, c_3, c_4)` are the coordinates
+    /// of `(1, 0, 0, 0)` in the basis. The signs of the basis vectors must be
+    /// chosen so that all coordinates are non-negative.
+    pub rounding: [<P::ScalarField as PrimeField>::BigInt; 4],
+    /// Evaluates `psi`.
+    pub endomorphism: fn(&G) -> G,
+}
+
+impl<P: ModelParameters, G> GLSParameters<P, G> {
+    /// Splits `k` as `k0 + k1 * lambda + k2 * lambda^2 + k3 * lambda^3`. All
+    /// parts are returned as a flag that is set if the part is negative, and
+    /// its absolute value.
+    pub fn decompose(
+        &self,
+        k: &P::ScalarField,
+    ) -> [(bool, <P::ScalarField as PrimeField>::BigInt); 4] {
+        let k_repr = k.into_repr();
+        let mut parts = [P::ScalarField::zero(); 4];
+        parts[0] = *k;
+        for (rounding, b) in self.rounding.iter().zip(&self.basis) {
+            // The coordinate of `(k, 0, 0, 0)` along `b`, rounded to an
+            // integer. It is at most the rounding constant, and hence smaller
+            // than `r`.
+            let c = P::ScalarField::from_repr(mul_high(&k_repr, rounding)).unwrap();
+            for (part, b_i) in parts.iter_mut().zip(b) {
+                *part -= &(c * b_i);
+            }
+        }
+        [
+            signed(parts[0]),
+            signed(parts[1]),
+            signed(parts[2]),
+            signed(parts[3]),
+        ]
+    }
+}
+
+impl<P: ModelParameters, G: ProjectiveCurve<ScalarField = P::ScalarField>> GLSParameters<P, G> {
+    /// Computes `k * p` as `k0 * p + k1 * psi(p) + k2 * psi^2(p) +
+    /// k3 * psi^3(p)`. `p` must be in the prime order subgroup.
+    pub fn mul(&self, p: &G, k: &P::ScalarField) -> G {
+        let parts = self.decompose(k);
+        let mut points = [*p; 4];
+        for i in 1..4 {
+            points[i] = (self.endomorphism)(&points[i - 1]);
+        }
+        for (point, (neg, _)) in points.iter_mut().zip(&parts) {
+            if *neg {
+                *point = -*point;
+            }
+        }
+        let [(_, k0), (_, k1), (_, k2), (_, k3)] = parts;
+        mul_bits_multi(&points, &[k0, k1, k2, k3])
+    }
+}
+
+/// Computes `sum_i k_i * p_i` for up to eight points, sharing the doublings
+/// between all multiplications. The sums of all subsets of the points are
+/// precomputed.
+pub fn mul_bits_multi<G: ProjectiveCurve, K: AsRef<[u64]>>(points: &[G], scalars: &[K]) -> G {
+    assert_eq!(points.len(), scalars.len());
+    assert!(points.len() <= 8);
+    let mut table = vec![G::zero(); 1 << points.len()];
+    for (i, point) in points.iter().enumerate() {
+        for j in 0..(1 << i) {
+            table[(1 << i) + j] = table[j] + point;
+        }
+    }
+    G::batch_normalization(&mut table);
+    let table: Vec<G::Affine> = table.into_iter().map(Into::into).collect();
+
+    let bit = |k: &[u64], i: usize| k.get(i / 64).map_or(false, |l| (l >> (i % 64)) & 1 == 1);
+    let n = 64 * scalars.iter().map(|k| k.as_ref().len()).max().unwrap_or(0);
+    let indices = (0..n).rev().map(|i| {
+        scalars
+            .iter()
+            .enumerate()
+            .filter(|(_, k)| bit(k.as_ref(), i))
+            .fold(0, |index, (j, _)| index | (1 << j))
+    });
+
+    let mut res = G::zero();
+    // Skip leading zeros.
+    for index in indices.skip_while(|&index| index == 0) {
+        res.double_in_place();
+        if index != 0 {
+            res.add_assign_mixed(&table[index]);
+        }
+    }
+    res
+}
+
+/// Computes `k1 * p1 + k2 * p2`, sharing the doublings between both
+/// multiplications.
+pub fn mul_bits_joint<G: ProjectiveCurve>(
+    p1: &G,
+    k1: impl AsRef<[u64]>,
+    p2: &G,
+    k2: impl AsRef<[u64]>,
+) -> G {
+    let (k1, k2) = (k1.as_ref(), k2.as_ref());
+    let mut table = [*p1, *p2, *p1 + p2];
+    G::batch_normalization(&mut table);
+    let [p1, p2, p12]: [G::Affine; 3] = [table[0].into(), table[1].into(), table[2].into()];
+
+    let bit = |k: &[u64], i: usize| k.get(i / 64).map_or(false, |l| (l >> (i % 64)) & 1 == 1);
+    let n = 64 * core::cmp::max(k1.len(), k2.len());
+    let bits = (0..n).rev().map(|i| (bit(k1, i), bit(k2, i)));
+
+    let mut res = G::zero();
+    // Skip leading zeros.
+    for (b1, b2) in bits.skip_while(|&(b1, b2)| !b1 && !b2) {
+        res.double_in_place();
+        match (b1, b2) {
+            (true, true) => res.add_assign_mixed(&p12),
+            (true, false) => res.add_assign_mixed(&p1),
+            (false, true) => res.add_assign_mixed(&p2),
+            (false, false) => {},
+        }
+    }
+    res
+}
+
+/// Returns the upper half of the product of `a` and `b`.
+fn mul_high<B: BigInteger>(a: &B, b: &B) -> B {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    let n = a.len();
+    let mut product = vec![0u64; 2 * n];
+    for i in 0..n {
+        let mut carry = 0;
+        for j in 0..n {
+            product[i + j] = mac_with_carry(product[i + j], a[i], b[j], &mut carry);
+        }
+        product[i + n] = carry;
+    }
+
+    let mut res = B::default();
+    res.as_mut().copy_from_slice(&product[n..]);
+    res
+}
+
+/// Interprets elements of `F` greater than `(r - 1) / 2` as negative.
+fn signed<F: PrimeField>(f: F) -> (bool, F::BigInt) {
+    let repr = f.into_repr();
+    if repr > F::modulus_minus_one_div_two() {
+        (true, (-f).into_repr())
+    } else {
+        (false, repr)
+    }
+}
@@ -1,8 +1,10 @@
 use crate::{
+    biginteger::BigInteger,
     bytes::{FromBytes, ToBytes},
     fields::{Field, PrimeField, SquareRootField},
     groups::Group,
-    CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize, UniformRand, Vec,
+    CanonicalDeserialize, CanonicalSerialize, ConditionallySelectable, ConstantSerializedSize,
+    ConstantTimeEq, UniformRand, Vec,
 };
 use core::{
     fmt::{Debug, Display},
@@ -11,9 +13,14 @@ use core::{
 };
 use num_traits::Zero;
 
+pub mod glv;
 pub mod models;
+mod pairing_output;
+pub mod rfc8032;
+pub mod sec1;
 
 pub use self::models::*;
+pub use self::pairing_output::*;
 
 pub trait PairingEngine: Sized + 'static + Copy + Debug + Sync + Send + Eq + PartialEq {
     /// This is the scalar field of the G1/G2 groups.
@@ -125,7 +132,7 @@ pub trait ProjectiveCurve:
     const COFACTOR: &'static [u64];
     type ScalarField: PrimeField + SquareRootField;
     type BaseField: Field;
-    type Affine: AffineCurve<Projective = Self, ScalarField = Self::ScalarField, BaseField = Self::BaseField>
+    type Affine: AffineCurve<ScalarField = Self::ScalarField, BaseField = Self::BaseField>
         + From<Self>
         + Into<Self>;
 
@@ -190,6 +197,71 @@ pub trait ProjectiveCurve:
         self = res;
         self
     }
+
+    /// Performs scalar multiplication of this element, which must be in the
+    /// prime order subgroup. Curves with an efficiently computable
+    /// endomorphism use the GLV method here, as the endomorphism only acts as
+    /// multiplication by a scalar on that subgroup.
+    #[must_use]
+    fn mul_glv(&self, other: &Self::ScalarField) -> Self {
+        (*self).mul(other.into_repr())
+    }
+}
+
+/// Constant-time counterparts of the `ProjectiveCurve` operations, for use
+/// when a point or a scalar is secret.
+pub trait ConstantTimeCurve: ProjectiveCurve + ConstantTimeEq + ConditionallySelectable {
+    /// Converts self into the affine representation, in time independent of
+    /// the value of `self`. Use this instead of `into_affine` when `self` is
+    /// secret.
+    #[must_use]
+    fn into_affine_ct(&self) -> Self::Affine;
+
+    /// Returns `self + other`, in time independent of the values of `self` and
+    /// `other`, including when either is zero or when they are equal.
+    #[must_use]
+    fn add_ct(&self, other: &Self) -> Self;
+
+    /// Returns `self + self`, in time independent of the value of `self`. By
+    /// default this is `self.add_ct(self)`.
+    #[must_use]
+    fn double_ct(&self) -> Self {
+        self.add_ct(self)
+    }
+
+    /// Performs scalar multiplication of this element in time independent of
+    /// the values of `self` and `scalar`, with a fixed window of 4 bits. Use
+    /// this instead of `mul` when the scalar or the point is secret.
+    #[must_use]
+    fn mul_ct(&self, scalar: &Self::ScalarField) -> Self {
+        const WINDOW: usize = 4;
+
+        // table[i] = i * self
+        let mut table = [Self::zero(); 1 << WINDOW];
+        for i in 1..table.len() {
+            table[i] = table[i - 1].add_ct(self);
+        }
+
+        let scalar = scalar.into_repr();
+        let bits = <Self::ScalarField as PrimeField>::size_in_bits();
+        let mut res = Self::zero();
+        for w in (0..bits.div_ceil(WINDOW)).rev() {
+            for _ in 0..WINDOW {
+                res = res.double_ct();
+            }
+            let digit = (0..WINDOW).fold(0u64, |digit, i| {
+                digit | (u64::from(scalar.get_bit(w * WINDOW + i)) << i)
+            });
+            // Read the whole table so that the access pattern does not depend
+            // on the digit.
+            let mut term = Self::zero();
+            for (j, entry) in table.iter().enumerate() {
+                term.conditional_assign(entry, (j as u64).ct_eq(&digit));
+            }
+            res = res.add_ct(&term);
+        }
+        res
+    }
 }
 
 /// Affine representation of an elliptic curve point guaranteed to be
@@ -216,6 +288,9 @@ pub trait AffineCurve:
     + From<<Self as AffineCurve>::Projective>
 {
     const COFACTOR: &'static [u64];
+    /// Whether `batch_add_in_place` is cheaper than adding points in
+    /// projective coordinates.
+    const FAST_BATCH_ADDITION: bool = false;
     type ScalarField: PrimeField + SquareRootField + Into<<Self::ScalarField as PrimeField>::BigInt>;
     type BaseField: Field;
     type Projective: ProjectiveCurve<Affine = Self, ScalarField = Self::ScalarField, BaseField = Self::BaseField>
@@ -242,6 +317,13 @@ pub trait AffineCurve:
     fn mul<S: Into<<Self::ScalarField as PrimeField>::BigInt>>(&self, other: S)
         -> Self::Projective;
 
+    /// Performs scalar multiplication of this element, which must be in the
+    /// prime order subgroup, as `ProjectiveCurve::mul_glv` does.
+    #[must_use]
+    fn mul_glv(&self, other: &Self::ScalarField) -> Self::Projective {
+        self.into_projective().mul_glv(other)
+    }
+
     /// Multiply this element by the cofactor and output the
     /// resulting projective element.
     #[must_use]
@@ -257,6 +339,55 @@ pub trait AffineCurve:
     /// `Self::ScalarField`.
     #[must_use]
     fn mul_by_cofactor_inv(&self) -> Self;
+
+    /// Maps this element into the prime order subgroup. Defaults to
+    /// multiplying by the cofactor, but curves may override this with a
+    /// cheaper map.
+    #[must_use]
+    fn clear_cofactor(&self) -> Self {
+        self.mul_by_cofactor()
+    }
+
+    /// Sets `points[i] = points[i] + points[j]` for every `(i, j)` in `pairs`.
+    /// No index may occur more than once in `pairs`. By default the sums are
+    /// computed in projective coordinates and normalized together; curves
+    /// that set `FAST_BATCH_ADDITION` compute them in affine coordinates with
+    /// a single field inversion.
+    fn batch_add_in_place(points: &mut [Self], pairs: &[(usize, usize)]) {
+        let sums = pairs
+            .iter()
+            .map(|&(i, j)| points[i].into_projective().add_mixed(&points[j]))
+            .collect::<Vec<_>>();
+        let sums = Self::Projective::batch_normalization_into_affine(&sums);
+        for (&(i, _), sum) in pairs.iter().zip(sums) {
+            points[i] = sum;
+        }
+    }
+
+    /// Returns the window size `VariableBaseMSM` uses for a multi-scalar
+    /// multiplication of `num_scalars` points. The curve models pick it with
+    /// `VariableBaseMSM::window_size_from_costs`, from the size of the scalar
+    /// field and the cost of their bucket additions.
+    #[inline]
+    fn msm_window_size(num_scalars: usize) -> usize {
+        crate::msm::VariableBaseMSM::default_window_size(num_scalars)
+    }
+
+    /// Returns the bucket sums of a window of `VariableBaseMSM` with window
+    /// size `c`. The bases of `terms` go into bucket `|d| - 1` for their digit
+    /// `d`, negated if `d` is negative. By default the buckets are summed in
+    /// affine coordinates if `FAST_BATCH_ADDITION` is set, and in projective
+    /// coordinates otherwise.
+    fn msm_bucket_sums<'a>(terms: impl Iterator<Item = (&'a i64, &'a Self)>, c: usize) -> Vec<Self>
+    where
+        Self: 'a,
+    {
+        if Self::FAST_BATCH_ADDITION {
+            crate::msm::accumulate_buckets_affine(terms, c)
+        } else {
+            crate::msm::accumulate_buckets::<Self::Projective>(terms, c)
+        }
+    }
 }
 
 impl<C: ProjectiveCurve> Group for C {
@@ -288,6 +419,15 @@ pub fn prepare_g2<E: PairingEngine>(g: impl Into<E::G2Affine>) -> E::G2Prepared
     E::G2Prepared::from(g)
 }
 
+/// A cycle of two elliptic curves, where the scalar field of each curve is the
+/// base field of the other. This lets a proof system over the scalar field of
+/// one curve verify arithmetic on the other curve natively, as needed for
+/// recursive proof composition.
+pub trait CurveCycle: Sized + 'static + Copy + Debug + Sync + Send {
+    type E1: ProjectiveCurve<BaseField = <Self::E2 as ProjectiveCurve>::ScalarField>;
+    type E2: ProjectiveCurve<BaseField = <Self::E1 as ProjectiveCurve>::ScalarField>;
+}
+
 /// A cycle of pairing-friendly elliptic curves.
 pub trait CycleEngine: Sized + 'static + Copy + Debug + Sync + Send
 where
@@ -300,3 +440,13 @@ where
         Fq = <Self::E1 as PairingEngine>::Fr,
     >;
 }
+
+/// The `G1` groups of a cycle of pairing-friendly curves form a curve cycle.
+impl<C: CycleEngine> CurveCycle for C
+where
+    <C::E2 as PairingEngine>::G1Projective: MulAssign<<C::E1 as PairingEngine>::Fq>,
+    <C::E2 as PairingEngine>::G2Projective: MulAssign<<C::E1 as PairingEngine>::Fq>,
+{
+    type E1 = <<C as CycleEngine>::E1 as PairingEngine>::G1Projective;
+    type E2 = <<C as CycleEngine>::E2 as PairingEngine>::G1Projective;
+}
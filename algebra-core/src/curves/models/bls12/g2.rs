@@ -4,9 +4,9 @@ use crate::{
         bls12::{Bls12Parameters, TwistType},
         models::SWModelParameters,
         short_weierstrass_jacobian::{GroupAffine, GroupProjective},
-        AffineCurve,
+        AffineCurve, ProjectiveCurve,
     },
-    fields::{BitIteratorBE, Field, Fp2},
+    fields::{BitIteratorBE, Field, Fp12Parameters, Fp2, Fp6Parameters},
     io::{Result as IoResult, Write},
     Vec,
 };
@@ -95,6 +95,66 @@ impl<P: Bls12Parameters> G2Prepared<P> {
     }
 }
 
+/// Maps a point of the twist into G2 with the method of Budroni and Pintore,
+/// as in Appendix G.3 of RFC 9380. This computes
+/// `[x^2 - x - 1] p + [x - 1] psi(p) + psi^2(2 p)`, which equals `h_eff * p`
+/// for `h_eff = 3 * (x^2 - 1) * h2`, where `h2` is the cofactor of G2.
+pub fn clear_cofactor<P: Bls12Parameters>(p: &G2Affine<P>) -> G2Affine<P> {
+    let psi = psi::<P>();
+    let p = G2Projective::<P>::from(*p);
+
+    let t1 = mul_by_x::<P>(&p);
+    let t2 = psi(&p);
+    let mut t3 = psi(&psi(&p.double()));
+    t3 -= &t2;
+    t3 += &mul_by_x::<P>(&(t1 + &t2));
+    t3 -= &t1;
+    t3 -= &p;
+    t3.into()
+}
+
+/// Tests membership of G2 by checking that `psi(p) = x * p`, as in Section 4
+/// of <https://eprint.iacr.org/2021/1130>. Since `psi^2 - (x + 1) * psi + q = 0`,
+/// such points are killed by `q - x = h1 * r`, where the cofactor `h1` of G1
+/// is coprime to the cofactor of G2.
+pub fn is_in_correct_subgroup_assuming_on_curve<P: Bls12Parameters>(p: &G2Affine<P>) -> bool {
+    let p = G2Projective::<P>::from(*p);
+    psi::<P>()(&p) == mul_by_x::<P>(&p)
+}
+
+/// Returns the untwist-Frobenius-twist endomorphism `psi` of the twist, which
+/// sends `(x, y)` to `(x^p * c_x, y^p * c_y)`. Its coefficients are
+/// `xi^((p - 1) / 3)` and `xi^((p - 1) / 2)` for a D-type twist, and their
+/// inverses for an M-type twist. It acts on G2 as multiplication by `x`.
+pub fn psi<P: Bls12Parameters>() -> impl Fn(&G2Projective<P>) -> G2Projective<P> {
+    let mut c_x = <P::Fp6Params as Fp6Parameters>::FROBENIUS_COEFF_FP6_C1[1];
+    let mut c_y = c_x * &<P::Fp12Params as Fp12Parameters>::FROBENIUS_COEFF_FP12_C1[1];
+    if let TwistType::M = P::TWIST_TYPE {
+        c_x = c_x.inverse().unwrap();
+        c_y = c_y.inverse().unwrap();
+    }
+
+    move |p| {
+        let mut res = *p;
+        res.x.frobenius_map(1);
+        res.x *= &c_x;
+        res.y.frobenius_map(1);
+        res.y *= &c_y;
+        res.z.frobenius_map(1);
+        res
+    }
+}
+
+/// Computes `x * p` for the curve parameter `x`.
+fn mul_by_x<P: Bls12Parameters>(p: &G2Projective<P>) -> G2Projective<P> {
+    let res = p.mul_bits(BitIteratorBE::new(P::X));
+    if P::X_IS_NEGATIVE {
+        -res
+    } else {
+        res
+    }
+}
+
 fn doubling_step<B: Bls12Parameters>(
     r: &mut G2HomProjective<B>,
     two_inv: &B::Fp,
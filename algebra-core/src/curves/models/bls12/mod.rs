@@ -1,7 +1,7 @@
 use crate::{
     curves::{
         models::{ModelParameters, SWModelParameters},
-        PairingEngine,
+        PairingEngine, PairingOutputEngine,
     },
     fields::{
         fp12_2over3over2::{Fp12, Fp12Parameters},
@@ -185,3 +185,7 @@ impl<P: Bls12Parameters> PairingEngine for Bls12<P> {
         })
     }
 }
+
+impl<P: Bls12Parameters> PairingOutputEngine for Bls12<P> {
+    type TargetField = Fp12<P::Fp12Params>;
+}
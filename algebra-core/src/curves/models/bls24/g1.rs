@@ -0,0 +1,46 @@
+use crate::{
+    bytes::ToBytes,
+    curves::{
+        bls24::Bls24Parameters,
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        AffineCurve,
+    },
+    io::{Result as IoResult, Write},
+};
+use num_traits::Zero;
+
+pub type G1Affine<P> = GroupAffine<<P as Bls24Parameters>::G1Parameters>;
+pub type G1Projective<P> = GroupProjective<<P as Bls24Parameters>::G1Parameters>;
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: Bls24Parameters"),
+    Debug(bound = "P: Bls24Parameters"),
+    PartialEq(bound = "P: Bls24Parameters"),
+    Eq(bound = "P: Bls24Parameters")
+)]
+pub struct G1Prepared<P: Bls24Parameters>(pub G1Affine<P>);
+
+impl<P: Bls24Parameters> From<G1Affine<P>> for G1Prepared<P> {
+    fn from(other: G1Affine<P>) -> Self {
+        G1Prepared(other)
+    }
+}
+
+impl<P: Bls24Parameters> G1Prepared<P> {
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: Bls24Parameters> Default for G1Prepared<P> {
+    fn default() -> Self {
+        G1Prepared(G1Affine::<P>::prime_subgroup_generator())
+    }
+}
+
+impl<P: Bls24Parameters> ToBytes for G1Prepared<P> {
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    curves::{
+        models::{ModelParameters, SWModelParameters},
+        PairingEngine, PairingOutputEngine,
+    },
+    fields::{
+        fp12_3over4::Fp12Parameters,
+        fp24_2over3over4::{Fp24, Fp24Parameters},
+        fp4::Fp4Parameters,
+        BitIteratorBE, Field, Fp2Parameters, Fp4, PrimeField, SquareRootField,
+    },
+};
+use num_traits::One;
+
+use core::marker::PhantomData;
+
+pub enum TwistType {
+    M,
+    D,
+}
+
+pub trait Bls24Parameters: 'static {
+    const X: &'static [u64];
+    const X_IS_NEGATIVE: bool;
+    const TWIST_TYPE: TwistType;
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInt>;
+    type Fp2Params: Fp2Parameters<Fp = Self::Fp>;
+    type Fp4Params: Fp4Parameters<Fp2Params = Self::Fp2Params>;
+    type Fp12Params: Fp12Parameters<Fp4Params = Self::Fp4Params>;
+    type Fp24Params: Fp24Parameters<Fp12Params = Self::Fp12Params>;
+    type G1Parameters: SWModelParameters<BaseField = Self::Fp>;
+    type G2Parameters: SWModelParameters<
+        BaseField = Fp4<Self::Fp4Params>,
+        ScalarField = <Self::G1Parameters as ModelParameters>::ScalarField,
+    >;
+}
+
+pub mod g1;
+pub mod g2;
+
+pub use self::{
+    g1::{G1Affine, G1Prepared, G1Projective},
+    g2::{G2Affine, G2Prepared, G2Projective},
+};
+
+#[derive(Derivative)]
+#[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Bls24<P: Bls24Parameters>(PhantomData<fn() -> P>);
+
+impl<P: Bls24Parameters> Bls24<P> {
+    // Evaluate the line function at point p.
+    fn ell(
+        f: &mut Fp24<P::Fp24Params>,
+        coeffs: &(Fp4<P::Fp4Params>, Fp4<P::Fp4Params>, Fp4<P::Fp4Params>),
+        p: &G1Affine<P>,
+    ) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                c2.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_014(&c0, &c1, &c2);
+            }
+            TwistType::D => {
+                c0.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_034(&c0, &c1, &c2);
+            }
+        }
+    }
+
+    fn exp_by_x(mut f: Fp24<P::Fp24Params>) -> Fp24<P::Fp24Params> {
+        f = f.cyclotomic_exp(P::X);
+        if P::X_IS_NEGATIVE {
+            f.conjugate();
+        }
+        f
+    }
+
+    // Computes `f^(x - 1)`.
+    fn exp_by_x_minus_one(f: Fp24<P::Fp24Params>) -> Fp24<P::Fp24Params> {
+        Self::exp_by_x(f) * &f.unitary_inverse()
+    }
+}
+
+impl<P: Bls24Parameters> PairingEngine for Bls24<P> {
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Projective = G1Projective<P>;
+    type G1Affine = G1Affine<P>;
+    type G1Prepared = G1Prepared<P>;
+    type G2Projective = G2Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Prepared = G2Prepared<P>;
+    type Fq = P::Fp;
+    type Fqe = Fp4<P::Fp4Params>;
+    type Fqk = Fp24<P::Fp24Params>;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        let mut pairs = vec![];
+        for (p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, q.ell_coeffs.iter()));
+            }
+        }
+
+        let mut f = Self::Fqk::one();
+
+        for i in BitIteratorBE::without_leading_zeros(P::X).skip(1) {
+            f.square_in_place();
+
+            for (p, ref mut coeffs) in &mut pairs {
+                Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+            }
+
+            if i {
+                for &mut (p, ref mut coeffs) in &mut pairs {
+                    Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        if P::X_IS_NEGATIVE {
+            f.conjugate();
+        }
+
+        f
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        // The easy part raises to the power (p^12 - 1)(p^4 + 1), and the hard
+        // part to 3 * (p^8 - p^4 + 1) / r, which for BLS24 curves equals
+        // (x - 1)^2 (x + p)(x^2 + p^2)(x^4 + p^4 - 1) + 3.
+        // As 3 does not divide r, this is still a non-degenerate pairing.
+
+        // f1 = f^(p^12)
+        let mut f1 = *f;
+        f1.conjugate();
+
+        f.inverse().map(|f2| {
+            // r = f^(p^12 - 1)
+            let mut r = f1 * &f2;
+
+            // r = f^((p^12 - 1)(p^4 + 1))
+            let mut f2 = r;
+            f2.frobenius_map(4);
+            r *= &f2;
+
+            // y0 = r^((x - 1)^2)
+            let y0 = Self::exp_by_x_minus_one(Self::exp_by_x_minus_one(r));
+
+            // y1 = y0^(x + p)
+            let mut y1 = y0;
+            y1.frobenius_map(1);
+            y1 *= &Self::exp_by_x(y0);
+
+            // y2 = y1^(x^2 + p^2)
+            let mut y2 = y1;
+            y2.frobenius_map(2);
+            y2 *= &Self::exp_by_x(Self::exp_by_x(y1));
+
+            // y3 = y2^(x^4 + p^4 - 1)
+            let mut y3 = y2;
+            y3.frobenius_map(4);
+            y3 *= &Self::exp_by_x(Self::exp_by_x(Self::exp_by_x(Self::exp_by_x(y2))));
+            y3 *= &y2.unitary_inverse();
+
+            // y3 * r^3
+            y3 *= &r.cyclotomic_square();
+            y3 * &r
+        })
+    }
+}
+
+impl<P: Bls24Parameters> PairingOutputEngine for Bls24<P> {
+    type TargetField = Fp24<P::Fp24Params>;
+}
@@ -4,9 +4,9 @@ use crate::{
         bn::{BnParameters, TwistType},
         models::SWModelParameters,
         short_weierstrass_jacobian::{GroupAffine, GroupProjective},
-        AffineCurve,
+        AffineCurve, ProjectiveCurve,
     },
-    fields::{Field, Fp2},
+    fields::{BitIteratorBE, Field, Fp12Parameters, Fp2, Fp6Parameters},
     io::{Result as IoResult, Write},
     Vec,
 };
@@ -118,6 +118,55 @@ impl<P: BnParameters> G2Prepared<P> {
     }
 }
 
+/// Maps a point of the twist into G2 with the method of Section 6.1 of
+/// Fuentes-Castañeda, Knapp and Rodríguez-Henríquez, "Faster hashing to G2".
+/// This computes `[x] p + psi([3 x] p) + psi^2([x] p) + psi^3(p)`, a multiple
+/// of `h2 * p` for the cofactor `h2` of G2.
+pub fn clear_cofactor<P: BnParameters>(p: &G2Affine<P>) -> G2Affine<P> {
+    let psi = psi::<P>();
+    let p = G2Projective::<P>::from(*p);
+
+    let x_times_p = mul_by_x::<P>(&p);
+    let mut res = psi(&psi(&psi(&p)));
+    res += &psi(&psi(&x_times_p));
+    res += &psi(&(x_times_p.double() + &x_times_p));
+    res += &x_times_p;
+    res.into()
+}
+
+/// Returns the untwist-Frobenius-twist endomorphism `psi` of the twist, which
+/// sends `(x, y)` to `(x^p * c_x, y^p * c_y)`. Its coefficients are
+/// `xi^((p - 1) / 3)` and `xi^((p - 1) / 2)` for a D-type twist, and their
+/// inverses for an M-type twist.
+fn psi<P: BnParameters>() -> impl Fn(&G2Projective<P>) -> G2Projective<P> {
+    let mut c_x = <P::Fp6Params as Fp6Parameters>::FROBENIUS_COEFF_FP6_C1[1];
+    let mut c_y = c_x * &<P::Fp12Params as Fp12Parameters>::FROBENIUS_COEFF_FP12_C1[1];
+    if let TwistType::M = P::TWIST_TYPE {
+        c_x = c_x.inverse().unwrap();
+        c_y = c_y.inverse().unwrap();
+    }
+
+    move |p| {
+        let mut res = *p;
+        res.x.frobenius_map(1);
+        res.x *= &c_x;
+        res.y.frobenius_map(1);
+        res.y *= &c_y;
+        res.z.frobenius_map(1);
+        res
+    }
+}
+
+/// Computes `x * p` for the curve parameter `x`.
+fn mul_by_x<P: BnParameters>(p: &G2Projective<P>) -> G2Projective<P> {
+    let res = p.mul_bits(BitIteratorBE::new(P::X));
+    if P::X_IS_NEGATIVE {
+        -res
+    } else {
+        res
+    }
+}
+
 fn mul_by_char<P: BnParameters>(r: G2Affine<P>) -> G2Affine<P> {
     // multiply by field characteristic
 
@@ -1,7 +1,7 @@
 use crate::{
     curves::{
         models::{ModelParameters, SWModelParameters},
-        PairingEngine,
+        PairingEngine, PairingOutputEngine,
     },
     fields::{
         fp12_2over3over2::{Fp12, Fp12Parameters},
@@ -213,3 +213,7 @@ impl<P: BnParameters> PairingEngine for Bn<P> {
         })
     }
 }
+
+impl<P: BnParameters> PairingOutputEngine for Bn<P> {
+    type TargetField = Fp12<P::Fp12Params>;
+}
@@ -1,7 +1,7 @@
 use crate::{
     curves::{
         models::{ModelParameters, SWModelParameters},
-        PairingEngine,
+        PairingEngine, PairingOutputEngine,
     },
     fields::{
         fp3::Fp3Parameters,
@@ -296,3 +296,7 @@ impl<P: BW6Parameters> PairingEngine for BW6<P> {
         Some(Self::final_exponentiation(f))
     }
 }
+
+impl<P: BW6Parameters> PairingOutputEngine for BW6<P> {
+    type TargetField = Fp6<P::Fp6Params>;
+}
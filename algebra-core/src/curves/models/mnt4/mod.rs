@@ -1,7 +1,7 @@
 use crate::{
     curves::{
         models::{ModelParameters, SWModelParameters},
-        PairingEngine,
+        PairingEngine, PairingOutputEngine,
     },
     fields::{
         fp2::{Fp2, Fp2Parameters},
@@ -217,3 +217,7 @@ impl<P: MNT4Parameters> PairingEngine for MNT4<P> {
         Some(Self::final_exponentiation(r))
     }
 }
+
+impl<P: MNT4Parameters> PairingOutputEngine for MNT4<P> {
+    type TargetField = Fp4<P::Fp4Params>;
+}
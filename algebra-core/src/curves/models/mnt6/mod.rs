@@ -1,7 +1,7 @@
 use crate::{
     curves::{
         models::{ModelParameters, SWModelParameters},
-        PairingEngine,
+        PairingEngine, PairingOutputEngine,
     },
     fields::{
         fp3::{Fp3, Fp3Parameters},
@@ -224,3 +224,7 @@ impl<P: MNT6Parameters> PairingEngine for MNT6<P> {
         Some(Self::final_exponentiation(r))
     }
 }
+
+impl<P: MNT6Parameters> PairingOutputEngine for MNT6<P> {
+    type TargetField = Fp6<P::Fp6Params>;
+}
@@ -1,12 +1,20 @@
-use crate::fields::{Field, PrimeField, SquareRootField};
+use crate::{
+    curves::glv::{GLSParameters, GLVParameters},
+    fields::{batch_inversion, BitIteratorBE, Field, PrimeField, SquareRootField},
+    Vec,
+};
+use num_traits::{One, Zero};
 
 pub mod bls12;
+pub mod bls24;
 pub mod bn;
 pub mod bw6;
 pub mod mnt4;
 pub mod mnt6;
+pub mod montgomery;
 pub mod short_weierstrass_jacobian;
 pub mod short_weierstrass_projective;
+pub mod short_weierstrass_xyzz;
 pub mod twisted_edwards_extended;
 
 pub trait ModelParameters: Send + Sync + 'static {
@@ -14,13 +22,23 @@ pub trait ModelParameters: Send + Sync + 'static {
     type ScalarField: PrimeField + SquareRootField + Into<<Self::ScalarField as PrimeField>::BigInt>;
 }
 
-pub trait SWModelParameters: ModelParameters {
+pub trait SWModelParameters: ModelParameters + Sized {
     const COEFF_A: Self::BaseField;
     const COEFF_B: Self::BaseField;
     const COFACTOR: &'static [u64];
     const COFACTOR_INV: Self::ScalarField;
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField);
 
+    /// Parameters of an efficiently computable endomorphism of the curve, if
+    /// there is one, which `mul_glv` uses for points of the prime order
+    /// subgroup.
+    const GLV: Option<GLVParameters<Self>> = None;
+
+    /// Parameters of the untwist-Frobenius-twist endomorphism on the twist of
+    /// a pairing-friendly curve, if `mul_glv` should split scalars in four
+    /// with it. These take precedence over `GLV`.
+    const GLS: Option<GLSParameters<Self>> = None;
+
     #[inline(always)]
     fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
         let mut copy = *elem;
@@ -34,15 +52,41 @@ pub trait SWModelParameters: ModelParameters {
         copy += &Self::COEFF_B;
         copy
     }
+
+    /// Maps a point of the curve into the prime order subgroup. By default
+    /// this multiplies by `COFACTOR`, but curves may use a cheaper multiple of
+    /// the prime order subgroup's cofactor.
+    #[inline]
+    fn clear_cofactor(
+        p: &short_weierstrass_jacobian::GroupAffine<Self>,
+    ) -> short_weierstrass_jacobian::GroupAffine<Self> {
+        p.scale_by_cofactor().into()
+    }
+
+    /// Checks that `p`, a point of the curve, is in the prime order subgroup.
+    /// By default this multiplies by the order of the subgroup, but curves
+    /// with an efficiently computable endomorphism may use a cheaper test.
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(
+        p: &short_weierstrass_jacobian::GroupAffine<Self>,
+    ) -> bool {
+        p.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic()))
+            .is_zero()
+    }
 }
 
-pub trait TEModelParameters: ModelParameters {
+pub trait TEModelParameters: ModelParameters + Sized {
     const COEFF_A: Self::BaseField;
     const COEFF_D: Self::BaseField;
     const COFACTOR: &'static [u64];
     const COFACTOR_INV: Self::ScalarField;
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField);
 
+    /// Parameters of an efficiently computable endomorphism of the curve, if
+    /// there is one, which `mul_glv` uses for points of the prime order
+    /// subgroup.
+    const GLV: Option<GLVParameters<Self, twisted_edwards_extended::GroupProjective<Self>>> = None;
+
     type MontgomeryModelParameters: MontgomeryModelParameters<BaseField = Self::BaseField>;
 
     #[inline(always)]
@@ -57,5 +101,72 @@ pub trait MontgomeryModelParameters: ModelParameters {
     const COEFF_A: Self::BaseField;
     const COEFF_B: Self::BaseField;
 
-    type TEModelParameters: TEModelParameters<BaseField = Self::BaseField>;
+    type TEModelParameters: TEModelParameters<
+        BaseField = Self::BaseField,
+        ScalarField = Self::ScalarField,
+    >;
+}
+
+/// Sets `points[i] = points[i] + points[j]` for every `(i, j)` in `pairs`, for
+/// affine points of the short Weierstrass curve of `P` whose coordinates and
+/// infinity flag `coords` gives access to. All the sums share one inversion.
+/// This implements `AffineCurve::batch_add_in_place` for both models.
+pub(crate) fn sw_batch_add_in_place<P: SWModelParameters, G>(
+    points: &mut [G],
+    pairs: &[(usize, usize)],
+    coords: fn(&mut G) -> (&mut P::BaseField, &mut P::BaseField, &mut bool),
+) {
+    let get = |p: &mut G| {
+        let (x, y, infinity) = coords(p);
+        (*x, *y, *infinity)
+    };
+
+    // The slope of each sum has denominator `x2 - x1`, or `2 * y1` when the
+    // two points are equal. Sums involving the point at infinity, or of a
+    // point and its negation, need no inversion and get a dummy `1`.
+    let mut denominators = pairs
+        .iter()
+        .map(|&(i, j)| {
+            let (x1, y1, infinity1) = get(&mut points[i]);
+            let (x2, y2, infinity2) = get(&mut points[j]);
+            if infinity1 || infinity2 {
+                P::BaseField::one()
+            } else if x1 != x2 {
+                x2 - &x1
+            } else if y1 == y2 && !y1.is_zero() {
+                y1.double()
+            } else {
+                P::BaseField::one()
+            }
+        })
+        .collect::<Vec<_>>();
+    batch_inversion(&mut denominators);
+
+    for (&(i, j), inverse) in pairs.iter().zip(denominators) {
+        let (x2, y2, infinity2) = get(&mut points[j]);
+        let (x1, y1, infinity1) = coords(&mut points[i]);
+        if infinity2 {
+            continue;
+        }
+        if *infinity1 {
+            *x1 = x2;
+            *y1 = y2;
+            *infinity1 = false;
+            continue;
+        }
+        let lambda = if *x1 != x2 {
+            (y2 - &*y1) * &inverse
+        } else if *y1 == y2 && !y1.is_zero() {
+            let x_squared = x1.square();
+            (x_squared.double() + &x_squared + &P::COEFF_A) * &inverse
+        } else {
+            *x1 = P::BaseField::zero();
+            *y1 = P::BaseField::one();
+            *infinity1 = true;
+            continue;
+        };
+        let x3 = lambda.square() - &*x1 - &x2;
+        *y1 = lambda * &(*x1 - &x3) - &*y1;
+        *x1 = x3;
+    }
 }
@@ -0,0 +1,1027 @@
+use crate::{
+    curves::models::{
+        twisted_edwards_extended as te, MontgomeryModelParameters as Parameters, TEModelParameters,
+    },
+    io::{Read, Result as IoResult, Write},
+    serialize::SerializationError,
+    CanonicalDeserialize, CanonicalSerialize, Choice, ConditionallySelectable,
+    ConstantSerializedSize, ConstantTimeEq, UniformRand, Vec,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    ops::{Add, AddAssign, MulAssign, Neg, Sub, SubAssign},
+};
+use num_traits::{One, Zero};
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+use crate::{
+    biginteger::BigInteger,
+    bytes::{FromBytes, ToBytes},
+    curves::{AffineCurve, ConstantTimeCurve, ProjectiveCurve},
+    fields::{BitIteratorBE, ConstantTimeField, Field, PrimeField, SquareRootField},
+};
+
+/// A point of the Montgomery curve `B * y^2 = x^3 + A * x^2 + x`.
+///
+/// The curve is birationally equivalent to the twisted Edwards curve of
+/// `P::TEModelParameters`, from which it takes its generator and cofactor.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    PartialEq(bound = "P: Parameters"),
+    Eq(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters"),
+    Hash(bound = "P: Parameters")
+)]
+#[must_use]
+pub struct GroupAffine<P: Parameters> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub infinity: bool,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P: Parameters> PartialEq<GroupProjective<P>> for GroupAffine<P> {
+    fn eq(&self, other: &GroupProjective<P>) -> bool {
+        self.into_projective() == *other
+    }
+}
+
+impl<P: Parameters> PartialEq<GroupAffine<P>> for GroupProjective<P> {
+    fn eq(&self, other: &GroupAffine<P>) -> bool {
+        *self == other.into_projective()
+    }
+}
+
+impl<P: Parameters> Display for GroupAffine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.infinity {
+            write!(f, "GroupAffine(Infinity)")
+        } else {
+            write!(f, "GroupAffine(x={}, y={})", self.x, self.y)
+        }
+    }
+}
+
+impl<P: Parameters> GroupAffine<P> {
+    pub fn new(x: P::BaseField, y: P::BaseField, infinity: bool) -> Self {
+        Self {
+            x,
+            y,
+            infinity,
+            _params: PhantomData,
+        }
+    }
+
+    pub fn scale_by_cofactor(&self) -> <Self as AffineCurve>::Projective {
+        self.mul_bits(BitIteratorBE::new(Self::COFACTOR))
+    }
+
+    /// Multiplies `self` by the scalar represented by `bits`. `bits` must be a big-endian
+    /// bit-wise decomposition of the scalar.
+    pub(crate) fn mul_bits(&self, bits: impl Iterator<Item = bool>) -> GroupProjective<P> {
+        let mut res = GroupProjective::zero();
+        for i in bits.skip_while(|b| !b) {
+            res.double_in_place();
+            if i {
+                res.add_assign_mixed(&self)
+            }
+        }
+        res
+    }
+
+    /// Attempts to construct an affine point given an x-coordinate. The
+    /// point is not guaranteed to be in the prime order subgroup.
+    ///
+    /// If and only if `greatest` is set will the lexicographically
+    /// largest y-coordinate be selected.
+    pub fn get_point_from_x(x: P::BaseField, greatest: bool) -> Option<Self> {
+        // Compute (x^3 + A * x^2 + x) / B
+        let y2 = P::COEFF_B
+            .inverse()
+            .map(|b_inv| (x.square() * &(x + &P::COEFF_A) + &x) * &b_inv);
+
+        y2.and_then(|y2| y2.sqrt()).map(|y| {
+            let negy = -y;
+
+            let y = if (y < negy) ^ greatest { y } else { negy };
+            Self::new(x, y, false)
+        })
+    }
+
+    /// Checks that the current point is on the elliptic curve.
+    pub fn is_on_curve(&self) -> bool {
+        if self.is_zero() {
+            true
+        } else {
+            let lhs = P::COEFF_B * &self.y.square();
+            let rhs = self.x.square() * &(self.x + &P::COEFF_A) + &self.x;
+            lhs == rhs
+        }
+    }
+
+    /// Checks that the current point is in the prime order subgroup given
+    /// the point on the curve.
+    pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        self.mul_bits(BitIteratorBE::new(P::ScalarField::characteristic()))
+            .is_zero()
+    }
+
+    /// Maps a point of the twisted Edwards curve to the Montgomery curve, by
+    /// `(x, y) -> ((1 + y) / (1 - y), (1 + y) / ((1 - y) * x))`. The
+    /// identity goes to the point at infinity, and `(0, -1)` to `(0, 0)`.
+    pub fn from_twisted_edwards(p: &te::GroupAffine<P::TEModelParameters>) -> Self {
+        // On the curve, only the points with x = 0 have y = 1 or y = -1, so
+        // the denominator is nonzero.
+        Self::checked_from_twisted_edwards(p).unwrap()
+    }
+
+    /// Maps `p` as `from_twisted_edwards`, or returns `None` if `p` is not on
+    /// the twisted Edwards curve and the map is undefined there.
+    fn checked_from_twisted_edwards(p: &te::GroupAffine<P::TEModelParameters>) -> Option<Self> {
+        let one = P::BaseField::one();
+        if p.is_zero() {
+            Some(Self::zero())
+        } else if p.y == -one {
+            Some(Self::new(P::BaseField::zero(), P::BaseField::zero(), false))
+        } else {
+            let one_plus_y = one + &p.y;
+            let w = ((one - &p.y) * &p.x).inverse()?;
+            let v = one_plus_y * &w;
+            Some(Self::new(v * &p.x, v, false))
+        }
+    }
+
+    /// Maps a point of the Montgomery curve to the twisted Edwards curve, by
+    /// `(x, y) -> (x / y, (x - 1) / (x + 1))`, inverting
+    /// `from_twisted_edwards`.
+    ///
+    /// Every point is mapped when the twisted Edwards curve is complete, as
+    /// the denominators vanish only at points that the curve then does not
+    /// have.
+    pub fn to_twisted_edwards(&self) -> te::GroupAffine<P::TEModelParameters> {
+        let one = P::BaseField::one();
+        if self.is_zero() {
+            te::GroupAffine::zero()
+        } else if self.x.is_zero() {
+            te::GroupAffine::new(P::BaseField::zero(), -one)
+        } else {
+            let w = (self.y * &(self.x + &one)).inverse().unwrap();
+            te::GroupAffine::new(
+                self.x * &(self.x + &one) * &w,
+                (self.x - &one) * &self.y * &w,
+            )
+        }
+    }
+}
+
+impl<P: Parameters> Zero for GroupAffine<P> {
+    fn zero() -> Self {
+        Self::new(P::BaseField::zero(), P::BaseField::one(), true)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.infinity
+    }
+}
+
+impl<P: Parameters> Add<Self> for GroupAffine<P> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let mut copy = self;
+        copy += &other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> AddAssign<&'a Self> for GroupAffine<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        let mut s_proj = GroupProjective::from(*self);
+        s_proj.add_assign_mixed(other);
+        *self = s_proj.into();
+    }
+}
+
+impl<P: Parameters> AffineCurve for GroupAffine<P> {
+    const COFACTOR: &'static [u64] = <P::TEModelParameters as TEModelParameters>::COFACTOR;
+    const FAST_BATCH_ADDITION: bool = true;
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+    type Projective = GroupProjective<P>;
+
+    fn prime_subgroup_generator() -> Self {
+        Self::from_twisted_edwards(&te::GroupAffine::prime_subgroup_generator())
+    }
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        te::GroupAffine::<P::TEModelParameters>::from_random_bytes(bytes)
+            .map(|p| Self::from_twisted_edwards(&p))
+    }
+
+    fn mul<S: Into<<Self::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> GroupProjective<P> {
+        let bits = BitIteratorBE::new(by.into());
+        self.mul_bits(bits)
+    }
+
+    #[inline]
+    fn mul_by_cofactor_to_projective(&self) -> Self::Projective {
+        self.scale_by_cofactor()
+    }
+
+    fn mul_by_cofactor_inv(&self) -> Self {
+        self.mul(<P::TEModelParameters as TEModelParameters>::COFACTOR_INV)
+            .into()
+    }
+
+    fn batch_add_in_place(points: &mut [Self], pairs: &[(usize, usize)]) {
+        // The slope of each sum has denominator `x2 - x1`, or `2 * B * y1` when
+        // the two points are equal. Sums involving the point at infinity, or
+        // of a point and its negation, need no inversion and get a dummy `1`.
+        let mut denominators = pairs
+            .iter()
+            .map(|&(i, j)| {
+                let (a, b) = (&points[i], &points[j]);
+                if a.infinity || b.infinity {
+                    P::BaseField::one()
+                } else if a.x != b.x {
+                    b.x - &a.x
+                } else if a.y == b.y && !a.y.is_zero() {
+                    (P::COEFF_B * &a.y).double()
+                } else {
+                    P::BaseField::one()
+                }
+            })
+            .collect::<Vec<_>>();
+        crate::fields::batch_inversion(&mut denominators);
+
+        for (&(i, j), inverse) in pairs.iter().zip(denominators) {
+            let b = points[j];
+            let a = &mut points[i];
+            if b.infinity {
+                continue;
+            }
+            if a.infinity {
+                *a = b;
+                continue;
+            }
+            let lambda = if a.x != b.x {
+                (b.y - &a.y) * &inverse
+            } else if a.y == b.y && !a.y.is_zero() {
+                let x_squared = a.x.square();
+                (x_squared.double()
+                    + &x_squared
+                    + &(P::COEFF_A * &a.x).double()
+                    + &P::BaseField::one())
+                    * &inverse
+            } else {
+                *a = Self::zero();
+                continue;
+            };
+            let x3 = P::COEFF_B * &lambda.square() - &P::COEFF_A - &a.x - &b.x;
+            a.y = lambda * &(a.x - &x3) - &a.y;
+            a.x = x3;
+        }
+    }
+}
+
+impl<P: Parameters> Neg for GroupAffine<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            Self::new(self.x, -self.y, false)
+        } else {
+            self
+        }
+    }
+}
+
+impl<P: Parameters> ToBytes for GroupAffine<P> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.x.write(&mut writer)?;
+        self.y.write(&mut writer)?;
+        self.infinity.write(writer)
+    }
+}
+
+impl<P: Parameters> FromBytes for GroupAffine<P> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let x = P::BaseField::read(&mut reader)?;
+        let y = P::BaseField::read(&mut reader)?;
+        let infinity = bool::read(reader)?;
+        Ok(Self::new(x, y, infinity))
+    }
+}
+
+impl<P: Parameters> Default for GroupAffine<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// A point of the Montgomery curve in homogeneous projective coordinates
+/// `(X : Y : Z)`, with `x = X / Z` and `y = Y / Z`.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    Eq(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters"),
+    Hash(bound = "P: Parameters")
+)]
+#[must_use]
+pub struct GroupProjective<P: Parameters> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub z: P::BaseField,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P: Parameters> Display for GroupProjective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", GroupAffine::from(*self))
+    }
+}
+
+impl<P: Parameters> PartialEq for GroupProjective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_zero() {
+            return other.is_zero();
+        }
+
+        if other.is_zero() {
+            return false;
+        }
+
+        // x1/z1 == x2/z2  <==> x1 * z2 == x2 * z1
+        (self.x * &other.z) == (other.x * &self.z) && (self.y * &other.z) == (other.y * &self.z)
+    }
+}
+
+impl<P: Parameters> Distribution<GroupProjective<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GroupProjective<P> {
+        let mut res = GroupProjective::prime_subgroup_generator();
+        res.mul_assign(P::ScalarField::rand(rng));
+        debug_assert!(GroupAffine::from(res).is_in_correct_subgroup_assuming_on_curve());
+        res
+    }
+}
+
+impl<P: Parameters> ToBytes for GroupProjective<P> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.x.write(&mut writer)?;
+        self.y.write(&mut writer)?;
+        self.z.write(writer)
+    }
+}
+
+impl<P: Parameters> FromBytes for GroupProjective<P> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let x = P::BaseField::read(&mut reader)?;
+        let y = P::BaseField::read(&mut reader)?;
+        let z = P::BaseField::read(reader)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+impl<P: Parameters> Default for GroupProjective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: Parameters> GroupProjective<P> {
+    pub fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            _params: PhantomData,
+        }
+    }
+
+    /// Maps a point of the twisted Edwards curve to the Montgomery curve, as
+    /// `GroupAffine::from_twisted_edwards` but without inversions.
+    pub fn from_twisted_edwards(p: &te::GroupProjective<P::TEModelParameters>) -> Self {
+        if p.is_zero() {
+            Self::zero()
+        } else if p.y == -p.z {
+            Self::new(
+                P::BaseField::zero(),
+                P::BaseField::zero(),
+                P::BaseField::one(),
+            )
+        } else {
+            // (X : Y : Z) = ((Z + Y) * X : (Z + Y) * Z : (Z - Y) * X)
+            let z_plus_y = p.z + &p.y;
+            Self::new(z_plus_y * &p.x, z_plus_y * &p.z, (p.z - &p.y) * &p.x)
+        }
+    }
+
+    /// Maps a point of the Montgomery curve to the twisted Edwards curve, as
+    /// `GroupAffine::to_twisted_edwards` but without inversions.
+    pub fn to_twisted_edwards(&self) -> te::GroupProjective<P::TEModelParameters> {
+        if self.is_zero() {
+            te::GroupProjective::zero()
+        } else if self.x.is_zero() {
+            te::GroupProjective::new(
+                P::BaseField::zero(),
+                -P::BaseField::one(),
+                P::BaseField::zero(),
+                P::BaseField::one(),
+            )
+        } else {
+            // (X : Y : T : Z) = (X * (X + Z) : Y * (X - Z) : X * (X - Z) : Y * (X + Z))
+            let x_plus_z = self.x + &self.z;
+            let x_minus_z = self.x - &self.z;
+            te::GroupProjective::new(
+                self.x * &x_plus_z,
+                self.y * &x_minus_z,
+                self.x * &x_minus_z,
+                self.y * &x_plus_z,
+            )
+        }
+    }
+}
+
+impl<P: Parameters> Zero for GroupProjective<P> {
+    // The point at infinity is always represented by Z = 0.
+    #[inline]
+    fn zero() -> Self {
+        Self::new(
+            P::BaseField::zero(),
+            P::BaseField::one(),
+            P::BaseField::zero(),
+        )
+    }
+
+    // The point at infinity is always represented by
+    // Z = 0.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+}
+
+impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
+    const COFACTOR: &'static [u64] = <P::TEModelParameters as TEModelParameters>::COFACTOR;
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+    type Affine = GroupAffine<P>;
+
+    #[inline]
+    fn prime_subgroup_generator() -> Self {
+        GroupAffine::prime_subgroup_generator().into()
+    }
+
+    #[inline]
+    fn is_normalized(&self) -> bool {
+        self.is_zero() || self.z.is_one()
+    }
+
+    fn batch_normalization(v: &mut [Self]) {
+        // Montgomery’s Trick and Fast Implementation of Masked AES
+        // Genelle, Prouff and Quisquater
+        // Section 3.2
+
+        // First pass: compute [a, ab, abc, ...]
+        let mut prod = Vec::with_capacity(v.len());
+        let mut tmp = P::BaseField::one();
+        for g in v.iter_mut()
+            // Ignore normalized elements
+            .filter(|g| !g.is_normalized())
+        {
+            tmp *= &g.z;
+            prod.push(tmp);
+        }
+
+        // Invert `tmp`.
+        tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
+
+        // Second pass: iterate backwards to compute inverses
+        for (g, s) in v.iter_mut()
+            // Backwards
+            .rev()
+                // Ignore normalized elements
+                .filter(|g| !g.is_normalized())
+                // Backwards, skip last element, fill in one for last term.
+                .zip(prod.into_iter().rev().skip(1).chain(Some(P::BaseField::one())))
+        {
+            // tmp := tmp * g.z; g.z := tmp * s = 1/z
+            let newtmp = tmp * &g.z;
+            g.z = tmp * &s;
+            tmp = newtmp;
+        }
+
+        // Perform affine transformations
+        for g in v.iter_mut().filter(|g| !g.is_normalized()) {
+            g.x *= &g.z; // x/z
+            g.y *= &g.z;
+            g.z = P::BaseField::one(); // z = 1
+        }
+    }
+
+    fn double_in_place(&mut self) -> &mut Self {
+        if self.is_zero() {
+            return self;
+        }
+        if self.y.is_zero() {
+            // A point of order two.
+            *self = Self::zero();
+            return self;
+        }
+        self.double_formula()
+    }
+
+    fn add_assign_mixed(&mut self, other: &GroupAffine<P>) {
+        if other.is_zero() {
+            return;
+        } else if self.is_zero() {
+            self.x = other.x;
+            self.y = other.y;
+            self.z = P::BaseField::one();
+            return;
+        }
+        self.add_with_z2(other.x, other.y, None);
+    }
+}
+
+impl<P: Parameters> ConstantTimeCurve for GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn into_affine_ct(&self) -> GroupAffine<P> {
+        // The inverse of zero is zero, which maps the point at infinity to
+        // (0, 0), so only y needs fixing up.
+        let is_zero = self.z.ct_eq(&P::BaseField::zero());
+        let z_inv = self.z.ct_inverse();
+        let x = self.x.ct_mul(&z_inv);
+        let y = self.y.ct_mul(&z_inv);
+        let y = P::BaseField::conditional_select(&y, &P::BaseField::one(), is_zero);
+        GroupAffine::new(x, y, is_zero.into())
+    }
+
+    fn add_ct(&self, other: &Self) -> Self {
+        let (mut sum, equal) = self.add_formula(other);
+        sum.conditional_assign(&self.double_ct(), equal);
+        sum.conditional_assign(other, self.z.ct_eq(&P::BaseField::zero()));
+        sum.conditional_assign(self, other.z.ct_eq(&P::BaseField::zero()));
+        sum
+    }
+
+    /// The formula of `double_in_place`, which maps the point at infinity and
+    /// points of order two to a point with `Z = 0`.
+    fn double_ct(&self) -> Self {
+        // The slope of the tangent is N / D, with
+        // N = 3*X^2 + 2*A*X*Z + Z^2 and D = 2*B*Y*Z.
+        let xx = self.x.ct_square();
+        let n = xx
+            .ct_double()
+            .ct_add(&xx)
+            .ct_add(&P::COEFF_A.ct_mul(&self.x.ct_mul(&self.z)).ct_double())
+            .ct_add(&self.z.ct_square());
+        let d = P::COEFF_B.ct_mul(&self.y).ct_mul(&self.z).ct_double();
+        let dd = d.ct_square();
+        let ddd = dd.ct_mul(&d);
+        // T = B*N^2*Z - D^2*(A*Z + 2*X)
+        let t = P::COEFF_B
+            .ct_mul(&n.ct_square())
+            .ct_mul(&self.z)
+            .ct_sub(&dd.ct_mul(&P::COEFF_A.ct_mul(&self.z).ct_add(&self.x.ct_double())));
+        // X3 = D*T
+        // Y3 = N*(D^2*X - T) - D^3*Y
+        // Z3 = D^3*Z
+        Self::new(
+            d.ct_mul(&t),
+            n.ct_mul(&dd.ct_mul(&self.x).ct_sub(&t))
+                .ct_sub(&ddd.ct_mul(&self.y)),
+            self.z.ct_mul(&ddd),
+        )
+    }
+}
+
+impl<P: Parameters> ConstantTimeEq for GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let self_is_zero = self.z.ct_eq(&P::BaseField::zero());
+        let other_is_zero = other.z.ct_eq(&P::BaseField::zero());
+        // The same comparison as `eq`.
+        let equal = self.x.ct_mul(&other.z).ct_eq(&other.x.ct_mul(&self.z))
+            & self.y.ct_mul(&other.z).ct_eq(&other.y.ct_mul(&self.z));
+        (self_is_zero & other_is_zero) | (!self_is_zero & !other_is_zero & equal)
+    }
+}
+
+impl<P: Parameters> ConditionallySelectable for GroupProjective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+impl<P: Parameters> GroupProjective<P> {
+    /// Adds `(x2 : y2 : z2)`, or the affine `(x2, y2)` if `z2` is `None`, to
+    /// `self`, when neither point is the point at infinity.
+    fn add_with_z2(&mut self, x2: P::BaseField, y2: P::BaseField, z2: Option<P::BaseField>) {
+        let (x1z2, y1z2, z1z2) = match z2 {
+            Some(z2) => (self.x * &z2, self.y * &z2, self.z * &z2),
+            None => (self.x, self.y, self.z),
+        };
+        let x2z1 = x2 * &self.z;
+        // The slope of the line through the points is N / D, with
+        // N = Y2*Z1 - Y1*Z2 and D = X2*Z1 - X1*Z2.
+        let n = y2 * &self.z - &y1z2;
+        let d = x2z1 - &x1z2;
+        if d.is_zero() {
+            if n.is_zero() {
+                self.double_in_place();
+            } else {
+                *self = Self::zero();
+            }
+            return;
+        }
+        let dd = d.square();
+        let ddd = dd * &d;
+        // T = B*N^2*Z1Z2 - D^2*(A*Z1Z2 + X1Z2 + X2Z1)
+        let t = P::COEFF_B * &n.square() * &z1z2 - &(dd * &(P::COEFF_A * &z1z2 + &x1z2 + &x2z1));
+        // X3 = D*T
+        self.x = d * &t;
+        // Y3 = N*(D^2*X1Z2 - T) - D^3*Y1Z2
+        self.y = n * &(dd * &x1z2 - &t) - &(ddd * &y1z2);
+        // Z3 = D^3*Z1Z2
+        self.z = ddd * &z1z2;
+    }
+
+    /// Computes `self + other` in time independent of both values, without
+    /// checking for the point at infinity, and returns it along with whether
+    /// the two points are equal, in which case the sum is wrong and the caller
+    /// must double instead. The sum of a point and its negation has `Z = 0`.
+    fn add_formula(&self, other: &Self) -> (Self, Choice)
+    where
+        P::BaseField: ConstantTimeField,
+    {
+        let x1z2 = self.x.ct_mul(&other.z);
+        let y1z2 = self.y.ct_mul(&other.z);
+        let z1z2 = self.z.ct_mul(&other.z);
+        let x2z1 = other.x.ct_mul(&self.z);
+        // The slope of the line through the points is N / D, with
+        // N = Y2*Z1 - Y1*Z2 and D = X2*Z1 - X1*Z2.
+        let n = other.y.ct_mul(&self.z).ct_sub(&y1z2);
+        let d = x2z1.ct_sub(&x1z2);
+        let dd = d.ct_square();
+        let ddd = dd.ct_mul(&d);
+        // T = B*N^2*Z1Z2 - D^2*(A*Z1Z2 + X1Z2 + X2Z1)
+        let t = P::COEFF_B
+            .ct_mul(&n.ct_square())
+            .ct_mul(&z1z2)
+            .ct_sub(&dd.ct_mul(&P::COEFF_A.ct_mul(&z1z2).ct_add(&x1z2).ct_add(&x2z1)));
+        // X3 = D*T
+        let x = d.ct_mul(&t);
+        // Y3 = N*(D^2*X1Z2 - T) - D^3*Y1Z2
+        let y = n
+            .ct_mul(&dd.ct_mul(&x1z2).ct_sub(&t))
+            .ct_sub(&ddd.ct_mul(&y1z2));
+        // Z3 = D^3*Z1Z2
+        let z = ddd.ct_mul(&z1z2);
+
+        let zero = P::BaseField::zero();
+        (Self::new(x, y, z), d.ct_eq(&zero) & n.ct_eq(&zero))
+    }
+
+    /// Doubles `self` in place without checking for the point at infinity or
+    /// for points of order two, which the formula maps to a point with
+    /// `Z = 0`.
+    fn double_formula(&mut self) -> &mut Self {
+        // The slope of the tangent is N / D, with
+        // N = 3*X^2 + 2*A*X*Z + Z^2 and D = 2*B*Y*Z.
+        let xx = self.x.square();
+        let n = xx.double() + &xx + &(P::COEFF_A * &(self.x * &self.z)).double() + &self.z.square();
+        let d = (P::COEFF_B * &self.y * &self.z).double();
+        let dd = d.square();
+        let ddd = dd * &d;
+        // T = B*N^2*Z - D^2*(A*Z + 2*X)
+        let t =
+            P::COEFF_B * &n.square() * &self.z - &(dd * &(P::COEFF_A * &self.z + &self.x.double()));
+        // X3 = D*T
+        // Y3 = N*(D^2*X - T) - D^3*Y
+        // Z3 = D^3*Z
+        let x3 = d * &t;
+        self.y = n * &(dd * &self.x - &t) - &(ddd * &self.y);
+        self.x = x3;
+        self.z *= &ddd;
+        self
+    }
+}
+
+impl<P: Parameters> Neg for GroupProjective<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            Self::new(self.x, -self.y, self.z)
+        } else {
+            self
+        }
+    }
+}
+
+crate::impl_additive_ops_from_ref!(GroupProjective, Parameters);
+
+impl<'a, P: Parameters> Add<&'a Self> for GroupProjective<P> {
+    type Output = Self;
+    fn add(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy += other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> AddAssign<&'a Self> for GroupProjective<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        if self.is_zero() {
+            *self = *other;
+            return;
+        }
+
+        if other.is_zero() {
+            return;
+        }
+
+        self.add_with_z2(other.x, other.y, Some(other.z));
+    }
+}
+
+impl<'a, P: Parameters> Sub<&'a Self> for GroupProjective<P> {
+    type Output = Self;
+    fn sub(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy -= other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> SubAssign<&'a Self> for GroupProjective<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += &(-(*other));
+    }
+}
+
+impl<P: Parameters> MulAssign<P::ScalarField> for GroupProjective<P> {
+    fn mul_assign(&mut self, other: P::ScalarField) {
+        *self = self.mul(other.into_repr())
+    }
+}
+
+// The affine point X, Y is represented in the projective
+// coordinates with Z = 1.
+impl<P: Parameters> From<GroupAffine<P>> for GroupProjective<P> {
+    fn from(p: GroupAffine<P>) -> GroupProjective<P> {
+        if p.is_zero() {
+            Self::zero()
+        } else {
+            Self::new(p.x, p.y, P::BaseField::one())
+        }
+    }
+}
+
+// The projective point X, Y, Z is represented in the affine
+// coordinates as X/Z, Y/Z.
+impl<P: Parameters> From<GroupProjective<P>> for GroupAffine<P> {
+    fn from(p: GroupProjective<P>) -> GroupAffine<P> {
+        if p.is_zero() {
+            GroupAffine::zero()
+        } else if p.z.is_one() {
+            // If Z is one, the point is already normalized.
+            GroupAffine::new(p.x, p.y, false)
+        } else {
+            // Z is nonzero, so it must have an inverse in a field.
+            let z_inv = p.z.inverse().unwrap();
+            let x = p.x * &z_inv;
+            let y = p.y * &z_inv;
+            GroupAffine::new(x, y, false)
+        }
+    }
+}
+
+/// The x-coordinate of a point of the Montgomery curve, in projective
+/// coordinates `(X : Z)` with `x = X / Z`. A point and its negation share
+/// their x-coordinate, so these support only doubling and differential
+/// addition, as used by the Montgomery ladder.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters")
+)]
+#[must_use]
+pub struct XZProjective<P: Parameters> {
+    pub x: P::BaseField,
+    pub z: P::BaseField,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P: Parameters> PartialEq for XZProjective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_zero() || other.is_zero() {
+            return self.is_zero() && other.is_zero();
+        }
+        self.x * &other.z == other.x * &self.z
+    }
+}
+
+impl<P: Parameters> Eq for XZProjective<P> {}
+
+impl<P: Parameters> XZProjective<P> {
+    pub fn new(x: P::BaseField, z: P::BaseField) -> Self {
+        Self {
+            x,
+            z,
+            _params: PhantomData,
+        }
+    }
+
+    /// The point at infinity, `(1 : 0)`.
+    pub fn zero() -> Self {
+        Self::new(P::BaseField::one(), P::BaseField::zero())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Returns the affine x-coordinate, or `None` for the point at infinity.
+    pub fn to_affine_x(&self) -> Option<P::BaseField> {
+        self.z.inverse().map(|z_inv| self.x * &z_inv)
+    }
+
+    /// Returns `(A + 2) / 4`, the constant of the doubling formula.
+    fn a24() -> P::BaseField {
+        let two = P::BaseField::one().double();
+        (P::COEFF_A + &two) * &two.double().inverse().unwrap()
+    }
+
+    pub fn double(&self) -> Self {
+        self.double_with_a24(&Self::a24())
+    }
+
+    fn double_with_a24(&self, a24: &P::BaseField) -> Self {
+        // https://www.hyperelliptic.org/EFD/g1p/auto-montgom-xz.html#doubling-dbl-1987-m-3
+        let aa = (self.x + &self.z).square();
+        let bb = (self.x - &self.z).square();
+        let c = aa - &bb;
+        Self::new(aa * &bb, c * &(bb + &(*a24 * &c)))
+    }
+
+    /// Returns `self + other`, given `difference = self - other`, which must
+    /// be neither the point at infinity nor `(0, 0)`.
+    pub fn differential_add(&self, other: &Self, difference: &Self) -> Self {
+        // https://www.hyperelliptic.org/EFD/g1p/auto-montgom-xz.html#diffadd-dadd-1987-m-3
+        let da = (other.x - &other.z) * &(self.x + &self.z);
+        let cb = (other.x + &other.z) * &(self.x - &self.z);
+        Self::new(
+            difference.z * &(da + &cb).square(),
+            difference.x * &(da - &cb).square(),
+        )
+    }
+
+    /// Swaps `a` and `b` if `swap` is one, and leaves them if it is zero,
+    /// with the same field operations in both cases.
+    fn conditional_swap(a: &mut Self, b: &mut Self, swap: &P::BaseField) {
+        let dx = (b.x - &a.x) * swap;
+        let dz = (b.z - &a.z) * swap;
+        a.x += &dx;
+        a.z += &dz;
+        b.x -= &dx;
+        b.z -= &dz;
+    }
+
+    /// Multiplies `self` by `by` with the Montgomery ladder. The ladder goes
+    /// through every bit of `by`, leading zeros included, and does the same
+    /// field operations whatever their values, so its running time does not
+    /// depend on the scalar.
+    pub fn mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> Self {
+        let by = by.into();
+        if self.is_zero() {
+            return *self;
+        }
+        if self.x.is_zero() {
+            // `(0, 0)` has order two, and the differential additions do not
+            // apply. The result is `(0, 0)` for odd scalars and the point at
+            // infinity for even ones.
+            let odd = P::BaseField::from(by.is_odd());
+            return Self::new(P::BaseField::one() - &odd, odd);
+        }
+
+        let a24 = Self::a24();
+        let zero = P::BaseField::zero();
+        let one = P::BaseField::one();
+        // Invariant: r1 - r0 = self.
+        let mut r0 = Self::zero();
+        let mut r1 = *self;
+        let mut swapped = zero;
+        for bit in BitIteratorBE::new(by) {
+            let bit = P::BaseField::from(bit);
+            // Swap when the bit differs from the previous one.
+            let swap = bit + &swapped - &(bit * &swapped).double();
+            Self::conditional_swap(&mut r0, &mut r1, &swap);
+            swapped = bit;
+
+            r1 = r0.differential_add(&r1, self);
+            r0 = r0.double_with_a24(&a24);
+        }
+        Self::conditional_swap(&mut r0, &mut r1, &swapped);
+        debug_assert!(swapped == zero || swapped == one);
+        r0
+    }
+}
+
+impl<P: Parameters> From<GroupAffine<P>> for XZProjective<P> {
+    fn from(p: GroupAffine<P>) -> Self {
+        if p.is_zero() {
+            Self::zero()
+        } else {
+            Self::new(p.x, P::BaseField::one())
+        }
+    }
+}
+
+impl<P: Parameters> From<GroupProjective<P>> for XZProjective<P> {
+    fn from(p: GroupProjective<P>) -> Self {
+        if p.is_zero() {
+            Self::zero()
+        } else {
+            Self::new(p.x, p.z)
+        }
+    }
+}
+
+// Points are serialized as their images on the twisted Edwards curve, whose
+// encoding needs a single flag bit and has no special case for the point at
+// infinity.
+impl<P: Parameters> CanonicalSerialize for GroupAffine<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.to_twisted_edwards().serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.to_twisted_edwards().serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        Self::UNCOMPRESSED_SIZE
+    }
+}
+
+impl<P: Parameters> ConstantSerializedSize for GroupAffine<P> {
+    const SERIALIZED_SIZE: usize =
+        <te::GroupAffine<P::TEModelParameters> as ConstantSerializedSize>::SERIALIZED_SIZE;
+    const UNCOMPRESSED_SIZE: usize =
+        <te::GroupAffine<P::TEModelParameters> as ConstantSerializedSize>::UNCOMPRESSED_SIZE;
+}
+
+impl<P: Parameters> CanonicalDeserialize for GroupAffine<P> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let p = te::GroupAffine::<P::TEModelParameters>::deserialize(reader)?;
+        Ok(Self::from_twisted_edwards(&p))
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let p = te::GroupAffine::<P::TEModelParameters>::deserialize_uncompressed(reader)?;
+        Self::checked_from_twisted_edwards(&p).ok_or(SerializationError::InvalidData)
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let p = te::GroupAffine::<P::TEModelParameters>::deserialize_unchecked(reader)?;
+        Self::checked_from_twisted_edwards(&p).ok_or(SerializationError::InvalidData)
+    }
+}
@@ -3,7 +3,8 @@ use crate::{
     io::{Read, Result as IoResult, Write},
     serialize::{Flags, SWFlags},
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, ConstantSerializedSize, UniformRand, Vec,
+    CanonicalSerializeWithFlags, Choice, ConditionallySelectable, ConstantSerializedSize,
+    ConstantTimeEq, UniformRand, Vec,
 };
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
@@ -18,13 +19,18 @@ use rand::{
 
 use crate::{
     bytes::{FromBytes, ToBytes},
-    curves::{AffineCurve, ProjectiveCurve},
-    fields::{BitIteratorBE, Field, PrimeField, SquareRootField},
+    curves::{AffineCurve, ConstantTimeCurve, ProjectiveCurve},
+    fields::{BitIteratorBE, ConstantTimeField, Field, PrimeField, SquareRootField},
 };
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// The largest window size of `VariableBaseMSM` for which short Weierstrass
+/// curves sum their buckets in XYZZ coordinates. With the window sizes of
+/// `msm_window_size`, this covers MSMs of up to about 450 points.
+pub const XYZZ_MSM_MAX_WINDOW_SIZE: usize = 6;
+
 #[derive(Derivative)]
 #[derivative(
     Copy(bound = "P: Parameters"),
@@ -82,7 +88,7 @@ impl<P: Parameters> GroupAffine<P> {
 
     /// Multiplies `self` by the scalar represented by `bits`. `bits` must be a big-endian
     /// bit-wise decomposition of the scalar.
-    pub(crate) fn mul_bits(&self, bits: impl Iterator<Item = bool>) -> GroupProjective<P> {
+    pub fn mul_bits(&self, bits: impl Iterator<Item = bool>) -> GroupProjective<P> {
         let mut res = GroupProjective::zero();
         // Skip leading zeros.
         for i in bits.skip_while(|b| !b) {
@@ -124,8 +130,7 @@ impl<P: Parameters> GroupAffine<P> {
     }
 
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
-        self.mul_bits(BitIteratorBE::new(P::ScalarField::characteristic()))
-            .is_zero()
+        P::is_in_correct_subgroup_assuming_on_curve(self)
     }
 }
 
@@ -160,6 +165,7 @@ impl<'a, P: Parameters> AddAssign<&'a Self> for GroupAffine<P> {
 
 impl<P: Parameters> AffineCurve for GroupAffine<P> {
     const COFACTOR: &'static [u64] = P::COFACTOR;
+    const FAST_BATCH_ADDITION: bool = true;
     type BaseField = P::BaseField;
     type ScalarField = P::ScalarField;
     type Projective = GroupProjective<P>;
@@ -202,6 +208,56 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
     fn mul_by_cofactor_inv(&self) -> Self {
         self.mul(P::COFACTOR_INV).into()
     }
+
+    #[inline]
+    fn clear_cofactor(&self) -> Self {
+        P::clear_cofactor(self)
+    }
+
+    fn batch_add_in_place(points: &mut [Self], pairs: &[(usize, usize)]) {
+        super::sw_batch_add_in_place::<P, _>(points, pairs, |p| {
+            (&mut p.x, &mut p.y, &mut p.infinity)
+        })
+    }
+
+    /// Counts multiplications in `P::BaseField`. Summing up a bucket takes a
+    /// mixed and a general Jacobian addition, 7M + 4S and 11M + 5S. Adding a
+    /// base to an affine bucket takes 2M + 1S, plus 3M for its share of the
+    /// batched inversion, and to an XYZZ bucket 8M + 2S.
+    fn msm_window_size(num_scalars: usize) -> usize {
+        let scalar_bits = P::ScalarField::size_in_bits();
+        let bucket_sum_cost = 27;
+        let c = crate::msm::VariableBaseMSM::window_size_from_costs(
+            num_scalars,
+            scalar_bits,
+            10,
+            bucket_sum_cost,
+        );
+        if c <= XYZZ_MSM_MAX_WINDOW_SIZE {
+            return c;
+        }
+        crate::msm::VariableBaseMSM::window_size_from_costs(
+            num_scalars,
+            scalar_bits,
+            6,
+            bucket_sum_cost,
+        )
+    }
+
+    fn msm_bucket_sums<'a>(terms: impl Iterator<Item = (&'a i64, &'a Self)>, c: usize) -> Vec<Self>
+    where
+        Self: 'a,
+    {
+        // Affine buckets need enough points per bucket to share the cost of
+        // their batched inversions, which small MSMs, with small windows, lack.
+        if c <= XYZZ_MSM_MAX_WINDOW_SIZE {
+            crate::msm::accumulate_buckets::<super::short_weierstrass_xyzz::GroupProjective<P>>(
+                terms, c,
+            )
+        } else {
+            crate::msm::accumulate_buckets_affine(terms, c)
+        }
+    }
 }
 
 impl<P: Parameters> Neg for GroupAffine<P> {
@@ -335,6 +391,93 @@ impl<P: Parameters> GroupProjective<P> {
             _params: PhantomData,
         }
     }
+
+    /// Multiplies `self` by the scalar represented by `bits`. `bits` must be a big-endian
+    /// bit-wise decomposition of the scalar.
+    pub fn mul_bits(&self, bits: impl Iterator<Item = bool>) -> Self {
+        let mut res = Self::zero();
+        // Skip leading zeros.
+        for i in bits.skip_while(|b| !b) {
+            res.double_in_place();
+            if i {
+                res += self;
+            }
+        }
+        res
+    }
+
+    /// Computes `k1 * self + k2 * other`, sharing the doublings between both
+    /// multiplications.
+    pub fn mul_bits_joint(
+        &self,
+        k1: impl AsRef<[u64]>,
+        other: &Self,
+        k2: impl AsRef<[u64]>,
+    ) -> Self {
+        crate::curves::glv::mul_bits_joint(self, k1, other, k2)
+    }
+
+    /// Computes `self + other` in time independent of both values, without
+    /// checking for the point at infinity, and returns it along with whether
+    /// the two points are equal, in which case the sum is wrong and the caller
+    /// must double instead. The sum of a point and its negation has `Z = 0`.
+    fn add_formula(&self, other: &Self) -> (Self, Choice)
+    where
+        P::BaseField: ConstantTimeField,
+    {
+        // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-add-2007-bl
+        // Works for all curves.
+
+        // Z1Z1 = Z1^2
+        let z1z1 = self.z.ct_square();
+
+        // Z2Z2 = Z2^2
+        let z2z2 = other.z.ct_square();
+
+        // U1 = X1*Z2Z2
+        let u1 = self.x.ct_mul(&z2z2);
+
+        // U2 = X2*Z1Z1
+        let u2 = other.x.ct_mul(&z1z1);
+
+        // S1 = Y1*Z2*Z2Z2
+        let s1 = self.y.ct_mul(&other.z).ct_mul(&z2z2);
+
+        // S2 = Y2*Z1*Z1Z1
+        let s2 = other.y.ct_mul(&self.z).ct_mul(&z1z1);
+
+        // H = U2-U1
+        let h = u2.ct_sub(&u1);
+
+        // I = (2*H)^2
+        let i = h.ct_double().ct_square();
+
+        // J = H*I
+        let j = h.ct_mul(&i);
+
+        // r = 2*(S2-S1)
+        let r = s2.ct_sub(&s1).ct_double();
+
+        // V = U1*I
+        let v = u1.ct_mul(&i);
+
+        // X3 = r^2 - J - 2*V
+        let x = r.ct_square().ct_sub(&j).ct_sub(&v.ct_double());
+
+        // Y3 = r*(V - X3) - 2*S1*J
+        let y = r.ct_mul(&v.ct_sub(&x)).ct_sub(&s1.ct_mul(&j).ct_double());
+
+        // Z3 = ((Z1+Z2)^2 - Z1Z1 - Z2Z2)*H
+        let z = self
+            .z
+            .ct_add(&other.z)
+            .ct_square()
+            .ct_sub(&z1z1)
+            .ct_sub(&z2z2)
+            .ct_mul(&h);
+
+        (Self::new(x, y, z), u1.ct_eq(&u2) & s1.ct_eq(&s2))
+    }
 }
 
 impl<P: Parameters> Zero for GroupProjective<P> {
@@ -441,7 +584,7 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
             let d = ((self.x + &b).square() - &a - &c).double();
 
             // E = 3*A
-            let e = a + a.double_in_place();
+            let e = a + &*a.double_in_place();
 
             // F = E^2
             let f = e.square();
@@ -454,7 +597,7 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
             self.x = f - &d - &d;
 
             // Y3 = E*(D-X3)-8*C
-            self.y = (d - &self.x) * &e - c.double_in_place().double_in_place().double_in_place();
+            self.y = (d - &self.x) * &e - &*c.double_in_place().double_in_place().double_in_place();
             self
         } else {
             // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
@@ -483,7 +626,7 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
             self.x = t;
             // Y3 = M*(S-T)-8*YYYY
             let old_y = self.y;
-            self.y = m * &(s - &t) - yyyy.double_in_place().double_in_place().double_in_place();
+            self.y = m * &(s - &t) - &*yyyy.double_in_place().double_in_place().double_in_place();
             // Z3 = (Y1+Z1)^2-YY-ZZ
             self.z = (old_y + &self.z).square() - &yy - &zz;
             self
@@ -559,6 +702,155 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
             self.z -= &hh;
         }
     }
+
+    /// Uses the GLS method if `P::GLS` is set, and the GLV method if
+    /// `P::GLV` is set.
+    #[inline]
+    fn mul_glv(&self, other: &P::ScalarField) -> Self {
+        match (P::GLS, P::GLV) {
+            (Some(gls), _) => gls.mul(self, other),
+            (None, Some(glv)) => glv.mul(self, other),
+            (None, None) => self.mul_bits(BitIteratorBE::new(other.into_repr())),
+        }
+    }
+}
+
+impl<P: Parameters> ConstantTimeCurve for GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn into_affine_ct(&self) -> GroupAffine<P> {
+        // The inverse of zero is zero, which maps the point at infinity to
+        // (0, 0), so only y needs fixing up.
+        let is_zero = self.z.ct_eq(&P::BaseField::zero());
+        let zinv = self.z.ct_inverse();
+        let zinv_squared = zinv.ct_square();
+        let x = self.x.ct_mul(&zinv_squared);
+        let y = self.y.ct_mul(&zinv_squared.ct_mul(&zinv));
+        let y = P::BaseField::conditional_select(&y, &P::BaseField::one(), is_zero);
+        GroupAffine::new(x, y, is_zero.into())
+    }
+
+    fn add_ct(&self, other: &Self) -> Self {
+        let (mut sum, equal) = self.add_formula(other);
+        sum.conditional_assign(&self.double_ct(), equal);
+        sum.conditional_assign(other, self.z.ct_eq(&P::BaseField::zero()));
+        sum.conditional_assign(self, other.z.ct_eq(&P::BaseField::zero()));
+        sum
+    }
+
+    /// The formulas of `double_in_place`, which map the point at infinity to
+    /// a point with `Z = 0`.
+    fn double_ct(&self) -> Self {
+        if P::COEFF_A.is_zero() {
+            // A = X1^2
+            let a = self.x.ct_square();
+
+            // B = Y1^2
+            let b = self.y.ct_square();
+
+            // C = B^2
+            let c = b.ct_square();
+
+            // D = 2*((X1+B)2-A-C)
+            let d = self
+                .x
+                .ct_add(&b)
+                .ct_square()
+                .ct_sub(&a)
+                .ct_sub(&c)
+                .ct_double();
+
+            // E = 3*A
+            let e = a.ct_double().ct_add(&a);
+
+            // F = E^2
+            let f = e.ct_square();
+
+            // Z3 = 2*Y1*Z1
+            let z = self.y.ct_mul(&self.z).ct_double();
+
+            // X3 = F-2*D
+            let x = f.ct_sub(&d.ct_double());
+
+            // Y3 = E*(D-X3)-8*C
+            let y = e
+                .ct_mul(&d.ct_sub(&x))
+                .ct_sub(&c.ct_double().ct_double().ct_double());
+            Self::new(x, y, z)
+        } else {
+            // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
+            // XX = X1^2
+            let xx = self.x.ct_square();
+
+            // YY = Y1^2
+            let yy = self.y.ct_square();
+
+            // YYYY = YY^2
+            let yyyy = yy.ct_square();
+
+            // ZZ = Z1^2
+            let zz = self.z.ct_square();
+
+            // S = 2*((X1+YY)^2-XX-YYYY)
+            let s = self
+                .x
+                .ct_add(&yy)
+                .ct_square()
+                .ct_sub(&xx)
+                .ct_sub(&yyyy)
+                .ct_double();
+
+            // M = 3*XX+a*ZZ^2
+            let m = xx
+                .ct_double()
+                .ct_add(&xx)
+                .ct_add(&P::COEFF_A.ct_mul(&zz.ct_square()));
+
+            // T = M^2-2*S
+            let t = m.ct_square().ct_sub(&s.ct_double());
+
+            // X3 = T
+            // Y3 = M*(S-T)-8*YYYY
+            let y = m
+                .ct_mul(&s.ct_sub(&t))
+                .ct_sub(&yyyy.ct_double().ct_double().ct_double());
+            // Z3 = (Y1+Z1)^2-YY-ZZ
+            let z = self.y.ct_add(&self.z).ct_square().ct_sub(&yy).ct_sub(&zz);
+            Self::new(t, y, z)
+        }
+    }
+}
+
+impl<P: Parameters> ConstantTimeEq for GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let self_is_zero = self.z.ct_eq(&P::BaseField::zero());
+        let other_is_zero = other.z.ct_eq(&P::BaseField::zero());
+        // The same comparison as `eq`.
+        let z1 = self.z.ct_square();
+        let z2 = other.z.ct_square();
+        let z1z1z1 = z1.ct_mul(&self.z);
+        let z2z2z2 = z2.ct_mul(&other.z);
+        let equal = self.x.ct_mul(&z2).ct_eq(&other.x.ct_mul(&z1))
+            & self.y.ct_mul(&z2z2z2).ct_eq(&other.y.ct_mul(&z1z1z1));
+        (self_is_zero & other_is_zero) | (!self_is_zero & !other_is_zero & equal)
+    }
+}
+
+impl<P: Parameters> ConditionallySelectable for GroupProjective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
 }
 
 impl<P: Parameters> Neg for GroupProjective<P> {
@@ -644,7 +936,7 @@ impl<'a, P: Parameters> AddAssign<&'a Self> for GroupProjective<P> {
             self.x = r.square() - &j - &(v.double());
 
             // Y3 = r*(V - X3) - 2*S1*J
-            self.y = r * &(v - &self.x) - (s1 * &j).double_in_place();
+            self.y = r * &(v - &self.x) - &*(s1 * &j).double_in_place();
 
             // Z3 = ((Z1+Z2)^2 - Z1Z1 - Z2Z2)*H
             self.z = ((self.z + &other.z).square() - &z1z1 - &z2z2) * &h;
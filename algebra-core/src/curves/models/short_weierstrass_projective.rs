@@ -3,7 +3,8 @@ use crate::{
     io::{Read, Result as IoResult, Write},
     serialize::{Flags, SWFlags},
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, ConstantSerializedSize, UniformRand, Vec,
+    CanonicalSerializeWithFlags, Choice, ConditionallySelectable, ConstantSerializedSize,
+    ConstantTimeEq, UniformRand, Vec,
 };
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
@@ -18,8 +19,8 @@ use rand::{
 
 use crate::{
     bytes::{FromBytes, ToBytes},
-    curves::{AffineCurve, ProjectiveCurve},
-    fields::{BitIteratorBE, Field, PrimeField, SquareRootField},
+    curves::{AffineCurve, ConstantTimeCurve, ProjectiveCurve},
+    fields::{BitIteratorBE, ConstantTimeField, Field, PrimeField, SquareRootField},
 };
 
 #[derive(Derivative)]
@@ -156,6 +157,7 @@ impl<'a, P: Parameters> AddAssign<&'a Self> for GroupAffine<P> {
 
 impl<P: Parameters> AffineCurve for GroupAffine<P> {
     const COFACTOR: &'static [u64] = P::COFACTOR;
+    const FAST_BATCH_ADDITION: bool = true;
     type BaseField = P::BaseField;
     type ScalarField = P::ScalarField;
     type Projective = GroupProjective<P>;
@@ -196,6 +198,12 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
     fn mul_by_cofactor_inv(&self) -> Self {
         self.mul(P::COFACTOR_INV).into()
     }
+
+    fn batch_add_in_place(points: &mut [Self], pairs: &[(usize, usize)]) {
+        super::sw_batch_add_in_place::<P, _>(points, pairs, |p| {
+            (&mut p.x, &mut p.y, &mut p.infinity)
+        })
+    }
 }
 
 impl<P: Parameters> Neg for GroupAffine<P> {
@@ -322,6 +330,47 @@ impl<P: Parameters> GroupProjective<P> {
             _params: PhantomData,
         }
     }
+
+    /// Computes `self + other` in time independent of both values, without
+    /// checking for the point at infinity, and returns it along with whether
+    /// the two points are equal, in which case the sum is wrong and the caller
+    /// must double instead. The sum of a point and its negation has `Z = 0`.
+    fn add_formula(&self, other: &Self) -> (Self, Choice)
+    where
+        P::BaseField: ConstantTimeField,
+    {
+        // https://www.hyperelliptic.org/EFD/g1p/data/shortw/projective/addition/add-1998-cmo-2
+
+        // Y1Z2 = Y1*Z2
+        let y1z2 = self.y.ct_mul(&other.z);
+        // X1Z2 = X1*Z2
+        let x1z2 = self.x.ct_mul(&other.z);
+        // Z1Z2 = Z1*Z2
+        let z1z2 = self.z.ct_mul(&other.z);
+        // u = Y2*Z1-Y1Z2
+        let u = self.z.ct_mul(&other.y).ct_sub(&y1z2);
+        // uu = u^2
+        let uu = u.ct_square();
+        // v = X2*Z1-X1Z2
+        let v = self.z.ct_mul(&other.x).ct_sub(&x1z2);
+        // vv = v^2
+        let vv = v.ct_square();
+        // vvv = v*vv
+        let vvv = v.ct_mul(&vv);
+        // R = vv*X1Z2
+        let r = vv.ct_mul(&x1z2);
+        // A = uu*Z1Z2-vvv-2*R
+        let a = uu.ct_mul(&z1z2).ct_sub(&vvv).ct_sub(&r.ct_double());
+        // X3 = v*A
+        let x = v.ct_mul(&a);
+        // Y3 = u*(R-A)-vvv*Y1Z2
+        let y = u.ct_mul(&r.ct_sub(&a)).ct_sub(&vvv.ct_mul(&y1z2));
+        // Z3 = vvv*Z1Z2
+        let z = vvv.ct_mul(&z1z2);
+
+        let zero = P::BaseField::zero();
+        (Self::new(x, y, z), u.ct_eq(&zero) & v.ct_eq(&zero))
+    }
 }
 
 impl<P: Parameters> Zero for GroupProjective<P> {
@@ -479,6 +528,88 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
     }
 }
 
+impl<P: Parameters> ConstantTimeCurve for GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn into_affine_ct(&self) -> GroupAffine<P> {
+        // The inverse of zero is zero, which maps the point at infinity to
+        // (0, 0), so only y needs fixing up.
+        let is_zero = self.z.ct_eq(&P::BaseField::zero());
+        let z_inv = self.z.ct_inverse();
+        let x = self.x.ct_mul(&z_inv);
+        let y = self.y.ct_mul(&z_inv);
+        let y = P::BaseField::conditional_select(&y, &P::BaseField::one(), is_zero);
+        GroupAffine::new(x, y, is_zero.into())
+    }
+
+    fn add_ct(&self, other: &Self) -> Self {
+        let (mut sum, equal) = self.add_formula(other);
+        sum.conditional_assign(&self.double_ct(), equal);
+        sum.conditional_assign(other, self.z.ct_eq(&P::BaseField::zero()));
+        sum.conditional_assign(self, other.z.ct_eq(&P::BaseField::zero()));
+        sum
+    }
+
+    /// The formula of `double_in_place`, which maps the point at infinity to
+    /// a point with `Z = 0`.
+    fn double_ct(&self) -> Self {
+        // https://www.hyperelliptic.org/EFD/g1p/auto-shortw-projective.html#doubling-dbl-2007-bl
+
+        // XX = X1^2
+        let xx = self.x.ct_square();
+        // ZZ = Z1^2
+        let zz = self.z.ct_square();
+        // w = a*ZZ + 3*XX
+        let w = P::COEFF_A.ct_mul(&zz).ct_add(&xx.ct_double()).ct_add(&xx);
+        // s = 2*Y1*Z1
+        let s = self.y.ct_mul(&self.z).ct_double();
+        // sss = s^3
+        let sss = s.ct_square().ct_mul(&s);
+        // R = Y1*s
+        let r = self.y.ct_mul(&s);
+        // RR = R2
+        let rr = r.ct_square();
+        // B = (X1+R)^2-XX-RR
+        let b = self.x.ct_add(&r).ct_square().ct_sub(&xx).ct_sub(&rr);
+        // h = w2-2*B
+        let h = w.ct_square().ct_sub(&b.ct_double());
+        // X3 = h*s
+        let x = h.ct_mul(&s);
+        // Y3 = w*(B-h)-2*RR
+        let y = w.ct_mul(&b.ct_sub(&h)).ct_sub(&rr.ct_double());
+        // Z3 = sss
+        Self::new(x, y, sss)
+    }
+}
+
+impl<P: Parameters> ConstantTimeEq for GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let self_is_zero = self.z.ct_eq(&P::BaseField::zero());
+        let other_is_zero = other.z.ct_eq(&P::BaseField::zero());
+        // The same comparison as `eq`.
+        let equal = self.x.ct_mul(&other.z).ct_eq(&other.x.ct_mul(&self.z))
+            & self.y.ct_mul(&other.z).ct_eq(&other.y.ct_mul(&self.z));
+        (self_is_zero & other_is_zero) | (!self_is_zero & !other_is_zero & equal)
+    }
+}
+
+impl<P: Parameters> ConditionallySelectable for GroupProjective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Neg for GroupProjective<P> {
     type Output = Self;
     fn neg(self) -> Self {
@@ -0,0 +1,440 @@
+use crate::{
+    bytes::{FromBytes, ToBytes},
+    curves::{
+        models::{
+            short_weierstrass_jacobian::{self, GroupAffine},
+            SWModelParameters as Parameters,
+        },
+        AffineCurve, ProjectiveCurve,
+    },
+    fields::{Field, PrimeField},
+    io::{Read, Result as IoResult, Write},
+    UniformRand, Vec,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    ops::{Add, AddAssign, MulAssign, Neg, Sub, SubAssign},
+};
+use num_traits::{One, Zero};
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+/// A point of `short_weierstrass_jacobian` in extended Jacobian coordinates
+/// `(X, Y, ZZ, ZZZ)`, with `x = X / ZZ`, `y = Y / ZZZ` and `ZZ^3 = ZZZ^2`.
+/// Mixed addition costs 8M + 2S and doubling 6M + 4S, without the special
+/// cases of Jacobian coordinates for `Z = 1`, which makes these a good fit
+/// for accumulating affine points, as in the buckets of `VariableBaseMSM`.
+///
+/// Its affine points are those of `short_weierstrass_jacobian`, and it
+/// converts to and from Jacobian points without inversions.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters"),
+    Hash(bound = "P: Parameters")
+)]
+#[must_use]
+pub struct GroupProjective<P: Parameters> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub zz: P::BaseField,
+    pub zzz: P::BaseField,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P: Parameters> PartialEq for GroupProjective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_zero() {
+            return other.is_zero();
+        }
+
+        if other.is_zero() {
+            return false;
+        }
+
+        // x1/zz1 == x2/zz2  <==> x1 * zz2 == x2 * zz1
+        if (self.x * &other.zz) != (other.x * &self.zz) {
+            false
+        } else {
+            (self.y * &other.zzz) == (other.y * &self.zzz)
+        }
+    }
+}
+
+impl<P: Parameters> Eq for GroupProjective<P> {}
+
+impl<P: Parameters> Display for GroupProjective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", GroupAffine::from(*self))
+    }
+}
+
+impl<P: Parameters> Distribution<GroupProjective<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GroupProjective<P> {
+        let mut res = GroupProjective::prime_subgroup_generator();
+        res.mul_assign(P::ScalarField::rand(rng));
+        debug_assert!(GroupAffine::from(res).is_in_correct_subgroup_assuming_on_curve());
+        res
+    }
+}
+
+impl<P: Parameters> ToBytes for GroupProjective<P> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.x.write(&mut writer)?;
+        self.y.write(&mut writer)?;
+        self.zz.write(&mut writer)?;
+        self.zzz.write(writer)
+    }
+}
+
+impl<P: Parameters> FromBytes for GroupProjective<P> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let x = P::BaseField::read(&mut reader)?;
+        let y = P::BaseField::read(&mut reader)?;
+        let zz = P::BaseField::read(&mut reader)?;
+        let zzz = P::BaseField::read(reader)?;
+        Ok(Self::new(x, y, zz, zzz))
+    }
+}
+
+impl<P: Parameters> Default for GroupProjective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: Parameters> GroupProjective<P> {
+    pub fn new(x: P::BaseField, y: P::BaseField, zz: P::BaseField, zzz: P::BaseField) -> Self {
+        Self {
+            x,
+            y,
+            zz,
+            zzz,
+            _params: PhantomData,
+        }
+    }
+
+    /// Sets `self` to `2 * other` for an affine `other` that is not the point
+    /// at infinity.
+    fn double_affine(&mut self, other: &GroupAffine<P>) {
+        // https://www.hyperelliptic.org/EFD/g1p/auto-shortw-xyzz.html#doubling-mdbl-2008-s-1
+
+        // U = 2*Y1
+        let u = other.y.double();
+        // V = U^2
+        let v = u.square();
+        // W = U*V
+        let w = u * &v;
+        // S = X1*V
+        let s = other.x * &v;
+        // M = 3*X1^2+a
+        let xx = other.x.square();
+        let m = xx + &xx.double() + &P::COEFF_A;
+        // X3 = M^2-2*S
+        self.x = m.square() - &s.double();
+        // Y3 = M*(S-X3)-W*Y1
+        self.y = m * &(s - &self.x) - &(w * &other.y);
+        // ZZ3 = V
+        self.zz = v;
+        // ZZZ3 = W
+        self.zzz = w;
+    }
+
+    fn to_affine_with_zzz_inv(&self, zzz_inv: &P::BaseField) -> GroupAffine<P> {
+        // 1/z = zz/zzz
+        let z_inv = self.zz * zzz_inv;
+        GroupAffine::new(self.x * &z_inv.square(), self.y * zzz_inv, false)
+    }
+}
+
+impl<P: Parameters> Zero for GroupProjective<P> {
+    // The point at infinity is always represented by ZZ = ZZZ = 0.
+    #[inline]
+    fn zero() -> Self {
+        Self::new(
+            P::BaseField::one(),
+            P::BaseField::one(),
+            P::BaseField::zero(),
+            P::BaseField::zero(),
+        )
+    }
+
+    // The point at infinity is always represented by
+    // ZZ = 0.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.zz.is_zero()
+    }
+}
+
+impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
+    const COFACTOR: &'static [u64] = P::COFACTOR;
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+    type Affine = GroupAffine<P>;
+
+    #[inline]
+    fn prime_subgroup_generator() -> Self {
+        GroupAffine::prime_subgroup_generator().into()
+    }
+
+    #[inline]
+    fn is_normalized(&self) -> bool {
+        self.is_zero() || (self.zz.is_one() && self.zzz.is_one())
+    }
+
+    fn batch_normalization(v: &mut [Self]) {
+        let affine = Self::batch_normalization_into_affine(v);
+        for (p, a) in v.iter_mut().zip(affine) {
+            *p = a.into();
+        }
+    }
+
+    /// Converts `v` into affine points, with a single inversion.
+    fn batch_normalization_into_affine(v: &[Self]) -> Vec<GroupAffine<P>> {
+        let mut zzz_inv = v
+            .iter()
+            .filter(|p| !p.is_zero())
+            .map(|p| p.zzz)
+            .collect::<Vec<_>>();
+        crate::fields::batch_inversion(&mut zzz_inv);
+
+        let mut zzz_inv = zzz_inv.into_iter();
+        v.iter()
+            .map(|p| {
+                if p.is_zero() {
+                    GroupAffine::zero()
+                } else {
+                    let zzz_inv = zzz_inv.next().unwrap();
+                    Self::to_affine_with_zzz_inv(p, &zzz_inv)
+                }
+            })
+            .collect()
+    }
+
+    fn double_in_place(&mut self) -> &mut Self {
+        if self.is_zero() {
+            return self;
+        }
+        // https://www.hyperelliptic.org/EFD/g1p/auto-shortw-xyzz.html#doubling-dbl-2008-s-1
+
+        // U = 2*Y1
+        let u = self.y.double();
+        // V = U^2
+        let v = u.square();
+        // W = U*V
+        let w = u * &v;
+        // S = X1*V
+        let s = self.x * &v;
+        // M = 3*X1^2+a*ZZ1^2
+        let xx = self.x.square();
+        let m = xx + &xx.double() + &P::mul_by_a(&self.zz.square());
+        // X3 = M^2-2*S
+        self.x = m.square() - &s.double();
+        // Y3 = M*(S-X3)-W*Y1
+        self.y = m * &(s - &self.x) - &(w * &self.y);
+        // ZZ3 = V*ZZ1
+        self.zz *= &v;
+        // ZZZ3 = W*ZZZ1
+        self.zzz *= &w;
+
+        self
+    }
+
+    fn add_assign_mixed(&mut self, other: &GroupAffine<P>) {
+        if other.is_zero() {
+            return;
+        } else if self.is_zero() {
+            *self = (*other).into();
+            return;
+        }
+        // https://www.hyperelliptic.org/EFD/g1p/auto-shortw-xyzz.html#addition-madd-2008-s
+
+        // P = X2*ZZ1-X1
+        let p = other.x * &self.zz - &self.x;
+        // R = Y2*ZZZ1-Y1
+        let r = other.y * &self.zzz - &self.y;
+        if p.is_zero() {
+            if r.is_zero() {
+                self.double_affine(other);
+            } else {
+                *self = Self::zero();
+            }
+            return;
+        }
+        // PP = P^2
+        let pp = p.square();
+        // PPP = P*PP
+        let ppp = p * &pp;
+        // Q = X1*PP
+        let q = self.x * &pp;
+        // X3 = R^2-PPP-2*Q
+        self.x = r.square() - &ppp - &q.double();
+        // Y3 = R*(Q-X3)-Y1*PPP
+        self.y = r * &(q - &self.x) - &(self.y * &ppp);
+        // ZZ3 = ZZ1*PP
+        self.zz *= &pp;
+        // ZZZ3 = ZZZ1*PPP
+        self.zzz *= &ppp;
+    }
+
+    /// Uses the GLS or GLV method of `short_weierstrass_jacobian`, if `P`
+    /// sets their parameters.
+    #[inline]
+    fn mul_glv(&self, other: &P::ScalarField) -> Self {
+        short_weierstrass_jacobian::GroupProjective::from(*self)
+            .mul_glv(other)
+            .into()
+    }
+}
+
+impl<P: Parameters> Neg for GroupProjective<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            Self::new(self.x, -self.y, self.zz, self.zzz)
+        } else {
+            self
+        }
+    }
+}
+
+crate::impl_additive_ops_from_ref!(GroupProjective, Parameters);
+
+impl<'a, P: Parameters> Add<&'a Self> for GroupProjective<P> {
+    type Output = Self;
+    fn add(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy += other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> AddAssign<&'a Self> for GroupProjective<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        if self.is_zero() {
+            *self = *other;
+            return;
+        }
+
+        if other.is_zero() {
+            return;
+        }
+
+        // https://www.hyperelliptic.org/EFD/g1p/auto-shortw-xyzz.html#addition-add-2008-s
+
+        // U1 = X1*ZZ2
+        let u1 = self.x * &other.zz;
+        // S1 = Y1*ZZZ2
+        let s1 = self.y * &other.zzz;
+        // P = X2*ZZ1-U1
+        let p = other.x * &self.zz - &u1;
+        // R = Y2*ZZZ1-S1
+        let r = other.y * &self.zzz - &s1;
+        if p.is_zero() {
+            if r.is_zero() {
+                // The two points are equal, so we double.
+                self.double_in_place();
+            } else {
+                *self = Self::zero();
+            }
+            return;
+        }
+        // PP = P^2
+        let pp = p.square();
+        // PPP = P*PP
+        let ppp = p * &pp;
+        // Q = U1*PP
+        let q = u1 * &pp;
+        // X3 = R^2-PPP-2*Q
+        self.x = r.square() - &ppp - &q.double();
+        // Y3 = R*(Q-X3)-S1*PPP
+        self.y = r * &(q - &self.x) - &(s1 * &ppp);
+        // ZZ3 = ZZ1*ZZ2*PP
+        self.zz *= &(other.zz * &pp);
+        // ZZZ3 = ZZZ1*ZZZ2*PPP
+        self.zzz *= &(other.zzz * &ppp);
+    }
+}
+
+impl<'a, P: Parameters> Sub<&'a Self> for GroupProjective<P> {
+    type Output = Self;
+    fn sub(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy -= other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> SubAssign<&'a Self> for GroupProjective<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += &(-(*other));
+    }
+}
+
+impl<P: Parameters> MulAssign<P::ScalarField> for GroupProjective<P> {
+    fn mul_assign(&mut self, other: P::ScalarField) {
+        *self = self.mul(other.into_repr())
+    }
+}
+
+// The affine point X, Y is represented in the extended jacobian
+// coordinates with ZZ = ZZZ = 1.
+impl<P: Parameters> From<GroupAffine<P>> for GroupProjective<P> {
+    fn from(p: GroupAffine<P>) -> GroupProjective<P> {
+        if p.is_zero() {
+            Self::zero()
+        } else {
+            Self::new(p.x, p.y, P::BaseField::one(), P::BaseField::one())
+        }
+    }
+}
+
+// The extended jacobian point X, Y, ZZ, ZZZ is represented in the affine
+// coordinates as X/ZZ, Y/ZZZ.
+impl<P: Parameters> From<GroupProjective<P>> for GroupAffine<P> {
+    fn from(p: GroupProjective<P>) -> GroupAffine<P> {
+        if p.is_zero() {
+            GroupAffine::zero()
+        } else if p.zz.is_one() && p.zzz.is_one() {
+            // If ZZ and ZZZ are one, the point is already normalized.
+            GroupAffine::new(p.x, p.y, false)
+        } else {
+            // ZZZ is nonzero, so it must have an inverse in a field.
+            p.to_affine_with_zzz_inv(&p.zzz.inverse().unwrap())
+        }
+    }
+}
+
+// The extended Jacobian point X, Y, ZZ, ZZZ is the Jacobian point
+// X * ZZ^2, Y * ZZZ^2, ZZZ, as ZZ^3 = ZZZ^2.
+impl<P: Parameters> From<GroupProjective<P>> for short_weierstrass_jacobian::GroupProjective<P> {
+    fn from(p: GroupProjective<P>) -> Self {
+        if p.is_zero() {
+            Self::zero()
+        } else {
+            Self::new(p.x * &p.zz.square(), p.y * &p.zzz.square(), p.zzz)
+        }
+    }
+}
+
+// The Jacobian point X, Y, Z is the extended Jacobian point X, Y, Z^2, Z^3.
+impl<P: Parameters> From<short_weierstrass_jacobian::GroupProjective<P>> for GroupProjective<P> {
+    fn from(p: short_weierstrass_jacobian::GroupProjective<P>) -> Self {
+        if p.is_zero() {
+            Self::zero()
+        } else {
+            let zz = p.z.square();
+            Self::new(p.x, p.y, zz, zz * &p.z)
+        }
+    }
+}
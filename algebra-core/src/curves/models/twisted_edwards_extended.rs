@@ -2,7 +2,8 @@ use crate::{
     io::{Read, Result as IoResult, Write},
     serialize::{EdwardsFlags, Flags},
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, ConstantSerializedSize, UniformRand, Vec,
+    CanonicalSerializeWithFlags, Choice, ConditionallySelectable, ConstantSerializedSize,
+    ConstantTimeEq, UniformRand, Vec,
 };
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
@@ -21,9 +22,9 @@ use crate::{
         models::{
             MontgomeryModelParameters as MontgomeryParameters, TEModelParameters as Parameters,
         },
-        AffineCurve, ProjectiveCurve,
+        AffineCurve, ConstantTimeCurve, ProjectiveCurve,
     },
-    fields::{BitIteratorBE, Field, PrimeField, SquareRootField},
+    fields::{BitIteratorBE, ConstantTimeField, Field, PrimeField, SquareRootField},
 };
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -162,6 +163,18 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
     fn mul_by_cofactor_inv(&self) -> Self {
         self.mul(P::COFACTOR_INV).into()
     }
+
+    /// Counts multiplications in `P::BaseField`. The buckets are extended
+    /// points, so adding a base to a bucket is a mixed addition, 10M, and
+    /// summing up a bucket takes a mixed and a general addition, 10M each.
+    fn msm_window_size(num_scalars: usize) -> usize {
+        crate::msm::VariableBaseMSM::window_size_from_costs(
+            num_scalars,
+            P::ScalarField::size_in_bits(),
+            10,
+            20,
+        )
+    }
 }
 
 impl<P: Parameters> Neg for GroupAffine<P> {
@@ -500,6 +513,84 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
         // Z3 = F*G
         self.z = f * &g;
     }
+
+    /// Uses the GLV method if `P::GLV` is set.
+    fn mul_glv(&self, other: &P::ScalarField) -> Self {
+        match P::GLV {
+            Some(glv) => glv.mul(self, other),
+            None => (*self).mul(other.into_repr()),
+        }
+    }
+}
+
+impl<P: Parameters> ConstantTimeCurve for GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn into_affine_ct(&self) -> GroupAffine<P> {
+        // Z is never zero in extended coordinates.
+        let z_inv = self.z.ct_inverse();
+        GroupAffine::new(self.x.ct_mul(&z_inv), self.y.ct_mul(&z_inv))
+    }
+
+    /// The unified addition of `add_assign`, which is complete.
+    fn add_ct(&self, other: &Self) -> Self {
+        // A = x1 * x2
+        let a = self.x.ct_mul(&other.x);
+
+        // B = y1 * y2
+        let b = self.y.ct_mul(&other.y);
+
+        // C = d * t1 * t2
+        let c = P::COEFF_D.ct_mul(&self.t).ct_mul(&other.t);
+
+        // D = z1 * z2
+        let d = self.z.ct_mul(&other.z);
+
+        // H = B - aA
+        let h = b.ct_sub(&P::COEFF_A.ct_mul(&a));
+
+        // E = (x1 + y1) * (x2 + y2) - A - B
+        let e = self
+            .x
+            .ct_add(&self.y)
+            .ct_mul(&other.x.ct_add(&other.y))
+            .ct_sub(&a)
+            .ct_sub(&b);
+
+        // F = D - C
+        let f = d.ct_sub(&c);
+
+        // G = D + C
+        let g = d.ct_add(&c);
+
+        Self::new(e.ct_mul(&f), g.ct_mul(&h), e.ct_mul(&h), f.ct_mul(&g))
+    }
+}
+
+impl<P: Parameters> ConstantTimeEq for GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Z is never zero, so there are no special cases.
+        self.x.ct_mul(&other.z).ct_eq(&other.x.ct_mul(&self.z))
+            & self.y.ct_mul(&other.z).ct_eq(&other.y.ct_mul(&self.z))
+    }
+}
+
+impl<P: Parameters> ConditionallySelectable for GroupProjective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
 }
 
 impl<P: Parameters> Neg for GroupProjective<P> {
@@ -0,0 +1,263 @@
+use crate::{
+    bytes::{FromBytes, ToBytes},
+    curves::{PairingEngine, ProjectiveCurve},
+    fields::{CyclotomicMultSubgroup, PrimeField, TorusCompression},
+    groups::Group,
+    io::{Read, Result as IoResult, Write},
+    msm::VariableBaseMSM,
+    CanonicalDeserialize, CanonicalSerialize, SerializationError, UniformRand,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Add, AddAssign, MulAssign, Neg, Sub, SubAssign},
+};
+use num_traits::{One, Zero};
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+/// A pairing engine whose target field has the cyclotomic arithmetic and torus
+/// compression that `PairingOutput` uses. The pairing engines of this crate
+/// implement it; an engine defined elsewhere only needs to set `TargetField`
+/// to its `Fqk`.
+pub trait PairingOutputEngine:
+    PairingEngine<Fqk = <Self as PairingOutputEngine>::TargetField>
+{
+    type TargetField: CyclotomicMultSubgroup + TorusCompression;
+}
+
+/// An element of the target group of the pairing of `E`, written additively.
+///
+/// Addition is multiplication in `E::Fqk`, and scalar multiplication is
+/// exponentiation in its cyclotomic subgroup.
+///
+/// Deserialization, including `FromBytes::read`, rejects elements of
+/// `E::Fqk` outside the target group. Use `from_bytes_unchecked` to skip the
+/// check for trusted input.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "E: PairingOutputEngine"),
+    Clone(bound = "E: PairingOutputEngine"),
+    PartialEq(bound = "E: PairingOutputEngine"),
+    Eq(bound = "E: PairingOutputEngine"),
+    Debug(bound = "E: PairingOutputEngine"),
+    Hash(bound = "E: PairingOutputEngine")
+)]
+#[must_use]
+pub struct PairingOutput<E: PairingOutputEngine>(pub E::Fqk);
+
+impl<E: PairingOutputEngine> PairingOutput<E> {
+    /// Returns the pairing of `p` and `q`.
+    pub fn pairing<G1, G2>(p: G1, q: G2) -> Self
+    where
+        G1: Into<E::G1Affine>,
+        G2: Into<E::G2Affine>,
+    {
+        Self(E::pairing(p, q))
+    }
+
+    /// Returns the pairing of the generators of G1 and G2, which generates
+    /// the target group.
+    pub fn generator() -> Self {
+        Self::pairing(
+            E::G1Projective::prime_subgroup_generator(),
+            E::G2Projective::prime_subgroup_generator(),
+        )
+    }
+
+    /// Checks that `self` is in the prime order target group.
+    pub fn is_in_correct_subgroup(&self) -> bool {
+        // Only elements of the cyclotomic subgroup can be compressed, and
+        // `cyclotomic_exp` needs its input to be in that subgroup.
+        self.0.torus_compress().is_some() && self.0.cyclotomic_exp(E::Fr::modulus()).is_one()
+    }
+
+    /// Reads an element of `E::Fqk` written by `ToBytes`, like `read`, but
+    /// without checking that it is in the target group.
+    pub fn from_bytes_unchecked<R: Read>(reader: R) -> IoResult<Self> {
+        E::Fqk::read(reader).map(Self)
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]`, that is, the product of the
+    /// `bases[i]^scalars[i]` in `E::Fqk`, with
+    /// `VariableBaseMSM::multi_scalar_mul_group`.
+    pub fn multi_scalar_mul(bases: &[Self], scalars: &[<E::Fr as PrimeField>::BigInt]) -> Self {
+        VariableBaseMSM::multi_scalar_mul_group(bases, scalars)
+    }
+}
+
+impl<E: PairingOutputEngine> Display for PairingOutput<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "PairingOutput({})", self.0)
+    }
+}
+
+impl<E: PairingOutputEngine> Default for PairingOutput<E> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<E: PairingOutputEngine> Zero for PairingOutput<E> {
+    #[inline]
+    fn zero() -> Self {
+        Self(E::Fqk::one())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl<E: PairingOutputEngine> Distribution<PairingOutput<E>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PairingOutput<E> {
+        let mut res = PairingOutput::generator();
+        res.mul_assign(E::Fr::rand(rng));
+        res
+    }
+}
+
+impl<E: PairingOutputEngine> ToBytes for PairingOutput<E> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl<E: PairingOutputEngine> FromBytes for PairingOutput<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        let p = Self::from_bytes_unchecked(reader)?;
+        if !p.is_in_correct_subgroup() {
+            return Err(crate::error("PairingOutput is not in the target group"));
+        }
+        Ok(p)
+    }
+}
+
+impl<E: PairingOutputEngine> Neg for PairingOutput<E> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0.cyclotomic_inverse())
+    }
+}
+
+crate::impl_additive_ops_from_ref!(PairingOutput, PairingOutputEngine);
+
+impl<'a, E: PairingOutputEngine> Add<&'a Self> for PairingOutput<E> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy += other;
+        copy
+    }
+}
+
+impl<'a, E: PairingOutputEngine> AddAssign<&'a Self> for PairingOutput<E> {
+    #[inline]
+    fn add_assign(&mut self, other: &'a Self) {
+        self.0 *= &other.0;
+    }
+}
+
+impl<'a, E: PairingOutputEngine> Sub<&'a Self> for PairingOutput<E> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy -= other;
+        copy
+    }
+}
+
+impl<'a, E: PairingOutputEngine> SubAssign<&'a Self> for PairingOutput<E> {
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Self) {
+        self.0 *= &other.0.cyclotomic_inverse();
+    }
+}
+
+impl<E: PairingOutputEngine> MulAssign<E::Fr> for PairingOutput<E> {
+    fn mul_assign(&mut self, other: E::Fr) {
+        self.0 = self.0.cyclotomic_exp(other.into_repr());
+    }
+}
+
+impl<E: PairingOutputEngine> Group for PairingOutput<E> {
+    type ScalarField = E::Fr;
+
+    #[inline]
+    fn double(&self) -> Self {
+        Self(self.0.cyclotomic_square())
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        self.0 = self.0.cyclotomic_square();
+        self
+    }
+}
+
+/// `serialize` writes the torus-compressed form, and `serialize_uncompressed`
+/// the full element of `E::Fqk`. Deserialization checks that the element is
+/// in the target group, except for `deserialize_unchecked`.
+impl<E: PairingOutputEngine> CanonicalSerialize for PairingOutput<E> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize_torus(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        // One is always compressible, and all compressed forms have the same
+        // size.
+        E::Fqk::one().torus_compress().unwrap().serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize(writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+}
+
+impl<E: PairingOutputEngine> CanonicalDeserialize for PairingOutput<E> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let p = Self(E::Fqk::deserialize_torus(reader)?);
+        if !p.is_in_correct_subgroup() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(p)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let p = Self::deserialize_unchecked(reader)?;
+        if !p.is_in_correct_subgroup() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(p)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        E::Fqk::deserialize(reader).map(Self)
+    }
+}
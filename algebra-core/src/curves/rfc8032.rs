@@ -0,0 +1,135 @@
+//! The encoding of points of twisted Edwards curves over prime fields of
+//! section 5.1.2 of [RFC 8032](https://www.rfc-editor.org/rfc/rfc8032), which
+//! is the one used by Ed25519 and Ed448 implementations.
+//!
+//! A point is encoded as its `y` coordinate in little-endian form, on the
+//! smallest number of bytes that leaves room for one more bit, and the most
+//! significant bit of the last byte holds the parity of the `x` coordinate.
+
+use crate::{
+    biginteger::BigInteger,
+    bytes::{FromBytes, ToBytes},
+    curves::models::{twisted_edwards_extended::GroupAffine, TEModelParameters},
+    fields::{Field, PrimeField, SquareRootField},
+    io::{Read, Write},
+    serialize::{
+        serialized_size_with_flags, CanonicalDeserialize, CanonicalSerialize,
+        ConstantSerializedSize, SerializationError,
+    },
+    Vec,
+};
+use num_traits::{One, Zero};
+
+/// A point of a twisted Edwards curve over a prime field, which is serialized
+/// with the RFC 8032 encoding instead of the encoding of `GroupAffine`.
+///
+/// The encoding has no uncompressed form, so `serialize_uncompressed` writes
+/// the same bytes as `serialize`.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: TEModelParameters"),
+    Clone(bound = "P: TEModelParameters"),
+    PartialEq(bound = "P: TEModelParameters"),
+    Eq(bound = "P: TEModelParameters"),
+    Debug(bound = "P: TEModelParameters"),
+    Hash(bound = "P: TEModelParameters")
+)]
+pub struct Rfc8032Affine<P: TEModelParameters>(pub GroupAffine<P>);
+
+impl<P: TEModelParameters> From<GroupAffine<P>> for Rfc8032Affine<P> {
+    #[inline]
+    fn from(p: GroupAffine<P>) -> Self {
+        Self(p)
+    }
+}
+
+impl<P: TEModelParameters> From<Rfc8032Affine<P>> for GroupAffine<P> {
+    #[inline]
+    fn from(p: Rfc8032Affine<P>) -> Self {
+        p.0
+    }
+}
+
+impl<P: TEModelParameters> CanonicalSerialize for Rfc8032Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        let len = byte_size::<P::BaseField>();
+        let mut bytes = Vec::new();
+        self.0.y.into_repr().write(&mut bytes)?;
+        bytes.resize(len, 0);
+        if self.0.x.into_repr().is_odd() {
+            bytes[len - 1] |= 0x80;
+        }
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        byte_size::<P::BaseField>()
+    }
+}
+
+impl<P: TEModelParameters> ConstantSerializedSize for Rfc8032Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    const SERIALIZED_SIZE: usize = byte_size::<P::BaseField>();
+    const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+}
+
+impl<P: TEModelParameters> CanonicalDeserialize for Rfc8032Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let p = Self::deserialize_unchecked(reader)?.0;
+        if !p.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self(p))
+    }
+
+    /// Decodes a point as in section 5.1.3 of RFC 8032. The point is on the
+    /// curve, but not necessarily in the prime order subgroup.
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let len = byte_size::<P::BaseField>();
+        let mut bytes = vec![0u8; len];
+        reader.read_exact(&mut bytes)?;
+        let x_is_odd = bytes[len - 1] >> 7 == 1;
+        bytes[len - 1] &= 0x7f;
+
+        let repr_len = <P::BaseField as PrimeField>::BigInt::NUM_LIMBS * 8;
+        if bytes.iter().skip(repr_len).any(|b| *b != 0) {
+            return Err(SerializationError::InvalidData);
+        }
+        bytes.resize(repr_len, 0);
+        let y = P::BaseField::from_repr(<P::BaseField as PrimeField>::BigInt::read(&bytes[..])?)
+            .ok_or(SerializationError::InvalidData)?;
+
+        // x^2 = (y^2 - 1) / (d * y^2 - a)
+        let y2 = y.square();
+        let numerator = y2 - &P::BaseField::one();
+        let denominator = P::COEFF_D * &y2 - &P::COEFF_A;
+        let mut x = denominator
+            .inverse()
+            .and_then(|denom| (denom * &numerator).sqrt())
+            .ok_or(SerializationError::InvalidData)?;
+        if x.is_zero() && x_is_odd {
+            return Err(SerializationError::InvalidData);
+        }
+        if x.into_repr().is_odd() != x_is_odd {
+            x = -x;
+        }
+        Ok(Self(GroupAffine::new(x, y)))
+    }
+}
+
+/// The number of bytes of the encoding of a point whose coordinates are
+/// elements of `F`, which is an element of `F` with one bit of sign.
+#[inline]
+const fn byte_size<F: PrimeField>() -> usize {
+    serialized_size_with_flags::<F>(1)
+}
@@ -0,0 +1,167 @@
+//! The encoding of points of short Weierstrass curves over prime fields of
+//! section 2.3.3 of [SEC 1](https://www.secg.org/sec1-v2.pdf), which is the one
+//! used by ECDSA and ECDH implementations.
+//!
+//! The point at infinity is encoded as the single byte `0x00`. Other points are
+//! encoded as `0x02 + (y mod 2)` followed by the big-endian `x` coordinate in
+//! compressed form, and as `0x04` followed by the big-endian `x` and `y`
+//! coordinates in uncompressed form.
+
+use crate::{
+    biginteger::BigInteger,
+    bytes::{FromBytes, ToBytes},
+    curves::models::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+    fields::PrimeField,
+    io::{Read, Write},
+    serialize::{buffer_byte_size, CanonicalDeserialize, CanonicalSerialize, SerializationError},
+    Vec,
+};
+use num_traits::Zero;
+
+const INFINITY: u8 = 0x00;
+const COMPRESSED_EVEN_Y: u8 = 0x02;
+const COMPRESSED_ODD_Y: u8 = 0x03;
+const UNCOMPRESSED: u8 = 0x04;
+
+/// A point of a short Weierstrass curve over a prime field, which is serialized
+/// with the SEC 1 encoding instead of the encoding of `GroupAffine`.
+///
+/// `serialize` writes the compressed form and `serialize_uncompressed` the
+/// uncompressed one, and deserialization accepts both forms.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: SWModelParameters"),
+    Clone(bound = "P: SWModelParameters"),
+    PartialEq(bound = "P: SWModelParameters"),
+    Eq(bound = "P: SWModelParameters"),
+    Debug(bound = "P: SWModelParameters"),
+    Hash(bound = "P: SWModelParameters")
+)]
+pub struct Sec1Affine<P: SWModelParameters>(pub GroupAffine<P>);
+
+impl<P: SWModelParameters> From<GroupAffine<P>> for Sec1Affine<P> {
+    #[inline]
+    fn from(p: GroupAffine<P>) -> Self {
+        Self(p)
+    }
+}
+
+impl<P: SWModelParameters> From<Sec1Affine<P>> for GroupAffine<P> {
+    #[inline]
+    fn from(p: Sec1Affine<P>) -> Self {
+        p.0
+    }
+}
+
+impl<P: SWModelParameters> CanonicalSerialize for Sec1Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        if self.0.is_zero() {
+            INFINITY.serialize(writer)
+        } else if self.0.y.into_repr().is_odd() {
+            COMPRESSED_ODD_Y.serialize(&mut writer)?;
+            write_be(&self.0.x, writer)
+        } else {
+            COMPRESSED_EVEN_Y.serialize(&mut writer)?;
+            write_be(&self.0.x, writer)
+        }
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        if self.0.is_zero() {
+            1
+        } else {
+            1 + byte_size::<P::BaseField>()
+        }
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        if self.0.is_zero() {
+            INFINITY.serialize(writer)
+        } else {
+            UNCOMPRESSED.serialize(&mut writer)?;
+            write_be(&self.0.x, &mut writer)?;
+            write_be(&self.0.y, writer)
+        }
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        if self.0.is_zero() {
+            1
+        } else {
+            1 + 2 * byte_size::<P::BaseField>()
+        }
+    }
+}
+
+impl<P: SWModelParameters> CanonicalDeserialize for Sec1Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let p = Self::deserialize_unchecked(reader)?.0;
+        if !p.is_on_curve() || !p.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self(p))
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let p = match u8::deserialize(&mut reader)? {
+            INFINITY => GroupAffine::zero(),
+            tag @ COMPRESSED_EVEN_Y | tag @ COMPRESSED_ODD_Y => {
+                let x = read_be(&mut reader)?;
+                let mut p = GroupAffine::<P>::get_point_from_x(x, true)
+                    .ok_or(SerializationError::InvalidData)?;
+                if p.y.into_repr().is_odd() != (tag == COMPRESSED_ODD_Y) {
+                    p.y = -p.y;
+                }
+                p
+            },
+            UNCOMPRESSED => {
+                let x = read_be(&mut reader)?;
+                let y = read_be(&mut reader)?;
+                GroupAffine::new(x, y, false)
+            },
+            _ => return Err(SerializationError::InvalidData),
+        };
+        Ok(Self(p))
+    }
+}
+
+/// The number of bytes of the encoding of an element of `F`.
+#[inline]
+fn byte_size<F: PrimeField>() -> usize {
+    buffer_byte_size(F::size_in_bits())
+}
+
+/// Writes `f` as a big-endian integer of `byte_size::<F>()` bytes.
+fn write_be<F: PrimeField, W: Write>(f: &F, mut writer: W) -> Result<(), SerializationError> {
+    let mut bytes = Vec::new();
+    f.into_repr().write(&mut bytes)?;
+    bytes.truncate(byte_size::<F>());
+    bytes.reverse();
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Reads a big-endian integer of `byte_size::<F>()` bytes, which must be
+/// smaller than the modulus.
+fn read_be<F: PrimeField, R: Read>(mut reader: R) -> Result<F, SerializationError> {
+    let mut bytes = vec![0u8; F::BigInt::NUM_LIMBS * 8];
+    let len = byte_size::<F>();
+    reader.read_exact(&mut bytes[..len])?;
+    bytes[..len].reverse();
+    F::from_repr(F::BigInt::read(&bytes[..])?).ok_or(SerializationError::InvalidData)
+}
@@ -24,7 +24,7 @@ macro_rules! impl_field_mul_assign {
                 {
                     if $limbs <= 6 {
                         llvm_asm_mul!($limbs, (self.0).0, (other.0).0, P::MODULUS.0, P::INV);
-                        self.reduce();
+                        self.ct_reduce_with_carry(false);
                         return;
                     }
                 }
@@ -43,7 +43,7 @@ macro_rules! impl_field_mul_assign {
                     r[$limbs - 1] = carry1 + carry2;
                 }
                 (self.0).0 = r;
-                self.reduce();
+                self.ct_reduce_with_carry(false);
             // Alternative implementation
             } else {
                 let mut r = [0u64; $limbs * 2];
@@ -69,7 +69,7 @@ macro_rules! impl_field_mul_assign {
                     _carry2 = carry;
                 }
                 (self.0).0.copy_from_slice(&r[$limbs..]);
-                self.reduce();
+                self.ct_reduce_with_carry(_carry2 != 0);
             }
         }
     };
@@ -119,7 +119,7 @@ macro_rules! impl_field_square_in_place {
             {
                 if $limbs <= 6 && _no_carry {
                     llvm_asm_square!($limbs, (self.0).0, P::MODULUS.0, P::INV);
-                    self.reduce();
+                    self.ct_reduce_with_carry(false);
                     return self;
                 }
             }
@@ -169,7 +169,7 @@ macro_rules! impl_field_square_in_place {
                 _carry2 = carry;
             }
             (self.0).0.copy_from_slice(&r[$limbs..]);
-            self.reduce();
+            self.ct_reduce_with_carry(_carry2 != 0);
             self
         }
     };
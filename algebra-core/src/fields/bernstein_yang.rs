@@ -0,0 +1,203 @@
+//! Constant-time modular inversion with the "safegcd" algorithm of Bernstein
+//! and Yang, "Fast constant-time gcd computation and modular inversion", in the
+//! variant of libsecp256k1 that uses half-delta divsteps on 62-bit limbs:
+//! <https://github.com/bitcoin-core/secp256k1/blob/master/doc/safegcd_implementation.md>
+//!
+//! The sequence of operations and memory accesses depends only on the size of
+//! the modulus.
+
+/// The largest number of 62-bit limbs used, enough for moduli of 832 bits.
+const MAX_LIMBS: usize = 15;
+
+const M62: u64 = u64::MAX >> 2;
+
+/// The number of divsteps done at a time. The transition matrix of each batch
+/// is scaled by `2^62`.
+const BATCH_SIZE: u32 = 59;
+
+/// Signed integers in base `2^62`, least significant limb first. All limbs but
+/// the last are in `[0, 2^62)`, and the last one carries the sign.
+type Signed62 = [i64; MAX_LIMBS];
+
+/// The transition matrix `[[u, v], [q, r]]` of a batch of divsteps.
+type Matrix = [i64; 4];
+
+/// Returns the inverse of `x` modulo the odd `modulus`, which has at most
+/// `modulus_bits` bits, or zero if `x` is zero. `x` must be smaller than
+/// `modulus`, and both are given as `u64` limbs, least significant first.
+pub(crate) fn inverse<B: AsRef<[u64]> + AsMut<[u64]> + Copy>(
+    x: &B,
+    modulus: &B,
+    modulus_bits: u32,
+) -> B {
+    let len = modulus.as_ref().len() * 64 / 62 + 1;
+    assert!(len <= MAX_LIMBS);
+
+    let m = to_signed62(modulus.as_ref(), len);
+    // The inverse of the modulus modulo 2^64, by Newton iteration.
+    let mut m_inv = 1u64;
+    for _ in 0..6 {
+        m_inv = m_inv.wrapping_mul(2u64.wrapping_sub((m[0] as u64).wrapping_mul(m_inv)));
+    }
+    let m_inv = m_inv & M62;
+
+    let mut d = [0i64; MAX_LIMBS];
+    let mut e = [0i64; MAX_LIMBS];
+    e[0] = 1;
+    let mut f = m;
+    let mut g = to_signed62(x.as_ref(), len);
+    // zeta = -(delta + 1/2), with delta starting at 1/2.
+    let mut zeta = -1;
+
+    // Bernstein and Yang show that (49 * d + 80) / 17 divsteps bring g to zero
+    // for inputs of d bits; half-delta divsteps need fewer.
+    let divsteps = (49 * modulus_bits + 80) / 17;
+    for _ in 0..(divsteps + BATCH_SIZE - 1) / BATCH_SIZE {
+        let (new_zeta, t) = divsteps_59(zeta, f[0] as u64, g[0] as u64);
+        zeta = new_zeta;
+        update_de(&mut d, &mut e, &t, &m, m_inv, len);
+        update_fg(&mut f, &mut g, &t, len);
+    }
+
+    // Now g = 0 and f = +-gcd(x, modulus) = +-1, and d is +-x^-1.
+    normalize(&mut d, f[len - 1], &m, len);
+    let mut result = *x;
+    from_signed62(&d, len, result.as_mut());
+    result
+}
+
+fn to_signed62(x: &[u64], len: usize) -> Signed62 {
+    let mut limbs = [0i64; MAX_LIMBS];
+    for (i, limb) in limbs.iter_mut().enumerate().take(len) {
+        let (word, shift) = (62 * i / 64, 62 * i % 64);
+        let mut v = x.get(word).map_or(0, |w| w >> shift);
+        if shift > 2 {
+            v |= x.get(word + 1).map_or(0, |w| w << (64 - shift));
+        }
+        *limb = (v & M62) as i64;
+    }
+    limbs
+}
+
+/// Writes the nonnegative `x`, whose limbs are all in `[0, 2^62)`, to `out`.
+fn from_signed62(x: &Signed62, len: usize, out: &mut [u64]) {
+    let limb = |i: usize| if i < len { x[i] as u64 } else { 0 };
+    for (j, word) in out.iter_mut().enumerate() {
+        let (i, shift) = (64 * j / 62, 64 * j % 62);
+        *word = (limb(i) >> shift) | (limb(i + 1) << (62 - shift));
+    }
+}
+
+/// Performs 59 half-delta divsteps on the bottom bits of `f` and `g`, starting
+/// from `zeta`, and returns the new `zeta` and the transition matrix scaled by
+/// `2^62`.
+fn divsteps_59(mut zeta: i64, f0: u64, g0: u64) -> (i64, Matrix) {
+    // The matrix starts as the identity times 2^3, and its first row doubles
+    // with every step. Its entries are kept modulo 2^64.
+    let (mut u, mut v, mut q, mut r) = (8u64, 0u64, 0u64, 8u64);
+    let (mut f, mut g) = (f0, g0);
+    for _ in 0..BATCH_SIZE {
+        // Masks for zeta < 0 and for g being odd.
+        let c1 = (zeta >> 63) as u64;
+        let c2 = (g & 1).wrapping_neg();
+        // Conditionally add f, u and v, negated if zeta < 0, to g, q and r.
+        let x = (f ^ c1).wrapping_sub(c1);
+        let y = (u ^ c1).wrapping_sub(c1);
+        let z = (v ^ c1).wrapping_sub(c1);
+        g = g.wrapping_add(x & c2);
+        q = q.wrapping_add(y & c2);
+        r = r.wrapping_add(z & c2);
+        // If both conditions hold, swap the roles of f and g by adding the new
+        // g, q and r to f, u and v, and turn zeta into -zeta - 2; otherwise
+        // decrement zeta.
+        let c1 = c1 & c2;
+        zeta = (zeta ^ c1 as i64) - 1;
+        f = f.wrapping_add(g & c1);
+        u = u.wrapping_add(q & c1);
+        v = v.wrapping_add(r & c1);
+        g >>= 1;
+        u <<= 1;
+        v <<= 1;
+    }
+    (zeta, [u as i64, v as i64, q as i64, r as i64])
+}
+
+/// Sets `[d, e]` to `t * [d, e] / 2^62` modulo `m`, keeping both in `(-2m, m)`.
+fn update_de(d: &mut Signed62, e: &mut Signed62, t: &Matrix, m: &Signed62, m_inv: u64, len: usize) {
+    let [u, v, q, r] = *t;
+    // Start md and me at the multiples of m that bring d and e back into range
+    // if they are negative.
+    let sd = d[len - 1] >> 63;
+    let se = e[len - 1] >> 63;
+    let mut md = (u & sd) + (v & se);
+    let mut me = (q & sd) + (r & se);
+    let mut cd = i128::from(u) * i128::from(d[0]) + i128::from(v) * i128::from(e[0]);
+    let mut ce = i128::from(q) * i128::from(d[0]) + i128::from(r) * i128::from(e[0]);
+    // Correct md and me so that t * [d, e] + m * [md, me] is divisible by 2^62.
+    md -= (m_inv.wrapping_mul(cd as u64).wrapping_add(md as u64) & M62) as i64;
+    me -= (m_inv.wrapping_mul(ce as u64).wrapping_add(me as u64) & M62) as i64;
+    cd += i128::from(m[0]) * i128::from(md);
+    ce += i128::from(m[0]) * i128::from(me);
+    cd >>= 62;
+    ce >>= 62;
+    for i in 1..len {
+        cd += i128::from(u) * i128::from(d[i])
+            + i128::from(v) * i128::from(e[i])
+            + i128::from(m[i]) * i128::from(md);
+        ce += i128::from(q) * i128::from(d[i])
+            + i128::from(r) * i128::from(e[i])
+            + i128::from(m[i]) * i128::from(me);
+        d[i - 1] = (cd as u64 & M62) as i64;
+        e[i - 1] = (ce as u64 & M62) as i64;
+        cd >>= 62;
+        ce >>= 62;
+    }
+    d[len - 1] = cd as i64;
+    e[len - 1] = ce as i64;
+}
+
+/// Sets `[f, g]` to `t * [f, g] / 2^62`, which is exact.
+fn update_fg(f: &mut Signed62, g: &mut Signed62, t: &Matrix, len: usize) {
+    let [u, v, q, r] = *t;
+    let mut cf = i128::from(u) * i128::from(f[0]) + i128::from(v) * i128::from(g[0]);
+    let mut cg = i128::from(q) * i128::from(f[0]) + i128::from(r) * i128::from(g[0]);
+    cf >>= 62;
+    cg >>= 62;
+    for i in 1..len {
+        cf += i128::from(u) * i128::from(f[i]) + i128::from(v) * i128::from(g[i]);
+        cg += i128::from(q) * i128::from(f[i]) + i128::from(r) * i128::from(g[i]);
+        f[i - 1] = (cf as u64 & M62) as i64;
+        g[i - 1] = (cg as u64 & M62) as i64;
+        cf >>= 62;
+        cg >>= 62;
+    }
+    f[len - 1] = cf as i64;
+    g[len - 1] = cg as i64;
+}
+
+/// Brings `x` from `(-2m, m)` to `[0, m)`, negating it first if `sign` is
+/// negative.
+fn normalize(x: &mut Signed62, sign: i64, m: &Signed62, len: usize) {
+    // Add m if x is negative, and negate if requested, which brings x into
+    // (-m, m).
+    let add = x[len - 1] >> 63;
+    let negate = sign >> 63;
+    for (x, m) in x.iter_mut().zip(m).take(len) {
+        *x += m & add;
+        *x = (*x ^ negate) - negate;
+    }
+    propagate_carries(x, len);
+    // Add m again if x is still negative.
+    let add = x[len - 1] >> 63;
+    for (x, m) in x.iter_mut().zip(m).take(len) {
+        *x += m & add;
+    }
+    propagate_carries(x, len);
+}
+
+fn propagate_carries(x: &mut Signed62, len: usize) {
+    for i in 0..len - 1 {
+        x[i + 1] += x[i] >> 62;
+        x[i] &= M62 as i64;
+    }
+}
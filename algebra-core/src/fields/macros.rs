@@ -32,12 +32,25 @@ macro_rules! impl_Fp {
                 self.0 < P::MODULUS
             }
 
+            /// Reduces a value below `2 * P::MODULUS`, given as `self` and a
+            /// carry into the bit above the backing integer, which can only
+            /// be set when the modulus uses all bits of its top limb.
             #[inline]
-            fn reduce(&mut self) {
-                if !self.is_valid() {
+            fn reduce_with_carry(&mut self, carry: bool) {
+                if carry || !self.is_valid() {
                     self.0.sub_noborrow(&P::MODULUS);
                 }
             }
+
+            /// The same as `reduce_with_carry`, without branching on the
+            /// value. Multiplication and squaring reduce with this as well,
+            /// since it is no slower next to the Montgomery reduction.
+            #[inline]
+            fn ct_reduce_with_carry(&mut self, carry: bool) {
+                let mut reduced = *self;
+                let borrow = reduced.0.sub_noborrow(&P::MODULUS);
+                self.conditional_assign(&reduced, Choice::from((carry | !borrow) as u8));
+            }
         }
 
         impl<P: $FpParameters> Zero for $Fp<P> {
@@ -76,10 +89,9 @@ macro_rules! impl_Fp {
 
             #[inline]
             fn double_in_place(&mut self) -> &mut Self {
-                // This cannot exceed the backing capacity.
+                let carry = (self.0).0[$limbs - 1] >> 63 != 0;
                 self.0.mul2();
-                // However, it may need to be reduced.
-                self.reduce();
+                self.reduce_with_carry(carry);
                 self
             }
 
@@ -98,7 +110,7 @@ macro_rules! impl_Fp {
                 let mask: u64 = 0xffffffffffffffff >> P::REPR_SHAVE_BITS;
                 // the flags will be at the same byte with the lowest shaven bits or the one after
                 let flags_byte_position: usize = 7 - P::REPR_SHAVE_BITS as usize / 8;
-                let flags_mask: u8 = ((1 << P::REPR_SHAVE_BITS % 8) - 1) << (8 - P::REPR_SHAVE_BITS % 8);
+                let flags_mask: u8 = (((1u16 << P::REPR_SHAVE_BITS % 8) - 1) << (8 - P::REPR_SHAVE_BITS % 8)) as u8;
                 // take the last 8 bytes and pass the mask
                 let last_bytes = &mut result_bytes[($limbs - 1) * 8..];
                 let mut flags: u8 = 0;
@@ -108,6 +120,13 @@ macro_rules! impl_Fp {
                     }
                     *b &= m;
                 }
+                // Flags that do not fit above the modulus are serialized in
+                // the top two bits of an additional byte.
+                if P::REPR_SHAVE_BITS < 2 {
+                    if let Some(b) = bytes.get($limbs * 8) {
+                        flags |= *b & 0xc0;
+                    }
+                }
 
                 Self::deserialize(&mut &result_bytes[..]).ok().map(|f| (f, flags))
             }
@@ -145,8 +164,9 @@ macro_rules! impl_Fp {
                             if b.0.is_even() {
                                 b.0.div2();
                             } else {
-                                b.0.add_nocarry(&P::MODULUS);
+                                let carry = b.0.add_nocarry(&P::MODULUS);
                                 b.0.div2();
+                                (b.0).0[$limbs - 1] |= (carry as u64) << 63;
                             }
                         }
 
@@ -156,8 +176,9 @@ macro_rules! impl_Fp {
                             if c.0.is_even() {
                                 c.0.div2();
                             } else {
-                                c.0.add_nocarry(&P::MODULUS);
+                                let carry = c.0.add_nocarry(&P::MODULUS);
                                 c.0.div2();
+                                (c.0).0[$limbs - 1] |= (carry as u64) << 63;
                             }
                         }
 
@@ -193,8 +214,80 @@ macro_rules! impl_Fp {
             }
         }
 
+        impl<P: $FpParameters> ConstantTimeField for $Fp<P> {
+            #[inline]
+            fn ct_add(&self, other: &Self) -> Self {
+                let mut result = *self;
+                let carry = result.0.add_nocarry(&other.0);
+                result.ct_reduce_with_carry(carry);
+                result
+            }
+
+            #[inline]
+            fn ct_sub(&self, other: &Self) -> Self {
+                // If `other` is larger than `self`, the subtraction borrows, and
+                // we add the modulus back.
+                let mut result = *self;
+                let borrow = result.0.sub_noborrow(&other.0);
+                let mut corrected = result;
+                corrected.0.add_nocarry(&P::MODULUS);
+                result.conditional_assign(&corrected, Choice::from(borrow as u8));
+                result
+            }
+
+            #[inline]
+            fn ct_double(&self) -> Self {
+                let mut result = *self;
+                let carry = (result.0).0[$limbs - 1] >> 63 != 0;
+                result.0.mul2();
+                result.ct_reduce_with_carry(carry);
+                result
+            }
+
+            /// Multiplication already reduces without branching.
+            #[inline]
+            fn ct_mul(&self, other: &Self) -> Self {
+                *self * other
+            }
+
+            #[inline]
+            fn ct_square(&self) -> Self {
+                self.square()
+            }
+
+            #[inline]
+            fn ct_inverse(&self) -> Self {
+                // Inverting the Montgomery form `aR` gives `a^{-1}R^{-1}`, and
+                // multiplying twice by `R^2` brings it to `a^{-1}R`.
+                let inverse = crate::fields::bernstein_yang::inverse(
+                    &self.0,
+                    &P::MODULUS,
+                    P::MODULUS_BITS,
+                );
+                let r2 = $Fp::<P>(P::R2, PhantomData);
+                $Fp::<P>(inverse, PhantomData) * &r2 * &r2
+            }
+        }
+
+        impl<P: $FpParameters> ConstantTimeEq for $Fp<P> {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.0.as_ref().ct_eq(other.0.as_ref())
+            }
+        }
+
+        impl<P: $FpParameters> ConditionallySelectable for $Fp<P> {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                let mut result = a.0;
+                for (r, b) in result.as_mut().iter_mut().zip(b.0.as_ref()) {
+                    r.conditional_assign(b, choice);
+                }
+                $Fp::<P>(result, PhantomData)
+            }
+        }
+
         impl<P: $FpParameters> PrimeField for $Fp<P> {
-            type Params = P;
             type BigInt = $BigIntegerType;
 
             #[inline]
@@ -211,10 +304,53 @@ macro_rules! impl_Fp {
             }
 
             impl_field_into_repr!($limbs, $BigIntegerType);
+
+            #[inline]
+            fn modulus() -> $BigIntegerType {
+                P::MODULUS
+            }
+
+            #[inline]
+            fn size_in_bits() -> usize {
+                P::MODULUS_BITS as usize
+            }
+
+            #[inline]
+            fn capacity() -> usize {
+                P::CAPACITY as usize
+            }
+
+            #[inline]
+            fn trace() -> $BigIntegerType {
+                P::T
+            }
+
+            #[inline]
+            fn trace_minus_one_div_two() -> $BigIntegerType {
+                P::T_MINUS_ONE_DIV_TWO
+            }
+
+            #[inline]
+            fn modulus_minus_one_div_two() -> $BigIntegerType {
+                P::MODULUS_MINUS_ONE_DIV_TWO
+            }
         }
 
         impl<P: $FpParameters> FftField for $Fp<P> {
-            type FftParams = P;
+            #[inline]
+            fn two_adicity() -> u32 {
+                P::TWO_ADICITY
+            }
+
+            #[inline]
+            fn small_subgroup_base() -> Option<u32> {
+                P::SMALL_SUBGROUP_BASE
+            }
+
+            #[inline]
+            fn small_subgroup_base_adicity() -> Option<u32> {
+                P::SMALL_SUBGROUP_BASE_ADICITY
+            }
 
             #[inline]
             fn two_adic_root_of_unity() -> Self {
@@ -422,10 +558,8 @@ macro_rules! impl_Fp {
         impl<'a, P: $FpParameters> AddAssign<&'a Self> for $Fp<P> {
             #[inline]
             fn add_assign(&mut self, other: &Self) {
-                // This cannot exceed the backing capacity.
-                self.0.add_nocarry(&other.0);
-                // However, it may need to be reduced
-                self.reduce();
+                let carry = self.0.add_nocarry(&other.0);
+                self.reduce_with_carry(carry);
             }
         }
 
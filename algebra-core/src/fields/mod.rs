@@ -3,7 +3,8 @@ use crate::{
     bytes::{FromBytes, ToBytes},
     fields::utils::k_adicity,
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, ConstantSerializedSize, UniformRand, Vec,
+    CanonicalSerializeWithFlags, ConditionallySelectable, ConstantSerializedSize, ConstantTimeEq,
+    UniformRand, Vec,
 };
 use core::{
     fmt::{Debug, Display},
@@ -21,9 +22,13 @@ pub mod utils;
 #[macro_use]
 pub mod arithmetic;
 
+pub(crate) mod bernstein_yang;
+
 pub mod models;
 pub use self::models::*;
 
+pub use algebra_core_field_derive::FpParameters;
+
 #[macro_export]
 macro_rules! field_new {
     ($name:ident, $c0:expr) => {
@@ -163,6 +168,24 @@ pub trait Field:
     }
 }
 
+/// Fields whose cyclotomic subgroup, which contains the target group of
+/// pairings, has cheaper squaring, inversion and exponentiation than the whole
+/// field. The methods may only be used on elements of that subgroup.
+pub trait CyclotomicMultSubgroup: Field {
+    /// Returns `self * self`.
+    #[must_use]
+    fn cyclotomic_square(&self) -> Self;
+
+    /// Returns the inverse of `self`.
+    #[must_use]
+    fn cyclotomic_inverse(&self) -> Self;
+
+    /// Exponentiates `self` by a number represented with `u64` limbs, least
+    /// significant limb first.
+    #[must_use]
+    fn cyclotomic_exp(&self, exponent: impl AsRef<[u64]>) -> Self;
+}
+
 /// A trait that defines parameters for a field that can be used for FFTs.
 pub trait FftParameters: 'static + Send + Sync + Sized {
     type BigInt: BigInteger;
@@ -231,7 +254,22 @@ pub trait FpParameters: FftParameters {
 
 /// The interface for fields that are able to be used in FFTs.
 pub trait FftField: Field {
-    type FftParams: FftParameters;
+    /// Let `N` be the size of the multiplicative group defined by the field.
+    /// Then the 2-adicity is the integer `s` such that `N = 2^s * t` for some
+    /// odd integer `t`.
+    fn two_adicity() -> u32;
+
+    /// Returns an integer `b` such that there exists a multiplicative subgroup
+    /// of size `b^k` for some integer `k`, if one is defined.
+    fn small_subgroup_base() -> Option<u32> {
+        None
+    }
+
+    /// Returns the integer `k` such that there exists a multiplicative subgroup
+    /// of size `small_subgroup_base()^k`, if one is defined.
+    fn small_subgroup_base_adicity() -> Option<u32> {
+        None
+    }
 
     /// Returns the 2^s root of unity.
     fn two_adic_root_of_unity() -> Self;
@@ -247,15 +285,15 @@ pub trait FftField: Field {
     /// If no small multiplicative subgroup is defined, this is the 2-adic root of unity of order n
     /// (for n a power of 2).
     /// If a small multiplicative subgroup is defined, this is the root of unity of order n for
-    /// the larger subgroup generated by `FftParams::LARGE_SUBGROUP_ROOT_OF_UNITY`
-    /// (for n = 2^i * FftParams::SMALL_SUBGROUP_BASE^j for some i, j).
+    /// the larger subgroup generated by `large_subgroup_root_of_unity()`
+    /// (for n = 2^i * small_subgroup_base()^j for some i, j).
     fn get_root_of_unity(n: usize) -> Option<Self> {
         let mut omega: Self;
         if let Some(large_subgroup_root_of_unity) = Self::large_subgroup_root_of_unity() {
-            let q = Self::FftParams::SMALL_SUBGROUP_BASE.expect(
+            let q = Self::small_subgroup_base().expect(
                 "LARGE_SUBGROUP_ROOT_OF_UNITY should only be set in conjunction with SMALL_SUBGROUP_BASE",
             ) as usize;
-            let small_subgroup_base_adicity = Self::FftParams::SMALL_SUBGROUP_BASE_ADICITY.expect(
+            let small_subgroup_base_adicity = Self::small_subgroup_base_adicity().expect(
                 "LARGE_SUBGROUP_ROOT_OF_UNITY should only be set in conjunction with SMALL_SUBGROUP_BASE_ADICITY",
             );
 
@@ -266,7 +304,7 @@ pub trait FftField: Field {
             let two_part = 1 << two_adicity;
 
             if n != two_part * q_part
-                || (two_adicity > Self::FftParams::TWO_ADICITY)
+                || (two_adicity > Self::two_adicity())
                 || (q_adicity > small_subgroup_base_adicity)
             {
                 return None;
@@ -277,7 +315,7 @@ pub trait FftField: Field {
                 omega = omega.pow(&[q as u64]);
             }
 
-            for _ in two_adicity..Self::FftParams::TWO_ADICITY {
+            for _ in two_adicity..Self::two_adicity() {
                 omega.square_in_place();
             }
         } else {
@@ -286,14 +324,14 @@ pub trait FftField: Field {
             let size = n.next_power_of_two() as u64;
             let log_size_of_group = crate::log2(usize::try_from(size).expect("too large"));
 
-            if n != size as usize || log_size_of_group > Self::FftParams::TWO_ADICITY {
+            if n != size as usize || log_size_of_group > Self::two_adicity() {
                 return None;
             }
 
             // Compute the generator for the multiplicative subgroup.
             // It should be 2^(log_size_of_group) root of unity.
             omega = Self::two_adic_root_of_unity();
-            for _ in log_size_of_group..Self::FftParams::TWO_ADICITY {
+            for _ in log_size_of_group..Self::two_adicity() {
                 omega.square_in_place();
             }
         }
@@ -304,12 +342,11 @@ pub trait FftField: Field {
 /// The interface for a prime field.
 pub trait PrimeField:
     Field<BasePrimeField = Self>
-    + FftField<FftParams = <Self as PrimeField>::Params>
+    + FftField
     + FromStr
     + From<<Self as PrimeField>::BigInt>
     + Into<<Self as PrimeField>::BigInt>
 {
-    type Params: FpParameters<BigInt = Self::BigInt>;
     type BigInt: BigInteger;
 
     /// Returns a prime field element from its underlying representation.
@@ -323,25 +360,23 @@ pub trait PrimeField:
         Self::two_adic_root_of_unity()
     }
 
+    /// Returns the modulus of the field.
+    fn modulus() -> Self::BigInt;
+
     /// Returns the field size in bits.
-    fn size_in_bits() -> usize {
-        Self::Params::MODULUS_BITS as usize
-    }
+    fn size_in_bits() -> usize;
+
+    /// Returns the number of bits that can be reliably stored.
+    fn capacity() -> usize;
 
     /// Returns the trace.
-    fn trace() -> Self::BigInt {
-        Self::Params::T
-    }
+    fn trace() -> Self::BigInt;
 
     /// Returns the trace minus one divided by two.
-    fn trace_minus_one_div_two() -> Self::BigInt {
-        Self::Params::T_MINUS_ONE_DIV_TWO
-    }
+    fn trace_minus_one_div_two() -> Self::BigInt;
 
     /// Returns the modulus minus one divided by two.
-    fn modulus_minus_one_div_two() -> Self::BigInt {
-        Self::Params::MODULUS_MINUS_ONE_DIV_TWO
-    }
+    fn modulus_minus_one_div_two() -> Self::BigInt;
 }
 
 /// The interface for a field that supports an efficient square-root operation.
@@ -357,6 +392,53 @@ pub trait SquareRootField: Field {
     fn sqrt_in_place(&mut self) -> Option<&mut Self>;
 }
 
+/// The interface for a field with constant-time comparison, selection and
+/// arithmetic, for computations on secret values.
+///
+/// The operators of `Field` may branch on the values of their operands, as
+/// the prime fields do to reduce sums and differences, which is faster but
+/// leaks timing information.
+pub trait ConstantTimeField: Field + ConstantTimeEq + ConditionallySelectable {
+    /// Returns `self + other`, in time independent of both values.
+    #[must_use]
+    fn ct_add(&self, other: &Self) -> Self;
+
+    /// Returns `self - other`, in time independent of both values.
+    #[must_use]
+    fn ct_sub(&self, other: &Self) -> Self;
+
+    /// Returns `self + self`, in time independent of the value of `self`. By
+    /// default this is `self.ct_add(self)`.
+    #[must_use]
+    fn ct_double(&self) -> Self {
+        self.ct_add(self)
+    }
+
+    /// Returns `-self`, in time independent of the value of `self`. By
+    /// default this is `Self::zero().ct_sub(self)`.
+    #[must_use]
+    fn ct_neg(&self) -> Self {
+        Self::zero().ct_sub(self)
+    }
+
+    /// Returns `self * other`, in time independent of both values.
+    #[must_use]
+    fn ct_mul(&self, other: &Self) -> Self;
+
+    /// Returns `self * self`, in time independent of the value of `self`. By
+    /// default this is `self.ct_mul(self)`.
+    #[must_use]
+    fn ct_square(&self) -> Self {
+        self.ct_mul(self)
+    }
+
+    /// Computes the multiplicative inverse of `self`, or zero if `self` is
+    /// zero, in time independent of the value of `self`. Use this instead of
+    /// `inverse` when `self` is secret.
+    #[must_use]
+    fn ct_inverse(&self) -> Self;
+}
+
 #[derive(Debug, PartialEq)]
 pub enum LegendreSymbol {
     Zero = 0,
@@ -462,15 +544,17 @@ impl<Slice: AsRef<[u64]>> Iterator for BitIteratorLE<Slice> {
 }
 
 use crate::biginteger::{
-    BigInteger256, BigInteger320, BigInteger384, BigInteger768, BigInteger832,
+    BigInteger256, BigInteger320, BigInteger384, BigInteger64, BigInteger768, BigInteger832,
 };
 
+impl_field_bigint_conv!(Fp64, BigInteger64, Fp64Parameters);
 impl_field_bigint_conv!(Fp256, BigInteger256, Fp256Parameters);
 impl_field_bigint_conv!(Fp320, BigInteger320, Fp320Parameters);
 impl_field_bigint_conv!(Fp384, BigInteger384, Fp384Parameters);
 impl_field_bigint_conv!(Fp768, BigInteger768, Fp768Parameters);
 impl_field_bigint_conv!(Fp832, BigInteger832, Fp832Parameters);
 
+impl_prime_field_serializer!(Fp64, Fp64Parameters, 8);
 impl_prime_field_serializer!(Fp256, Fp256Parameters, 32);
 impl_prime_field_serializer!(Fp320, Fp320Parameters, 40);
 impl_prime_field_serializer!(Fp384, Fp384Parameters, 48);
@@ -509,6 +593,33 @@ pub fn batch_inversion<F: Field>(v: &mut [F]) {
     }
 }
 
+/// Inverts every nonzero element of `v` in place, like `batch_inversion`, but
+/// in time that depends only on the length of `v` and not on which of its
+/// elements are zero.
+pub fn batch_inversion_ct<F: ConstantTimeField>(v: &mut [F]) {
+    // First pass: compute [a, ab, abc, ...], multiplying by one in place of
+    // the zero elements.
+    let mut prod = Vec::with_capacity(v.len());
+    let mut tmp = F::one();
+    for f in v.iter() {
+        prod.push(tmp);
+        tmp = tmp.ct_mul(&F::conditional_select(f, &F::one(), f.ct_eq(&F::zero())));
+    }
+
+    // Invert `tmp`, which is nonzero.
+    tmp = tmp.ct_inverse();
+
+    // Second pass: iterate backwards to compute inverses, leaving the zero
+    // elements unchanged.
+    for (f, s) in v.iter_mut().rev().zip(prod.into_iter().rev()) {
+        let is_zero = f.ct_eq(&F::zero());
+        // tmp := tmp * f; f := tmp * s = 1/f
+        let new_tmp = tmp.ct_mul(&F::conditional_select(f, &F::one(), is_zero));
+        f.conditional_assign(&tmp.ct_mul(&s), !is_zero);
+        tmp = new_tmp;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::BitIteratorLE;
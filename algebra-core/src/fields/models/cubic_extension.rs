@@ -1,7 +1,7 @@
 use crate::{
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, ConstantSerializedSize, EmptyFlags, Flags, SerializationError,
-    UniformRand,
+    CanonicalSerializeWithFlags, Choice, ConditionallySelectable, ConstantSerializedSize,
+    ConstantTimeEq, EmptyFlags, Flags, SerializationError, UniformRand,
 };
 use core::{
     cmp::{Ord, Ordering, PartialOrd},
@@ -17,7 +17,7 @@ use rand::{
 
 use crate::{
     bytes::{FromBytes, ToBytes},
-    fields::{Field, PrimeField},
+    fields::{ConstantTimeField, Field, PrimeField},
     io::{Read, Result as IoResult, Write},
     Box, ToConstraintField, Vec,
 };
@@ -264,6 +264,87 @@ impl<P: CubicExtParameters> Field for CubicExtField<P> {
     }
 }
 
+impl<P: CubicExtParameters> ConstantTimeField for CubicExtField<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn ct_add(&self, other: &Self) -> Self {
+        Self::new(
+            self.c0.ct_add(&other.c0),
+            self.c1.ct_add(&other.c1),
+            self.c2.ct_add(&other.c2),
+        )
+    }
+
+    fn ct_sub(&self, other: &Self) -> Self {
+        Self::new(
+            self.c0.ct_sub(&other.c0),
+            self.c1.ct_sub(&other.c1),
+            self.c2.ct_sub(&other.c2),
+        )
+    }
+
+    /// The formula of `mul`, multiplying by `P::NONRESIDUE` instead of calling
+    /// `P::mul_base_field_by_nonresidue`, which may use the operators.
+    fn ct_mul(&self, other: &Self) -> Self {
+        let nr = |x: &P::BaseField| P::NONRESIDUE.ct_mul(x);
+        let (a, b, c) = (self.c0, self.c1, self.c2);
+        let (d, e, f) = (other.c0, other.c1, other.c2);
+
+        let ad = a.ct_mul(&d);
+        let be = b.ct_mul(&e);
+        let cf = c.ct_mul(&f);
+
+        let x = b.ct_add(&c).ct_mul(&e.ct_add(&f)).ct_sub(&be).ct_sub(&cf);
+        let y = a.ct_add(&b).ct_mul(&d.ct_add(&e)).ct_sub(&ad).ct_sub(&be);
+        let z = a
+            .ct_add(&c)
+            .ct_mul(&d.ct_add(&f))
+            .ct_sub(&ad)
+            .ct_add(&be)
+            .ct_sub(&cf);
+
+        Self::new(ad.ct_add(&nr(&x)), y.ct_add(&nr(&cf)), z)
+    }
+
+    /// The formula of `inverse`, which gives zero for zero.
+    fn ct_inverse(&self) -> Self {
+        let nr = |x: &P::BaseField| P::NONRESIDUE.ct_mul(x);
+        let (c0, c1, c2) = (self.c0, self.c1, self.c2);
+
+        let s0 = c0.ct_square().ct_sub(&nr(&c1.ct_mul(&c2)));
+        let s1 = nr(&c2.ct_square()).ct_sub(&c0.ct_mul(&c1));
+        let s2 = c1.ct_square().ct_sub(&c0.ct_mul(&c2));
+
+        let a3 = nr(&c2.ct_mul(&s1).ct_add(&c1.ct_mul(&s2)));
+        let t6 = c0.ct_mul(&s0).ct_add(&a3).ct_inverse();
+
+        Self::new(t6.ct_mul(&s0), t6.ct_mul(&s1), t6.ct_mul(&s2))
+    }
+}
+
+impl<P: CubicExtParameters> ConstantTimeEq for CubicExtField<P>
+where
+    P::BaseField: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+}
+
+impl<P: CubicExtParameters> ConditionallySelectable for CubicExtField<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+            P::BaseField::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
 /// `CubicExtField` elements are ordered lexicographically.
 impl<P: CubicExtParameters> Ord for CubicExtField<P> {
     #[inline(always)]
@@ -484,6 +565,8 @@ impl<P: CubicExtParameters> fmt::Display for CubicExtField<P> {
 }
 
 impl<P: CubicExtParameters> CanonicalSerializeWithFlags for CubicExtField<P> {
+    const SPARE_BITS: usize = <P::BaseField as CanonicalSerializeWithFlags>::SPARE_BITS;
+
     #[inline]
     fn serialize_with_flags<W: Write, F: Flags>(
         &self,
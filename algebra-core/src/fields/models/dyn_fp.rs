@@ -0,0 +1,1132 @@
+//! Prime fields whose modulus is chosen at runtime.
+//!
+//! An element of `DynFp256<P>` (and likewise of the other sizes) lives in the
+//! prime field whose modulus is held by the [`DynFpContext`] that
+//! `P::context()` returns. The context derives the Montgomery constants, the
+//! 2-adicity and a 2-adic root of unity from the modulus, so these fields
+//! implement `PrimeField`, `SquareRootField` and `FftField` like the `Fp*`
+//! types do, and can be used with `ff-fft` domains. A [`DynFpContextCell`]
+//! can hold the context when it is only known at runtime.
+
+use core::{
+    cmp::{Ord, Ordering, PartialOrd},
+    fmt::{Display, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+use num_traits::{One, Zero};
+
+use crate::{
+    biginteger::{
+        arithmetic as fa, BigInteger, BigInteger128, BigInteger256, BigInteger320, BigInteger384,
+        BigInteger64, BigInteger768, BigInteger832,
+    },
+    bytes::{FromBytes, ToBytes},
+    fields::{
+        BitIteratorBE, ConstantTimeField, FftField, Field, LegendreSymbol, PrimeField,
+        SquareRootField,
+    },
+    io::{Read, Result as IoResult, Write},
+    serialize::{
+        buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+        CanonicalSerializeWithFlags, ConstantSerializedSize, EmptyFlags, Flags, SerializationError,
+    },
+    Choice, ConditionallySelectable, ConstantTimeEq,
+};
+
+/// The largest number of limbs of a modulus, that of `BigInteger832`.
+const MAX_LIMBS: usize = 13;
+
+/// The parameters of a prime field, derived from its modulus at runtime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynFpContext<B: BigInteger> {
+    modulus: B,
+    modulus_bits: u32,
+    /// -modulus^{-1} mod 2^64
+    inv: u64,
+    /// 2^(64 * B::NUM_LIMBS) mod modulus, which is one in Montgomery form.
+    r: B,
+    /// r^2 mod modulus
+    r2: B,
+    two_adicity: u32,
+    /// t for 2^two_adicity * t = modulus - 1
+    t: B,
+    /// (t - 1) / 2
+    t_minus_one_div_two: B,
+    /// (modulus - 1) / 2
+    modulus_minus_one_div_two: B,
+    /// The multiplicative generator, in Montgomery form.
+    generator: B,
+    /// generator^t, in Montgomery form.
+    two_adic_root_of_unity: B,
+}
+
+impl<B: BigInteger> DynFpContext<B> {
+    /// Derives the parameters of the field of integers modulo `modulus`, with
+    /// `generator` as the generator of the multiplicative group that
+    /// `multiplicative_generator` returns. Returns `None` if `modulus` is not
+    /// an odd probable prime, if it uses the most significant bit of `B`,
+    /// which additions need as headroom, or if `generator` is not a quadratic
+    /// non-residue modulo `modulus`.
+    ///
+    /// Primality is checked with the Miller-Rabin test for the first twelve
+    /// primes as bases, which is deterministic below 2^78 only. Larger
+    /// composites built to pass these bases are accepted, so a modulus that
+    /// comes from an untrusted source must be proven prime by the caller.
+    ///
+    /// Checking that `generator` generates the whole multiplicative group
+    /// needs the factorization of `modulus - 1`, so only the factor 2 is
+    /// checked, which is all that square roots and FFTs rely on. The caller
+    /// must ensure that `generator` is a primitive root.
+    pub fn new(modulus: B, generator: B) -> Option<Self> {
+        let limbs = B::NUM_LIMBS;
+        let modulus_bits = modulus.num_bits();
+        if limbs > MAX_LIMBS
+            || modulus.is_even()
+            || modulus_bits < 2
+            || modulus_bits as usize >= 64 * limbs
+        {
+            return None;
+        }
+
+        // The inverse of the modulus modulo 2^64, by Newton iteration.
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.as_ref()[0].wrapping_mul(inv)));
+        }
+
+        // R and R^2 modulo the modulus, by repeated doubling of one.
+        let double = |x: &mut B| {
+            x.mul2();
+            if *x >= modulus {
+                x.sub_noborrow(&modulus);
+            }
+        };
+        let mut r = B::from(1);
+        for _ in 0..64 * limbs {
+            double(&mut r);
+        }
+        let mut r2 = r;
+        for _ in 0..64 * limbs {
+            double(&mut r2);
+        }
+
+        let mut modulus_minus_one_div_two = modulus;
+        modulus_minus_one_div_two.div2();
+        let mut t = modulus_minus_one_div_two;
+        let mut two_adicity = 1;
+        while t.is_even() {
+            t.div2();
+            two_adicity += 1;
+        }
+        let mut t_minus_one_div_two = t;
+        t_minus_one_div_two.div2();
+
+        let mut context = Self {
+            modulus,
+            modulus_bits,
+            inv: inv.wrapping_neg(),
+            r,
+            r2,
+            two_adicity,
+            t,
+            t_minus_one_div_two,
+            modulus_minus_one_div_two,
+            generator: B::default(),
+            two_adic_root_of_unity: B::default(),
+        };
+        if !context.is_probable_prime() || generator >= modulus {
+            return None;
+        }
+
+        let generator = context.to_montgomery(&generator);
+        if !context.is_quadratic_non_residue(&generator) {
+            return None;
+        }
+        context.generator = generator;
+        context.two_adic_root_of_unity = context.pow(&generator, context.t.as_ref());
+        Some(context)
+    }
+
+    /// Returns the modulus of the field.
+    pub fn modulus(&self) -> &B {
+        &self.modulus
+    }
+
+    /// Returns the number of bits of the modulus.
+    pub fn modulus_bits(&self) -> u32 {
+        self.modulus_bits
+    }
+
+    /// Returns the 2-adicity of the multiplicative group.
+    pub fn two_adicity(&self) -> u32 {
+        self.two_adicity
+    }
+
+    /// Clears the bits of `x` above the most significant bit of the modulus.
+    fn clear_unused_bits(&self, x: &mut B) {
+        let modulus_bits = self.modulus_bits as usize;
+        for (i, limb) in x.as_mut().iter_mut().enumerate() {
+            if 64 * i >= modulus_bits {
+                *limb = 0;
+            } else if modulus_bits - 64 * i < 64 {
+                *limb &= (1 << (modulus_bits - 64 * i)) - 1;
+            }
+        }
+    }
+
+    fn is_quadratic_non_residue(&self, x: &B) -> bool {
+        self.pow(x, self.modulus_minus_one_div_two.as_ref()) == self.neg(&self.r)
+    }
+
+    fn is_probable_prime(&self) -> bool {
+        let one = self.r;
+        let minus_one = self.neg(&one);
+        [2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]
+            .iter()
+            .all(|&base| {
+                let base = B::from(base);
+                if base >= self.modulus {
+                    return true;
+                }
+                let mut x = self.pow(&self.to_montgomery(&base), self.t.as_ref());
+                if x == one || x == minus_one {
+                    return true;
+                }
+                for _ in 1..self.two_adicity {
+                    x = self.mul(&x, &x);
+                    if x == minus_one {
+                        return true;
+                    }
+                }
+                false
+            })
+    }
+
+    #[inline]
+    fn add_assign(&self, a: &mut B, b: &B) {
+        // This cannot exceed the backing capacity.
+        a.add_nocarry(b);
+        if *a >= self.modulus {
+            a.sub_noborrow(&self.modulus);
+        }
+    }
+
+    #[inline]
+    fn sub_assign(&self, a: &mut B, b: &B) {
+        // If `b` is larger than `a`, add the modulus to `a` first.
+        if *b > *a {
+            a.add_nocarry(&self.modulus);
+        }
+        a.sub_noborrow(b);
+    }
+
+    /// The same as `add_assign`, without branching on the values.
+    #[inline]
+    fn ct_add_assign(&self, a: &mut B, b: &B) {
+        a.add_nocarry(b);
+        self.ct_reduce(a);
+    }
+
+    /// The same as `sub_assign`, without branching on the values.
+    #[inline]
+    fn ct_sub_assign(&self, a: &mut B, b: &B) {
+        let borrow = a.sub_noborrow(b);
+        let mut corrected = *a;
+        corrected.add_nocarry(&self.modulus);
+        conditional_assign(a, &corrected, Choice::from(borrow as u8));
+    }
+
+    /// Subtracts the modulus from `a`, which is less than twice the modulus,
+    /// if `a` is at least the modulus, without branching on `a`.
+    #[inline]
+    fn ct_reduce(&self, a: &mut B) {
+        let mut reduced = *a;
+        let borrow = reduced.sub_noborrow(&self.modulus);
+        conditional_assign(a, &reduced, Choice::from(!borrow as u8));
+    }
+
+    #[inline]
+    fn neg(&self, a: &B) -> B {
+        if a.is_zero() {
+            *a
+        } else {
+            let mut result = self.modulus;
+            result.sub_noborrow(a);
+            result
+        }
+    }
+
+    /// Returns `a * b / R` modulo the modulus, for `a * b < R * modulus`.
+    #[inline]
+    fn mul(&self, a: &B, b: &B) -> B {
+        let mut result = self.montgomery_reduce(&mut Self::product(a, b));
+        if result >= self.modulus {
+            result.sub_noborrow(&self.modulus);
+        }
+        result
+    }
+
+    /// The same as `mul`, without branching on the values.
+    #[inline]
+    fn ct_mul(&self, a: &B, b: &B) -> B {
+        let mut result = self.montgomery_reduce(&mut Self::product(a, b));
+        self.ct_reduce(&mut result);
+        result
+    }
+
+    #[inline]
+    fn product(a: &B, b: &B) -> [u64; 2 * MAX_LIMBS] {
+        let limbs = B::NUM_LIMBS;
+        let (a, b) = (a.as_ref(), b.as_ref());
+        let mut r = [0u64; 2 * MAX_LIMBS];
+        for (i, a) in a.iter().enumerate() {
+            let mut carry = 0;
+            for (j, b) in b.iter().enumerate() {
+                r[i + j] = fa::mac_with_carry(r[i + j], *a, *b, &mut carry);
+            }
+            r[limbs + i] = carry;
+        }
+        r
+    }
+
+    /// Returns `r / R` modulo the modulus, possibly plus the modulus, for
+    /// `r < R * modulus`.
+    #[inline]
+    fn montgomery_reduce(&self, r: &mut [u64; 2 * MAX_LIMBS]) -> B {
+        let limbs = B::NUM_LIMBS;
+        let modulus = self.modulus.as_ref();
+        let mut carry2 = 0;
+        for i in 0..limbs {
+            let k = r[i].wrapping_mul(self.inv);
+            let mut carry = 0;
+            fa::mac_with_carry(r[i], k, modulus[0], &mut carry);
+            for (j, m) in modulus.iter().enumerate().skip(1) {
+                r[i + j] = fa::mac_with_carry(r[i + j], k, *m, &mut carry);
+            }
+            r[limbs + i] = fa::adc(r[limbs + i], carry2, &mut carry);
+            carry2 = carry;
+        }
+        let mut result = B::default();
+        result.as_mut().copy_from_slice(&r[limbs..2 * limbs]);
+        result
+    }
+
+    /// Returns the Montgomery form of `a`, which may be any `B`.
+    #[inline]
+    fn to_montgomery(&self, a: &B) -> B {
+        self.mul(a, &self.r2)
+    }
+
+    #[inline]
+    fn from_montgomery(&self, a: &B) -> B {
+        let mut r = [0u64; 2 * MAX_LIMBS];
+        r[..B::NUM_LIMBS].copy_from_slice(a.as_ref());
+        let mut result = self.montgomery_reduce(&mut r);
+        if result >= self.modulus {
+            result.sub_noborrow(&self.modulus);
+        }
+        result
+    }
+
+    fn pow(&self, a: &B, exp: &[u64]) -> B {
+        let mut result = self.r;
+        for bit in BitIteratorBE::without_leading_zeros(exp) {
+            result = self.mul(&result, &result);
+            if bit {
+                result = self.mul(&result, a);
+            }
+        }
+        result
+    }
+}
+
+/// Sets `a` to `b` if `choice` is set, without branching on `choice`.
+#[inline]
+fn conditional_assign<B: BigInteger>(a: &mut B, b: &B, choice: Choice) {
+    for (a, b) in a.as_mut().iter_mut().zip(b.as_ref()) {
+        a.conditional_assign(b, choice);
+    }
+}
+
+/// A place to keep the context of a `DynFp*` field that is only known at
+/// runtime, for `DynFpParameters::context` to return. The cell must be a
+/// `static`, as `context` returns a `'static` reference.
+///
+/// The context can only be set once, as elements created under one context
+/// must not be used under another. Fields with different moduli need their own
+/// cells and `DynFpParameters`.
+#[cfg(feature = "std")]
+pub struct DynFpContextCell<B: BigInteger>(once_cell::sync::OnceCell<DynFpContext<B>>);
+
+#[cfg(feature = "std")]
+impl<B: BigInteger> DynFpContextCell<B> {
+    /// Returns an empty cell.
+    pub const fn new() -> Self {
+        Self(once_cell::sync::OnceCell::new())
+    }
+
+    /// Sets the context of the field. Returns `context` back if the context
+    /// has already been set.
+    pub fn set(&self, context: DynFpContext<B>) -> Result<(), DynFpContext<B>> {
+        self.0.set(context)
+    }
+
+    /// Returns the context of the field. Once the context is set, this is a
+    /// single atomic load, so it can be called on every field operation.
+    ///
+    /// # Panics
+    /// This method panics if no context has been set.
+    #[inline]
+    pub fn get(&'static self) -> &'static DynFpContext<B> {
+        self.0
+            .get()
+            .expect("the context of a DynFp field must be set before use")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: BigInteger> Default for DynFpContextCell<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The parameters of a prime field whose modulus is chosen at runtime.
+pub trait DynFpParameters: 'static + Send + Sync + Sized {
+    type BigInt: BigInteger;
+
+    /// Returns the context of the field.
+    fn context() -> &'static DynFpContext<Self::BigInt>;
+}
+
+macro_rules! impl_dyn_fp {
+    ($DynFp:ident, $DynFpParameters:ident, $BigInteger:ident, $limbs:expr) => {
+        pub trait $DynFpParameters: DynFpParameters<BigInt = $BigInteger> {}
+
+        impl<P: DynFpParameters<BigInt = $BigInteger>> $DynFpParameters for P {}
+
+        #[derive(Derivative)]
+        #[derivative(
+            Default(bound = ""),
+            Hash(bound = ""),
+            Clone(bound = ""),
+            Copy(bound = ""),
+            Debug(bound = ""),
+            PartialEq(bound = ""),
+            Eq(bound = "")
+        )]
+        pub struct $DynFp<P>(
+            pub $BigInteger,
+            #[derivative(Debug = "ignore")]
+            #[doc(hidden)]
+            pub PhantomData<P>,
+        );
+
+        impl<P> $DynFp<P> {
+            #[inline]
+            pub const fn new(element: $BigInteger) -> Self {
+                Self(element, PhantomData)
+            }
+        }
+
+        impl<P: $DynFpParameters> Zero for $DynFp<P> {
+            #[inline]
+            fn zero() -> Self {
+                Self::new($BigInteger::from(0))
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+        }
+
+        impl<P: $DynFpParameters> One for $DynFp<P> {
+            #[inline]
+            fn one() -> Self {
+                Self::new(P::context().r)
+            }
+
+            #[inline]
+            fn is_one(&self) -> bool {
+                self.0 == P::context().r
+            }
+        }
+
+        impl<P: $DynFpParameters> Field for $DynFp<P> {
+            type BasePrimeField = Self;
+
+            #[inline]
+            fn double(&self) -> Self {
+                let mut temp = *self;
+                temp.double_in_place();
+                temp
+            }
+
+            #[inline]
+            fn double_in_place(&mut self) -> &mut Self {
+                let value = self.0;
+                P::context().add_assign(&mut self.0, &value);
+                self
+            }
+
+            #[inline]
+            fn characteristic<'a>() -> &'a [u64] {
+                P::context().modulus.as_ref()
+            }
+
+            #[inline]
+            fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
+                let mut result_bytes = [0u8; $limbs * 8];
+                for (result_byte, in_byte) in result_bytes.iter_mut().zip(bytes.iter()) {
+                    *result_byte = *in_byte;
+                }
+
+                // The flags are the unused bits of the byte with the most
+                // significant bit of the modulus, and all higher bits are
+                // cleared.
+                let modulus_bits = P::context().modulus_bits as usize;
+                let top_byte = (modulus_bits - 1) / 8;
+                let flags_mask = (0xffu16 << (modulus_bits - 8 * top_byte)) as u8;
+                let flags = result_bytes[top_byte] & flags_mask;
+                result_bytes[top_byte] &= !flags_mask;
+                for b in &mut result_bytes[top_byte + 1..] {
+                    *b = 0;
+                }
+
+                Self::deserialize(&mut &result_bytes[..])
+                    .ok()
+                    .map(|f| (f, flags))
+            }
+
+            #[inline]
+            fn square(&self) -> Self {
+                let mut temp = *self;
+                temp.square_in_place();
+                temp
+            }
+
+            #[inline]
+            fn square_in_place(&mut self) -> &mut Self {
+                self.0 = P::context().mul(&self.0, &self.0);
+                self
+            }
+
+            #[inline]
+            fn inverse(&self) -> Option<Self> {
+                if self.is_zero() {
+                    None
+                } else {
+                    // Guajardo Kumar Paar Pelzl
+                    // Efficient Software-Implementation of Finite Fields with Applications to
+                    // Cryptography
+                    // Algorithm 16 (BEA for Inversion in Fp)
+
+                    let context = P::context();
+                    let one = $BigInteger::from(1);
+
+                    let mut u = self.0;
+                    let mut v = context.modulus;
+                    let mut b = Self::new(context.r2); // Avoids unnecessary reduction step.
+                    let mut c = Self::zero();
+
+                    while u != one && v != one {
+                        while u.is_even() {
+                            u.div2();
+
+                            if b.0.is_odd() {
+                                b.0.add_nocarry(&context.modulus);
+                            }
+                            b.0.div2();
+                        }
+
+                        while v.is_even() {
+                            v.div2();
+
+                            if c.0.is_odd() {
+                                c.0.add_nocarry(&context.modulus);
+                            }
+                            c.0.div2();
+                        }
+
+                        if v < u {
+                            u.sub_noborrow(&v);
+                            b.sub_assign(&c);
+                        } else {
+                            v.sub_noborrow(&u);
+                            c.sub_assign(&b);
+                        }
+                    }
+
+                    if u == one {
+                        Some(b)
+                    } else {
+                        Some(c)
+                    }
+                }
+            }
+
+            fn inverse_in_place(&mut self) -> Option<&mut Self> {
+                if let Some(inverse) = self.inverse() {
+                    *self = inverse;
+                    Some(self)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn frobenius_map(&mut self, _: usize) {
+                // No-op: No effect in a prime field.
+            }
+        }
+
+        impl<P: $DynFpParameters> ConstantTimeField for $DynFp<P> {
+            #[inline]
+            fn ct_add(&self, other: &Self) -> Self {
+                let mut result = self.0;
+                P::context().ct_add_assign(&mut result, &other.0);
+                Self::new(result)
+            }
+
+            #[inline]
+            fn ct_sub(&self, other: &Self) -> Self {
+                let mut result = self.0;
+                P::context().ct_sub_assign(&mut result, &other.0);
+                Self::new(result)
+            }
+
+            #[inline]
+            fn ct_mul(&self, other: &Self) -> Self {
+                Self::new(P::context().ct_mul(&self.0, &other.0))
+            }
+
+            #[inline]
+            fn ct_inverse(&self) -> Self {
+                // Inverting the Montgomery form `aR` gives `a^{-1}R^{-1}`, and
+                // multiplying twice by `R^2` brings it to `a^{-1}R`.
+                let context = P::context();
+                let inverse = crate::fields::bernstein_yang::inverse(
+                    &self.0,
+                    &context.modulus,
+                    context.modulus_bits,
+                );
+                let r2 = Self::new(context.r2);
+                Self::new(inverse).ct_mul(&r2).ct_mul(&r2)
+            }
+        }
+
+        impl<P: $DynFpParameters> ConstantTimeEq for $DynFp<P> {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.0.as_ref().ct_eq(other.0.as_ref())
+            }
+        }
+
+        impl<P: $DynFpParameters> ConditionallySelectable for $DynFp<P> {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                let mut result = a.0;
+                conditional_assign(&mut result, &b.0, choice);
+                Self::new(result)
+            }
+        }
+
+        impl<P: $DynFpParameters> PrimeField for $DynFp<P> {
+            type BigInt = $BigInteger;
+
+            #[inline]
+            fn from_repr(r: $BigInteger) -> Option<Self> {
+                let context = P::context();
+                if r < context.modulus {
+                    Some(Self::new(context.to_montgomery(&r)))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn into_repr(&self) -> $BigInteger {
+                P::context().from_montgomery(&self.0)
+            }
+
+            #[inline]
+            fn modulus() -> $BigInteger {
+                P::context().modulus
+            }
+
+            #[inline]
+            fn size_in_bits() -> usize {
+                P::context().modulus_bits as usize
+            }
+
+            #[inline]
+            fn capacity() -> usize {
+                P::context().modulus_bits as usize - 1
+            }
+
+            #[inline]
+            fn trace() -> $BigInteger {
+                P::context().t
+            }
+
+            #[inline]
+            fn trace_minus_one_div_two() -> $BigInteger {
+                P::context().t_minus_one_div_two
+            }
+
+            #[inline]
+            fn modulus_minus_one_div_two() -> $BigInteger {
+                P::context().modulus_minus_one_div_two
+            }
+        }
+
+        impl<P: $DynFpParameters> FftField for $DynFp<P> {
+            #[inline]
+            fn two_adicity() -> u32 {
+                P::context().two_adicity
+            }
+
+            #[inline]
+            fn two_adic_root_of_unity() -> Self {
+                Self::new(P::context().two_adic_root_of_unity)
+            }
+
+            #[inline]
+            fn large_subgroup_root_of_unity() -> Option<Self> {
+                None
+            }
+
+            #[inline]
+            fn multiplicative_generator() -> Self {
+                Self::new(P::context().generator)
+            }
+        }
+
+        impl<P: $DynFpParameters> SquareRootField for $DynFp<P> {
+            #[inline]
+            fn legendre(&self) -> LegendreSymbol {
+                use crate::fields::LegendreSymbol::*;
+
+                // s = self^((MODULUS - 1) // 2)
+                let s = self.pow(P::context().modulus_minus_one_div_two);
+                if s.is_zero() {
+                    Zero
+                } else if s.is_one() {
+                    QuadraticResidue
+                } else {
+                    QuadraticNonResidue
+                }
+            }
+
+            fn sqrt(&self) -> Option<Self> {
+                use crate::fields::LegendreSymbol::*;
+
+                // Tonelli-Shanks, as in `sqrt_impl!`.
+                match self.legendre() {
+                    Zero => Some(*self),
+                    QuadraticNonResidue => None,
+                    QuadraticResidue => {
+                        let context = P::context();
+                        let mut z = Self::qnr_to_t();
+                        let mut w = self.pow(context.t_minus_one_div_two);
+                        let mut x = w * self;
+                        let mut b = x * &w;
+
+                        let mut v = context.two_adicity as usize;
+                        while !b.is_one() {
+                            let mut k = 0usize;
+
+                            let mut b2k = b;
+                            while !b2k.is_one() {
+                                // invariant: b2k = b^(2^k) after entering this loop
+                                b2k.square_in_place();
+                                k += 1;
+                            }
+
+                            let j = v - k - 1;
+                            w = z;
+                            for _ in 0..j {
+                                w.square_in_place();
+                            }
+
+                            z = w.square();
+                            b *= &z;
+                            x *= &w;
+                            v = k;
+                        }
+
+                        Some(x)
+                    },
+                }
+            }
+
+            fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+                (*self).sqrt().map(|sqrt| {
+                    *self = sqrt;
+                    self
+                })
+            }
+        }
+
+        impl<P: $DynFpParameters> Ord for $DynFp<P> {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.into_repr().cmp(&other.into_repr())
+            }
+        }
+
+        impl<P: $DynFpParameters> PartialOrd for $DynFp<P> {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<P: $DynFpParameters> From<u64> for $DynFp<P> {
+            /// Converts `other`, reduced modulo the modulus.
+            fn from(other: u64) -> Self {
+                // `other * R^2 < R * MODULUS`, so this reduces `other` too.
+                Self::new(P::context().to_montgomery(&$BigInteger::from(other)))
+            }
+        }
+
+        impl<P: $DynFpParameters> From<u128> for $DynFp<P> {
+            /// Converts `other`, reduced modulo the modulus.
+            fn from(other: u128) -> Self {
+                let upper = Self::from((other >> 64) as u64);
+                let lower = Self::from(other as u64);
+                let two_to_64 = Self::from(1u64 << 63).double();
+                upper * &two_to_64 + &lower
+            }
+        }
+
+        impl_dyn_fp_from_int!($DynFp, u32, $DynFpParameters);
+        impl_dyn_fp_from_int!($DynFp, u16, $DynFpParameters);
+        impl_dyn_fp_from_int!($DynFp, u8, $DynFpParameters);
+        impl_dyn_fp_from_int!($DynFp, bool, $DynFpParameters);
+
+        impl_field_bigint_conv!($DynFp, $BigInteger, $DynFpParameters);
+
+        impl<P: $DynFpParameters> rand::distributions::Distribution<$DynFp<P>>
+            for rand::distributions::Standard
+        {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $DynFp<P> {
+                let context = P::context();
+                loop {
+                    let mut tmp: $BigInteger = rng.sample(rand::distributions::Standard);
+                    context.clear_unused_bits(&mut tmp);
+
+                    if tmp < context.modulus {
+                        return $DynFp::new(tmp);
+                    }
+                }
+            }
+        }
+
+        impl<P: $DynFpParameters> ToBytes for $DynFp<P> {
+            #[inline]
+            fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+                self.into_repr().write(writer)
+            }
+        }
+
+        impl<P: $DynFpParameters> FromBytes for $DynFp<P> {
+            #[inline]
+            fn read<R: Read>(reader: R) -> IoResult<Self> {
+                $BigInteger::read(reader).and_then(|b| match $DynFp::from_repr(b) {
+                    Some(f) => Ok(f),
+                    None => Err(crate::error("FromBytes::read failed")),
+                })
+            }
+        }
+
+        impl<P: $DynFpParameters> FromStr for $DynFp<P> {
+            type Err = ();
+
+            /// Interpret a string of numbers as a (congruent) prime field element.
+            /// Does not accept unnecessary leading zeroes or a blank string.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if s.is_empty() || (s.starts_with('0') && s != "0") {
+                    return Err(());
+                }
+
+                let ten = Self::from(10u64);
+                let mut res = Self::zero();
+                for c in s.chars() {
+                    let digit = c.to_digit(10).ok_or(())?;
+                    res.mul_assign(&ten);
+                    res.add_assign(&Self::from(u64::from(digit)));
+                }
+                Ok(res)
+            }
+        }
+
+        impl<P: $DynFpParameters> Display for $DynFp<P> {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                write!(f, stringify!("({})"$DynFp), self.into_repr())
+            }
+        }
+
+        impl<P: $DynFpParameters> Neg for $DynFp<P> {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self::new(P::context().neg(&self.0))
+            }
+        }
+
+        impl<'a, P: $DynFpParameters> Add<&'a $DynFp<P>> for $DynFp<P> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: &Self) -> Self {
+                let mut result = self;
+                result.add_assign(other);
+                result
+            }
+        }
+
+        impl<'a, P: $DynFpParameters> Sub<&'a $DynFp<P>> for $DynFp<P> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: &Self) -> Self {
+                let mut result = self;
+                result.sub_assign(other);
+                result
+            }
+        }
+
+        impl<'a, P: $DynFpParameters> Mul<&'a $DynFp<P>> for $DynFp<P> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: &Self) -> Self {
+                let mut result = self;
+                result.mul_assign(other);
+                result
+            }
+        }
+
+        impl<'a, P: $DynFpParameters> Div<&'a $DynFp<P>> for $DynFp<P> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, other: &Self) -> Self {
+                let mut result = self;
+                result.mul_assign(&other.inverse().unwrap());
+                result
+            }
+        }
+
+        impl_additive_ops_from_ref!($DynFp, $DynFpParameters);
+        impl_multiplicative_ops_from_ref!($DynFp, $DynFpParameters);
+
+        impl<'a, P: $DynFpParameters> AddAssign<&'a Self> for $DynFp<P> {
+            #[inline]
+            fn add_assign(&mut self, other: &Self) {
+                P::context().add_assign(&mut self.0, &other.0);
+            }
+        }
+
+        impl<'a, P: $DynFpParameters> SubAssign<&'a Self> for $DynFp<P> {
+            #[inline]
+            fn sub_assign(&mut self, other: &Self) {
+                P::context().sub_assign(&mut self.0, &other.0);
+            }
+        }
+
+        impl<'a, P: $DynFpParameters> MulAssign<&'a Self> for $DynFp<P> {
+            #[inline]
+            fn mul_assign(&mut self, other: &Self) {
+                self.0 = P::context().mul(&self.0, &other.0);
+            }
+        }
+
+        impl<'a, P: $DynFpParameters> DivAssign<&'a Self> for $DynFp<P> {
+            #[inline]
+            fn div_assign(&mut self, other: &Self) {
+                self.mul_assign(&other.inverse().unwrap());
+            }
+        }
+
+        /// Elements are serialized with all the bytes of the `BigInteger`, as
+        /// the modulus is not known at compile time. The top bit is always
+        /// unused, and flags that do not fit in it take one more byte.
+        impl<P: $DynFpParameters> CanonicalSerializeWithFlags for $DynFp<P> {
+            const SPARE_BITS: usize = 1;
+
+            fn serialize_with_flags<W: Write, F: Flags>(
+                &self,
+                mut writer: W,
+                flags: F,
+            ) -> Result<(), SerializationError> {
+                let output_byte_size = buffer_byte_size(64 * $limbs - 1 + F::len());
+                if output_byte_size > $limbs * 8 + 1 {
+                    return Err(SerializationError::NotEnoughSpace);
+                }
+
+                let mut bytes = [0u8; $limbs * 8 + 1];
+                self.write(&mut bytes[..])?;
+                bytes[output_byte_size - 1] |= flags.u8_bitmask();
+
+                writer.write_all(&bytes[..output_byte_size])?;
+                Ok(())
+            }
+        }
+
+        impl<P: $DynFpParameters> ConstantSerializedSize for $DynFp<P> {
+            const SERIALIZED_SIZE: usize = $limbs * 8;
+            const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+        }
+
+        impl<P: $DynFpParameters> CanonicalSerialize for $DynFp<P> {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+                self.serialize_with_flags(writer, EmptyFlags)
+            }
+
+            #[inline]
+            fn serialized_size(&self) -> usize {
+                Self::SERIALIZED_SIZE
+            }
+        }
+
+        impl<P: $DynFpParameters> CanonicalDeserializeWithFlags for $DynFp<P> {
+            fn deserialize_with_flags<R: Read, F: Flags>(
+                mut reader: R,
+            ) -> Result<(Self, F), SerializationError> {
+                let output_byte_size = buffer_byte_size(64 * $limbs - 1 + F::len());
+                if output_byte_size > $limbs * 8 + 1 {
+                    return Err(SerializationError::NotEnoughSpace);
+                }
+
+                let mut masked_bytes = [0u8; $limbs * 8 + 1];
+                reader.read_exact(&mut masked_bytes[..output_byte_size])?;
+                let flags = F::from_u8_remove_flags(&mut masked_bytes[output_byte_size - 1]);
+                if masked_bytes[$limbs * 8] != 0 {
+                    return Err(SerializationError::InvalidData);
+                }
+
+                Ok((Self::read(&masked_bytes[..$limbs * 8])?, flags))
+            }
+        }
+
+        impl<P: $DynFpParameters> CanonicalDeserialize for $DynFp<P> {
+            #[inline]
+            fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+                Ok(Self::read(reader)?)
+            }
+        }
+    };
+}
+
+macro_rules! impl_dyn_fp_from_int {
+    ($field: ident, $int: ident, $params: ident) => {
+        impl<P: $params> From<$int> for $field<P> {
+            fn from(other: $int) -> Self {
+                Self::from(u64::from(other))
+            }
+        }
+    };
+}
+
+impl_dyn_fp!(DynFp64, DynFp64Parameters, BigInteger64, 1);
+impl_dyn_fp!(DynFp128, DynFp128Parameters, BigInteger128, 2);
+impl_dyn_fp!(DynFp256, DynFp256Parameters, BigInteger256, 4);
+impl_dyn_fp!(DynFp320, DynFp320Parameters, BigInteger320, 5);
+impl_dyn_fp!(DynFp384, DynFp384Parameters, BigInteger384, 6);
+impl_dyn_fp!(DynFp768, DynFp768Parameters, BigInteger768, 12);
+impl_dyn_fp!(DynFp832, DynFp832Parameters, BigInteger832, 13);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::UniformRand;
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    /// The Mersenne prime 2^61 - 1.
+    const MODULUS: u64 = (1 << 61) - 1;
+
+    static CONTEXT: DynFpContextCell<BigInteger64> = DynFpContextCell::new();
+
+    struct Parameters;
+
+    impl DynFpParameters for Parameters {
+        type BigInt = BigInteger64;
+
+        fn context() -> &'static DynFpContext<BigInteger64> {
+            CONTEXT.get()
+        }
+    }
+
+    type F = DynFp64<Parameters>;
+
+    fn context(modulus: u64, generator: u64) -> Option<DynFpContext<BigInteger64>> {
+        DynFpContext::new(BigInteger64::from(modulus), BigInteger64::from(generator))
+    }
+
+    #[test]
+    fn test_context() {
+        // The moduli with their smallest primitive roots.
+        for (p, g) in &[(3, 2), (5, 2), (97, 5), (2013265921, 31), (MODULUS, 37)] {
+            let context = context(*p, *g).unwrap();
+            assert_eq!(context.modulus().0, [*p]);
+            assert_eq!(context.two_adicity(), (*p - 1).trailing_zeros());
+            assert_eq!(context.from_montgomery(&context.r).0, [1]);
+            assert_eq!(context.from_montgomery(&context.generator).0, [*g]);
+        }
+        // Zero, one, even numbers, numbers that use the top bit, and
+        // composites, including a Carmichael number and 3215031751, a strong
+        // pseudoprime to the bases 2, 3, 5 and 7.
+        for n in &[0, 1, 2, 4, 9, 561, 3215031751, 1 << 63 | 1, u64::MAX] {
+            assert!(context(*n, 3).is_none());
+        }
+
+        // 2 is a quadratic residue modulo 2^61 - 1, and generators must be
+        // reduced.
+        assert!(context(MODULUS, 2).is_none());
+        assert!(context(MODULUS, MODULUS + 37).is_none());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        CONTEXT.set(context(MODULUS, 37).unwrap()).unwrap();
+        let p = u128::from(MODULUS);
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        for _ in 0..100 {
+            let a = F::rand(&mut rng);
+            let b = F::rand(&mut rng);
+            let (x, y) = (
+                u128::from(a.into_repr().0[0]),
+                u128::from(b.into_repr().0[0]),
+            );
+            assert!(x < p && y < p);
+
+            assert_eq!(u128::from((a + &b).into_repr().0[0]), (x + y) % p);
+            assert_eq!(u128::from((a - &b).into_repr().0[0]), (x + p - y) % p);
+            assert_eq!(u128::from((a * &b).into_repr().0[0]), x * y % p);
+            assert_eq!(u128::from((-a).into_repr().0[0]), (p - x) % p);
+            assert_eq!(a * &a.inverse().unwrap(), F::one());
+            assert_eq!(a.ct_inverse(), a.inverse().unwrap());
+            let sqrt = a.square().sqrt().unwrap();
+            assert!(sqrt == a || sqrt == -a);
+
+            // Conversions reduce modulo the modulus.
+            let n = u128::from(rng.gen::<u64>()) << 64 | u128::from(rng.gen::<u64>());
+            assert_eq!(u128::from(F::from(n).into_repr().0[0]), n % p);
+        }
+
+        let root = F::two_adic_root_of_unity();
+        assert_eq!(root.pow([1 << F::two_adicity()]), F::one());
+        assert_eq!(root.pow([1 << (F::two_adicity() - 1)]), -F::one());
+        assert_eq!(F::from_str("2305843009213693951").unwrap(), F::zero());
+    }
+}
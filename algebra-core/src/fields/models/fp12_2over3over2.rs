@@ -51,8 +51,16 @@ impl<P: Fp12Parameters> QuadExtParameters for Fp12ParamsWrapper<P> {
         fe.mul_assign_by_fp2(Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD]);
     }
 
+    fn cyclotomic_square(fe: &Fp12<P>) -> Fp12<P> {
+        fe.cyclotomic_square()
+    }
+
     fn cyclotomic_exp(fe: &Fp12<P>, exponent: impl AsRef<[u64]>) -> Fp12<P> {
-        let mut res = QuadExtField::one();
+        if characteristic_square_mod_6_is_one(Fp12::<P>::characteristic()) {
+            return fe.cyclotomic_exp_compressed(exponent);
+        }
+
+        let mut res = Fp12::<P>::one();
         let self_inverse = fe.unitary_inverse();
 
         let mut found_nonzero = false;
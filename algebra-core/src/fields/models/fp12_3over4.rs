@@ -0,0 +1,136 @@
+use super::cubic_extension::*;
+use crate::fields::*;
+use core::marker::PhantomData;
+
+type Fp2Params<P> = <<P as Fp12Parameters>::Fp4Params as Fp4Parameters>::Fp2Params;
+
+pub trait Fp12Parameters: 'static + Send + Sync + Copy {
+    type Fp4Params: Fp4Parameters;
+
+    const NONRESIDUE: Fp4<Self::Fp4Params>;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP12_C1: &'static [Fp4<Self::Fp4Params>];
+    const FROBENIUS_COEFF_FP12_C2: &'static [Fp4<Self::Fp4Params>];
+
+    #[inline(always)]
+    fn mul_fp4_by_nonresidue(fe: &Fp4<Self::Fp4Params>) -> Fp4<Self::Fp4Params> {
+        Self::NONRESIDUE * fe
+    }
+}
+
+pub struct Fp12ParamsWrapper<P: Fp12Parameters>(PhantomData<P>);
+
+impl<P: Fp12Parameters> CubicExtParameters for Fp12ParamsWrapper<P> {
+    type BasePrimeField = <Fp2Params<P> as Fp2Parameters>::Fp;
+    type BaseField = Fp4<P::Fp4Params>;
+    type FrobCoeff = Fp4<P::Fp4Params>;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 12;
+
+    const NONRESIDUE: Self::BaseField = P::NONRESIDUE;
+
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP12_C1;
+    const FROBENIUS_COEFF_C2: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP12_C2;
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField {
+        P::mul_fp4_by_nonresidue(fe)
+    }
+
+    fn mul_base_field_by_frob_coeff(
+        c1: &mut Self::BaseField,
+        c2: &mut Self::BaseField,
+        power: usize,
+    ) {
+        *c1 *= &Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD];
+        *c2 *= &Self::FROBENIUS_COEFF_C2[power % Self::DEGREE_OVER_BASE_PRIME_FIELD];
+    }
+}
+
+pub type Fp12<P> = CubicExtField<Fp12ParamsWrapper<P>>;
+
+impl<P: Fp12Parameters> Fp12<P> {
+    pub fn mul_assign_by_fp4(&mut self, other: Fp4<P::Fp4Params>) {
+        self.c0 *= &other;
+        self.c1 *= &other;
+        self.c2 *= &other;
+    }
+
+    pub fn mul_by_fp(&mut self, element: &<Fp2Params<P> as Fp2Parameters>::Fp) {
+        self.c0.mul_by_fp(&element);
+        self.c1.mul_by_fp(&element);
+        self.c2.mul_by_fp(&element);
+    }
+
+    pub fn mul_by_1(&mut self, c1: &Fp4<P::Fp4Params>) {
+        let mut b_b = self.c1;
+        b_b.mul_assign(c1);
+
+        let mut t1 = *c1;
+        {
+            let mut tmp = self.c1;
+            tmp.add_assign(&self.c2);
+
+            t1.mul_assign(&tmp);
+            t1.sub_assign(&b_b);
+            t1 = P::mul_fp4_by_nonresidue(&t1);
+        }
+
+        let mut t2 = *c1;
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c1);
+
+            t2.mul_assign(&tmp);
+            t2.sub_assign(&b_b);
+        }
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = b_b;
+    }
+
+    pub fn mul_by_01(&mut self, c0: &Fp4<P::Fp4Params>, c1: &Fp4<P::Fp4Params>) {
+        let mut a_a = self.c0;
+        let mut b_b = self.c1;
+        a_a.mul_assign(c0);
+        b_b.mul_assign(c1);
+
+        let mut t1 = *c1;
+        {
+            let mut tmp = self.c1;
+            tmp.add_assign(&self.c2);
+
+            t1.mul_assign(&tmp);
+            t1.sub_assign(&b_b);
+            t1 = P::mul_fp4_by_nonresidue(&t1);
+            t1.add_assign(&a_a);
+        }
+
+        let mut t3 = *c0;
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c2);
+
+            t3.mul_assign(&tmp);
+            t3.sub_assign(&a_a);
+            t3.add_assign(&b_b);
+        }
+
+        let mut t2 = *c0;
+        t2.add_assign(c1);
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c1);
+
+            t2.mul_assign(&tmp);
+            t2.sub_assign(&a_a);
+            t2.sub_assign(&b_b);
+        }
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = t3;
+    }
+}
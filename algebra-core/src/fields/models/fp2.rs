@@ -1,5 +1,5 @@
 use super::quadratic_extension::*;
-use crate::fields::PrimeField;
+use crate::fields::{FftField, PrimeField};
 use core::marker::PhantomData;
 
 pub trait Fp2Parameters: 'static + Send + Sync {
@@ -18,6 +18,21 @@ pub trait Fp2Parameters: 'static + Send + Sync {
     }
 }
 
+/// The FFT parameters of a quadratic extension whose multiplicative group has
+/// a larger 2-adic subgroup than that of the base field, such as the complex
+/// extension of a Mersenne prime field.
+pub trait Fp2FftParameters: Fp2Parameters {
+    /// Let `N` be the order of the multiplicative group of the extension, then
+    /// `N = 2^s * t` for odd `t`, and `TWO_ADICITY = s`.
+    const TWO_ADICITY: u32;
+
+    /// A generator of the multiplicative group of the extension.
+    const GENERATOR: (Self::Fp, Self::Fp);
+
+    /// `GENERATOR^t`, a root of unity of order `2^TWO_ADICITY`.
+    const TWO_ADIC_ROOT_OF_UNITY: (Self::Fp, Self::Fp);
+}
+
 pub struct Fp2ParamsWrapper<P: Fp2Parameters>(PhantomData<P>);
 
 impl<P: Fp2Parameters> QuadExtParameters for Fp2ParamsWrapper<P> {
@@ -49,3 +64,25 @@ impl<P: Fp2Parameters> Fp2<P> {
         self.c1 *= other;
     }
 }
+
+impl<P: Fp2FftParameters> FftField for Fp2<P> {
+    #[inline]
+    fn two_adicity() -> u32 {
+        P::TWO_ADICITY
+    }
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self::new(P::TWO_ADIC_ROOT_OF_UNITY.0, P::TWO_ADIC_ROOT_OF_UNITY.1)
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self::new(P::GENERATOR.0, P::GENERATOR.1)
+    }
+}
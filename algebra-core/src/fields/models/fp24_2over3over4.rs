@@ -0,0 +1,185 @@
+use super::quadratic_extension::*;
+use crate::{
+    fields::{
+        fp12_3over4::{Fp12, Fp12Parameters},
+        Field, Fp2Parameters, Fp4, Fp4Parameters,
+    },
+    Zero,
+};
+use core::marker::PhantomData;
+use core::ops::{AddAssign, SubAssign};
+
+type Fp4Params<P> = <<P as Fp24Parameters>::Fp12Params as Fp12Parameters>::Fp4Params;
+type Fp2Params<P> = <Fp4Params<P> as Fp4Parameters>::Fp2Params;
+
+pub trait Fp24Parameters: 'static + Send + Sync + Copy {
+    type Fp12Params: Fp12Parameters;
+
+    /// This *must* equal (0, 1, 0).
+    const NONRESIDUE: Fp12<Self::Fp12Params>;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP24_C1: &'static [Fp4<Fp4Params<Self>>];
+
+    /// Multiply by quadratic nonresidue w.
+    #[inline(always)]
+    fn mul_fp12_by_nonresidue(fe: &Fp12<Self::Fp12Params>) -> Fp12<Self::Fp12Params> {
+        let new_c0 = Self::Fp12Params::mul_fp4_by_nonresidue(&fe.c2);
+        let new_c1 = fe.c0;
+        let new_c2 = fe.c1;
+        Fp12::new(new_c0, new_c1, new_c2)
+    }
+}
+
+pub struct Fp24ParamsWrapper<P: Fp24Parameters>(PhantomData<P>);
+
+impl<P: Fp24Parameters> QuadExtParameters for Fp24ParamsWrapper<P> {
+    type BasePrimeField = <Fp2Params<P> as Fp2Parameters>::Fp;
+    type BaseField = Fp12<P::Fp12Params>;
+    type FrobCoeff = Fp4<Fp4Params<P>>;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 24;
+
+    const NONRESIDUE: Self::BaseField = P::NONRESIDUE;
+
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP24_C1;
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField {
+        P::mul_fp12_by_nonresidue(fe)
+    }
+
+    fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize) {
+        fe.mul_assign_by_fp4(Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD]);
+    }
+
+    fn cyclotomic_square(fe: &Fp24<P>) -> Fp24<P> {
+        fe.cyclotomic_square()
+    }
+
+    fn cyclotomic_exp(fe: &Fp24<P>, exponent: impl AsRef<[u64]>) -> Fp24<P> {
+        // The cyclotomic subgroup is the torus `T6` over `Fp4`, whose order
+        // `p^4` is 1 mod 6 as `Fp12` needs a cubic non-residue of `Fp4`.
+        fe.cyclotomic_exp_compressed(exponent)
+    }
+}
+
+pub type Fp24<P> = QuadExtField<Fp24ParamsWrapper<P>>;
+
+impl<P: Fp24Parameters> Fp24<P> {
+    pub fn mul_by_fp(&mut self, element: &<Fp2Params<P> as Fp2Parameters>::Fp) {
+        self.c0.mul_by_fp(&element);
+        self.c1.mul_by_fp(&element);
+    }
+
+    pub fn mul_by_034(
+        &mut self,
+        c0: &Fp4<Fp4Params<P>>,
+        c3: &Fp4<Fp4Params<P>>,
+        c4: &Fp4<Fp4Params<P>>,
+    ) {
+        let a0 = self.c0.c0 * c0;
+        let a1 = self.c0.c1 * c0;
+        let a2 = self.c0.c2 * c0;
+        let a = Fp12::new(a0, a1, a2);
+        let mut b = self.c1;
+        b.mul_by_01(&c3, &c4);
+
+        let c0 = *c0 + c3;
+        let c1 = c4;
+        let mut e = self.c0 + &self.c1;
+        e.mul_by_01(&c0, &c1);
+        self.c1 = e - &(a + &b);
+        self.c0 = a + &P::mul_fp12_by_nonresidue(&b);
+    }
+
+    pub fn mul_by_014(
+        &mut self,
+        c0: &Fp4<Fp4Params<P>>,
+        c1: &Fp4<Fp4Params<P>>,
+        c4: &Fp4<Fp4Params<P>>,
+    ) {
+        let mut aa = self.c0;
+        aa.mul_by_01(c0, c1);
+        let mut bb = self.c1;
+        bb.mul_by_1(c4);
+        let mut o = *c1;
+        o.add_assign(c4);
+        self.c1.add_assign(&self.c0);
+        self.c1.mul_by_01(c0, &o);
+        self.c1.sub_assign(&aa);
+        self.c1.sub_assign(&bb);
+        self.c0 = bb;
+        self.c0 = P::mul_fp12_by_nonresidue(&self.c0);
+        self.c0.add_assign(&aa);
+    }
+
+    /// Squares `self`, which must be in the cyclotomic subgroup, with the
+    /// method of Granger and Scott for `Fp12` in `fp12_2over3over2`, working
+    /// over `Fp4` instead of `Fp2`.
+    pub fn cyclotomic_square(&self) -> Self {
+        let mut result = Self::zero();
+        let fp4_nr = <P::Fp12Params as Fp12Parameters>::mul_fp4_by_nonresidue;
+
+        let mut z0 = self.c0.c0;
+        let mut z4 = self.c0.c1;
+        let mut z3 = self.c0.c2;
+        let mut z2 = self.c1.c0;
+        let mut z1 = self.c1.c1;
+        let mut z5 = self.c1.c2;
+
+        // t0 + t1*y = (z0 + z1*y)^2 = a^2
+        let mut tmp = z0 * &z1;
+        let t0 = (z0 + &z1) * &(z0 + &fp4_nr(&z1)) - &tmp - &fp4_nr(&tmp);
+        let t1 = tmp.double();
+
+        // t2 + t3*y = (z2 + z3*y)^2 = b^2
+        tmp = z2 * &z3;
+        let t2 = (z2 + &z3) * &(z2 + &fp4_nr(&z3)) - &tmp - &fp4_nr(&tmp);
+        let t3 = tmp.double();
+
+        // t4 + t5*y = (z4 + z5*y)^2 = c^2
+        tmp = z4 * &z5;
+        let t4 = (z4 + &z5) * &(z4 + &fp4_nr(&z5)) - &tmp - &fp4_nr(&tmp);
+        let t5 = tmp.double();
+
+        // for A
+
+        // z0 = 3 * t0 - 2 * z0
+        z0 = t0 - &z0;
+        z0 = z0 + &z0;
+        result.c0.c0 = z0 + &t0;
+
+        // z1 = 3 * t1 + 2 * z1
+        z1 = t1 + &z1;
+        z1 = z1 + &z1;
+        result.c1.c1 = z1 + &t1;
+
+        // for B
+
+        // z2 = 3 * (xi * t5) + 2 * z2
+        tmp = fp4_nr(&t5);
+        z2 = tmp + &z2;
+        z2 = z2 + &z2;
+        result.c1.c0 = z2 + &tmp;
+
+        // z3 = 3 * t4 - 2 * z3
+        z3 = t4 - &z3;
+        z3 = z3 + &z3;
+        result.c0.c2 = z3 + &t4;
+
+        // for C
+
+        // z4 = 3 * t2 - 2 * z4
+        z4 = t2 - &z4;
+        z4 = z4 + &z4;
+        result.c0.c1 = z4 + &t2;
+
+        // z5 = 3 * t3 + 2 * z5
+        z5 = t3 + &z5;
+        z5 = z5 + &z5;
+        result.c1.c2 = z5 + &t3;
+
+        result
+    }
+}
@@ -0,0 +1,605 @@
+//! Prime fields whose modulus fits in a single 64-bit limb.
+//!
+//! Unlike the `Fp*` types built with `impl_Fp!`, `Fp64` stores elements in
+//! canonical form rather than Montgomery form, and reduces products with
+//! `Fp64Parameters::reduce`, which parameters for special primes such as
+//! Goldilocks or Mersenne-31 can override with a cheaper reduction. As a
+//! consequence, `GENERATOR` and `TWO_ADIC_ROOT_OF_UNITY` are given in
+//! canonical form, and `Fp64Parameters` has no Montgomery constants.
+
+use core::{
+    cmp::{Ord, Ordering, PartialOrd},
+    fmt::{Display, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+use num_traits::{One, Zero};
+
+use crate::{
+    biginteger::{BigInteger as _BigInteger, BigInteger64},
+    bytes::{FromBytes, ToBytes},
+    fields::{
+        BitIteratorBE, ConstantTimeField, FftField, FftParameters, Field, LegendreSymbol,
+        PrimeField, SquareRootField,
+    },
+    io::{Read, Result as IoResult, Write},
+    serialize::CanonicalDeserialize,
+    Choice, ConditionallySelectable, ConstantTimeEq,
+};
+
+/// The parameters of an `Fp64` field: those of `FpParameters`, without the
+/// Montgomery constants `R`, `R2` and `INV`.
+pub trait Fp64Parameters: FftParameters<BigInt = BigInteger64> {
+    /// The modulus of the field.
+    const MODULUS: BigInteger64;
+
+    /// The number of bits needed to represent the `Self::MODULUS`.
+    const MODULUS_BITS: u32;
+
+    /// The number of bits that must be shaved from the beginning of
+    /// the representation when randomly sampling.
+    const REPR_SHAVE_BITS: u32;
+
+    /// A multiplicative generator of the field, in canonical form.
+    const GENERATOR: BigInteger64;
+
+    /// The number of bits that can be reliably stored.
+    /// (Should equal `SELF::MODULUS_BITS - 1`)
+    const CAPACITY: u32;
+
+    /// t for 2^s * t = MODULUS - 1
+    const T: BigInteger64;
+
+    /// (t - 1) / 2
+    const T_MINUS_ONE_DIV_TWO: BigInteger64;
+
+    /// (Self::MODULUS - 1) / 2
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger64;
+
+    /// floor(2^128 / MODULUS), used by the default Barrett reduction.
+    const BARRETT: u128 = u128::MAX / (Self::MODULUS.0[0] as u128);
+
+    /// Reduces `x`, which is less than `MODULUS^2`, modulo `MODULUS`.
+    ///
+    /// The default is a Barrett reduction, whose estimated quotient
+    /// floor(x * BARRETT / 2^128) is at most one too small.
+    #[inline(always)]
+    fn reduce(x: u128) -> u64 {
+        let modulus = u128::from(Self::MODULUS.0[0]);
+        let q = mul_high(x, Self::BARRETT);
+        let mut r = x - q * modulus;
+        if r >= modulus {
+            r -= modulus;
+        }
+        r as u64
+    }
+}
+
+/// Returns the high 128 bits of the 256-bit product `a * b`.
+#[inline(always)]
+fn mul_high(a: u128, b: u128) -> u128 {
+    let (a0, a1) = (a as u64 as u128, a >> 64);
+    let (b0, b1) = (b as u64 as u128, b >> 64);
+    let lo = a0 * b0;
+    let mid0 = a0 * b1;
+    let mid1 = a1 * b0;
+    let carry = ((lo >> 64) + (mid0 as u64 as u128) + (mid1 as u64 as u128)) >> 64;
+    a1 * b1 + (mid0 >> 64) + (mid1 >> 64) + carry
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = ""),
+    Hash(bound = ""),
+    Clone(bound = ""),
+    Copy(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+pub struct Fp64<P>(
+    pub BigInteger64,
+    #[derivative(Debug = "ignore")]
+    #[doc(hidden)]
+    pub PhantomData<P>,
+);
+
+impl<P> Fp64<P> {
+    #[inline]
+    pub const fn new(element: BigInteger64) -> Self {
+        Self(element, PhantomData)
+    }
+}
+
+impl<P: Fp64Parameters> Fp64<P> {
+    #[inline]
+    pub(crate) fn is_valid(&self) -> bool {
+        self.0 < P::MODULUS
+    }
+
+    #[inline(always)]
+    fn modulus() -> u64 {
+        P::MODULUS.0[0]
+    }
+}
+
+impl<P: Fp64Parameters> Zero for Fp64<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(BigInteger64::from(0))
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: Fp64Parameters> One for Fp64<P> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(BigInteger64::from(1))
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        (self.0).0[0] == 1
+    }
+}
+
+impl<P: Fp64Parameters> Field for Fp64<P> {
+    type BasePrimeField = Self;
+
+    #[inline]
+    fn double(&self) -> Self {
+        let mut temp = *self;
+        temp.double_in_place();
+        temp
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        let value = *self;
+        self.add_assign(&value);
+        self
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::MODULUS.as_ref()
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
+        let mut result_bytes = [0u8; 8];
+        for (result_byte, in_byte) in result_bytes.iter_mut().zip(bytes.iter()) {
+            *result_byte = *in_byte;
+        }
+
+        let mask: u64 = 0xffffffffffffffff >> P::REPR_SHAVE_BITS;
+        // the flags will be at the same byte with the lowest shaven bits or the one after
+        let flags_byte_position: usize = 7 - P::REPR_SHAVE_BITS as usize / 8;
+        let flags_mask = (0xffu16 << (8 - P::REPR_SHAVE_BITS % 8)) as u8;
+        let mut flags: u8 = 0;
+        for (i, (b, m)) in result_bytes.iter_mut().zip(&mask.to_le_bytes()).enumerate() {
+            if i == flags_byte_position {
+                flags = *b & flags_mask
+            }
+            *b &= m;
+        }
+
+        Self::deserialize(&mut &result_bytes[..])
+            .ok()
+            .map(|f| (f, flags))
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut temp = *self;
+        temp.square_in_place();
+        temp
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        let value = *self;
+        self.mul_assign(&value);
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Fermat's little theorem.
+            Some(self.pow([Self::modulus() - 2]))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {
+        // No-op: No effect in a prime field.
+    }
+}
+
+impl<P: Fp64Parameters> ConstantTimeField for Fp64<P> {
+    #[inline]
+    fn ct_add(&self, other: &Self) -> Self {
+        let (sum, carry) = (self.0).0[0].overflowing_add((other.0).0[0]);
+        let (reduced, borrow) = sum.overflowing_sub(Self::modulus());
+        Self::new(BigInteger64::from(u64::conditional_select(
+            &sum,
+            &reduced,
+            Choice::from((carry | !borrow) as u8),
+        )))
+    }
+
+    #[inline]
+    fn ct_sub(&self, other: &Self) -> Self {
+        let (difference, borrow) = (self.0).0[0].overflowing_sub((other.0).0[0]);
+        Self::new(BigInteger64::from(u64::conditional_select(
+            &difference,
+            &difference.wrapping_add(Self::modulus()),
+            Choice::from(borrow as u8),
+        )))
+    }
+
+    /// Reduces the product with the default Barrett reduction rather than
+    /// `P::reduce`, which may branch.
+    #[inline]
+    fn ct_mul(&self, other: &Self) -> Self {
+        let modulus = u128::from(Self::modulus());
+        let x = u128::from((self.0).0[0]) * u128::from((other.0).0[0]);
+        let r = x - mul_high(x, P::BARRETT) * modulus;
+        let (reduced, borrow) = r.overflowing_sub(modulus);
+        let r =
+            u64::conditional_select(&(r as u64), &(reduced as u64), Choice::from(!borrow as u8));
+        Self::new(BigInteger64::from(r))
+    }
+
+    #[inline]
+    fn ct_inverse(&self) -> Self {
+        // Fermat's little theorem, with the public exponent `MODULUS - 2`.
+        let mut result = Self::one();
+        for bit in BitIteratorBE::without_leading_zeros([Self::modulus() - 2]) {
+            result = result.ct_square();
+            if bit {
+                result = result.ct_mul(self);
+            }
+        }
+        result
+    }
+}
+
+impl<P: Fp64Parameters> ConstantTimeEq for Fp64<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (self.0).0[0].ct_eq(&(other.0).0[0])
+    }
+}
+
+impl<P: Fp64Parameters> ConditionallySelectable for Fp64<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(BigInteger64::from(u64::conditional_select(
+            &(a.0).0[0],
+            &(b.0).0[0],
+            choice,
+        )))
+    }
+}
+
+impl<P: Fp64Parameters> PrimeField for Fp64<P> {
+    type BigInt = BigInteger64;
+
+    #[inline]
+    fn from_repr(r: BigInteger64) -> Option<Self> {
+        let r = Self::new(r);
+        if r.is_valid() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn into_repr(&self) -> BigInteger64 {
+        self.0
+    }
+
+    #[inline]
+    fn modulus() -> BigInteger64 {
+        P::MODULUS
+    }
+
+    #[inline]
+    fn size_in_bits() -> usize {
+        P::MODULUS_BITS as usize
+    }
+
+    #[inline]
+    fn capacity() -> usize {
+        P::CAPACITY as usize
+    }
+
+    #[inline]
+    fn trace() -> BigInteger64 {
+        P::T
+    }
+
+    #[inline]
+    fn trace_minus_one_div_two() -> BigInteger64 {
+        P::T_MINUS_ONE_DIV_TWO
+    }
+
+    #[inline]
+    fn modulus_minus_one_div_two() -> BigInteger64 {
+        P::MODULUS_MINUS_ONE_DIV_TWO
+    }
+}
+
+impl<P: Fp64Parameters> FftField for Fp64<P> {
+    #[inline]
+    fn two_adicity() -> u32 {
+        P::TWO_ADICITY
+    }
+
+    #[inline]
+    fn small_subgroup_base() -> Option<u32> {
+        P::SMALL_SUBGROUP_BASE
+    }
+
+    #[inline]
+    fn small_subgroup_base_adicity() -> Option<u32> {
+        P::SMALL_SUBGROUP_BASE_ADICITY
+    }
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self::new(P::TWO_ADIC_ROOT_OF_UNITY)
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        Some(Self::new(P::LARGE_SUBGROUP_ROOT_OF_UNITY?))
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self::new(P::GENERATOR)
+    }
+}
+
+impl<P: Fp64Parameters> SquareRootField for Fp64<P> {
+    #[inline]
+    fn legendre(&self) -> LegendreSymbol {
+        use crate::fields::LegendreSymbol::*;
+
+        // s = self^((MODULUS - 1) // 2)
+        let s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
+        if s.is_zero() {
+            Zero
+        } else if s.is_one() {
+            QuadraticResidue
+        } else {
+            QuadraticNonResidue
+        }
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        sqrt_impl!(Self, P, self)
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        (*self).sqrt().map(|sqrt| {
+            *self = sqrt;
+            self
+        })
+    }
+}
+
+impl<P: Fp64Parameters> Ord for Fp64<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<P: Fp64Parameters> PartialOrd for Fp64<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp64Parameters> From<u64> for Fp64<P> {
+    /// Converts `other`, reduced modulo the modulus.
+    fn from(other: u64) -> Self {
+        Self::new(BigInteger64::from(other % Self::modulus()))
+    }
+}
+
+impl<P: Fp64Parameters> From<u128> for Fp64<P> {
+    /// Converts `other`, reduced modulo the modulus.
+    fn from(other: u128) -> Self {
+        let upper = Self::from((other >> 64) as u64);
+        let lower = Self::from(other as u64);
+        // 2^64 = (2^64 - 1) + 1.
+        let two_to_64 = Self::from(u64::MAX) + &Self::one();
+        upper * &two_to_64 + &lower
+    }
+}
+
+macro_rules! impl_fp64_from_int {
+    ($int: ident) => {
+        impl<P: Fp64Parameters> From<$int> for Fp64<P> {
+            fn from(other: $int) -> Self {
+                Self::from(u64::from(other))
+            }
+        }
+    };
+}
+
+impl_fp64_from_int!(u32);
+impl_fp64_from_int!(u16);
+impl_fp64_from_int!(u8);
+impl_fp64_from_int!(bool);
+
+impl_prime_field_standard_sample!(Fp64, Fp64Parameters);
+
+impl<P: Fp64Parameters> ToBytes for Fp64<P> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl<P: Fp64Parameters> FromBytes for Fp64<P> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        BigInteger64::read(reader).and_then(|b| match Fp64::from_repr(b) {
+            Some(f) => Ok(f),
+            None => Err(crate::error("FromBytes::read failed")),
+        })
+    }
+}
+
+impl<P: Fp64Parameters> FromStr for Fp64<P> {
+    type Err = ();
+
+    /// Interpret a string of numbers as a (congruent) prime field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || (s.starts_with('0') && s != "0") {
+            return Err(());
+        }
+
+        let ten = Self::from(10u64);
+        let mut res = Self::zero();
+        for c in s.chars() {
+            let digit = c.to_digit(10).ok_or(())?;
+            res.mul_assign(&ten);
+            res.add_assign(&Self::from(u64::from(digit)));
+        }
+        Ok(res)
+    }
+}
+
+impl<P: Fp64Parameters> Display for Fp64<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Fp64({})", self.0)
+    }
+}
+
+impl<P: Fp64Parameters> Neg for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            Self::new(BigInteger64::from(Self::modulus() - (self.0).0[0]))
+        }
+    }
+}
+
+impl<'a, P: Fp64Parameters> Add<&'a Fp64<P>> for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp64Parameters> Sub<&'a Fp64<P>> for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp64Parameters> Mul<&'a Fp64<P>> for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp64Parameters> Div<&'a Fp64<P>> for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl_additive_ops_from_ref!(Fp64, Fp64Parameters);
+impl_multiplicative_ops_from_ref!(Fp64, Fp64Parameters);
+
+impl<'a, P: Fp64Parameters> AddAssign<&'a Self> for Fp64<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // The sum may not fit in 64 bits if the modulus does not leave a
+        // spare bit.
+        let (sum, carry) = (self.0).0[0].overflowing_add((other.0).0[0]);
+        (self.0).0[0] = if carry || sum >= Self::modulus() {
+            sum.wrapping_sub(Self::modulus())
+        } else {
+            sum
+        };
+    }
+}
+
+impl<'a, P: Fp64Parameters> SubAssign<&'a Self> for Fp64<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        let (difference, borrow) = (self.0).0[0].overflowing_sub((other.0).0[0]);
+        (self.0).0[0] = if borrow {
+            difference.wrapping_add(Self::modulus())
+        } else {
+            difference
+        };
+    }
+}
+
+impl<'a, P: Fp64Parameters> MulAssign<&'a Self> for Fp64<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        (self.0).0[0] = P::reduce(u128::from((self.0).0[0]) * u128::from((other.0).0[0]));
+    }
+}
+
+impl<'a, P: Fp64Parameters> DivAssign<&'a Self> for Fp64<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
@@ -44,6 +44,11 @@ impl<P: Fp6Parameters> QuadExtParameters for Fp6ParamsWrapper<P> {
     fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize) {
         fe.mul_assign_by_fp(&Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD]);
     }
+
+    fn cyclotomic_exp(fe: &Fp6<P>, exponent: impl AsRef<[u64]>) -> Fp6<P> {
+        // `Fp3` needs a cubic non-residue, so the characteristic is 1 mod 6.
+        fe.cyclotomic_exp_compressed(exponent)
+    }
 }
 
 pub type Fp6<P> = QuadExtField<Fp6ParamsWrapper<P>>;
@@ -14,9 +14,13 @@ use crate::{
         BigInteger768, BigInteger832,
     },
     bytes::{FromBytes, ToBytes},
-    fields::{FftField, Field, FpParameters, LegendreSymbol, PrimeField, SquareRootField},
+    fields::{
+        ConstantTimeField, FftField, Field, FpParameters, LegendreSymbol, PrimeField,
+        SquareRootField,
+    },
     io::{Read, Result as IoResult, Write},
     serialize::CanonicalDeserialize,
+    Choice, ConditionallySelectable, ConstantTimeEq,
 };
 
 #[cfg(use_asm)]
@@ -31,6 +35,12 @@ impl_Fp!(Fp384, Fp384Parameters, BigInteger384, BigInteger384, 6);
 impl_Fp!(Fp768, Fp768Parameters, BigInteger768, BigInteger768, 12);
 impl_Fp!(Fp832, Fp832Parameters, BigInteger832, BigInteger832, 13);
 
+pub mod dyn_fp;
+pub use self::dyn_fp::*;
+
+pub mod fp64;
+pub use self::fp64::*;
+
 pub mod fp2;
 pub use self::fp2::*;
 
@@ -48,8 +58,16 @@ pub use self::fp6_3over2::*;
 pub mod fp12_2over3over2;
 pub use self::fp12_2over3over2::*;
 
+pub mod fp12_3over4;
+
+pub mod fp24_2over3over4;
+pub use self::fp24_2over3over4::*;
+
 pub mod quadratic_extension;
 pub use quadratic_extension::*;
 
 pub mod cubic_extension;
 pub use cubic_extension::*;
+
+pub mod torus;
+pub use torus::*;
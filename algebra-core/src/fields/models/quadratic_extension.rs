@@ -1,8 +1,8 @@
 use crate::{
     io::{Read, Result as IoResult, Write},
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, ConstantSerializedSize, EmptyFlags, Flags, SerializationError,
-    UniformRand,
+    CanonicalSerializeWithFlags, Choice, ConditionallySelectable, ConstantSerializedSize,
+    ConstantTimeEq, EmptyFlags, Flags, SerializationError, UniformRand,
 };
 use core::{
     cmp::{Ord, Ordering, PartialOrd},
@@ -17,8 +17,12 @@ use rand::{
 };
 
 use crate::{
+    biginteger::arithmetic::find_wnaf,
     bytes::{FromBytes, ToBytes},
-    fields::{Field, LegendreSymbol, PrimeField, SquareRootField},
+    fields::{
+        batch_inversion, ConstantTimeField, CubicExtField, CubicExtParameters,
+        CyclotomicMultSubgroup, Field, LegendreSymbol, PrimeField, SquareRootField,
+    },
     Box, ToConstraintField, Vec,
 };
 
@@ -52,6 +56,12 @@ pub trait QuadExtParameters: 'static + Send + Sync + Sized {
     /// the appropriate Frobenius coefficient.
     fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize);
 
+    /// A specializable method for squaring that is to be used *only* when
+    /// `fe` is known to be in the cyclotomic subgroup.
+    fn cyclotomic_square(fe: &QuadExtField<Self>) -> QuadExtField<Self> {
+        fe.square()
+    }
+
     /// A specializable method for exponentiating that is to be used
     /// *only* when `fe` is known to be in the cyclotommic subgroup.
     fn cyclotomic_exp(fe: &QuadExtField<Self>, exponent: impl AsRef<[u64]>) -> QuadExtField<Self> {
@@ -59,7 +69,7 @@ pub trait QuadExtParameters: 'static + Send + Sync + Sized {
         let self_inverse = fe.unitary_inverse();
 
         let mut found_nonzero = false;
-        let naf = crate::biginteger::arithmetic::find_wnaf(exponent.as_ref());
+        let naf = find_wnaf(exponent.as_ref());
 
         for &value in naf.iter().rev() {
             if found_nonzero {
@@ -132,8 +142,130 @@ impl<P: QuadExtParameters> QuadExtField<P> {
     }
 
     pub fn mul_assign_by_basefield(&mut self, element: &P::BaseField) {
-        self.c0.mul_assign(&element);
-        self.c1.mul_assign(&element);
+        self.c0.mul_assign(element);
+        self.c1.mul_assign(element);
+    }
+}
+
+/// Karabina's compressed squaring in the cyclotomic subgroup of sextic
+/// extensions `F[v, w]/(v^3 - xi, w^2 - v)`, such as `Fp12` over `Fp2` and the
+/// `Fp6` of MNT6 and BW6 over `Fp`, see
+/// [[Kar12]](https://eprint.iacr.org/2010/542.pdf).
+///
+/// Writing an element as `(g0 + g1 * v + g2 * v^2) + (g3 + g4 * v + g5 * v^2) * w`,
+/// the compressed form only keeps `g1, g2, g3, g5`, from which squares can be
+/// computed and `g0, g4` recovered with one division. The compressed form is
+/// stored in a `QuadExtField` whose `c0.c0` and `c1.c1` are ignored.
+impl<P, Q> QuadExtField<P>
+where
+    P: QuadExtParameters<BaseField = CubicExtField<Q>>,
+    Q: CubicExtParameters,
+{
+    /// Squares the compressed form of `self`, which must be in the cyclotomic
+    /// subgroup. The result is in compressed form, with `c0.c0` and `c1.c1`
+    /// set to zero.
+    pub fn cyclotomic_square_compressed(&self) -> Self {
+        let nr = Q::mul_base_field_by_nonresidue;
+        let (g1, g2, g3, g5) = (self.c0.c1, self.c0.c2, self.c1.c0, self.c1.c2);
+
+        let g1_square = g1.square();
+        let g2_square = g2.square();
+        let g3_square = g3.square();
+        let g5_square = g5.square();
+        // 2 * g1 * g5 and 2 * g2 * g3
+        let g1_g5 = (g1 + &g5).square() - &g1_square - &g5_square;
+        let g2_g3 = (g2 + &g3).square() - &g2_square - &g3_square;
+
+        // h1 = 3 * (g3^2 + xi * g2^2) - 2 * g1
+        let t = g3_square + &nr(&g2_square);
+        let h1 = (t - &g1).double() + &t;
+        // h2 = 3 * (g1^2 + xi * g5^2) - 2 * g2
+        let t = g1_square + &nr(&g5_square);
+        let h2 = (t - &g2).double() + &t;
+        // h3 = 6 * xi * g1 * g5 + 2 * g3
+        let t = nr(&g1_g5);
+        let h3 = (t + &g3).double() + &t;
+        // h5 = 6 * g2 * g3 + 2 * g5
+        let h5 = (g2_g3 + &g5).double() + &g2_g3;
+
+        let zero = Q::BaseField::zero();
+        Self::new(
+            CubicExtField::new(zero, h1, h2),
+            CubicExtField::new(h3, zero, h5),
+        )
+    }
+
+    /// Recovers `g0` and `g4` of the compressed form `self` of an element of
+    /// the cyclotomic subgroup.
+    pub fn decompress_karabina(&self) -> Self {
+        let mut result = [*self];
+        Self::batch_decompress_karabina(&mut result);
+        result[0]
+    }
+
+    /// Recovers `g0` and `g4` of each compressed element of `v`, with a single
+    /// inversion.
+    pub fn batch_decompress_karabina(v: &mut [Self]) {
+        let nr = Q::mul_base_field_by_nonresidue;
+        let mut numerators = Vec::with_capacity(v.len());
+        let mut denominators = Vec::with_capacity(v.len());
+        for f in v.iter() {
+            let (g1, g2, g3, g5) = (f.c0.c1, f.c0.c2, f.c1.c0, f.c1.c2);
+            if g3.is_zero() {
+                // g4 = 2 * g1 * g5 / g2
+                numerators.push((g1 * &g5).double());
+                denominators.push(g2);
+            } else {
+                // g4 = (xi * g5^2 + 3 * g1^2 - 2 * g2) / (4 * g3)
+                let g1_square = g1.square();
+                numerators.push(nr(&g5.square()) + &(g1_square - &g2).double() + &g1_square);
+                denominators.push(g3.double().double());
+            }
+        }
+        // When g2 = g3 = 0, the element is one and g4 = 0, which is what we
+        // get as `batch_inversion` leaves zeros untouched.
+        batch_inversion(&mut denominators);
+
+        for (f, (numerator, denominator)) in v.iter_mut().zip(numerators.iter().zip(&denominators))
+        {
+            let (g1, g2, g3, g5) = (f.c0.c1, f.c0.c2, f.c1.c0, f.c1.c2);
+            let g4 = *numerator * denominator;
+            // g0 = xi * (2 * g4^2 + g3 * g5 - 3 * g1 * g2) + 1
+            let g1_g2 = g1 * &g2;
+            let t = (g4.square() - &g1_g2).double() - &g1_g2 + &(g3 * &g5);
+            f.c0.c0 = nr(&t) + &Q::BaseField::one();
+            f.c1.c1 = g4;
+        }
+    }
+
+    /// Exponentiates `self`, which must be in the cyclotomic subgroup, by
+    /// squaring in compressed form and decompressing the powers `self^(2^i)`
+    /// needed by the NAF of `exponent` together.
+    pub(crate) fn cyclotomic_exp_compressed(&self, exponent: impl AsRef<[u64]>) -> Self {
+        let naf = find_wnaf(exponent.as_ref());
+        let len = naf.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+
+        let mut powers = Vec::new();
+        let mut power = *self;
+        for (i, &digit) in naf[..len].iter().enumerate() {
+            if i > 0 {
+                power = power.cyclotomic_square_compressed();
+            }
+            if digit != 0 {
+                powers.push(power);
+            }
+        }
+        Self::batch_decompress_karabina(&mut powers);
+
+        let mut res = Self::one();
+        for (power, &digit) in powers.iter().zip(naf.iter().filter(|&&d| d != 0)) {
+            if digit > 0 {
+                res *= power;
+            } else {
+                res *= &power.unitary_inverse();
+            }
+        }
+        res
     }
 }
 
@@ -249,6 +381,78 @@ impl<P: QuadExtParameters> Field for QuadExtField<P> {
     }
 }
 
+impl<P: QuadExtParameters> CyclotomicMultSubgroup for QuadExtField<P> {
+    fn cyclotomic_square(&self) -> Self {
+        P::cyclotomic_square(self)
+    }
+
+    fn cyclotomic_inverse(&self) -> Self {
+        self.unitary_inverse()
+    }
+
+    fn cyclotomic_exp(&self, exponent: impl AsRef<[u64]>) -> Self {
+        P::cyclotomic_exp(self, exponent)
+    }
+}
+
+impl<P: QuadExtParameters> ConstantTimeField for QuadExtField<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn ct_add(&self, other: &Self) -> Self {
+        Self::new(self.c0.ct_add(&other.c0), self.c1.ct_add(&other.c1))
+    }
+
+    fn ct_sub(&self, other: &Self) -> Self {
+        Self::new(self.c0.ct_sub(&other.c0), self.c1.ct_sub(&other.c1))
+    }
+
+    /// The formula of `mul`, multiplying by `P::NONRESIDUE` instead of calling
+    /// `P::mul_base_field_by_nonresidue`, which may use the operators.
+    fn ct_mul(&self, other: &Self) -> Self {
+        let v0 = self.c0.ct_mul(&other.c0);
+        let v1 = self.c1.ct_mul(&other.c1);
+        let c1 = self
+            .c0
+            .ct_add(&self.c1)
+            .ct_mul(&other.c0.ct_add(&other.c1))
+            .ct_sub(&v0)
+            .ct_sub(&v1);
+        Self::new(v0.ct_add(&P::NONRESIDUE.ct_mul(&v1)), c1)
+    }
+
+    fn ct_inverse(&self) -> Self {
+        // The same formula as `inverse`, which gives zero for zero.
+        let v0 = self
+            .c0
+            .ct_square()
+            .ct_sub(&P::NONRESIDUE.ct_mul(&self.c1.ct_square()));
+        let v1 = v0.ct_inverse();
+        Self::new(self.c0.ct_mul(&v1), self.c1.ct_mul(&v1).ct_neg())
+    }
+}
+
+impl<P: QuadExtParameters> ConstantTimeEq for QuadExtField<P>
+where
+    P::BaseField: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+impl<P: QuadExtParameters> ConditionallySelectable for QuadExtField<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
 impl<'a, P: QuadExtParameters> SquareRootField for QuadExtField<P>
 where
     P::BaseField: SquareRootField,
@@ -479,6 +683,8 @@ impl<P: QuadExtParameters> fmt::Display for QuadExtField<P> {
 }
 
 impl<P: QuadExtParameters> CanonicalSerializeWithFlags for QuadExtField<P> {
+    const SPARE_BITS: usize = <P::BaseField as CanonicalSerializeWithFlags>::SPARE_BITS;
+
     #[inline]
     fn serialize_with_flags<W: Write, F: Flags>(
         &self,
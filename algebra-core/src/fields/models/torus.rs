@@ -0,0 +1,182 @@
+//! Compression of elements of cyclotomic subgroups with the algebraic tori
+//! `T2` and `T6`, see [[RS03]](https://eprint.iacr.org/2003/038.pdf).
+//!
+//! The cyclotomic subgroup of a quadratic extension `F[w]/(w^2 - nu)` is the
+//! torus `T2(F)` of elements of norm one, which is parametrized by `F`. For
+//! the sextic extensions `F[v, w]/(v^3 - xi, w^2 - v)`, such as `Fp12` over
+//! `Fp2` and `Fp24` over `Fp4`, the cyclotomic subgroup is the smaller torus
+//! `T6(F)`, which is parametrized by `F^2`.
+//! Both parametrizations cover the whole subgroup, except for `-1` in the case
+//! of `T2`, which has even order and so is never a pairing value.
+
+use crate::{
+    fields::{
+        fp12_3over4, fp6_2over3, CubicExtField, CubicExtParameters, Field, Fp12, Fp12Parameters,
+        Fp2, Fp24, Fp24Parameters, Fp3Parameters, Fp4, Fp4Parameters, Fp6Parameters, QuadExtField,
+        QuadExtParameters,
+    },
+    io::{Read, Write},
+    CanonicalDeserialize, CanonicalSerialize, One, SerializationError, Zero,
+};
+use core::fmt::Debug;
+
+type Fp3Fp<P> = <<P as fp6_2over3::Fp6Parameters>::Fp3Params as Fp3Parameters>::Fp;
+type Fp12Fp2Params<P> = <<P as Fp12Parameters>::Fp6Params as Fp6Parameters>::Fp2Params;
+type Fp24Fp4Params<P> =
+    <<P as Fp24Parameters>::Fp12Params as fp12_3over4::Fp12Parameters>::Fp4Params;
+
+/// Fields whose cyclotomic subgroup, which contains the target group of
+/// pairings, can be compressed with an algebraic torus.
+pub trait TorusCompression: Field {
+    /// The compressed form of an element of the cyclotomic subgroup.
+    type Compressed: Copy + Debug + PartialEq + CanonicalSerialize + CanonicalDeserialize;
+
+    /// Compresses `self`, or returns `None` if it is not in the cyclotomic
+    /// subgroup or is `-1`.
+    fn torus_compress(&self) -> Option<Self::Compressed>;
+
+    /// Decompresses `compressed` to an element of the cyclotomic subgroup, or
+    /// returns `None` if it is not the compressed form of any element.
+    fn torus_decompress(compressed: &Self::Compressed) -> Option<Self>;
+
+    /// Serializes the compressed form of `self`, which must be in the
+    /// cyclotomic subgroup.
+    fn serialize_torus<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.torus_compress()
+            .ok_or(SerializationError::InvalidData)?
+            .serialize(writer)
+    }
+
+    /// Deserializes an element of the cyclotomic subgroup from its compressed
+    /// form.
+    fn deserialize_torus<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let compressed = Self::Compressed::deserialize(reader)?;
+        Self::torus_decompress(&compressed).ok_or(SerializationError::InvalidData)
+    }
+}
+
+impl<P: QuadExtParameters> QuadExtField<P> {
+    /// Compresses `self` to `T2`: an element `c0 + c1 * w` of norm one other
+    /// than `-1` is `(1 + s * w) / (1 - s * w)` for `s = c1 / (1 + c0)`.
+    /// Returns `None` if `self` does not have norm one or is `-1`.
+    pub fn t2_compress(&self) -> Option<P::BaseField> {
+        if !self.norm().is_one() {
+            return None;
+        }
+        (P::BaseField::one() + &self.c0)
+            .inverse()
+            .map(|inverse| self.c1 * &inverse)
+    }
+
+    /// Decompresses an element of `T2`, which is any `s` in the base field.
+    pub fn t2_decompress(s: &P::BaseField) -> Self {
+        // (1 + s * w) / (1 - s * w) = (1 + nu * s^2 + 2 * s * w) / (1 - nu * s^2),
+        // where the denominator is nonzero as nu is not a square.
+        let nu_s_square = P::mul_base_field_by_nonresidue(&s.square());
+        let one = P::BaseField::one();
+        let denominator = (one - &nu_s_square).inverse().unwrap();
+        Self::new(
+            (one + &nu_s_square) * &denominator,
+            s.double() * &denominator,
+        )
+    }
+}
+
+impl<P, Q> QuadExtField<P>
+where
+    P: QuadExtParameters<BaseField = CubicExtField<Q>>,
+    Q: CubicExtParameters,
+{
+    /// Compresses `self` to `T6`. An element `c0 + c1 * w` of norm one other
+    /// than one is `(t + w) / (t - w)` for `t = (1 + c0) / c1`, and writing
+    /// `t = a + b * v + c * v^2`, it is in `T6` exactly when
+    /// `3 * a * b = 1 + 3 * xi * c^2`. Then `b` is nonzero, as `xi` is not a
+    /// square and `-3` is, and `(b, c)` determines `t`. One is compressed to
+    /// `(0, 0)`. Returns `None` if `self` is not in `T6`.
+    pub fn t6_compress(&self) -> Option<(Q::BaseField, Q::BaseField)> {
+        if self.is_one() {
+            return Some((Q::BaseField::zero(), Q::BaseField::zero()));
+        }
+        if !self.norm().is_one() {
+            return None;
+        }
+        let t = (P::BaseField::one() + &self.c0) * &self.c1.inverse()?;
+        let a_b = t.c0 * &t.c1;
+        let xi_c_square = Q::mul_base_field_by_nonresidue(&t.c2.square());
+        if a_b.double() + &a_b != Q::BaseField::one() + &xi_c_square.double() + &xi_c_square {
+            return None;
+        }
+        Some((t.c1, t.c2))
+    }
+
+    /// Decompresses an element of `T6`, or returns `None` if `(b, c)` is not
+    /// the compressed form of any element.
+    pub fn t6_decompress(b: &Q::BaseField, c: &Q::BaseField) -> Option<Self> {
+        if b.is_zero() {
+            return if c.is_zero() { Some(Self::one()) } else { None };
+        }
+        // a = (1 + 3 * xi * c^2) / (3 * b)
+        let xi_c_square = Q::mul_base_field_by_nonresidue(&c.square());
+        let a = (Q::BaseField::one() + &xi_c_square.double() + &xi_c_square)
+            * &(b.double() + b).inverse().unwrap();
+        let t = CubicExtField::new(a, *b, *c);
+
+        // (t + w) / (t - w) = (t^2 + nu + 2 * t * w) / (t^2 - nu), where the
+        // denominator is nonzero as nu is not a square.
+        let t_square = t.square();
+        let nu = P::mul_base_field_by_nonresidue(&P::BaseField::one());
+        let denominator = (t_square - &nu).inverse().unwrap();
+        Some(Self::new(
+            (t_square + &nu) * &denominator,
+            t.double() * &denominator,
+        ))
+    }
+}
+
+impl<P: Fp4Parameters> TorusCompression for Fp4<P> {
+    type Compressed = Fp2<P::Fp2Params>;
+
+    fn torus_compress(&self) -> Option<Self::Compressed> {
+        self.t2_compress()
+    }
+
+    fn torus_decompress(compressed: &Self::Compressed) -> Option<Self> {
+        Some(Self::t2_decompress(compressed))
+    }
+}
+
+impl<P: fp6_2over3::Fp6Parameters> TorusCompression for fp6_2over3::Fp6<P> {
+    type Compressed = (Fp3Fp<P>, Fp3Fp<P>);
+
+    fn torus_compress(&self) -> Option<Self::Compressed> {
+        self.t6_compress()
+    }
+
+    fn torus_decompress(compressed: &Self::Compressed) -> Option<Self> {
+        Self::t6_decompress(&compressed.0, &compressed.1)
+    }
+}
+
+impl<P: Fp12Parameters> TorusCompression for Fp12<P> {
+    type Compressed = (Fp2<Fp12Fp2Params<P>>, Fp2<Fp12Fp2Params<P>>);
+
+    fn torus_compress(&self) -> Option<Self::Compressed> {
+        self.t6_compress()
+    }
+
+    fn torus_decompress(compressed: &Self::Compressed) -> Option<Self> {
+        Self::t6_decompress(&compressed.0, &compressed.1)
+    }
+}
+
+impl<P: Fp24Parameters> TorusCompression for Fp24<P> {
+    type Compressed = (Fp4<Fp24Fp4Params<P>>, Fp4<Fp24Fp4Params<P>>);
+
+    fn torus_compress(&self) -> Option<Self::Compressed> {
+        self.t6_compress()
+    }
+
+    fn torus_decompress(compressed: &Self::Compressed) -> Option<Self> {
+        Self::t6_decompress(&compressed.0, &compressed.1)
+    }
+}
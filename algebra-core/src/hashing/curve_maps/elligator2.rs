@@ -0,0 +1,66 @@
+use crate::{
+    curves::{
+        models::{MontgomeryModelParameters, TEModelParameters},
+        twisted_edwards_extended::GroupAffine,
+    },
+    fields::{Field, SquareRootField},
+    hashing::{curve_maps::is_square, HashToField, MapToCurve},
+};
+use core::marker::PhantomData;
+use num_traits::{One, Zero};
+
+/// Parameters for the Elligator 2 map of Section 6.7.1 of the specification.
+/// The map targets the Montgomery form `K * t^2 = s^3 + J * s^2 + s` given by
+/// `Self::MontgomeryModelParameters`, whose points are then sent to the twisted
+/// Edwards curve with the rational map of Appendix D.1.
+pub trait Elligator2Parameters: TEModelParameters {
+    /// The constant `Z` of the specification: a non-square in the base field.
+    const ZETA: Self::BaseField;
+}
+
+/// The Elligator 2 map to the twisted Edwards curve defined by `P`.
+pub struct Elligator2Map<P: Elligator2Parameters>(PhantomData<fn() -> P>);
+
+impl<P: Elligator2Parameters> MapToCurve<GroupAffine<P>> for Elligator2Map<P>
+where
+    P::BaseField: HashToField,
+{
+    fn map_to_curve(u: P::BaseField) -> GroupAffine<P> {
+        let j = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_A;
+        let k = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_B;
+        let k_inv = k.inverse().unwrap();
+        let j_on_k = j * &k_inv;
+        let k_inv_sq = k_inv.square();
+        let one = P::BaseField::one();
+
+        // x1 = -(J / K) * inv0(1 + Z * u^2), replaced by -(J / K) if it is zero
+        let x1 = (one + &(P::ZETA * &u.square()))
+            .inverse()
+            .map(|den| -j_on_k * &den)
+            .filter(|x1| !x1.is_zero())
+            .unwrap_or(-j_on_k);
+        let gx1 = ((x1 + &j_on_k) * &x1 + &k_inv_sq) * &x1;
+
+        let (x, y) = if is_square(&gx1) {
+            let y = gx1.sqrt().unwrap();
+            (x1, if y.sgn0() { y } else { -y })
+        } else {
+            let x2 = -x1 - &j_on_k;
+            let gx2 = ((x2 + &j_on_k) * &x2 + &k_inv_sq) * &x2;
+            let y = gx2.sqrt().unwrap();
+            (x2, if y.sgn0() { -y } else { y })
+        };
+
+        // (s, t) on the Montgomery curve, sent to (s / t, (s - 1) / (s + 1)).
+        let s = x * &k;
+        let t = y * &k;
+        match (t * &(s + &one)).inverse() {
+            Some(den) => {
+                let v = s * &(s + &one) * &den;
+                let w = (s - &one) * &t * &den;
+                GroupAffine::new(v, w)
+            },
+            None => GroupAffine::zero(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::fields::SquareRootField;
+
+pub mod elligator2;
+pub mod svdw;
+pub mod swu;
+pub mod wb;
+
+/// The `is_square` predicate of the specification, which treats zero as a
+/// square.
+#[inline]
+fn is_square<F: SquareRootField>(f: &F) -> bool {
+    !f.legendre().is_qnr()
+}
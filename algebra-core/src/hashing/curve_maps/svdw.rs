@@ -0,0 +1,60 @@
+use crate::{
+    curves::{models::SWModelParameters, short_weierstrass_jacobian::GroupAffine},
+    fields::{Field, SquareRootField},
+    hashing::{curve_maps::is_square, HashToField, MapToCurve},
+};
+use core::marker::PhantomData;
+use num_traits::{One, Zero};
+
+/// Parameters for the Shallue-van de Woestijne map of Section 6.6.1 of the
+/// specification, which applies to any short Weierstrass curve. Writing
+/// `g(x) = x^3 + a * x + b`, the constants are
+///
+/// * `C1 = g(Z)`
+/// * `C2 = -Z / 2`
+/// * `C3 = sqrt(-g(Z) * (3 * Z^2 + 4 * a))`, with `sgn0(C3) = 0`
+/// * `C4 = -4 * g(Z) / (3 * Z^2 + 4 * a)`
+pub trait SVDWParameters: SWModelParameters {
+    /// The constant `Z` of the specification.
+    const ZETA: Self::BaseField;
+    const C1: Self::BaseField;
+    const C2: Self::BaseField;
+    const C3: Self::BaseField;
+    const C4: Self::BaseField;
+}
+
+/// The Shallue-van de Woestijne map to the curve defined by `P`.
+pub struct SVDWMap<P: SVDWParameters>(PhantomData<fn() -> P>);
+
+impl<P: SVDWParameters> MapToCurve<GroupAffine<P>> for SVDWMap<P>
+where
+    P::BaseField: HashToField,
+{
+    fn map_to_curve(u: P::BaseField) -> GroupAffine<P> {
+        let g = |x: &P::BaseField| (x.square() + &P::COEFF_A) * x + &P::COEFF_B;
+        let one = P::BaseField::one();
+
+        let u2_c1 = u.square() * &P::C1;
+        let tv2 = one + &u2_c1;
+        let tv1 = one - &u2_c1;
+        // inv0(tv1 * tv2)
+        let tv3 = (tv1 * &tv2).inverse().unwrap_or_else(P::BaseField::zero);
+        let tv4 = u * &tv1 * &tv3 * &P::C3;
+
+        let x1 = P::C2 - &tv4;
+        let x2 = P::C2 + &tv4;
+        let x = if is_square(&g(&x1)) {
+            x1
+        } else if is_square(&g(&x2)) {
+            x2
+        } else {
+            P::ZETA + &(P::C4 * &(tv2.square() * &tv3).square())
+        };
+
+        let mut y = g(&x).sqrt().unwrap();
+        if u.sgn0() != y.sgn0() {
+            y = -y;
+        }
+        GroupAffine::new(x, y, false)
+    }
+}
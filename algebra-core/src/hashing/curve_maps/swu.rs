@@ -0,0 +1,55 @@
+use crate::{
+    curves::{models::SWModelParameters, short_weierstrass_jacobian::GroupAffine},
+    fields::{Field, SquareRootField},
+    hashing::{curve_maps::is_square, HashToField, MapToCurve},
+};
+use core::marker::PhantomData;
+use num_traits::One;
+
+/// Parameters for the simplified Shallue-van de Woestijne-Ulas map of
+/// Section 6.6.2 of the specification. The map only applies to curves
+/// `y^2 = x^3 + a * x + b` with `a * b != 0`.
+pub trait SWUParameters: SWModelParameters {
+    /// The constant `Z` of the specification: a non-square in the base field
+    /// such that `Z != -1` and `g(b / (Z * a))` is a square.
+    const ZETA: Self::BaseField;
+}
+
+/// The simplified SWU map to the curve defined by `P`.
+pub struct SWUMap<P: SWUParameters>(PhantomData<fn() -> P>);
+
+impl<P: SWUParameters> MapToCurve<GroupAffine<P>> for SWUMap<P>
+where
+    P::BaseField: HashToField,
+{
+    fn map_to_curve(u: P::BaseField) -> GroupAffine<P> {
+        let a = P::COEFF_A;
+        let b = P::COEFF_B;
+        let one = P::BaseField::one();
+
+        // tv1 = inv0(Z^2 * u^4 + Z * u^2)
+        let zeta_u2 = P::ZETA * &u.square();
+        let tv1 = zeta_u2.square() + &zeta_u2;
+
+        // x1 = (-b / a) * (1 + tv1), or b / (Z * a) if tv1 = 0
+        let x1 = match tv1.inverse() {
+            Some(tv1_inv) => -b * &a.inverse().unwrap() * &(one + &tv1_inv),
+            None => b * &(P::ZETA * &a).inverse().unwrap(),
+        };
+        let gx1 = (x1.square() + &a) * &x1 + &b;
+
+        let (x, mut y) = if is_square(&gx1) {
+            (x1, gx1.sqrt().unwrap())
+        } else {
+            // x2 = Z * u^2 * x1
+            let x2 = zeta_u2 * &x1;
+            let gx2 = (x2.square() + &a) * &x2 + &b;
+            (x2, gx2.sqrt().unwrap())
+        };
+
+        if u.sgn0() != y.sgn0() {
+            y = -y;
+        }
+        GroupAffine::new(x, y, false)
+    }
+}
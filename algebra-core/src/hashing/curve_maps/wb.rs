@@ -0,0 +1,64 @@
+use crate::{
+    curves::{models::SWModelParameters, short_weierstrass_jacobian::GroupAffine},
+    fields::Field,
+    hashing::{
+        curve_maps::swu::{SWUMap, SWUParameters},
+        HashToField, MapToCurve,
+    },
+};
+use core::marker::PhantomData;
+use num_traits::Zero;
+
+/// Parameters for the map of Wahby and Boneh, Section 6.6.3 of the
+/// specification. It covers curves with `a * b = 0`, such as the BLS12 curves,
+/// by applying the simplified SWU map to an isogenous curve and then evaluating
+/// the isogeny.
+///
+/// The isogeny sends `(x, y)` to `(x_num(x) / x_den(x), y * y_num(x) / y_den(x))`.
+/// Each polynomial is given by its coefficients in increasing order of degree.
+pub trait WBParameters: SWModelParameters {
+    /// The curve that is isogenous to this one and has `a * b != 0`.
+    type IsogenousCurve: SWUParameters<BaseField = Self::BaseField>;
+
+    const ISOGENY_X_NUMERATOR: &'static [Self::BaseField];
+    const ISOGENY_X_DENOMINATOR: &'static [Self::BaseField];
+    const ISOGENY_Y_NUMERATOR: &'static [Self::BaseField];
+    const ISOGENY_Y_DENOMINATOR: &'static [Self::BaseField];
+
+    /// Evaluates the isogeny from `Self::IsogenousCurve` at `p`.
+    fn isogeny_map(p: &GroupAffine<Self::IsogenousCurve>) -> GroupAffine<Self> {
+        let x_den = evaluate(Self::ISOGENY_X_DENOMINATOR, &p.x);
+        let y_den = evaluate(Self::ISOGENY_Y_DENOMINATOR, &p.x);
+        match (x_den * &y_den).inverse() {
+            Some(den_inv) if !p.infinity => {
+                let x = evaluate(Self::ISOGENY_X_NUMERATOR, &p.x) * &y_den * &den_inv;
+                let y = evaluate(Self::ISOGENY_Y_NUMERATOR, &p.x) * &p.y * &x_den * &den_inv;
+                GroupAffine::new(x, y, false)
+            },
+            // The kernel of the isogeny is sent to the point at infinity.
+            _ => GroupAffine::zero(),
+        }
+    }
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at `x`, using Horner's
+/// rule.
+fn evaluate<F: Field>(coeffs: &[F], x: &F) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * x + coeff)
+}
+
+/// The simplified SWU map composed with an isogeny, to the curve defined by `P`.
+pub struct WBMap<P: WBParameters>(PhantomData<fn() -> P>);
+
+impl<P: WBParameters> MapToCurve<GroupAffine<P>> for WBMap<P>
+where
+    P::BaseField: HashToField,
+{
+    fn map_to_curve(u: P::BaseField) -> GroupAffine<P> {
+        let p = SWUMap::<P::IsogenousCurve>::map_to_curve(u);
+        P::isogeny_map(&p)
+    }
+}
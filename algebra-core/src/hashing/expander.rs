@@ -0,0 +1,69 @@
+use crate::{hashing::HashToCurveError, Vec};
+use digest::{core_api::BlockSizeUser, Digest};
+
+/// Domain separation tags longer than this are hashed down first, see
+/// Section 5.3.3 of the specification.
+const MAX_DST_LENGTH: usize = 255;
+
+const LONG_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expands `msg` into `len_in_bytes` uniformly random bytes, using the
+/// fixed-output hash function `D` and the domain separation tag `dst`.
+///
+/// This is `expand_message_xmd` from Section 5.3.1 of the
+/// [hash-to-curve specification](https://www.rfc-editor.org/rfc/rfc9380.html).
+pub fn expand_message_xmd<D: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToCurveError> {
+    let b_in_bytes = <D as Digest>::output_size();
+    let r_in_bytes = <D as BlockSizeUser>::block_size();
+
+    let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
+    if ell > 255 || len_in_bytes > u16::max_value() as usize {
+        return Err(HashToCurveError::OutputTooLong(len_in_bytes));
+    }
+
+    let mut dst_prime = if dst.len() > MAX_DST_LENGTH {
+        let mut hasher = D::new();
+        hasher.update(LONG_DST_PREFIX);
+        hasher.update(dst);
+        hasher.finalize().to_vec()
+    } else {
+        dst.to_vec()
+    };
+    dst_prime.push(dst_prime.len() as u8);
+
+    let mut hasher = D::new();
+    hasher.update(&vec![0u8; r_in_bytes]);
+    hasher.update(msg);
+    hasher.update(&(len_in_bytes as u16).to_be_bytes());
+    hasher.update(&[0u8]);
+    hasher.update(&dst_prime);
+    let b_0 = hasher.finalize();
+
+    let mut hasher = D::new();
+    hasher.update(&b_0);
+    hasher.update(&[1u8]);
+    hasher.update(&dst_prime);
+    let mut b_i = hasher.finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let mut hasher = D::new();
+        let xored = b_0
+            .iter()
+            .zip(b_i.iter())
+            .map(|(l, r)| l ^ r)
+            .collect::<Vec<_>>();
+        hasher.update(&xored);
+        hasher.update(&[i as u8]);
+        hasher.update(&dst_prime);
+        b_i = hasher.finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
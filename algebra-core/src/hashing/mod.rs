@@ -0,0 +1,215 @@
+//! Hashing to finite fields and elliptic curves, following the IETF
+//! [hash-to-curve specification](https://www.rfc-editor.org/rfc/rfc9380.html).
+//!
+//! Arbitrary messages are first expanded into uniformly random field elements
+//! with [`hash_to_field`], and these are then sent to the curve with one of the
+//! maps in [`curve_maps`].
+use crate::{
+    biginteger::BigInteger,
+    curves::{AffineCurve, ProjectiveCurve},
+    fields::{
+        CubicExtField, CubicExtParameters, Field, PrimeField, QuadExtField, QuadExtParameters,
+    },
+    Vec,
+};
+use core::fmt;
+use digest::{core_api::BlockSizeUser, Digest};
+use num_traits::Zero;
+
+pub mod curve_maps;
+
+mod expander;
+pub use expander::expand_message_xmd;
+
+#[cfg(test)]
+mod tests;
+
+/// The target security level `k` of the specification, in bits. It determines
+/// how many uniform bytes are reduced into each base prime field element.
+const SECURITY_BITS: usize = 128;
+
+/// This is an error that could occur while hashing to a field or curve.
+#[derive(Debug)]
+pub enum HashToCurveError {
+    /// `expand_message_xmd` was asked for more output than the construction
+    /// can produce.
+    OutputTooLong(usize),
+}
+
+#[cfg(feature = "std")]
+impl crate::Error for HashToCurveError {}
+
+impl fmt::Display for HashToCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            HashToCurveError::OutputTooLong(len) => write!(
+                f,
+                "cannot expand a message into {} bytes with expand_message_xmd",
+                len
+            ),
+        }
+    }
+}
+
+/// Fields whose elements can be produced by [`hash_to_field`].
+pub trait HashToField: Field {
+    /// Returns the degree of `Self` over `Self::BasePrimeField`.
+    fn extension_degree() -> usize;
+
+    /// Builds an element from its coefficients over the base prime field, in
+    /// the order `c0, c1, ...` of the tower. Returns `None` if `elems` does not
+    /// contain exactly `Self::extension_degree()` elements.
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self>;
+
+    /// The `sgn0` function of Section 4.1 of the specification: the parity of
+    /// the first non-zero coefficient over the base prime field.
+    fn sgn0(&self) -> bool;
+}
+
+impl<F: PrimeField> HashToField for F {
+    #[inline]
+    fn extension_degree() -> usize {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[F]) -> Option<Self> {
+        if elems.len() == 1 {
+            Some(elems[0])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn sgn0(&self) -> bool {
+        self.into_repr().is_odd()
+    }
+}
+
+impl<P: QuadExtParameters> HashToField for QuadExtField<P>
+where
+    P::BaseField: HashToField<BasePrimeField = P::BasePrimeField>,
+{
+    #[inline]
+    fn extension_degree() -> usize {
+        P::DEGREE_OVER_BASE_PRIME_FIELD
+    }
+
+    fn from_base_prime_field_elems(elems: &[P::BasePrimeField]) -> Option<Self> {
+        if elems.len() != P::DEGREE_OVER_BASE_PRIME_FIELD {
+            return None;
+        }
+        let (c0, c1) = elems.split_at(P::DEGREE_OVER_BASE_PRIME_FIELD / 2);
+        Some(QuadExtField::new(
+            P::BaseField::from_base_prime_field_elems(c0)?,
+            P::BaseField::from_base_prime_field_elems(c1)?,
+        ))
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+}
+
+impl<P: CubicExtParameters> HashToField for CubicExtField<P>
+where
+    P::BaseField: HashToField<BasePrimeField = P::BasePrimeField>,
+{
+    #[inline]
+    fn extension_degree() -> usize {
+        P::DEGREE_OVER_BASE_PRIME_FIELD
+    }
+
+    fn from_base_prime_field_elems(elems: &[P::BasePrimeField]) -> Option<Self> {
+        if elems.len() != P::DEGREE_OVER_BASE_PRIME_FIELD {
+            return None;
+        }
+        let base_degree = P::DEGREE_OVER_BASE_PRIME_FIELD / 3;
+        Some(CubicExtField::new(
+            P::BaseField::from_base_prime_field_elems(&elems[..base_degree])?,
+            P::BaseField::from_base_prime_field_elems(&elems[base_degree..2 * base_degree])?,
+            P::BaseField::from_base_prime_field_elems(&elems[2 * base_degree..])?,
+        ))
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0()
+            || (self.c0.is_zero() && self.c1.sgn0())
+            || (self.c0.is_zero() && self.c1.is_zero() && self.c2.sgn0())
+    }
+}
+
+/// Interprets `bytes` as a big-endian integer and reduces it modulo the
+/// characteristic of `F`.
+fn from_be_bytes_mod_order<F: PrimeField>(bytes: &[u8]) -> F {
+    let radix = F::from(256u64);
+    bytes
+        .iter()
+        .fold(F::zero(), |acc, byte| acc * &radix + &F::from(*byte))
+}
+
+/// Hashes `msg` to `count` elements of `F` under the domain separation tag
+/// `dst`, as in Section 5.2 of the specification. The message is expanded with
+/// [`expand_message_xmd`] instantiated with `D`.
+pub fn hash_to_field<F: HashToField, D: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<F>, HashToCurveError> {
+    let modulus_bits = <F::BasePrimeField as PrimeField>::size_in_bits();
+    let len_per_elem = (modulus_bits + SECURITY_BITS + 7) / 8;
+    let m = F::extension_degree();
+
+    let uniform_bytes = expand_message_xmd::<D>(msg, dst, count * m * len_per_elem)?;
+    let elems = uniform_bytes
+        .chunks(len_per_elem)
+        .map(from_be_bytes_mod_order::<F::BasePrimeField>)
+        .collect::<Vec<_>>();
+    Ok(elems
+        .chunks(m)
+        .map(|coeffs| F::from_base_prime_field_elems(coeffs).unwrap())
+        .collect())
+}
+
+/// A deterministic map from elements of the base field to points of the curve
+/// `G`, together with the hash-to-curve constructions of Section 3 of the
+/// specification built on top of it.
+pub trait MapToCurve<G: AffineCurve> {
+    /// Maps `u` to a point of the curve. The result is not necessarily in the
+    /// prime order subgroup.
+    fn map_to_curve(u: G::BaseField) -> G;
+
+    /// Hashes `msg` to a point in the prime order subgroup of `G`, so that the
+    /// output distribution is indistinguishable from random. This is the
+    /// `hash_to_curve` (random oracle) construction.
+    fn hash_to_curve<D: Digest + BlockSizeUser>(
+        msg: &[u8],
+        dst: &[u8],
+    ) -> Result<G, HashToCurveError>
+    where
+        G::BaseField: HashToField,
+    {
+        let u = hash_to_field::<G::BaseField, D>(msg, dst, 2)?;
+        let q0 = Self::map_to_curve(u[0]);
+        let q1 = Self::map_to_curve(u[1]);
+        Ok(q0
+            .into_projective()
+            .add_mixed(&q1)
+            .into_affine()
+            .clear_cofactor())
+    }
+
+    /// Encodes `msg` as a point in the prime order subgroup of `G`. This is the
+    /// cheaper `encode_to_curve` (nonuniform) construction, whose output is
+    /// not uniformly distributed.
+    fn encode_to_curve<D: Digest + BlockSizeUser>(
+        msg: &[u8],
+        dst: &[u8],
+    ) -> Result<G, HashToCurveError>
+    where
+        G::BaseField: HashToField,
+    {
+        let u = hash_to_field::<G::BaseField, D>(msg, dst, 1)?;
+        Ok(Self::map_to_curve(u[0]).clear_cofactor())
+    }
+}
@@ -84,8 +84,12 @@ pub use to_field_vec::ToConstraintField;
 pub mod msm;
 pub use self::msm::*;
 
+pub mod hashing;
+
 pub use num_traits::{One, Zero};
 
+pub use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
 pub mod prelude {
     pub use crate::biginteger::BigInteger;
 
@@ -1,4 +1,8 @@
-use crate::{BigInteger, FpParameters, PrimeField, ProjectiveCurve, Vec};
+use crate::{
+    io::{Read, Write},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError},
+    BigInteger, PrimeField, ProjectiveCurve, Vec, Zero,
+};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
@@ -56,8 +60,7 @@ impl FixedBaseMSM {
         for outer in 0..outerc {
             let mut inner = 0usize;
             for i in 0..window {
-                if outer * window + i
-                    < (<T::ScalarField as PrimeField>::Params::MODULUS_BITS as usize)
+                if outer * window + i < (<T::ScalarField as PrimeField>::size_in_bits())
                     && scalar_val[outer * window + i]
                 {
                     inner |= 1 << i;
@@ -86,4 +89,213 @@ impl FixedBaseMSM {
             .map(|e| Self::windowed_mul::<T>(outerc, window, table, e))
             .collect::<Vec<_>>()
     }
+
+    /// Computes `g * v[i]` for every `i`, where `g` is the generator of
+    /// `table`.
+    pub fn multi_scalar_mul_comb<T: ProjectiveCurve>(
+        table: &CombTable<T>,
+        v: &[T::ScalarField],
+    ) -> Vec<T> {
+        #[cfg(feature = "parallel")]
+        let v_iter = v.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let v_iter = v.iter();
+
+        v_iter.map(|e| table.mul(e)).collect::<Vec<_>>()
+    }
+}
+
+/// A table of multiples of a fixed generator `g` for the comb method of Lim
+/// and Lee, "More Flexible Exponentiation with Precomputation" (CRYPTO '94).
+///
+/// The bits of a scalar are arranged in `teeth` rows of `a` consecutive bits,
+/// and every row is split into `blocks` blocks of `e` bits. The table holds,
+/// for each block and each subset of the rows, the sum of the multiples of
+/// `g` by the lowest bits of that block in those rows. A multiplication then
+/// takes `e` doublings and `blocks * e` mixed additions, with a table of
+/// `blocks * 2^teeth` points.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "T: ProjectiveCurve"),
+    Debug(bound = "T: ProjectiveCurve"),
+    PartialEq(bound = "T: ProjectiveCurve"),
+    Eq(bound = "T: ProjectiveCurve")
+)]
+pub struct CombTable<T: ProjectiveCurve> {
+    teeth: usize,
+    blocks: usize,
+    table: Vec<T::Affine>,
+}
+
+impl<T: ProjectiveCurve> CombTable<T> {
+    /// Builds the table for the generator `g`. The number of blocks may be
+    /// lowered so that no block is empty.
+    pub fn new(g: T, teeth: usize, blocks: usize) -> Self {
+        assert!(teeth > 0 && teeth <= MAX_TEETH);
+        let (row_size, blocks, block_size) = comb_shape::<T>(teeth, blocks);
+
+        // rows[i] = g * 2^(i * row_size)
+        let mut rows = Vec::with_capacity(teeth);
+        let mut g_row = g;
+        for _ in 0..teeth {
+            rows.push(g_row);
+            for _ in 0..row_size {
+                g_row.double_in_place();
+            }
+        }
+
+        let mut table = Vec::with_capacity(blocks << teeth);
+        for _ in 0..blocks {
+            let start = table.len();
+            table.push(T::zero());
+            for (i, row) in rows.iter_mut().enumerate() {
+                for u in 0..(1 << i) {
+                    let sum = table[start + u] + &*row;
+                    table.push(sum);
+                }
+                for _ in 0..block_size {
+                    row.double_in_place();
+                }
+            }
+        }
+
+        Self {
+            teeth,
+            blocks,
+            table: T::batch_normalization_into_affine(&table),
+        }
+    }
+
+    /// Returns the generator of the table.
+    pub fn generator(&self) -> T {
+        self.table[1].into()
+    }
+
+    /// Returns the number of rows of the comb.
+    pub fn teeth(&self) -> usize {
+        self.teeth
+    }
+
+    /// Returns the number of blocks every row is split into.
+    pub fn blocks(&self) -> usize {
+        self.blocks
+    }
+
+    /// Computes `g * scalar`, where `g` is the generator of the table.
+    pub fn mul(&self, scalar: &T::ScalarField) -> T {
+        let scalar = scalar.into_repr();
+        let (row_size, blocks, block_size) = comb_shape::<T>(self.teeth, self.blocks);
+
+        let mut res = T::zero();
+        for t in (0..block_size).rev() {
+            res.double_in_place();
+            for j in 0..blocks {
+                let column = j * block_size + t;
+                if column >= row_size {
+                    continue;
+                }
+                let index = (0..self.teeth)
+                    .filter(|i| scalar.get_bit(i * row_size + column))
+                    .fold(0, |index, i| index | (1 << i));
+                if index != 0 {
+                    res.add_assign_mixed(&self.table[(j << self.teeth) + index]);
+                }
+            }
+        }
+        res
+    }
+
+    /// Checks that deserialized parts describe a table built by `new`.
+    fn from_parts(
+        teeth: usize,
+        blocks: usize,
+        table: Vec<T::Affine>,
+    ) -> Result<Self, SerializationError> {
+        if teeth == 0
+            || teeth > MAX_TEETH
+            || comb_shape::<T>(teeth, blocks).1 != blocks
+            || table.len() != blocks << teeth
+            || table.iter().step_by(1 << teeth).any(|p| !p.is_zero())
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self {
+            teeth,
+            blocks,
+            table,
+        })
+    }
+}
+
+/// The largest number of teeth of a `CombTable`.
+const MAX_TEETH: usize = 24;
+
+/// Returns the number of bits in a row of a comb with `teeth` rows, the
+/// number of blocks, and the number of bits in a block.
+fn comb_shape<T: ProjectiveCurve>(teeth: usize, blocks: usize) -> (usize, usize, usize) {
+    let scalar_size = <T::ScalarField as PrimeField>::size_in_bits();
+    let row_size = (scalar_size + teeth - 1) / teeth;
+    let blocks = core::cmp::min(core::cmp::max(blocks, 1), row_size);
+    let block_size = (row_size + blocks - 1) / blocks;
+    // Drop the blocks that would lie entirely beyond the end of a row.
+    let blocks = (row_size + block_size - 1) / block_size;
+    (row_size, blocks, block_size)
+}
+
+impl<T: ProjectiveCurve> CanonicalSerialize for CombTable<T> {
+    #[inline]
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.teeth.serialize(&mut writer)?;
+        self.blocks.serialize(&mut writer)?;
+        self.table.serialize(&mut writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.teeth.serialized_size() + self.blocks.serialized_size() + self.table.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.teeth.serialize(&mut writer)?;
+        self.blocks.serialize(&mut writer)?;
+        self.table.serialize_uncompressed(&mut writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.teeth.serialize(&mut writer)?;
+        self.blocks.serialize(&mut writer)?;
+        self.table.serialize_unchecked(&mut writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.teeth.serialized_size()
+            + self.blocks.serialized_size()
+            + self.table.uncompressed_size()
+    }
+}
+
+impl<T: ProjectiveCurve> CanonicalDeserialize for CombTable<T> {
+    #[inline]
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let teeth = usize::deserialize(&mut reader)?;
+        let blocks = usize::deserialize(&mut reader)?;
+        Self::from_parts(teeth, blocks, Vec::deserialize(&mut reader)?)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let teeth = usize::deserialize(&mut reader)?;
+        let blocks = usize::deserialize(&mut reader)?;
+        Self::from_parts(teeth, blocks, Vec::deserialize_uncompressed(&mut reader)?)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let teeth = usize::deserialize(&mut reader)?;
+        let blocks = usize::deserialize(&mut reader)?;
+        Self::from_parts(teeth, blocks, Vec::deserialize_unchecked(&mut reader)?)
+    }
 }
@@ -1,5 +1,7 @@
 use crate::{
-    prelude::{AffineCurve, BigInteger, FpParameters, One, PrimeField, ProjectiveCurve, Zero},
+    io::{Read, Write},
+    prelude::{AffineCurve, PrimeField, ProjectiveCurve, Zero},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError},
     Vec,
 };
 #[cfg(feature = "parallel")]
@@ -8,76 +10,81 @@ use rayon::prelude::*;
 pub struct VariableBaseMSM;
 
 impl VariableBaseMSM {
-    fn msm_inner<G: AffineCurve>(
-        bases: &[G],
-        scalars: &[<G::ScalarField as PrimeField>::BigInt],
-    ) -> G::Projective
-    where
-        G::Projective: ProjectiveCurve<Affine = G>,
-    {
-        let c = if scalars.len() < 32 {
+    /// The window size used for `num_scalars` points by curves that do not
+    /// pick their own.
+    pub fn default_window_size(num_scalars: usize) -> usize {
+        if num_scalars < 32 {
             3
         } else {
-            super::ln_without_floats(scalars.len()) + 2
-        };
+            super::ln_without_floats(num_scalars) + 2
+        }
+    }
 
-        let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
-        let fr_one = G::ScalarField::one().into_repr();
+    /// Returns the window size that minimizes the estimated cost of an MSM of
+    /// `num_scalars` points, over scalars of `scalar_bits` bits, where adding
+    /// a base to a bucket costs `bucket_add_cost` and summing up a bucket
+    /// costs `bucket_sum_cost`, in any common unit. Each of the
+    /// `(scalar_bits + c) / c` windows adds every base to a bucket and sums
+    /// up its `2^(c - 1)` buckets; the doublings between windows are
+    /// negligible.
+    pub fn window_size_from_costs(
+        num_scalars: usize,
+        scalar_bits: usize,
+        bucket_add_cost: usize,
+        bucket_sum_cost: usize,
+    ) -> usize {
+        let cost = |c: usize| {
+            let num_windows = ((scalar_bits + c) / c) as u128;
+            let bucket_adds = num_scalars as u128 * bucket_add_cost as u128;
+            let bucket_sums = (bucket_sum_cost as u128) << (c - 1);
+            num_windows * (bucket_adds + bucket_sums)
+        };
+        (2..=MAX_WINDOW_SIZE).min_by_key(|&c| cost(c)).unwrap()
+    }
 
-        let zero = G::Projective::zero();
-        let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
+    /// Computes a multi-scalar multiplication with the bucket method, over
+    /// signed digits of `c` bits of the first `size` scalars. The digits are
+    /// stored scalar by scalar, `num_windows` per scalar, and
+    /// `window_sum(digits, num_windows, w)` returns the sum of the bases
+    /// weighted by their digits of window `w`, and of the windows it covers
+    /// for bases shifted by multiples of `windows_per_copy` windows. The
+    /// sums of the `windows_per_copy` lowest windows are then combined.
+    fn msm_with_window_sums<S, F>(
+        size: usize,
+        c: usize,
+        windows_per_copy: usize,
+        scalars: &[<S::ScalarField as PrimeField>::BigInt],
+        window_sum: F,
+    ) -> S
+    where
+        S: crate::groups::Group,
+        F: Fn(&[i64], usize, usize) -> S + Send + Sync,
+    {
+        let zero = S::zero();
+        if size == 0 {
+            return zero;
+        }
+        let num_windows = num_windows::<S::ScalarField>(c);
 
+        let mut digits = vec![0i64; size * num_windows];
         #[cfg(feature = "parallel")]
-        let window_starts_iter = window_starts.into_par_iter();
+        let digits_iter = digits.par_chunks_mut(num_windows);
         #[cfg(not(feature = "parallel"))]
-        let window_starts_iter = window_starts.into_iter();
-
-        // Each window is of size `c`.
-        // We divide up the bits 0..num_bits into windows of size `c`, and
-        // in parallel process each such window.
-        let window_sums: Vec<_> = window_starts_iter
-            .map(|w_start| {
-                let mut res = zero;
-                // We don't need the "zero" bucket, so we only have 2^c - 1 buckets
-                let mut buckets = vec![zero; (1 << c) - 1];
-                scalars
-                    .iter()
-                    .zip(bases)
-                    .filter(|(s, _)| !s.is_zero())
-                    .for_each(|(&scalar, base)| {
-                        if scalar == fr_one {
-                            // We only process unit scalars once in the first window.
-                            if w_start == 0 {
-                                res.add_assign_mixed(base);
-                            }
-                        } else {
-                            let mut scalar = scalar;
-
-                            // We right-shift by w_start, thus getting rid of the
-                            // lower bits.
-                            scalar.divn(w_start as u32);
-
-                            // We mod the remaining bits by the window size.
-                            let scalar = scalar.as_ref()[0] % (1 << c);
-
-                            // If the scalar is non-zero, we update the corresponding
-                            // bucket.
-                            // (Recall that `buckets` doesn't have a zero bucket.)
-                            if scalar != 0 {
-                                buckets[(scalar - 1) as usize].add_assign_mixed(base);
-                            }
-                        }
-                    });
-                let buckets = G::Projective::batch_normalization_into_affine(&buckets);
+        let digits_iter = digits.chunks_mut(num_windows);
+        digits_iter
+            .zip(scalars)
+            .for_each(|(digits, scalar)| make_digits(scalar.as_ref(), c, digits));
 
-                let mut running_sum = G::Projective::zero();
-                for b in buckets.into_iter().rev() {
-                    running_sum.add_assign_mixed(&b);
-                    res += running_sum;
-                }
+        let windows: Vec<_> = (0..windows_per_copy).collect();
 
-                res
-            })
+        #[cfg(feature = "parallel")]
+        let windows_iter = windows.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let windows_iter = windows.into_iter();
+
+        // We process the windows in parallel.
+        let window_sums: Vec<_> = windows_iter
+            .map(|w| window_sum(&digits, num_windows, w))
             .collect();
 
         // We store the sum for the lowest window.
@@ -97,10 +104,501 @@ impl VariableBaseMSM {
                 })
     }
 
+    fn msm_inner<G: AffineCurve>(
+        bases: &[G],
+        num_bases: usize,
+        c: usize,
+        windows_per_copy: usize,
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G::Projective
+    where
+        G::Projective: ProjectiveCurve<Affine = G>,
+    {
+        let size = core::cmp::min(num_bases, scalars.len());
+        Self::msm_with_window_sums(
+            size,
+            c,
+            windows_per_copy,
+            scalars,
+            |digits, num_windows, w| {
+                // Each window is of size `c`, and its digits lie in
+                // `[-2^(c - 1), 2^(c - 1)]`. The `j`-th copy of the bases is
+                // shifted by `j * windows_per_copy` windows, so its digits
+                // for window `w + j * windows_per_copy` go into the same
+                // buckets as the digits of the unshifted bases for window `w`.
+                let terms = (w..num_windows)
+                    .step_by(windows_per_copy)
+                    .zip(bases.chunks(num_bases))
+                    .flat_map(|(w, copy)| digits.iter().skip(w).step_by(num_windows).zip(copy));
+                let buckets = G::msm_bucket_sums(terms, c);
+
+                let mut res = G::Projective::zero();
+                let mut running_sum = G::Projective::zero();
+                for b in buckets.into_iter().rev() {
+                    running_sum.add_assign_mixed(&b);
+                    res += running_sum;
+                }
+                res
+            },
+        )
+    }
+
     pub fn multi_scalar_mul<G: AffineCurve>(
         bases: &[G],
         scalars: &[<G::ScalarField as PrimeField>::BigInt],
     ) -> G::Projective {
-        Self::msm_inner(bases, scalars)
+        let size = core::cmp::min(bases.len(), scalars.len());
+        let c = core::cmp::max(G::msm_window_size(size), 2);
+        Self::msm_inner(bases, size, c, num_windows::<G::ScalarField>(c), scalars)
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]` in any group, such as the target
+    /// group of a pairing, with the bucket method of `multi_scalar_mul`, but
+    /// summing the buckets with the group law of `G`. Negative digits
+    /// subtract their bases, so this suits groups with cheap negation.
+    pub fn multi_scalar_mul_group<G: crate::groups::Group>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G {
+        let size = core::cmp::min(bases.len(), scalars.len());
+        let c = core::cmp::max(Self::default_window_size(size), 2);
+        Self::msm_with_window_sums(
+            size,
+            c,
+            num_windows::<G::ScalarField>(c),
+            scalars,
+            |digits, num_windows, w| {
+                let mut buckets = vec![G::zero(); 1 << (c - 1)];
+                for (digits, base) in digits.chunks(num_windows).zip(bases) {
+                    let d = digits[w];
+                    if d > 0 {
+                        buckets[d as usize - 1] += base;
+                    } else if d < 0 {
+                        buckets[(-d) as usize - 1] -= base;
+                    }
+                }
+
+                let mut res = G::zero();
+                let mut running_sum = G::zero();
+                for b in buckets.into_iter().rev() {
+                    running_sum += &b;
+                    res += &running_sum;
+                }
+                res
+            },
+        )
+    }
+
+    /// Computes the same multi-scalar multiplication as `multi_scalar_mul`,
+    /// over bases prepared with `PreparedMSMBases::new`.
+    pub fn multi_scalar_mul_prepared<G: AffineCurve>(
+        bases: &PreparedMSMBases<G>,
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G::Projective {
+        Self::msm_inner(
+            &bases.bases,
+            bases.num_bases,
+            bases.window_size,
+            bases.windows_per_copy(),
+            scalars,
+        )
+    }
+
+    /// Computes the same multi-scalar multiplication as `multi_scalar_mul`,
+    /// over `num_bases` bases read from `reader` as written by
+    /// `serialize_uncompressed`. At most `chunk_size` bases are held in
+    /// memory at a time, and the next chunk is read while the current one is
+    /// multiplied. All `num_bases` bases are read, even when there are fewer
+    /// scalars.
+    pub fn multi_scalar_mul_from_reader<G: AffineCurve, R: Read + Send>(
+        reader: R,
+        num_bases: usize,
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        chunk_size: usize,
+    ) -> Result<G::Projective, SerializationError> {
+        Self::msm_from_reader(reader, num_bases, scalars, chunk_size, |reader| {
+            G::deserialize_uncompressed(reader)
+        })
+    }
+
+    /// Like `multi_scalar_mul_from_reader`, but does not check that the bases
+    /// read are valid, as `deserialize_unchecked`.
+    pub fn multi_scalar_mul_from_reader_unchecked<G: AffineCurve, R: Read + Send>(
+        reader: R,
+        num_bases: usize,
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        chunk_size: usize,
+    ) -> Result<G::Projective, SerializationError> {
+        Self::msm_from_reader(reader, num_bases, scalars, chunk_size, |reader| {
+            G::deserialize_unchecked(reader)
+        })
+    }
+
+    fn msm_from_reader<G: AffineCurve, R: Read + Send>(
+        mut reader: R,
+        num_bases: usize,
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        chunk_size: usize,
+        read_base: fn(&mut R) -> Result<G, SerializationError>,
+    ) -> Result<G::Projective, SerializationError> {
+        let chunk_size = core::cmp::max(chunk_size, 1);
+        let read_chunk = |reader: &mut R, len: usize, chunk: &mut Vec<G>| {
+            chunk.clear();
+            for _ in 0..len {
+                chunk.push(read_base(reader)?);
+            }
+            Ok::<_, SerializationError>(())
+        };
+
+        let mut chunk = Vec::with_capacity(core::cmp::min(chunk_size, num_bases));
+        let mut next_chunk = Vec::with_capacity(chunk.capacity());
+        let mut num_read = core::cmp::min(chunk_size, num_bases);
+        read_chunk(&mut reader, num_read, &mut chunk)?;
+
+        let mut result = G::Projective::zero();
+        while !chunk.is_empty() {
+            let start = core::cmp::min(num_read - chunk.len(), scalars.len());
+            let chunk_scalars = &scalars[start..core::cmp::min(num_read, scalars.len())];
+            let next_len = core::cmp::min(chunk_size, num_bases - num_read);
+
+            #[cfg(feature = "parallel")]
+            let (read, sum) = rayon::join(
+                || read_chunk(&mut reader, next_len, &mut next_chunk),
+                || Self::multi_scalar_mul(&chunk, chunk_scalars),
+            );
+            #[cfg(not(feature = "parallel"))]
+            let (read, sum) = (
+                read_chunk(&mut reader, next_len, &mut next_chunk),
+                Self::multi_scalar_mul(&chunk, chunk_scalars),
+            );
+            read?;
+
+            result += &sum;
+            num_read += next_len;
+            core::mem::swap(&mut chunk, &mut next_chunk);
+        }
+        Ok(result)
+    }
+}
+
+/// Bases for `VariableBaseMSM::multi_scalar_mul_prepared`, stored together
+/// with copies of themselves multiplied by powers of two.
+///
+/// Every copy covers a group of consecutive windows of the scalars, so an
+/// MSM over `k` copies makes only `1 / k` as many passes over the buckets,
+/// with `1 / k` as many doublings. This pays off when MSMs over the same
+/// bases are computed many times, at the cost of `k` times the memory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedMSMBases<G: AffineCurve> {
+    window_size: usize,
+    num_bases: usize,
+    bases: Vec<G>,
+}
+
+impl<G: AffineCurve> PreparedMSMBases<G> {
+    /// Prepares `bases`, storing up to `num_copies` shifted copies of them.
+    /// There are never more copies than windows of the smallest size.
+    pub fn new(bases: &[G], num_copies: usize) -> Self {
+        let num_copies = core::cmp::min(
+            core::cmp::max(num_copies, 1),
+            num_windows::<G::ScalarField>(2),
+        );
+        let c = core::cmp::max(G::msm_window_size(bases.len() * num_copies), 2);
+        let num_windows = num_windows::<G::ScalarField>(c);
+        let windows_per_copy = (num_windows + num_copies - 1) / num_copies;
+        let num_copies = (num_windows + windows_per_copy - 1) / windows_per_copy;
+
+        let mut prepared = Vec::with_capacity(bases.len() * num_copies);
+        prepared.extend_from_slice(bases);
+        let mut shifted = bases
+            .iter()
+            .map(|b| b.into_projective())
+            .collect::<Vec<_>>();
+        for _ in 1..num_copies {
+            #[cfg(feature = "parallel")]
+            let shifted_iter = shifted.par_iter_mut();
+            #[cfg(not(feature = "parallel"))]
+            let shifted_iter = shifted.iter_mut();
+            shifted_iter.for_each(|p| {
+                for _ in 0..c * windows_per_copy {
+                    p.double_in_place();
+                }
+            });
+            prepared.extend(G::Projective::batch_normalization_into_affine(&shifted));
+        }
+
+        Self {
+            window_size: c,
+            num_bases: bases.len(),
+            bases: prepared,
+        }
+    }
+
+    /// Returns the number of bases.
+    pub fn len(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Returns `true` if there are no bases.
+    pub fn is_empty(&self) -> bool {
+        self.num_bases == 0
+    }
+
+    /// Returns the bases, without their shifted copies.
+    pub fn bases(&self) -> &[G] {
+        &self.bases[..self.num_bases]
+    }
+
+    /// Returns the number of copies of the bases stored, including the bases
+    /// themselves.
+    pub fn num_copies(&self) -> usize {
+        if self.num_bases == 0 {
+            1
+        } else {
+            self.bases.len() / self.num_bases
+        }
+    }
+
+    fn windows_per_copy(&self) -> usize {
+        let num_windows = num_windows::<G::ScalarField>(self.window_size);
+        let num_copies = self.num_copies();
+        (num_windows + num_copies - 1) / num_copies
+    }
+
+    /// Checks that deserialized parts describe bases prepared by `new`.
+    fn from_parts(
+        window_size: usize,
+        num_bases: usize,
+        bases: Vec<G>,
+    ) -> Result<Self, SerializationError> {
+        if window_size < 2
+            || window_size > MAX_WINDOW_SIZE
+            || (num_bases == 0 && !bases.is_empty())
+            || (num_bases != 0 && (bases.is_empty() || bases.len() % num_bases != 0))
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        let prepared = Self {
+            window_size,
+            num_bases,
+            bases,
+        };
+        let num_windows = num_windows::<G::ScalarField>(window_size);
+        let windows_per_copy = prepared.windows_per_copy();
+        if (num_windows + windows_per_copy - 1) / windows_per_copy != prepared.num_copies() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(prepared)
+    }
+}
+
+/// The largest window size accepted when deserializing `PreparedMSMBases`.
+const MAX_WINDOW_SIZE: usize = 32;
+
+impl<G: AffineCurve> CanonicalSerialize for PreparedMSMBases<G> {
+    #[inline]
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.window_size.serialize(&mut writer)?;
+        self.num_bases.serialize(&mut writer)?;
+        self.bases.serialize(&mut writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.window_size.serialized_size()
+            + self.num_bases.serialized_size()
+            + self.bases.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.window_size.serialize(&mut writer)?;
+        self.num_bases.serialize(&mut writer)?;
+        self.bases.serialize_uncompressed(&mut writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.window_size.serialize(&mut writer)?;
+        self.num_bases.serialize(&mut writer)?;
+        self.bases.serialize_unchecked(&mut writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.window_size.serialized_size()
+            + self.num_bases.serialized_size()
+            + self.bases.uncompressed_size()
+    }
+}
+
+impl<G: AffineCurve> CanonicalDeserialize for PreparedMSMBases<G> {
+    #[inline]
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let window_size = usize::deserialize(&mut reader)?;
+        let num_bases = usize::deserialize(&mut reader)?;
+        Self::from_parts(window_size, num_bases, Vec::deserialize(&mut reader)?)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let window_size = usize::deserialize(&mut reader)?;
+        let num_bases = usize::deserialize(&mut reader)?;
+        Self::from_parts(
+            window_size,
+            num_bases,
+            Vec::deserialize_uncompressed(&mut reader)?,
+        )
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let window_size = usize::deserialize(&mut reader)?;
+        let num_bases = usize::deserialize(&mut reader)?;
+        Self::from_parts(
+            window_size,
+            num_bases,
+            Vec::deserialize_unchecked(&mut reader)?,
+        )
+    }
+}
+
+/// The number of signed digits of elements of `F` for window size `c`.
+/// Signed digits need one bit more than the scalars themselves.
+fn num_windows<F: PrimeField>(c: usize) -> usize {
+    let num_bits = F::size_in_bits();
+    (num_bits + c) / c
+}
+
+/// Writes the signed base `2^c` digits of the integer with little-endian
+/// `limbs` to `digits`, least significant first. All digits lie in
+/// `[-2^(c - 1), 2^(c - 1)]`, and only the last one may be `2^(c - 1)`.
+fn make_digits(limbs: &[u64], c: usize, digits: &mut [i64]) {
+    let window_mask = (1 << c) - 1;
+    let mut carry = 0;
+    for (i, digit) in digits.iter_mut().enumerate() {
+        let offset = i * c;
+        let (limb, shift) = (offset / 64, offset % 64);
+        let mut bits = limbs.get(limb).map_or(0, |&l| l >> shift);
+        if shift + c > 64 {
+            bits |= limbs.get(limb + 1).map_or(0, |&l| l << (64 - shift));
+        }
+        let coeff = carry + (bits & window_mask) as i64;
+        // Digits of at least `2^(c - 1)` borrow from the next window.
+        carry = (coeff + (1 << (c - 1))) >> c;
+        *digit = coeff - (carry << c);
+    }
+    // The scalars are smaller than `2^(c * digits.len() - 1)`, so the last
+    // digit absorbs the final carry.
+    *digits.last_mut().unwrap() += carry << c;
+}
+
+/// Sorts the bases of `terms`, which are pairs of a digit and a base, into
+/// `2^(c - 1)` buckets according to their digits, negating the bases whose
+/// digit is negative, and returns the sum of each bucket.
+///
+/// This sums the buckets in the projective representation `B`, with mixed
+/// additions.
+pub(crate) fn accumulate_buckets<'a, B: ProjectiveCurve>(
+    terms: impl Iterator<Item = (&'a i64, &'a B::Affine)>,
+    c: usize,
+) -> Vec<B::Affine> {
+    let mut buckets = vec![B::zero(); 1 << (c - 1)];
+    for (&d, base) in terms.filter(|&(&d, _)| d != 0) {
+        let bucket = &mut buckets[d.abs() as usize - 1];
+        if d > 0 {
+            bucket.add_assign_mixed(base);
+        } else {
+            bucket.add_assign_mixed(&-*base);
+        }
+    }
+    B::batch_normalization_into_affine(&buckets)
+}
+
+/// Sorts the bases of `terms`, which are pairs of a digit and a base, into
+/// `2^(c - 1)` buckets according to their digits, negating the bases whose
+/// digit is negative, and returns the sum of each bucket.
+///
+/// This sums the buckets in affine coordinates. Points are processed in batches,
+/// and each batch is reduced by adding up pairs of points of the same bucket
+/// in rounds, so that all additions of a round share one inversion.
+pub(crate) fn accumulate_buckets_affine<'a, G: AffineCurve>(
+    terms: impl Iterator<Item = (&'a i64, &'a G)>,
+    c: usize,
+) -> Vec<G> {
+    let num_buckets = 1 << (c - 1);
+    let batch_size = core::cmp::max(num_buckets << 3, 1 << 12);
+
+    let mut buckets = vec![G::zero(); num_buckets];
+    let mut starts = vec![0usize; num_buckets + 1];
+    let mut points = Vec::with_capacity(batch_size + num_buckets);
+    let mut groups = Vec::with_capacity(num_buckets);
+    let mut pairs = Vec::new();
+    let mut batch = Vec::with_capacity(batch_size);
+
+    let mut terms = terms.filter(|&(&d, _)| d != 0).peekable();
+    while terms.peek().is_some() {
+        batch.clear();
+        batch.extend(terms.by_ref().take(batch_size));
+
+        // Count the points of each bucket, including its current sum, and lay
+        // the buckets out one after the other in `points`.
+        starts.iter_mut().for_each(|s| *s = 0);
+        for &(&d, _) in &batch {
+            starts[d.abs() as usize] += 1;
+        }
+        for (b, bucket) in buckets.iter().enumerate() {
+            if starts[b + 1] != 0 && !bucket.is_zero() {
+                starts[b + 1] += 1;
+            }
+        }
+        for b in 0..num_buckets {
+            starts[b + 1] += starts[b];
+        }
+
+        points.clear();
+        points.resize(starts[num_buckets], G::zero());
+        let mut next = starts.clone();
+        for (b, bucket) in buckets.iter().enumerate() {
+            if next[b] != starts[b + 1] && !bucket.is_zero() {
+                points[next[b]] = *bucket;
+                next[b] += 1;
+            }
+        }
+        for &(&d, base) in &batch {
+            let b = d.abs() as usize - 1;
+            points[next[b]] = if d > 0 { *base } else { -*base };
+            next[b] += 1;
+        }
+
+        // Add up the points of each bucket pairwise, halving their number in
+        // every round, until each bucket's sum is at its start.
+        groups.clear();
+        groups.extend(
+            (0..num_buckets)
+                .filter(|&b| starts[b + 1] - starts[b] > 1)
+                .map(|b| (starts[b], starts[b + 1])),
+        );
+        let mut stride = 1;
+        while !groups.is_empty() {
+            pairs.clear();
+            for &(start, end) in &groups {
+                pairs.extend(
+                    (start..end - stride)
+                        .step_by(2 * stride)
+                        .map(|i| (i, i + stride)),
+                );
+            }
+            G::batch_add_in_place(&mut points, &pairs);
+            stride *= 2;
+            groups.retain(|&(start, end)| end - start > stride);
+        }
+
+        for (b, bucket) in buckets.iter_mut().enumerate() {
+            if starts[b] != starts[b + 1] {
+                *bucket = points[starts[b]];
+            }
+        }
     }
+    buckets
 }
@@ -16,6 +16,10 @@ use core::convert::TryFrom;
 
 /// Serializer in little endian format allowing to encode flags.
 pub trait CanonicalSerializeWithFlags: CanonicalSerialize {
+    /// The number of top bits of the last byte written by `serialize` that are
+    /// always zero. Flags that fit in them take no additional byte.
+    const SPARE_BITS: usize;
+
     /// Serializes `self` and `flags` into `writer`.
     fn serialize_with_flags<W: Write, F: Flags>(
         &self,
@@ -300,9 +304,25 @@ pub const fn buffer_byte_size(modulus_bits: usize) -> usize {
     (modulus_bits + 7) / 8
 }
 
+/// Returns the size in bytes of an element of `F` serialized together with
+/// `flags_bits` bits of flags. The flags occupy the top bits of the last byte,
+/// which is an additional byte if the spare bits of `F` do not suffice.
+#[inline]
+pub const fn serialized_size_with_flags<F: CanonicalSerializeWithFlags + ConstantSerializedSize>(
+    flags_bits: usize,
+) -> usize {
+    if flags_bits > F::SPARE_BITS {
+        F::SERIALIZED_SIZE + buffer_byte_size(flags_bits - F::SPARE_BITS)
+    } else {
+        F::SERIALIZED_SIZE
+    }
+}
+
 macro_rules! impl_prime_field_serializer {
     ($field: ident, $params: ident, $byte_size: expr) => {
         impl<P: $params> CanonicalSerializeWithFlags for $field<P> {
+            const SPARE_BITS: usize = (8 - P::MODULUS_BITS as usize % 8) % 8;
+
             #[allow(unused_qualifications)]
             fn serialize_with_flags<W: crate::io::Write, F: crate::serialize::Flags>(
                 &self,
@@ -311,13 +331,14 @@ macro_rules! impl_prime_field_serializer {
             ) -> Result<(), crate::serialize::SerializationError> {
                 const BYTE_SIZE: usize = $byte_size;
 
-                let (output_bit_size, output_byte_size) =
-                    crate::serialize::buffer_bit_byte_size($field::<P>::size_in_bits());
-                if F::len() > (output_bit_size - P::MODULUS_BITS as usize) {
+                // The flags may need one more byte than the element itself.
+                let output_byte_size =
+                    crate::serialize::buffer_byte_size(P::MODULUS_BITS as usize + F::len());
+                if output_byte_size > BYTE_SIZE + 1 {
                     return Err(crate::serialize::SerializationError::NotEnoughSpace);
                 }
 
-                let mut bytes = [0u8; BYTE_SIZE];
+                let mut bytes = [0u8; BYTE_SIZE + 1];
                 self.write(&mut bytes[..])?;
 
                 bytes[output_byte_size - 1] |= flags.u8_bitmask();
@@ -328,9 +349,8 @@ macro_rules! impl_prime_field_serializer {
         }
 
         impl<P: $params> ConstantSerializedSize for $field<P> {
-            const SERIALIZED_SIZE: usize = crate::serialize::buffer_byte_size(
-                <$field<P> as crate::PrimeField>::Params::MODULUS_BITS as usize,
-            );
+            const SERIALIZED_SIZE: usize =
+                crate::serialize::buffer_byte_size(P::MODULUS_BITS as usize);
             const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
         }
 
@@ -357,18 +377,21 @@ macro_rules! impl_prime_field_serializer {
             ) -> Result<(Self, F), crate::serialize::SerializationError> {
                 const BYTE_SIZE: usize = $byte_size;
 
-                let (output_bit_size, output_byte_size) =
-                    crate::serialize::buffer_bit_byte_size($field::<P>::size_in_bits());
-                if F::len() > (output_bit_size - P::MODULUS_BITS as usize) {
+                let output_byte_size =
+                    crate::serialize::buffer_byte_size(P::MODULUS_BITS as usize + F::len());
+                if output_byte_size > BYTE_SIZE + 1 {
                     return Err(crate::serialize::SerializationError::NotEnoughSpace);
                 }
 
-                let mut masked_bytes = [0; BYTE_SIZE];
+                let mut masked_bytes = [0; BYTE_SIZE + 1];
                 reader.read_exact(&mut masked_bytes[..output_byte_size])?;
 
                 let flags = F::from_u8_remove_flags(&mut masked_bytes[output_byte_size - 1]);
+                if masked_bytes[BYTE_SIZE] != 0 {
+                    return Err(crate::serialize::SerializationError::InvalidData);
+                }
 
-                Ok((Self::read(&masked_bytes[..])?, flags))
+                Ok((Self::read(&masked_bytes[..BYTE_SIZE])?, flags))
             }
         }
 
@@ -438,9 +461,10 @@ macro_rules! impl_sw_curve_serializer {
 
         impl<P: $params> ConstantSerializedSize for GroupAffine<P> {
             const SERIALIZED_SIZE: usize =
-                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+                crate::serialize::serialized_size_with_flags::<P::BaseField>(2);
             const UNCOMPRESSED_SIZE: usize =
-                2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE
+                    + crate::serialize::serialized_size_with_flags::<P::BaseField>(2);
         }
 
         impl<P: $params> CanonicalDeserialize for GroupAffine<P> {
@@ -531,7 +555,7 @@ macro_rules! impl_edwards_curve_serializer {
 
         impl<P: $params> ConstantSerializedSize for GroupAffine<P> {
             const SERIALIZED_SIZE: usize =
-                <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+                crate::serialize::serialized_size_with_flags::<P::BaseField>(1);
             const UNCOMPRESSED_SIZE: usize =
                 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
         }
@@ -5,7 +5,7 @@ use crate::{
         short_weierstrass_jacobian::{GroupAffine as SWAffine, GroupProjective as SWProjective},
         twisted_edwards_extended::{GroupAffine as TEAffine, GroupProjective as TEProjective},
     },
-    Box, Field, FpParameters, PrimeField, Vec,
+    Box, Field, PrimeField, Vec,
 };
 
 type Error = Box<dyn crate::Error>;
@@ -87,8 +87,7 @@ where
 impl<ConstraintF: PrimeField> ToConstraintField<ConstraintF> for [u8] {
     #[inline]
     fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
-        use core::convert::TryFrom;
-        let max_size = usize::try_from(<ConstraintF as PrimeField>::Params::CAPACITY / 8).unwrap();
+        let max_size = ConstraintF::capacity() / 8;
         let bigint_size = <ConstraintF as PrimeField>::BigInt::NUM_LIMBS * 8;
         let fes = self
             .chunks(max_size)
@@ -0,0 +1,53 @@
+use algebra_core::{
+    biginteger::BigInteger64 as BigInteger,
+    fields::{FftParameters, Fp64, Fp64Parameters},
+};
+
+pub type Fq = Fp64<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp64Parameters for FqParameters {
+    /// MODULUS = 2013265921
+    const MODULUS: BigInteger = BigInteger([2013265921u64]);
+
+    const MODULUS_BITS: u32 = 31;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 33;
+
+    const GENERATOR: BigInteger = BigInteger([31u64]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([1006632960u64]);
+
+    const T: BigInteger = BigInteger([15u64]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([7u64]);
+
+    /// Barrett reduction of `x < MODULUS^2 < 2^62`.
+    #[inline(always)]
+    fn reduce(x: u128) -> u64 {
+        // floor(2^64 / MODULUS)
+        const BARRETT: u64 = 9162596893;
+        let x = x as u64;
+
+        // The estimated quotient is at most one too small.
+        let q = ((u128::from(x) * u128::from(BARRETT)) >> 64) as u64;
+        let r = x - q * Self::MODULUS.0[0];
+        if r >= Self::MODULUS.0[0] {
+            r - Self::MODULUS.0[0]
+        } else {
+            r
+        }
+    }
+}
+
+impl FftParameters for FqParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 27;
+
+    /// 31^t
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([440564289u64]);
+}
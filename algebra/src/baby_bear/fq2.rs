@@ -0,0 +1,27 @@
+use super::*;
+use algebra_core::{biginteger::BigInteger64 as BigInteger, field_new, fields::*};
+
+pub type Fq2 = Fp2<Fq2Parameters>;
+
+pub struct Fq2Parameters;
+
+impl Fp2Parameters for Fq2Parameters {
+    type Fp = Fq;
+
+    /// NONRESIDUE = 11
+    const NONRESIDUE: Fq = field_new!(Fq, BigInteger([11u64]));
+
+    /// QUADRATIC_NONRESIDUE = U
+    const QUADRATIC_NONRESIDUE: (Fq, Fq) = (
+        field_new!(Fq, BigInteger([0u64])),
+        field_new!(Fq, BigInteger([1u64])),
+    );
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: &'static [Fq] = &[
+        // NONRESIDUE**(((q^0) - 1) / 2)
+        field_new!(Fq, BigInteger([1u64])),
+        // NONRESIDUE**(((q^1) - 1) / 2)
+        field_new!(Fq, BigInteger([2013265920u64])),
+    ];
+}
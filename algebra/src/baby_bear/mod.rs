@@ -0,0 +1,23 @@
+//! This module implements the BabyBear field, the prime field of order
+//! p = 15 * 2^27 + 1, along with its quadratic and cubic extensions.
+//!
+//! Elements fit in 31 bits, so products of two elements fit in a `u64`, and
+//! the multiplicative group has a subgroup of order 2^27 for FFTs.
+//!
+//! Field information:
+//! * Field: p = 2013265921
+//! * valuation(p - 1, 2) = 27
+//! * Quadratic extension: Fq2 = Fq[u]/(u^2 - 11)
+//! * Cubic extension: Fq3 = Fq[u]/(u^3 - 2)
+
+mod fq;
+pub use fq::*;
+
+mod fq2;
+pub use fq2::*;
+
+mod fq3;
+pub use fq3::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,87 @@
+use algebra_core::{test_rng, CanonicalSerialize, FftField, Field, One, Zero};
+use rand::Rng;
+
+use crate::baby_bear::*;
+
+use crate::tests::fields::{
+    fft_field_test, field_serialization_test, field_test, fp64_reduce_test, frobenius_test,
+    primefield_test, sqrt_field_test,
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    primefield_test::<Fq>();
+    fft_field_test::<Fq>();
+    fp64_reduce_test::<FqParameters>();
+    let byte_size = Fq::zero().serialized_size();
+    assert_eq!(byte_size, 4);
+    field_serialization_test::<Fq>(byte_size);
+}
+
+#[test]
+fn test_fq2() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = rng.gen();
+        let b: Fq2 = rng.gen();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
+    let byte_size = Fq2::zero().serialized_size();
+    field_serialization_test::<Fq2>(byte_size);
+}
+
+#[test]
+fn test_fq3() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq3 = rng.gen();
+        let b: Fq3 = rng.gen();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq3, _>(Fq::characteristic(), 13);
+    let byte_size = Fq3::zero().serialized_size();
+    field_serialization_test::<Fq3>(byte_size);
+}
+
+#[test]
+fn test_fq_modulus() {
+    // p = 15 * 2^27 + 1
+    assert_eq!(Fq::from(15u64) * Fq::from(2u64).pow([27]), -Fq::one());
+    assert_eq!(-Fq::one(), Fq::from(2013265920u64));
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    // 31^15, the generator of the subgroup of order 2^27 used by Plonky3.
+    assert_eq!(Fq::two_adicity(), 27);
+    assert_eq!(Fq::two_adic_root_of_unity(), Fq::from(0x1a427a41u64));
+    let mut root = Fq::two_adic_root_of_unity();
+    for _ in 1..Fq::two_adicity() {
+        root.square_in_place();
+    }
+    assert_eq!(root, -Fq::one());
+}
+
+#[test]
+fn test_extension_nonresidues() {
+    // Fq2 = Fq[u]/(u^2 - 11) and Fq3 = Fq[u]/(u^3 - 2).
+    let u = Fq2::new(Fq::zero(), Fq::one());
+    assert_eq!(u.square(), Fq2::new(Fq::from(11u64), Fq::zero()));
+    let u = Fq3::new(Fq::zero(), Fq::one(), Fq::zero());
+    assert_eq!(
+        u.square() * u,
+        Fq3::new(Fq::from(2u64), Fq::zero(), Fq::zero())
+    );
+}
@@ -1,10 +1,15 @@
 use algebra_core::{
     biginteger::{BigInteger256, BigInteger384},
-    curves::models::{ModelParameters, SWModelParameters},
-    field_new, Zero,
+    curves::{
+        bls12::Bls12Parameters,
+        glv::GLVParameters,
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    field_new, BitIteratorBE, Zero,
 };
 
-use crate::bls12_377::{Fq, Fr};
+use crate::bls12_377::{self, Fq, Fr};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Parameters;
@@ -46,12 +51,120 @@ impl SWModelParameters for Parameters {
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
         (G1_GENERATOR_X, G1_GENERATOR_Y);
 
+    const GLV: Option<GLVParameters<Self>> = Some(GLVParameters {
+        lambda: GLV_LAMBDA,
+        basis: GLV_BASIS,
+        rounding: GLV_ROUNDING,
+        endomorphism,
+    });
+
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    fn clear_cofactor(p: &GroupAffine<Self>) -> GroupAffine<Self> {
+        p.mul_bits(BitIteratorBE::new(H_EFF)).into()
+    }
+
+    /// Tests membership of G1 as in Section 6 of <https://eprint.iacr.org/2021/1130>.
+    /// `endomorphism` acts on G1 as multiplication by `x^2 - 1`, and the kernel
+    /// of `endomorphism - (x^2 - 1)` is exactly G1, as its degree is
+    /// `x^4 - x^2 + 1 = r`.
+    fn is_in_correct_subgroup_assuming_on_curve(p: &GroupAffine<Self>) -> bool {
+        let x = || BitIteratorBE::new(bls12_377::Parameters::X);
+        let p = GroupProjective::from(*p);
+        endomorphism(&p) + &p == p.mul_bits(x()).mul_bits(x())
+    }
 }
 
+/// The effective cofactor `h_eff = x - 1` used to clear the cofactor of points
+/// obtained by hashing to G1.
+const H_EFF: &[u64] = &[0x8508c00000000000];
+
+/// The endomorphism `(x, y) -> (BETA * x, y)`.
+fn endomorphism(p: &GroupProjective<Parameters>) -> GroupProjective<Parameters> {
+    let mut res = *p;
+    res.x *= &BETA;
+    res
+}
+
+/// BETA = 80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945,
+/// a primitive cube root of unity in Fq.
+#[rustfmt::skip]
+pub const BETA: Fq = field_new!(Fq, BigInteger384([
+    0xdacd106da5847973,
+    0xd8fe2454bac2a79a,
+    0x1ada4fd6fd832edc,
+    0xfb9868449d150908,
+    0xd63eb8aeea32285e,
+    0x167d6a36f873fd0,
+]));
+
+/// GLV_LAMBDA = 91893752504881257701523279626832445440,
+/// the eigenvalue of `endomorphism` on G1.
+#[rustfmt::skip]
+const GLV_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0xae8012cd506fe7e2,
+    0x8adb5f3b1ec9d536,
+    0x15b65fe3a66fe319,
+    0x91f331343200452,
+]));
+
+/// GLV_BASIS = [
+///     (91893752504881257701523279626832445440, -1),
+///     (1, 91893752504881257701523279626832445441),
+/// ]
+#[rustfmt::skip]
+const GLV_BASIS: [(Fr, Fr); 2] = [
+    (
+        field_new!(Fr, BigInteger256([
+            0xae8012cd506fe7e2,
+            0x8adb5f3b1ec9d536,
+            0x15b65fe3a66fe319,
+            0x91f331343200452,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x8cf500000000000e,
+            0xe75281ef6000000e,
+            0x49dc37a90b0ba012,
+            0x55f8b2c6e710ab9,
+        ])),
+    ),
+    (
+        field_new!(Fr, BigInteger256([
+            0x7d1c7ffffffffff3,
+            0x7257f50f6ffffff2,
+            0x16d81575512c0fee,
+            0xd4bda322bbb9a9d,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x218b12cd506fe7d4,
+            0xa388dd4bbec9d528,
+            0xcbda283a9b644306,
+            0x3bfa7e6d4aef998,
+        ])),
+    ),
+];
+
+/// GLV_ROUNDING = [round(2^256 * b2 / d), round(-2^256 * b1 / d)], where
+/// d = a1 * b2 - a2 * b1 = r
+#[rustfmt::skip]
+const GLV_ROUNDING: [BigInteger256; 2] = [
+    BigInteger256([
+        0x7f72ed32af90182c,
+        0xb3f7aa969fd37160,
+        0x3,
+        0x0,
+    ]),
+    BigInteger256([
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+    ]),
+];
+
 /// G1_GENERATOR_X =
 /// 81937999373150964239938255573465948239988671502647976594219695644855304257327692006745978603320413799295628339695
 #[rustfmt::skip]
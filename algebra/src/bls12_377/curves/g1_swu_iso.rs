@@ -0,0 +1,213 @@
+use algebra_core::{
+    biginteger::BigInteger384,
+    curves::models::{ModelParameters, SWModelParameters},
+    field_new,
+    hashing::curve_maps::{swu::SWUParameters, wb::WBParameters},
+};
+
+use crate::bls12_377::{g1, Fq, Fr};
+
+/// The curve `y^2 = x^3 + A' * x + B'` that is 2-isogenous to G1. It is the
+/// target of the simplified SWU map when hashing to G1.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SwuIsoParameters;
+
+impl ModelParameters for SwuIsoParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for SwuIsoParameters {
+    /// COEFF_A = 258664426012969092796408009721202742408018065645352501567204841856062976176281513834280849065051431927238430294002
+    #[rustfmt::skip]
+    const COEFF_A: Fq = field_new!(Fq, BigInteger384([
+        0xef6dc9934d3ce250,
+        0x74af9b7f7e982df3,
+        0xff914ed397c8e910,
+        0x95d6f551d83676ca,
+        0x83527885cb405a6f,
+        0xc361fbac151eaf,
+    ]));
+
+    /// COEFF_B = 22
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, BigInteger384([
+        0x9a76bffffffff2e9,
+        0x5a3e286faffff932,
+        0xdc25c143d08286d2,
+        0xe1cd141e77fcf991,
+        0x3167b6320cca6b5c,
+        0x63347edb6f8ed7,
+    ]));
+
+    /// COFACTOR = the cofactor of G1, since isogenous curves have the same order
+    const COFACTOR: &'static [u64] = g1::Parameters::COFACTOR;
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    const COFACTOR_INV: Fr = g1::Parameters::COFACTOR_INV;
+
+    /// AFFINE_GENERATOR_COEFFS = (ISO_GENERATOR_X, ISO_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (ISO_GENERATOR_X, ISO_GENERATOR_Y);
+}
+
+impl SWUParameters for SwuIsoParameters {
+    /// ZETA = -11
+    #[rustfmt::skip]
+    const ZETA: Fq = field_new!(Fq, BigInteger384([
+        0xf54900000000068c,
+        0xde669a6a40000366,
+        0xa166d075f4c36096,
+        0x1c2ae2ea447c0cfe,
+        0xca69a7c72feb6eef,
+        0xa582e39e2ac109,
+    ]));
+}
+
+impl WBParameters for g1::Parameters {
+    type IsogenousCurve = SwuIsoParameters;
+
+    #[rustfmt::skip]
+    const ISOGENY_X_NUMERATOR: &'static [Fq] = &[
+        field_new!(Fq, BigInteger384([
+            0x823123adc3dca4eb,
+            0x7a0f9955afee024c,
+            0xd28c7eda6a6936da,
+            0xa3f9423c4b6291ac,
+            0xd71abcad5823fd42,
+            0x10be7dd0a10e548,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0xac492836d2c23c6d,
+            0x8999b34a056153a5,
+            0x4db250a866fb9367,
+            0x2e621304854e2c3f,
+            0x2e7c2456fee6b206,
+            0x23815f9d6011c0,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0x40b37fffffffffda,
+            0x945027e0dfffffec,
+            0x67df6cea629f4ffc,
+            0x1ed3a5eddb9f18c1,
+            0x933d256fe00f213a,
+            0x23599878bf7d26,
+        ])),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_X_DENOMINATOR: &'static [Fq] = &[
+        field_new!(Fq, BigInteger384([
+            0xb124a0db4b08f1b4,
+            0x2666cd2815854e96,
+            0x36c942a19bee4d9e,
+            0xb9884c121538b0fd,
+            0xb9f0915bfb9ac818,
+            0x8e057e75804700,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0x2cdffffffffff68,
+            0x51409f837fffffb1,
+            0x9f7db3a98a7d3ff2,
+            0x7b4e97b76e7c6305,
+            0x4cf495bf803c84e8,
+            0x8d6661e2fdf49a,
+        ])),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_Y_NUMERATOR: &'static [Fq] = &[
+        field_new!(Fq, BigInteger384([
+            0xc02040000000010b,
+            0x8da461e1000008a,
+            0x47d767c707ae1818,
+            0x42595071ff9b6645,
+            0xbf8effb14c3760a4,
+            0xb6c71aca88a4dc,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0x71b06cd965863b7,
+            0x87569d4402cfa42,
+            0xbf01385547102ade,
+            0x2767fb48c5ee44b5,
+            0xd5d0b6afb3393ded,
+            0x195ce06a2426d14,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0xc4f21c523c235aa4,
+            0x59ec3b912011fd78,
+            0x84052a14777e011b,
+            0xd2a48980486fcc26,
+            0xa8d7b962b4aaafa6,
+            0x10c5f3277f2a315,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0x2059bfffffffffed,
+            0x4a2813f06ffffff6,
+            0xb3efb675314fa7fe,
+            0xf69d2f6edcf8c60,
+            0x499e92b7f007909d,
+            0x11accc3c5fbe93,
+        ])),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_Y_DENOMINATOR: &'static [Fq] = &[
+        field_new!(Fq, BigInteger384([
+            0x78aa4000000004c3,
+            0xbb1d1bb090000279,
+            0x60ec8942da31d86d,
+            0x73f3d01d8efc2280,
+            0xeb0c634543ffb46d,
+            0x9f7bc32f5f8ded,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0xf454523c235b15a,
+            0x45780f4ee11fdb3d,
+            0xcb9e8369dc96259,
+            0xc5c85fb95369a8ca,
+            0x4ca26eaebc0d0824,
+            0xfc8ec4ca4549e,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0x136de291e11ad51c,
+            0x73346778408febc4,
+            0xa45bc7e4d3cae8da,
+            0x2c98e4363faa12f7,
+            0x2dd1b413f2d0584a,
+            0x1aa107b6080d502,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0x2cdffffffffff68,
+            0x51409f837fffffb1,
+            0x9f7db3a98a7d3ff2,
+            0x7b4e97b76e7c6305,
+            0x4cf495bf803c84e8,
+            0x8d6661e2fdf49a,
+        ])),
+    ];
+}
+
+/// ISO_GENERATOR_X =
+/// 183898640136580512316530045470998831691790391453237259434516336279447756609241220664846162561503820562316877867830
+#[rustfmt::skip]
+const ISO_GENERATOR_X: Fq = field_new!(Fq, BigInteger384([
+    0x8aaa23ef398be8d5,
+    0x9877a4985c1ce24a,
+    0xc4ef519c8148f683,
+    0xf5cd437fac35dde0,
+    0xc38e940ca017b2c3,
+    0x39c4ef7db3af4e,
+]));
+
+/// ISO_GENERATOR_Y =
+/// 69018534046895515891776145953191511526693172354818719412306559690461416836925400134233128432719372819569406562974
+#[rustfmt::skip]
+const ISO_GENERATOR_Y: Fq = field_new!(Fq, BigInteger384([
+    0xf545a77aed02c4ed,
+    0xe52ee7b5c00ae7c2,
+    0xda88b827fc642af3,
+    0x727a7967c6d93abb,
+    0x2f9a498730c474d,
+    0x184922714cdd994,
+]));
@@ -1,10 +1,15 @@
 use algebra_core::{
     biginteger::{BigInteger256, BigInteger384},
-    curves::models::{ModelParameters, SWModelParameters},
+    curves::{
+        bls12,
+        glv::GLSParameters,
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
     field_new, Zero,
 };
 
-use crate::bls12_377::{g1, Fq, Fq2, Fr};
+use crate::bls12_377::{self, g1, Fq, Fq2, Fr};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Parameters;
@@ -69,12 +74,152 @@ impl SWModelParameters for Parameters {
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
         (G2_GENERATOR_X, G2_GENERATOR_Y);
 
+    const GLS: Option<GLSParameters<Self>> = Some(GLSParameters {
+        lambda: GLS_LAMBDA,
+        basis: GLS_BASIS,
+        rounding: GLS_ROUNDING,
+        endomorphism,
+    });
+
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    fn clear_cofactor(p: &GroupAffine<Self>) -> GroupAffine<Self> {
+        bls12::g2::clear_cofactor::<bls12_377::Parameters>(p)
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &GroupAffine<Self>) -> bool {
+        bls12::g2::is_in_correct_subgroup_assuming_on_curve::<bls12_377::Parameters>(p)
+    }
+}
+
+/// The untwist-Frobenius-twist endomorphism `psi`, which acts on G2 as
+/// multiplication by `x`.
+fn endomorphism(p: &GroupProjective<Parameters>) -> GroupProjective<Parameters> {
+    bls12::g2::psi::<bls12_377::Parameters>()(p)
 }
 
+/// GLS_LAMBDA = 9586122913090633729,
+/// the eigenvalue of `endomorphism` on G2, which is `x` modulo `r`.
+#[rustfmt::skip]
+const GLS_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0xb37c4571163f9bde,
+    0x8e8553bb20591d46,
+    0x556114953708e25,
+    0xa4211cb0be6c504,
+]));
+
+/// GLS_BASIS = [
+///     (9586122913090633729, -1, 0, 0),
+///     (0, 9586122913090633729, -1, 0),
+///     (0, 0, 9586122913090633729, -1),
+///     (1, 0, -1, 9586122913090633729),
+/// ]
+#[rustfmt::skip]
+const GLS_BASIS: [[Fr; 4]; 4] = [
+    [
+        field_new!(Fr, BigInteger256([
+            0xb37c4571163f9bde,
+            0x8e8553bb20591d46,
+            0x556114953708e25,
+            0xa4211cb0be6c504,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x8cf500000000000e,
+            0xe75281ef6000000e,
+            0x49dc37a90b0ba012,
+            0x55f8b2c6e710ab9,
+        ])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+    ],
+    [
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([
+            0xb37c4571163f9bde,
+            0x8e8553bb20591d46,
+            0x556114953708e25,
+            0xa4211cb0be6c504,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x8cf500000000000e,
+            0xe75281ef6000000e,
+            0x49dc37a90b0ba012,
+            0x55f8b2c6e710ab9,
+        ])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+    ],
+    [
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([
+            0xb37c4571163f9bde,
+            0x8e8553bb20591d46,
+            0x556114953708e25,
+            0xa4211cb0be6c504,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x8cf500000000000e,
+            0xe75281ef6000000e,
+            0x49dc37a90b0ba012,
+            0x55f8b2c6e710ab9,
+        ])),
+    ],
+    [
+        field_new!(Fr, BigInteger256([
+            0x7d1c7ffffffffff3,
+            0x7257f50f6ffffff2,
+            0x16d81575512c0fee,
+            0xd4bda322bbb9a9d,
+        ])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([
+            0x8cf500000000000e,
+            0xe75281ef6000000e,
+            0x49dc37a90b0ba012,
+            0x55f8b2c6e710ab9,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xb37c4571163f9bde,
+            0x8e8553bb20591d46,
+            0x556114953708e25,
+            0xa4211cb0be6c504,
+        ])),
+    ],
+];
+
+/// GLS_ROUNDING = [round(2^256 * c_1), ..., round(2^256 * c_4)], where
+/// (c_1, c_2, c_3, c_4) are the coordinates of (1, 0, 0, 0) in GLS_BASIS
+#[rustfmt::skip]
+const GLS_ROUNDING: [BigInteger256; 4] = [
+    BigInteger256([
+        0x2979759b442f60d0,
+        0xf63abaff9084ce15,
+        0xeca0125755aed064,
+        0x1,
+    ]),
+    BigInteger256([
+        0x7f72ed32af90181e,
+        0xb3f7aa969fd37160,
+        0x3,
+        0x0,
+    ]),
+    BigInteger256([
+        0x2030ba8ee9c06423,
+        0x7,
+        0x0,
+        0x0,
+    ]),
+    BigInteger256([
+        0xe,
+        0x0,
+        0x0,
+        0x0,
+    ]),
+];
+
 #[rustfmt::skip]
 pub const G2_GENERATOR_X: Fq2 = field_new!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 #[rustfmt::skip]
@@ -1,19 +1,27 @@
 #![allow(unused_imports)]
 use algebra_core::{
-    curves::{models::SWModelParameters, AffineCurve, PairingEngine, ProjectiveCurve},
+    curves::{
+        bls12::Bls12Parameters, models::SWModelParameters, AffineCurve, PairingEngine,
+        ProjectiveCurve,
+    },
     fields::{Field, FpParameters, PrimeField, SquareRootField},
+    hashing::curve_maps::wb::WBMap,
     test_rng, CanonicalSerialize, One, Zero,
 };
-use core::ops::{AddAssign, MulAssign};
+use core::{
+    ops::{AddAssign, MulAssign},
+    str::FromStr,
+};
 use rand::Rng;
 
 use crate::{
     bls12_377::{
-        g1, g2, Bls12_377, Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+        self, g1, g2, Bls12_377, Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
     },
     tests::{
-        curves::{curve_tests, sw_tests},
+        curves::{curve_tests, sw_clear_cofactor_test, sw_tests},
         groups::group_test,
+        hashing::{hash_to_curve_test, sw_map_to_curve_test},
     },
 };
 
@@ -61,6 +69,13 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g2_clear_cofactor() {
+    // The method of Budroni and Pintore multiplies by `3 * (x^2 - 1) * h2`.
+    let x = Fr::from(<bls12_377::Parameters as Bls12Parameters>::X[0]);
+    sw_clear_cofactor_test::<g2::Parameters>(Fr::from(3u64) * &(x.square() - &Fr::one()));
+}
+
 #[test]
 fn test_bilinearity() {
     let mut rng = test_rng();
@@ -120,3 +135,94 @@ fn test_g1_generator_raw() {
         x.add_assign(&Fq::one());
     }
 }
+
+/// Regression vectors: the coordinates of the outputs of the
+/// BLS12377G1_XMD:SHA-256_SSWU_RO_ suite for each of `messages()`, as computed
+/// by this library. RFC 9380 does not define this suite, so there are no
+/// published vectors for it, and these only detect changes to its output.
+const G1_HASH_TO_CURVE_VECTORS: [[&str; 2]; 5] = [
+    [
+        "175014090114307787472476859654210957698593647426619404902682598333374433275288666367023590828087217175574983525065",
+        "228479478528229854463142162955759877731977392628897171713172739015219224645462374548177161518680933671642575342815",
+    ],
+    [
+        "206424530901769415315436479123213642547635438202567018365922267171942819343835791177844450832330453711321270669583",
+        "189564686572243036525526752833719693578303419478911511369515292876165521228444346521396784737238267026273893685197",
+    ],
+    [
+        "254221314063684013694945147165594667749809189686037210877128634045387188384111573768484540609771647908362585637663",
+        "168481180580029400492517857184238871382095693888718320690514505818594677982481751200347079908491341970471341625671",
+    ],
+    [
+        "89856848634305588282355221668633836147892958607536687388158743575973195710677949585754842742201872706788967065415",
+        "123089064851710319869365506398021566140857216089700669022729297093182783672431361205469904353058102848624494020250",
+    ],
+    [
+        "118619814107545874147318278556407150296983008284243344025070026206711364709018162696725710079366677014260455144670",
+        "255715400167204415967346707469270861491254579065186949589386201446812622285324516938378742393637384690170377823539",
+    ],
+];
+
+#[test]
+fn test_g1_hash_to_curve() {
+    sw_map_to_curve_test::<g1::Parameters, WBMap<g1::Parameters>>();
+
+    let points = hash_to_curve_test::<G1Affine, WBMap<g1::Parameters>>(
+        b"QUUX-V01-CS02-with-BLS12377G1_XMD:SHA-256_SSWU_RO_",
+    );
+    for (p, [x, y]) in points.iter().zip(G1_HASH_TO_CURVE_VECTORS.iter()) {
+        assert_eq!(p.x, Fq::from_str(x).unwrap());
+        assert_eq!(p.y, Fq::from_str(y).unwrap());
+    }
+}
+
+/// Regression vectors: the coordinates of the outputs of the
+/// BLS12377G2_XMD:SHA-256_SSWU_RO_ suite for each of `messages()`, as computed
+/// by this library. RFC 9380 does not define this suite, so there are no
+/// published vectors for it, and these only detect changes to its output.
+const G2_HASH_TO_CURVE_VECTORS: [[&str; 4]; 5] = [
+    [
+        "178885741991390415696562612990557122813667470134284927171603993281721736444767810349890330092442317471878154310698",
+        "58278604026433338703977324987039778737774680971439853706672278256610867341379040680854909802615794427455036210922",
+        "134966134003763621445080242470977167236258762193391258192341324550450163601127967221562531443759724239265134546313",
+        "1225964056387202125496142256388106688969952063882672681840622803911499062052195221096175999185804774106705528161",
+    ],
+    [
+        "11589914927338237145162821222364124015063857302103718479951967029882940492189765546426919893357385293263593227447",
+        "91077463169738764337892638633046312389713824515012850451952024040872188073085297839462041041455936461047933770130",
+        "216795776213588137820700568151916155442498722232014529542953254420754734084132577802413605844438186023088446721564",
+        "73665059683663604097619828037384542926487183933474659872327558687948403690121892374510401928786167372380952959122",
+    ],
+    [
+        "132821656713965690005727149790386003681781482216166639866892272939025226879570530105431990312889122639456846970826",
+        "215631804643541879915157183248287226234095741324212474786938551792599984832571249449489609096755673336955992094321",
+        "29548826432878001904100512441567781921859118963532276694001809973821866311300920022349962464959889253465855978268",
+        "125320529110474458531490607701000747064605594962049533132394060592259476094946965517943941682740959235786959035146",
+    ],
+    [
+        "123671936175830853419004201911459443439264064013762425674911125761464542304988199527371239551906225403524267393054",
+        "171448974937886133915339931072425349613912831164266845769972166174070840625644756151830061814285424159992690924415",
+        "121911966185017289929539036019481038480751451537493943375712837714101110570230254719631092502871590615300905353245",
+        "255559612510110520050527636860096849943643748150041499814874206044814628628010202023552635260974274262830872070791",
+    ],
+    [
+        "113258282776501195991804363879611614628058443240133122843393654694400193647931468496794930967594114673567397115663",
+        "131535532353595436041730347637878891772158703014616671096236090841550420111677936916707308755900365818698596778410",
+        "162197511463321275680075253337153783270221308979395212425763002915273339971377626315712071957948098195649256493553",
+        "39025077727072823583106058931447910767576398867998964439758164656843825480572388522083591280337875629935758614026",
+    ],
+];
+
+#[test]
+fn test_g2_hash_to_curve() {
+    sw_map_to_curve_test::<g2::Parameters, WBMap<g2::Parameters>>();
+
+    let points = hash_to_curve_test::<G2Affine, WBMap<g2::Parameters>>(
+        b"QUUX-V01-CS02-with-BLS12377G2_XMD:SHA-256_SSWU_RO_",
+    );
+    let fq2 = |c0: &str, c1: &str| Fq2::new(Fq::from_str(c0).unwrap(), Fq::from_str(c1).unwrap());
+    for (p, [x0, x1, y0, y1]) in points.iter().zip(G2_HASH_TO_CURVE_VECTORS.iter()) {
+        assert_eq!(p.x, fq2(x0, x1));
+        assert_eq!(p.y, fq2(y0, y1));
+    }
+}
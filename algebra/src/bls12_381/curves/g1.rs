@@ -4,9 +4,11 @@ use crate::{
     bls12_381::*,
     curves::{
         bls12,
+        bls12::Bls12Parameters,
+        glv::GLVParameters,
         models::{ModelParameters, SWModelParameters},
     },
-    field_new, Zero,
+    field_new, BitIteratorBE, Zero,
 };
 
 pub type G1Affine = bls12::G1Affine<bls12_381::Parameters>;
@@ -52,12 +54,120 @@ impl SWModelParameters for Parameters {
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
         (G1_GENERATOR_X, G1_GENERATOR_Y);
 
+    const GLV: Option<GLVParameters<Self>> = Some(GLVParameters {
+        lambda: GLV_LAMBDA,
+        basis: GLV_BASIS,
+        rounding: GLV_ROUNDING,
+        endomorphism,
+    });
+
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    fn clear_cofactor(p: &G1Affine) -> G1Affine {
+        p.mul_bits(BitIteratorBE::new(H_EFF)).into()
+    }
+
+    /// Tests membership of G1 as in Section 6 of <https://eprint.iacr.org/2021/1130>.
+    /// `endomorphism` acts on G1 as multiplication by `x^2 - 1`, and the kernel
+    /// of `endomorphism - (x^2 - 1)` is exactly G1, as its degree is
+    /// `x^4 - x^2 + 1 = r`.
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        let x = || BitIteratorBE::new(bls12_381::Parameters::X);
+        let p = G1Projective::from(*p);
+        endomorphism(&p) + &p == p.mul_bits(x()).mul_bits(x())
+    }
 }
 
+/// The effective cofactor `h_eff = 1 - x` used to clear the cofactor of points
+/// obtained by hashing to G1, as in Section 8.8.1 of RFC 9380.
+const H_EFF: &[u64] = &[0xd201000000010001];
+
+/// The endomorphism `(x, y) -> (BETA * x, y)`.
+fn endomorphism(p: &G1Projective) -> G1Projective {
+    let mut res = *p;
+    res.x *= &BETA;
+    res
+}
+
+/// BETA = 4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436,
+/// a primitive cube root of unity in Fq.
+#[rustfmt::skip]
+pub const BETA: Fq = field_new!(Fq, BigInteger384([
+    0xcd03c9e48671f071,
+    0x5dab22461fcda5d2,
+    0x587042afd3851b95,
+    0x8eb60ebe01bacb9e,
+    0x3f97d6e83d050d2,
+    0x18f0206554638741,
+]));
+
+/// GLV_LAMBDA = 228988810152649578064853576960394133503,
+/// the eigenvalue of `endomorphism` on G1.
+#[rustfmt::skip]
+const GLV_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0x92d9090b093011d2,
+    0xfc9cbd719d6aa073,
+    0xc1f14ef0cd65a1a6,
+    0x17f6d35e72fcdeb,
+]));
+
+/// GLV_BASIS = [
+///     (228988810152649578064853576960394133503, -1),
+///     (1, 228988810152649578064853576960394133504),
+/// ]
+#[rustfmt::skip]
+const GLV_BASIS: [(Fr, Fr); 2] = [
+    (
+        field_new!(Fr, BigInteger256([
+            0x92d9090b093011d2,
+            0xfc9cbd719d6aa073,
+            0xc1f14ef0cd65a1a6,
+            0x17f6d35e72fcdeb,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xfffffffd00000003,
+            0xfb38ec08fffb13fc,
+            0x99ad88181ce5880f,
+            0x5bc8f5f97cd877d8,
+        ])),
+    ),
+    (
+        field_new!(Fr, BigInteger256([
+            0x1fffffffe,
+            0x5884b7fa00034802,
+            0x998c4fefecbc4ff5,
+            0x1824b159acc5056f,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x92d9090d093011d0,
+            0x5521756b9d6de875,
+            0x5b7d9ee0ba21f19c,
+            0x19a41e8f93f4d35b,
+        ])),
+    ),
+];
+
+/// GLV_ROUNDING = [round(2^256 * b2 / d), round(-2^256 * b1 / d)], where
+/// d = a1 * b2 - a2 * b1 = r
+#[rustfmt::skip]
+const GLV_ROUNDING: [BigInteger256; 2] = [
+    BigInteger256([
+        0x63f6e522f6cfee30,
+        0x7c6becf1e01faadd,
+        0x1,
+        0x0,
+    ]),
+    BigInteger256([
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+    ]),
+];
+
 /// G1_GENERATOR_X =
 /// 3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507
 #[rustfmt::skip]
@@ -4,6 +4,7 @@ use crate::{
     bls12_381::*,
     curves::{
         bls12,
+        glv::GLSParameters,
         models::{ModelParameters, SWModelParameters},
     },
     field_new, Zero,
@@ -56,12 +57,152 @@ impl SWModelParameters for Parameters {
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
         (G2_GENERATOR_X, G2_GENERATOR_Y);
 
+    const GLS: Option<GLSParameters<Self>> = Some(GLSParameters {
+        lambda: GLS_LAMBDA,
+        basis: GLS_BASIS,
+        rounding: GLS_ROUNDING,
+        endomorphism,
+    });
+
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        bls12::g2::clear_cofactor::<bls12_381::Parameters>(p)
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        bls12::g2::is_in_correct_subgroup_assuming_on_curve::<bls12_381::Parameters>(p)
+    }
+}
+
+/// The untwist-Frobenius-twist endomorphism `psi`, which acts on G2 as
+/// multiplication by `x`.
+fn endomorphism(p: &G2Projective) -> G2Projective {
+    bls12::g2::psi::<bls12_381::Parameters>()(p)
 }
 
+/// GLS_LAMBDA = 52435875175126190479447740508185965837690552500527637822588526323715639541761,
+/// the eigenvalue of `endomorphism` on G2, which is `x` modulo `r`.
+#[rustfmt::skip]
+const GLS_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0xffbbd1c8d0027db3,
+    0xd48fb4574a0586d2,
+    0xd0a61ee2afab23f5,
+    0x22e5ffa1f952631e,
+]));
+
+/// GLS_BASIS = [
+///     (15132376222941642752, 1, 0, 0),
+///     (0, -15132376222941642752, -1, 0),
+///     (0, 0, 15132376222941642752, 1),
+///     (1, 0, -1, -15132376222941642752),
+/// ]
+#[rustfmt::skip]
+const GLS_BASIS: [[Fr; 4]; 4] = [
+    [
+        field_new!(Fr, BigInteger256([
+            0x442e362ffd824e,
+            0x7f2defabb5f8d52c,
+            0x6293b92559f6b40f,
+            0x5107a7b1304b1a29,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x1fffffffe,
+            0x5884b7fa00034802,
+            0x998c4fefecbc4ff5,
+            0x1824b159acc5056f,
+        ])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+    ],
+    [
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([
+            0xffbbd1c8d0027db3,
+            0xd48fb4574a0586d2,
+            0xd0a61ee2afab23f5,
+            0x22e5ffa1f952631e,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xfffffffd00000003,
+            0xfb38ec08fffb13fc,
+            0x99ad88181ce5880f,
+            0x5bc8f5f97cd877d8,
+        ])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+    ],
+    [
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([
+            0x442e362ffd824e,
+            0x7f2defabb5f8d52c,
+            0x6293b92559f6b40f,
+            0x5107a7b1304b1a29,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x1fffffffe,
+            0x5884b7fa00034802,
+            0x998c4fefecbc4ff5,
+            0x1824b159acc5056f,
+        ])),
+    ],
+    [
+        field_new!(Fr, BigInteger256([
+            0x1fffffffe,
+            0x5884b7fa00034802,
+            0x998c4fefecbc4ff5,
+            0x1824b159acc5056f,
+        ])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([
+            0xfffffffd00000003,
+            0xfb38ec08fffb13fc,
+            0x99ad88181ce5880f,
+            0x5bc8f5f97cd877d8,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xffbbd1c8d0027db3,
+            0xd48fb4574a0586d2,
+            0xd0a61ee2afab23f5,
+            0x22e5ffa1f952631e,
+        ])),
+    ],
+];
+
+/// GLS_ROUNDING = [round(2^256 * c_1), ..., round(2^256 * c_4)], where
+/// (c_1, c_2, c_3, c_4) are the coordinates of (1, 0, 0, 0) in GLS_BASIS
+#[rustfmt::skip]
+const GLS_ROUNDING: [BigInteger256; 4] = [
+    BigInteger256([
+        0x92078a5e8573b29d,
+        0x33cfcc0d3e76ec28,
+        0x381204ca56cd56b5,
+        0x1,
+    ]),
+    BigInteger256([
+        0x63f6e522f6cfee2e,
+        0x7c6becf1e01faadd,
+        0x1,
+        0x0,
+    ]),
+    BigInteger256([
+        0xcfbe4f7bd0027db3,
+        0x1,
+        0x0,
+        0x0,
+    ]),
+    BigInteger256([
+        0x2,
+        0x0,
+        0x0,
+        0x0,
+    ]),
+];
+
 pub const G2_GENERATOR_X: Fq2 = field_new!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field_new!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
@@ -0,0 +1,366 @@
+use crate::{
+    biginteger::BigInteger384,
+    bls12_381::{g2, Fq, Fq2, Fr},
+    curves::models::{ModelParameters, SWModelParameters},
+    field_new,
+    hashing::curve_maps::{swu::SWUParameters, wb::WBParameters},
+};
+
+/// The curve `y^2 = x^3 + A' * x + B'` that is 3-isogenous to G2. It is the
+/// target of the simplified SWU map in the BLS12381G2_XMD:SHA-256_SSWU_RO_ suite, see
+/// Section 8.8.2 and Appendix E.3 of the hash-to-curve specification.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SwuIsoParameters;
+
+impl ModelParameters for SwuIsoParameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for SwuIsoParameters {
+    /// COEFF_A = 240 * u
+    #[rustfmt::skip]
+    const COEFF_A: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fq, BigInteger384([
+            0xe53a000003135242,
+            0x1080c0fdef80285,
+            0xe7889edbe340f6bd,
+            0xb51375126310601,
+            0x2d6985717c744ab,
+            0x1220b4e979ea5467,
+        ])),
+    );
+
+    /// COEFF_B = 1012 + 1012 * u
+    #[rustfmt::skip]
+    const COEFF_B: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger384([
+            0x22ea00000cf89db2,
+            0x6ec832df71380aa4,
+            0x6e1b94403db5a66e,
+            0x75bf3c53a79473ba,
+            0x3dd3a569412c0a34,
+            0x125cdb5e74dc4fd1,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0x22ea00000cf89db2,
+            0x6ec832df71380aa4,
+            0x6e1b94403db5a66e,
+            0x75bf3c53a79473ba,
+            0x3dd3a569412c0a34,
+            0x125cdb5e74dc4fd1,
+        ])),
+    );
+
+    /// COFACTOR = the cofactor of G2, since isogenous curves have the same order
+    const COFACTOR: &'static [u64] = g2::Parameters::COFACTOR;
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    const COFACTOR_INV: Fr = g2::Parameters::COFACTOR_INV;
+
+    /// AFFINE_GENERATOR_COEFFS = (ISO_GENERATOR_X, ISO_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (ISO_GENERATOR_X, ISO_GENERATOR_Y);
+}
+
+impl SWUParameters for SwuIsoParameters {
+    /// ZETA = -(2 + u)
+    #[rustfmt::skip]
+    const ZETA: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger384([
+            0x87ebfffffff9555c,
+            0x656fffe5da8ffffa,
+            0xfd0749345d33ad2,
+            0xd951e663066576f4,
+            0xde291a3d41e980d3,
+            0x815664c7dfe040d,
+        ])),
+        field_new!(Fq, BigInteger384([
+            0x43f5fffffffcaaae,
+            0x32b7fff2ed47fffd,
+            0x7e83a49a2e99d69,
+            0xeca8f3318332bb7a,
+            0xef148d1ea0f4c069,
+            0x40ab3263eff0206,
+        ])),
+    );
+}
+
+impl WBParameters for g2::Parameters {
+    type IsogenousCurve = SwuIsoParameters;
+
+    #[rustfmt::skip]
+    const ISOGENY_X_NUMERATOR: &'static [Fq2] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0x47f671c71ce05e62,
+                0x6dd57071206393e,
+                0x7c80cd2af3fd71a2,
+                0x48103ea9e6cd062,
+                0xc54516acc8d037f6,
+                0x13808f550920ea41,
+            ])),
+            field_new!(Fq, BigInteger384([
+                0x47f671c71ce05e62,
+                0x6dd57071206393e,
+                0x7c80cd2af3fd71a2,
+                0x48103ea9e6cd062,
+                0xc54516acc8d037f6,
+                0x13808f550920ea41,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+            field_new!(Fq, BigInteger384([
+                0x5fe55555554c71d0,
+                0x873fffdd236aaaa3,
+                0x6a6b4619b26ef918,
+                0x21c2888408874945,
+                0x2836cda7028cabc5,
+                0xac73310a7fd5abd,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0xa0c5555555971c3,
+                0xdb0c00101f9eaaae,
+                0xb1fb2f941d797997,
+                0xd3960742ef416e1c,
+                0xb70040e2c20556f4,
+                0x149d7861e581393b,
+            ])),
+            field_new!(Fq, BigInteger384([
+                0xaff2aaaaaaa638e8,
+                0x439fffee91b55551,
+                0xb535a30cd9377c8c,
+                0x90e144420443a4a2,
+                0x941b66d3814655e2,
+                0x563998853fead5e,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0x40aac71c71c725ed,
+                0x190955557a84e38e,
+                0xd817050a8f41abc3,
+                0xd86485d4c87f6fb1,
+                0x696eb479f885d059,
+                0x198e1a74328002d2,
+            ])),
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_X_DENOMINATOR: &'static [Fq2] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+            field_new!(Fq, BigInteger384([
+                0x1f3affffff13ab97,
+                0xf25bfc611da3ff3e,
+                0xca3757cb3819b208,
+                0x3e6427366f8cec18,
+                0x3977bc86095b089,
+                0x4f69db13f39a952,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0x447600000027552e,
+                0xdcb8009a43480020,
+                0x6f7ee9ce4a6e8b59,
+                0xb10330b7c0a95bc6,
+                0x6140b1fcfb1e54b7,
+                0x381be097f0bb4e1,
+            ])),
+            field_new!(Fq, BigInteger384([
+                0x7588ffffffd8557d,
+                0x41f3ff646e0bffdf,
+                0xf7b1e8d2ac426aca,
+                0xb3741acd32dbb6f8,
+                0xe9daf5b9482d581f,
+                0x167f53e0ba7431b8,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0x760900000002fffd,
+                0xebf4000bc40c0002,
+                0x5f48985753c758ba,
+                0x77ce585370525745,
+                0x5c071a97a256ec6d,
+                0x15f65ec3fa80e493,
+            ])),
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_Y_NUMERATOR: &'static [Fq2] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0x96d8f684bdfc77be,
+                0xb530e4f43b66d0e2,
+                0x184a88ff379652fd,
+                0x57cb23ecfae804e1,
+                0xfd2e39eada3eba9,
+                0x8c8055e31c5d5c3,
+            ])),
+            field_new!(Fq, BigInteger384([
+                0x96d8f684bdfc77be,
+                0xb530e4f43b66d0e2,
+                0x184a88ff379652fd,
+                0x57cb23ecfae804e1,
+                0xfd2e39eada3eba9,
+                0x8c8055e31c5d5c3,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+            field_new!(Fq, BigInteger384([
+                0xbf0a71c71c91b406,
+                0x4d6d55d28b7638fd,
+                0x9d82f98e5f205aee,
+                0xa27aa27b1d1a18d5,
+                0x2c3b2b2d2938e86,
+                0xc7d13420b09807f,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0xd7f9555555531c74,
+                0x21cffff748daaaa8,
+                0x5a9ad1866c9bbe46,
+                0x4870a2210221d251,
+                0x4a0db369c0a32af1,
+                0x2b1ccc429ff56af,
+            ])),
+            field_new!(Fq, BigInteger384([
+                0xe205aaaaaaac8e37,
+                0xfcdc000768795556,
+                0xc96011a8a1537dd,
+                0x1c06a963f163406e,
+                0x10df44c82a881e6,
+                0x174f45260f808feb,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0xa470bda12f67f35c,
+                0xc0fe38e23327b425,
+                0xc9d3d0f2c6f0678d,
+                0x1c55c9935b5a982e,
+                0x27f6c0e2f0746764,
+                0x117c5e6e28aa9054,
+            ])),
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const ISOGENY_Y_DENOMINATOR: &'static [Fq2] = &[
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0x162fffffa765adf,
+                0x8f7bea480083fb75,
+                0x561b3c2259e93611,
+                0x11e19fc1a9c875d5,
+                0xca713efc00367660,
+                0x3c6a03d41da1151,
+            ])),
+            field_new!(Fq, BigInteger384([
+                0x162fffffa765adf,
+                0x8f7bea480083fb75,
+                0x561b3c2259e93611,
+                0x11e19fc1a9c875d5,
+                0xca713efc00367660,
+                0x3c6a03d41da1151,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+            field_new!(Fq, BigInteger384([
+                0x5db0fffffd3b02c5,
+                0xd713f52358ebfdba,
+                0x5ea60761a84d161a,
+                0xbb2c75a34ea6c44a,
+                0xac6735921c1119b,
+                0xee3d913bdacfbf6,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0x66b10000003affc5,
+                0xcb1400e764ec0030,
+                0xa73e5eb56fa5d106,
+                0x8984c913a0fe09a9,
+                0x11e10afb78ad7f13,
+                0x5429d0e3e918f52,
+            ])),
+            field_new!(Fq, BigInteger384([
+                0x534dffffffc4aae6,
+                0x5397ff174c67ffcf,
+                0xbff273eb870b251d,
+                0xdaf2827152870915,
+                0x393a9cbaca9e2dc3,
+                0x14be74dbfaee5748,
+            ])),
+        ),
+        field_new!(Fq2,
+            field_new!(Fq, BigInteger384([
+                0x760900000002fffd,
+                0xebf4000bc40c0002,
+                0x5f48985753c758ba,
+                0x77ce585370525745,
+                0x5c071a97a256ec6d,
+                0x15f65ec3fa80e493,
+            ])),
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    ];
+}
+
+/// ISO_GENERATOR_X =
+/// 2595569946714414516067015540153643524656442638788025933727967960306287756885400469291119095920626560658971252184199 + 1037079738597573406765355774006601850633656296583542639082316151670128374872040593053087014315526494961765370307992 * u
+#[rustfmt::skip]
+const ISO_GENERATOR_X: Fq2 = field_new!(Fq2,
+    field_new!(Fq, BigInteger384([
+        0x2f2c4dc171f636f9,
+        0x1d1260d7ff38b449,
+        0x7271c09feb70064b,
+        0xf132141b430f294f,
+        0xee5d55a613665702,
+        0x156c4525901bcd80,
+    ])),
+    field_new!(Fq, BigInteger384([
+        0x3762cf6548f9631a,
+        0x68d8fbce10db3552,
+        0xcb663efe1b7e436c,
+        0x5aa5df248ce92727,
+        0x537f7d8d75ecaf2d,
+        0x1677f45d8c09910e,
+    ])),
+);
+
+/// ISO_GENERATOR_Y =
+/// 3927929472994661655038722055497331445175131868678630546921475383290711810401295661250673209427965906654429357114487 + 3300326318345570015758639333209189167876318321385223785506096497597561910823001330832964776707374262378602791224889 * u
+#[rustfmt::skip]
+const ISO_GENERATOR_Y: Fq2 = field_new!(Fq2,
+    field_new!(Fq, BigInteger384([
+        0xb3e1c93c9dfe9de0,
+        0x210449794dafe91e,
+        0xfee65e6504db56c3,
+        0xaee6c060d97f3f79,
+        0xa17ff004beee4027,
+        0x61b77fad7d1180a,
+    ])),
+    field_new!(Fq, BigInteger384([
+        0xaf93a3853843b3a4,
+        0xb6d58a896871213a,
+        0xa2be5bac00408317,
+        0x1355b162f2498188,
+        0xf2e5b8ef3fd20351,
+        0x9fb5089c930a3aa,
+    ])),
+);
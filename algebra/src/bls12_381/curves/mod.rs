@@ -3,7 +3,10 @@ use algebra_core::curves::bls12::{Bls12, Bls12Parameters, TwistType};
 use crate::bls12_381::{Fq, Fq12Parameters, Fq2Parameters, Fq6Parameters};
 
 pub mod g1;
+pub mod g1_swu_iso;
 pub mod g2;
+pub mod g2_swu_iso;
+pub mod zcash;
 
 #[cfg(test)]
 mod tests;
@@ -11,6 +14,7 @@ mod tests;
 pub use self::{
     g1::{G1Affine, G1Projective},
     g2::{G2Affine, G2Projective},
+    zcash::{ZcashG1Affine, ZcashG2Affine},
 };
 
 pub type Bls12_381 = Bls12<Parameters>;
@@ -1,19 +1,32 @@
 #![allow(unused_imports)]
 use algebra_core::{
-    curves::{models::SWModelParameters, AffineCurve, PairingEngine, ProjectiveCurve},
-    fields::{Field, FpParameters, PrimeField, SquareRootField},
-    test_rng, CanonicalSerialize, One, Zero,
+    curves::{
+        bls12::Bls12Parameters, models::SWModelParameters, AffineCurve, PairingEngine,
+        ProjectiveCurve,
+    },
+    fields::{Field, FpParameters, PrimeField, SquareRootField, TorusCompression},
+    hashing::curve_maps::wb::WBMap,
+    test_rng, CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize, One, UniformRand,
+    Zero,
+};
+use core::{
+    ops::{AddAssign, MulAssign},
+    str::FromStr,
 };
-use core::ops::{AddAssign, MulAssign};
 use rand::Rng;
 
 use crate::{
     bls12_381::{
-        g1, g2, Bls12_381, Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+        self, g1, g2, Bls12_381, Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+        ZcashG1Affine, ZcashG2Affine,
     },
     tests::{
-        curves::{curve_tests, sw_tests},
+        curves::{
+            curve_tests, pairing_output_test, pairing_torus_compression_test,
+            sw_clear_cofactor_test, sw_tests, sw_xyzz_tests,
+        },
         groups::group_test,
+        hashing::{hash_to_curve_test, sw_map_to_curve_test},
     },
 };
 
@@ -22,6 +35,7 @@ fn test_g1_projective_curve() {
     curve_tests::<G1Projective>();
 
     sw_tests::<g1::Parameters>();
+    sw_xyzz_tests::<g1::Parameters>();
 }
 
 #[test]
@@ -61,6 +75,13 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g2_clear_cofactor() {
+    // The method of Budroni and Pintore multiplies by `3 * (x^2 - 1) * h2`.
+    let x = Fr::from(<bls12_381::Parameters as Bls12Parameters>::X[0]);
+    sw_clear_cofactor_test::<g2::Parameters>(Fr::from(3u64) * &(x.square() - &Fr::one()));
+}
+
 #[test]
 fn test_bilinearity() {
     let mut rng = test_rng();
@@ -89,6 +110,19 @@ fn test_bilinearity() {
     assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
 }
 
+#[test]
+fn test_torus_compression() {
+    pairing_torus_compression_test::<Bls12_381>();
+
+    // The compressed forms (0, c) are invalid, except for (0, 0) which is one.
+    assert_eq!(Fq12::torus_decompress(&(Fq2::zero(), Fq2::one())), None);
+}
+
+#[test]
+fn test_pairing_output() {
+    pairing_output_test::<Bls12_381>();
+}
+
 #[test]
 fn test_g1_generator_raw() {
     let mut x = Fq::zero();
@@ -120,3 +154,238 @@ fn test_g1_generator_raw() {
         x.add_assign(&Fq::one());
     }
 }
+
+/// The coordinates of the outputs of the BLS12381G1_XMD:SHA-256_SSWU_RO_ suite of
+/// Appendix J.9.1 of RFC 9380, for each of `messages()`.
+const G1_HASH_TO_CURVE_VECTORS: [[&str; 2]; 5] = [
+    [
+        "794311575721400831362957049303781044852006323422624111893352859557450008308620925451441746926395141598720928151969",
+        "1343412193624222137939591894701031123123641958980729764240763391191550653712890272928110356903136085217047453540965",
+    ],
+    [
+        "513738460217615943921285247703448567647875874745567372796164155472383127756567780059136521508428662765965997467907",
+        "1786897908129645780825838873875416513994655004408749907941296449131605892957529391590865627492442562626458913769565",
+    ],
+    [
+        "2751628761372137084683207295437105268166375184027748372156952770986741873369176463286511518644061904904607431667096",
+        "563036982304416203921640398061260377444881693369806087719971277317609936727208012968659302318886963927918562170633",
+    ],
+    [
+        "3380432694887674439773082418192083720584748080704959172978586229921475315220434165460350679208315690319508336723080",
+        "3698526739072864408749571082270628561764415577445404115596990919801523793138348254443092179877354467167123794222392",
+    ],
+    [
+        "1256967425542823069694513550918025689490036478501181600525944653952846100887848729514132077573887342346961531624702",
+        "880372082403694543476959909256504267215588055450016885103797700856746532134585942561958795215862304181527267736264",
+    ],
+];
+
+#[test]
+fn test_g1_hash_to_curve() {
+    sw_map_to_curve_test::<g1::Parameters, WBMap<g1::Parameters>>();
+
+    let points = hash_to_curve_test::<G1Affine, WBMap<g1::Parameters>>(
+        b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_",
+    );
+    for (p, [x, y]) in points.iter().zip(G1_HASH_TO_CURVE_VECTORS.iter()) {
+        assert_eq!(p.x, Fq::from_str(x).unwrap());
+        assert_eq!(p.y, Fq::from_str(y).unwrap());
+    }
+}
+
+/// The coordinates of the outputs of the BLS12381G2_XMD:SHA-256_SSWU_RO_ suite of
+/// Appendix J.10.1 of RFC 9380, for each of `messages()`.
+const G2_HASH_TO_CURVE_VECTORS: [[&str; 4]; 5] = [
+    [
+        "193548053368451749411421515628510806626565736652086807419354395577367693778571452628423727082668900187036482254730",
+        "891930009643099423308102777951250899694559203647724988361022851024990473423938537113948850338098230396747396259901",
+        "771717272055834152378281705972671257005357145478800908373659404991537354153455452961747174765859335819766715637138",
+        "2810310118582126634041133454180705304393079139103252956502404531123692847658283858246402311867775854528543237781718",
+    ],
+    [
+        "424958340463073975547762735517193206833255107941790909009827635556634414746056077714431786321247871628515967727334",
+        "3018679803970127877262826393814472528557413504329194740495363852840690589001358162447917674089074634504498585239512",
+        "3621308185128395459888995526527127556614768604472132176060423302734876099689739385100475320409412954617897892887112",
+        "102447784096837908713257069727879782642075240724579670654226801345708452018676587771714457671432122751958633012502",
+    ],
+    [
+        "2785790728239146617702443308248535381016035748520698399690132325213972292102741627498014391457605127656937478044880",
+        "3855709393631831880910167818276435187147963371126198799654803099743427431977934703201153169947378798970358200024876",
+        "821938378705205565995357931232097952117504537366318395539093959918654729488074273868834599496909844419980823111624",
+        "1802420335575779950982935580421454302087567926385222707947527353462942499437987207287862072369052390195154530059198",
+    ],
+    [
+        "3949041098513688455491231180749724794697192943196730030853285011755806989731870696216017360514887069032515603535834",
+        "1416893694506131976809002935212216317132941942570763849323065381335907430566747765697423320407614734575486820936593",
+        "3227453710863835032992962605851449401391399355135442728893790186263669279022343042444878900124369614767241382891922",
+        "1498738834073759871886466122933996764471889514532827927202777922460876335493588931070034160657995151627624577390178",
+    ],
+    [
+        "254155017921606149907129844368549510385368618440139550318910532874259603395336903946742408725761795820224536519988",
+        "2768431459296730426779166218544149791601585986233130583011501727704972362141149700714785450629498506208393873593705",
+        "1755339344744337457318565116062025669984750617937721245220711425551575490663761638802010265668157125441634554205566",
+        "560643043433789571968941329642646582974304556331567393300563909451776257854214387388500126524984624222885267024722",
+    ],
+];
+
+#[test]
+fn test_g2_hash_to_curve() {
+    sw_map_to_curve_test::<g2::Parameters, WBMap<g2::Parameters>>();
+
+    let points = hash_to_curve_test::<G2Affine, WBMap<g2::Parameters>>(
+        b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_",
+    );
+    let fq2 = |c0: &str, c1: &str| Fq2::new(Fq::from_str(c0).unwrap(), Fq::from_str(c1).unwrap());
+    for (p, [x0, x1, y0, y1]) in points.iter().zip(G2_HASH_TO_CURVE_VECTORS.iter()) {
+        assert_eq!(p.x, fq2(x0, x1));
+        assert_eq!(p.y, fq2(y0, y1));
+    }
+}
+
+/// Multiples of the generators of G1 and G2 with their compressed and
+/// uncompressed Zcash encodings.
+const G1_ZCASH_VECTORS: &[[&str; 3]] = &[
+    [
+        "0",
+        "c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        "400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+    ],
+    [
+        "1",
+        "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+        "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+    ],
+    [
+        "2",
+        "a572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e",
+        "0572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28",
+    ],
+    [
+        "3",
+        "89ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e5224",
+        "09ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e5224032b80d3a6f5b09f8a84623389c5f80ca69a0cddabc3097f9d9c27310fd43be6e745256c634af45ca3473b0590ae30d1",
+    ],
+    [
+        "52435875175126190479447740508185965837690552500527637822603658699938581184512",
+        "b7f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+        "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca",
+    ],
+];
+
+const G2_ZCASH_VECTORS: &[[&str; 3]] = &[
+    [
+        "0",
+        "c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        "400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+    ],
+    [
+        "1",
+        "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+        "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+    ],
+    [
+        "2",
+        "aa4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c335771638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053",
+        "0a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c335771638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a0530f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf30468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899",
+    ],
+    [
+        "52435875175126190479447740508185965837690552500527637822603658699938581184512",
+        "b3e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+        "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb813fa4d4a0ad8b1ce186ed5061789213d993923066dddaf1040bc3ff59f825c78df74f2d75467e25e0f55f8a00fa030ed0d1b3cc2c7027888be51d9ef691d77bcb679afda66c73f17f9ee3837a55024f78c71363275a75d75d86bab79f74782aa",
+    ],
+];
+
+fn zcash_serialization_test<G, A>(vectors: &[[&str; 3]])
+where
+    G: AffineCurve + From<A>,
+    A: CanonicalSerialize
+        + CanonicalDeserialize
+        + ConstantSerializedSize
+        + From<G>
+        + Copy
+        + Eq
+        + core::fmt::Debug,
+{
+    let generator = G::prime_subgroup_generator();
+    for [k, compressed, uncompressed] in vectors.iter() {
+        let p = A::from(
+            generator
+                .mul(G::ScalarField::from_str(k).ok().unwrap())
+                .into_affine(),
+        );
+
+        let mut serialized = vec![];
+        p.serialize(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), A::SERIALIZED_SIZE);
+        assert_eq!(hex::encode(&serialized), *compressed);
+        assert_eq!(A::deserialize(&serialized[..]).unwrap(), p);
+
+        let mut serialized = vec![];
+        p.serialize_uncompressed(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), A::UNCOMPRESSED_SIZE);
+        assert_eq!(hex::encode(&serialized), *uncompressed);
+        assert_eq!(A::deserialize(&serialized[..]).unwrap(), p);
+
+        // The sort flag is only set in the compressed form.
+        serialized[0] |= 0x20;
+        assert!(A::deserialize(&serialized[..]).is_err());
+    }
+
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let p = A::from(G::Projective::rand(&mut rng).into_affine());
+        let mut serialized = vec![];
+        p.serialize(&mut serialized).unwrap();
+        assert_eq!(A::deserialize(&serialized[..]).unwrap(), p);
+
+        // The negation of the point differs only in the sort flag.
+        serialized[0] ^= 0x20;
+        assert_eq!(
+            G::from(A::deserialize(&serialized[..]).unwrap()),
+            -G::from(p)
+        );
+
+        // The compression flag must match the length of the encoding.
+        serialized[0] ^= 0x80;
+        assert!(A::deserialize(&serialized[..]).is_err());
+
+        let mut serialized = vec![];
+        p.serialize_uncompressed(&mut serialized).unwrap();
+        assert_eq!(A::deserialize(&serialized[..]).unwrap(), p);
+
+        // A point that is not on the curve.
+        let last = serialized.len() - 1;
+        serialized[last] ^= 1;
+        assert!(A::deserialize(&serialized[..]).is_err());
+    }
+
+    // The point at infinity with a sort flag or a nonzero coordinate.
+    let mut serialized = vec![0u8; A::SERIALIZED_SIZE];
+    serialized[0] = 0xe0;
+    assert!(A::deserialize(&serialized[..]).is_err());
+    serialized[0] = 0xc0;
+    serialized[A::SERIALIZED_SIZE - 1] = 1;
+    assert!(A::deserialize(&serialized[..]).is_err());
+
+    // An x coordinate that is not reduced.
+    let mut serialized = vec![0xff; A::SERIALIZED_SIZE];
+    serialized[0] = 0x9f;
+    assert!(A::deserialize(&serialized[..]).is_err());
+}
+
+#[test]
+fn test_g1_zcash_serialization() {
+    zcash_serialization_test::<G1Affine, ZcashG1Affine>(G1_ZCASH_VECTORS);
+
+    // (0, 2) is a point of order 3, which is on the curve but not in G1.
+    let mut serialized = [0u8; ZcashG1Affine::SERIALIZED_SIZE];
+    serialized[0] = 0x80;
+    let p = ZcashG1Affine::deserialize_unchecked(&serialized[..]).unwrap();
+    assert_eq!(p.0, G1Affine::new(Fq::zero(), Fq::from(2u64), false));
+    assert!(ZcashG1Affine::deserialize(&serialized[..]).is_err());
+}
+
+#[test]
+fn test_g2_zcash_serialization() {
+    zcash_serialization_test::<G2Affine, ZcashG2Affine>(G2_ZCASH_VECTORS);
+}
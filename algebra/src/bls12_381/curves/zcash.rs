@@ -0,0 +1,247 @@
+//! The encoding of points of BLS12-381 introduced by Zcash, which is the one
+//! of the [IETF draft on pairing-friendly curves](https://datatracker.ietf.org/doc/draft-irtf-cfrg-pairing-friendly-curves/)
+//! and the one used by most other BLS12-381 libraries.
+//!
+//! Points are encoded as the big-endian `x` coordinate in compressed form, and
+//! as the big-endian `x` and `y` coordinates in uncompressed form. Elements of
+//! `Fq2` are encoded as `c1` followed by `c0`. The three most significant bits
+//! of the first byte, which are always unused by `Fq`, hold the flags:
+//! * bit 7 is set in the compressed form;
+//! * bit 6 is set for the point at infinity, whose other bits are all zero;
+//! * bit 5 is set in the compressed form of a point whose `y` coordinate is
+//!   the lexicographically largest of `y` and `-y`.
+
+use crate::{
+    bls12_381::{Fq, Fq2, G1Affine, G2Affine},
+    io::{Read, Write},
+    serialize::{
+        CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize, SerializationError,
+    },
+};
+use algebra_core::{
+    biginteger::BigInteger384,
+    curves::models::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+    fields::{Field, PrimeField},
+    Zero,
+};
+
+const COMPRESSION_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SORT_FLAG: u8 = 0x20;
+const FLAGS_MASK: u8 = COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG;
+
+/// The size of the largest encoding, the uncompressed one of G2.
+const MAX_SIZE: usize = 4 * 48;
+
+macro_rules! impl_zcash_affine {
+    ($name:ident, $affine:ty, $field:ty, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// `serialize` writes the compressed form and `serialize_uncompressed`
+        /// the uncompressed one, and deserialization accepts both forms.
+        #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+        pub struct $name(pub $affine);
+
+        impl From<$affine> for $name {
+            #[inline]
+            fn from(p: $affine) -> Self {
+                Self(p)
+            }
+        }
+
+        impl From<$name> for $affine {
+            #[inline]
+            fn from(p: $name) -> Self {
+                p.0
+            }
+        }
+
+        impl CanonicalSerialize for $name {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+                serialize(&self.0, true, writer)
+            }
+
+            #[inline]
+            fn serialized_size(&self) -> usize {
+                Self::SERIALIZED_SIZE
+            }
+
+            #[inline]
+            fn serialize_uncompressed<W: Write>(
+                &self,
+                writer: W,
+            ) -> Result<(), SerializationError> {
+                serialize(&self.0, false, writer)
+            }
+
+            #[inline]
+            fn uncompressed_size(&self) -> usize {
+                Self::UNCOMPRESSED_SIZE
+            }
+        }
+
+        impl ConstantSerializedSize for $name {
+            const SERIALIZED_SIZE: usize = <$field as ZcashField>::BYTE_SIZE;
+            const UNCOMPRESSED_SIZE: usize = 2 * Self::SERIALIZED_SIZE;
+        }
+
+        impl CanonicalDeserialize for $name {
+            fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+                let p = Self::deserialize_unchecked(reader)?.0;
+                if !p.is_on_curve() || !p.is_in_correct_subgroup_assuming_on_curve() {
+                    return Err(SerializationError::InvalidData);
+                }
+                Ok(Self(p))
+            }
+
+            #[inline]
+            fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+                Self::deserialize(reader)
+            }
+
+            #[inline]
+            fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+                deserialize_unchecked(reader).map(Self)
+            }
+        }
+    };
+}
+
+impl_zcash_affine!(
+    ZcashG1Affine,
+    G1Affine,
+    Fq,
+    "A point of G1, which is serialized with the Zcash encoding instead of the
+encoding of `GroupAffine`."
+);
+impl_zcash_affine!(
+    ZcashG2Affine,
+    G2Affine,
+    Fq2,
+    "A point of G2, which is serialized with the Zcash encoding instead of the
+encoding of `GroupAffine`."
+);
+
+/// A base field of BLS12-381, whose elements are encoded as big-endian
+/// integers.
+trait ZcashField: Field {
+    /// The number of bytes of the encoding of an element.
+    const BYTE_SIZE: usize;
+
+    /// Writes `self` to the first `BYTE_SIZE` bytes of `bytes`.
+    fn write_be(&self, bytes: &mut [u8]);
+
+    /// Reads an element from the first `BYTE_SIZE` bytes of `bytes`, which
+    /// must encode integers smaller than the modulus.
+    fn read_be(bytes: &[u8]) -> Option<Self>;
+}
+
+impl ZcashField for Fq {
+    const BYTE_SIZE: usize = 48;
+
+    fn write_be(&self, bytes: &mut [u8]) {
+        let repr = self.into_repr();
+        for (limb, chunk) in repr.0.iter().rev().zip(bytes.chunks_mut(8)) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+    }
+
+    fn read_be(bytes: &[u8]) -> Option<Self> {
+        let mut repr = BigInteger384::default();
+        for (limb, chunk) in repr.0.iter_mut().rev().zip(bytes.chunks(8)) {
+            *limb = chunk.iter().fold(0, |acc, b| (acc << 8) | u64::from(*b));
+        }
+        Self::from_repr(repr)
+    }
+}
+
+impl ZcashField for Fq2 {
+    const BYTE_SIZE: usize = 2 * Fq::BYTE_SIZE;
+
+    fn write_be(&self, bytes: &mut [u8]) {
+        let (c1, c0) = bytes.split_at_mut(Fq::BYTE_SIZE);
+        self.c1.write_be(c1);
+        self.c0.write_be(c0);
+    }
+
+    fn read_be(bytes: &[u8]) -> Option<Self> {
+        let c1 = Fq::read_be(bytes)?;
+        let c0 = Fq::read_be(&bytes[Fq::BYTE_SIZE..])?;
+        Some(Self::new(c0, c1))
+    }
+}
+
+fn serialize<P: SWModelParameters, W: Write>(
+    p: &GroupAffine<P>,
+    compressed: bool,
+    mut writer: W,
+) -> Result<(), SerializationError>
+where
+    P::BaseField: ZcashField,
+{
+    let size = P::BaseField::BYTE_SIZE;
+    let mut bytes = [0u8; MAX_SIZE];
+    let bytes = if compressed {
+        &mut bytes[..size]
+    } else {
+        &mut bytes[..2 * size]
+    };
+    if p.is_zero() {
+        bytes[0] = INFINITY_FLAG;
+    } else {
+        p.x.write_be(bytes);
+        if compressed {
+            // The ordering of `Fq2` compares `c1` first, as the encoding does.
+            if p.y > -p.y {
+                bytes[0] |= SORT_FLAG;
+            }
+        } else {
+            p.y.write_be(&mut bytes[size..]);
+        }
+    }
+    if compressed {
+        bytes[0] |= COMPRESSION_FLAG;
+    }
+    writer.write_all(bytes)?;
+    Ok(())
+}
+
+fn deserialize_unchecked<P: SWModelParameters, R: Read>(
+    mut reader: R,
+) -> Result<GroupAffine<P>, SerializationError>
+where
+    P::BaseField: ZcashField,
+{
+    let size = P::BaseField::BYTE_SIZE;
+    let mut bytes = [0u8; MAX_SIZE];
+    reader.read_exact(&mut bytes[..size])?;
+    let flags = bytes[0] & FLAGS_MASK;
+    bytes[0] &= !FLAGS_MASK;
+
+    let compressed = flags & COMPRESSION_FLAG != 0;
+    let bytes = if compressed {
+        &bytes[..size]
+    } else {
+        reader.read_exact(&mut bytes[size..2 * size])?;
+        &bytes[..2 * size]
+    };
+
+    if flags & INFINITY_FLAG != 0 {
+        if flags & SORT_FLAG != 0 || bytes.iter().any(|b| *b != 0) {
+            return Err(SerializationError::InvalidData);
+        }
+        return Ok(GroupAffine::zero());
+    }
+
+    let x = P::BaseField::read_be(bytes).ok_or(SerializationError::InvalidData)?;
+    if compressed {
+        GroupAffine::get_point_from_x(x, flags & SORT_FLAG != 0)
+            .ok_or(SerializationError::InvalidData)
+    } else if flags & SORT_FLAG != 0 {
+        Err(SerializationError::InvalidData)
+    } else {
+        let y = P::BaseField::read_be(&bytes[size..]).ok_or(SerializationError::InvalidData)?;
+        Ok(GroupAffine::new(x, y, false))
+    }
+}
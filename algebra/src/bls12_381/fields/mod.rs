@@ -1,6 +1,14 @@
-#[cfg(any(feature = "bls12_381", feature = "ed_on_bls12_381"))]
+#[cfg(any(
+    feature = "bls12_381",
+    feature = "ed_on_bls12_381",
+    feature = "ed_on_bls12_381_bandersnatch"
+))]
 pub mod fr;
-#[cfg(any(feature = "bls12_381", feature = "ed_on_bls12_381"))]
+#[cfg(any(
+    feature = "bls12_381",
+    feature = "ed_on_bls12_381",
+    feature = "ed_on_bls12_381_bandersnatch"
+))]
 pub use self::fr::*;
 
 #[cfg(feature = "bls12_381")]
@@ -1,8 +1,8 @@
 use algebra_core::{
-    biginteger::{BigInteger, BigInteger384},
+    biginteger::{BigInteger, BigInteger256, BigInteger384},
     fields::{
-        FftField, FftParameters, Field, Fp12Parameters, Fp2Parameters, Fp6Parameters, FpParameters,
-        SquareRootField,
+        FftField, FftParameters, Field, Fp12Parameters, Fp12ParamsWrapper, Fp2Parameters,
+        Fp6Parameters, FpParameters, SquareRootField,
     },
     One, UniformRand, Zero,
 };
@@ -16,8 +16,12 @@ use rand_xorshift::XorShiftRng;
 use crate::{
     bls12_381::{
         Fq, Fq12, Fq12Parameters, Fq2, Fq2Parameters, Fq6, Fq6Parameters, FqParameters, Fr,
+        FrParameters,
+    },
+    tests::fields::{
+        cyclotomic_compressed_test, field_test, fp_parameters_test, frobenius_test,
+        primefield_test, sqrt_field_test,
     },
-    tests::fields::{field_test, frobenius_test, primefield_test, sqrt_field_test},
 };
 
 pub(crate) const ITERATIONS: usize = 5;
@@ -78,6 +82,7 @@ fn test_fq12() {
         field_test(g, h);
     }
     frobenius_test::<Fq12, _>(Fq::characteristic(), 13);
+    cyclotomic_compressed_test::<Fp12ParamsWrapper<Fq12Parameters>, _>(2);
 }
 
 #[test]
@@ -2324,3 +2329,207 @@ fn test_fq12_mul_by_014() {
         assert_eq!(a, b);
     }
 }
+
+/// The hand-written `FqParameters` that `#[derive(FpParameters)]` replaced.
+struct HandWrittenFqParameters;
+
+impl FftParameters for HandWrittenFqParameters {
+    type BigInt = BigInteger384;
+
+    const TWO_ADICITY: u32 = 1;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger384 = BigInteger384([
+        0x43f5fffffffcaaae,
+        0x32b7fff2ed47fffd,
+        0x7e83a49a2e99d69,
+        0xeca8f3318332bb7a,
+        0xef148d1ea0f4c069,
+        0x40ab3263eff0206,
+    ]);
+}
+
+impl FpParameters for HandWrittenFqParameters {
+    /// MODULUS = 4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787
+    #[rustfmt::skip]
+    const MODULUS: BigInteger384 = BigInteger384([
+        0xb9feffffffffaaab,
+        0x1eabfffeb153ffff,
+        0x6730d2a0f6b0f624,
+        0x64774b84f38512bf,
+        0x4b1ba7b6434bacd7,
+        0x1a0111ea397fe69a,
+    ]);
+
+    const MODULUS_BITS: u32 = 381;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 3;
+
+    #[rustfmt::skip]
+    const R: BigInteger384 = BigInteger384([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger384 = BigInteger384([
+        0xf4df1f341c341746,
+        0xa76e6a609d104f1,
+        0x8de5476c4c95b6d5,
+        0x67eb88a9939d83c0,
+        0x9a793e85b519952d,
+        0x11988fe592cae3aa,
+    ]);
+
+    const INV: u64 = 0x89f3fffcfffcfffd;
+
+    // GENERATOR = 2
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger384 = BigInteger384([
+        0x321300000006554f,
+        0xb93c0018d6c40005,
+        0x57605e0db0ddbb51,
+        0x8b256521ed1f9bcb,
+        0x6cf28d7901622c03,
+        0x11ebab9dbb81e28c,
+    ]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger384 = BigInteger384([
+        0xdcff7fffffffd555,
+        0xf55ffff58a9ffff,
+        0xb39869507b587b12,
+        0xb23ba5c279c2895f,
+        0x258dd3db21a5d66b,
+        0xd0088f51cbff34d,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    #[rustfmt::skip]
+    const T: BigInteger384 = BigInteger384([
+        0xdcff7fffffffd555,
+        0xf55ffff58a9ffff,
+        0xb39869507b587b12,
+        0xb23ba5c279c2895f,
+        0x258dd3db21a5d66b,
+        0xd0088f51cbff34d,
+    ]);
+
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger384 = BigInteger384([
+        0xee7fbfffffffeaaa,
+        0x7aaffffac54ffff,
+        0xd9cc34a83dac3d89,
+        0xd91dd2e13ce144af,
+        0x92c6e9ed90d2eb35,
+        0x680447a8e5ff9a6,
+    ]);
+}
+
+#[test]
+fn test_fq_derived_parameters() {
+    fp_parameters_test::<HandWrittenFqParameters, FqParameters>();
+}
+
+/// The hand-written `FrParameters` that `#[derive(FpParameters)]` replaced.
+struct HandWrittenFrParameters;
+
+impl FftParameters for HandWrittenFrParameters {
+    type BigInt = BigInteger256;
+
+    const TWO_ADICITY: u32 = 32;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger256 = BigInteger256([
+        0xb9b58d8c5f0e466a,
+        0x5b1b4c801819d7ec,
+        0xaf53ae352a31e64,
+        0x5bf3adda19e9b27b,
+    ]);
+}
+
+impl FpParameters for HandWrittenFrParameters {
+    /// MODULUS = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+    #[rustfmt::skip]
+    const MODULUS: BigInteger256 = BigInteger256([
+        0xffffffff00000001,
+        0x53bda402fffe5bfe,
+        0x3339d80809a1d805,
+        0x73eda753299d7d48,
+    ]);
+
+    const MODULUS_BITS: u32 = 255;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 1;
+
+    #[rustfmt::skip]
+    const R: BigInteger256 = BigInteger256([
+        0x1fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger256 = BigInteger256([
+        0xc999e990f3f29c6d,
+        0x2b6cedcb87925c23,
+        0x5d314967254398f,
+        0x748d9d99f59ff11,
+    ]);
+
+    const INV: u64 = 0xfffffffeffffffff;
+
+    //
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger256 = BigInteger256([
+        0xefffffff1,
+        0x17e363d300189c0f,
+        0xff9c57876f8457b0,
+        0x351332208fc5a8c4,
+    ]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        0x7fffffff80000000,
+        0xa9ded2017fff2dff,
+        0x199cec0404d0ec02,
+        0x39f6d3a994cebea4,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    // T = (MODULUS - 1) / 2^S =
+    // 12208678567578594777604504606729831043093128246378069236549469339647
+    #[rustfmt::skip]
+    const T: BigInteger256 = BigInteger256([
+        0xfffe5bfeffffffff,
+        0x9a1d80553bda402,
+        0x299d7d483339d808,
+        0x73eda753,
+    ]);
+
+    // (T - 1) / 2 =
+    // 6104339283789297388802252303364915521546564123189034618274734669823
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        0x7fff2dff7fffffff,
+        0x4d0ec02a9ded201,
+        0x94cebea4199cec04,
+        0x39f6d3a9,
+    ]);
+}
+
+#[test]
+fn test_fr_derived_parameters() {
+    fp_parameters_test::<HandWrittenFrParameters, FrParameters>();
+}
@@ -0,0 +1,73 @@
+use crate::{
+    biginteger::{BigInteger256, BigInteger320},
+    bls24_315,
+    bls24_315::*,
+    curves::{
+        bls24,
+        models::{ModelParameters, SWModelParameters},
+    },
+    field_new, Zero,
+};
+
+pub type G1Affine = bls24::G1Affine<bls24_315::Parameters>;
+pub type G1Projective = bls24::G1Projective<bls24_315::Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = FQ_ZERO;
+
+    /// COEFF_B = 1
+    const COEFF_B: Fq = FQ_ONE;
+
+    /// COFACTOR = (x - 1)^2 / 3 = 3452012412914368512
+    const COFACTOR: &'static [u64] = &[0x2fe8030000000000];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    /// = 11502027748484983061746766725372608623013042341922603258659095701186171371503
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, BigInteger256([
+        0x9ee21baf28ca5801,
+        0xff0284a82cd8d2c3,
+        0x8e3b956639f674ba,
+        0x7edd76780d92707,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G1_GENERATOR_X, G1_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// G1_GENERATOR_X =
+/// 34223510504517033132712852754388476272837911830964394866541204856091481856889569724484362330263
+#[rustfmt::skip]
+pub const G1_GENERATOR_X: Fq = field_new!(Fq, BigInteger320([
+    0x95cdd3898f3e49d3,
+    0x67714ab92c2d48ba,
+    0x4d7b84b342dfd873,
+    0xa1e82adc86bc1bb7,
+    0x251ba4203abbf0a,
+]));
+
+/// G1_GENERATOR_Y =
+/// 24215295174889464585413596429561903295150472552154479431771837786124301185073987899223459122783
+#[rustfmt::skip]
+pub const G1_GENERATOR_Y: Fq = field_new!(Fq, BigInteger320([
+    0x3585be0325a5782c,
+    0x38b68b77915adb35,
+    0x4bece17d6681c6af,
+    0x826ee0633620195b,
+    0x2f52fa21edddc62,
+]));
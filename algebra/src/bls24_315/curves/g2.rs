@@ -0,0 +1,167 @@
+use crate::{
+    biginteger::{BigInteger256, BigInteger320},
+    bls24_315,
+    bls24_315::*,
+    curves::{
+        bls24,
+        models::{ModelParameters, SWModelParameters},
+    },
+    field_new, Zero,
+};
+
+pub type G2Affine = bls24::G2Affine<bls24_315::Parameters>;
+pub type G2Projective = bls24::G2Projective<bls24_315::Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq4;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = [[0, 0], [0, 0]]
+    const COEFF_A: Fq4 = FQ4_ZERO;
+
+    /// COEFF_B = 1 / V = [[0, 0], [0, 1 / 13]]
+    #[rustfmt::skip]
+    const COEFF_B: Fq4 = field_new!(Fq4,
+        FQ2_ZERO,
+        field_new!(Fq2,
+            FQ_ZERO,
+            field_new!(Fq, BigInteger320([
+                0xcde092b3f08d89d5,
+                0x38553725c12a12cf,
+                0xee56fbee79819ed5,
+                0x506e44dec78d1fd2,
+                0x27cdf800d3fe579,
+            ])),
+        ),
+    );
+
+    /// COFACTOR = #E'(Fq4) / r
+    /// = 216079035500590602943546242140422432107555648541092228905249925297233022840522997069049628086159486821981928133195442045258836056038368698198752015929588430502672406127261882483243231901352617383373863699144968206692699635819037532045432968648848220192219321417343498967027189130043882684380082463571969
+    #[rustfmt::skip]
+    const COFACTOR: &'static [u64] = &[
+        0xdf101e0000000001,
+        0xc6fe9ac0018b940a,
+        0x15b52c02797e31a2,
+        0xcd34eace3e7e7012,
+        0x83eb09e6f58e9055,
+        0x98dd4b373acdeee1,
+        0xfe00fe2b41efdc36,
+        0xbf73a8d2cc439fff,
+        0x469c774cb87cd287,
+        0x7a0dded7e599b628,
+        0xb006921322861513,
+        0x446602b3ec97c88d,
+        0x9f9a3e8f21811c01,
+        0xa3d20ecd4e893c62,
+        0xcf9c5e2d1e9744e1,
+        0x142a76791a4e,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    /// = 4554155043303784494090402065353399334353191703800238427501238319613167445417
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, BigInteger256([
+        0x3b379cc2b2cef5fb,
+        0xdcbeab25fd806773,
+        0x3b6fcd88160efd64,
+        0x13a9a4b820922379,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G2_GENERATOR_X, G2_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// G2_GENERATOR_X = [
+///     [24614737899199071964341749845083777103809664018538138889239909664991294445469052467064654073699,
+///      17049297748993841127032249156255993089778266476087413538366212660716380683149731996715975282972],
+///     [11950668649125904104557740112865942804623051114821811669564995102755430514441092495782202668342,
+///      3603055379462539802413979855826194299714805833759849528529386570240639115620788686893505938793],
+/// ]
+#[rustfmt::skip]
+pub const G2_GENERATOR_X: Fq4 = field_new!(Fq4,
+    field_new!(Fq2,
+        field_new!(Fq, BigInteger320([
+            0x66c2245bd9d7881f,
+            0xef97dff7a4455961,
+            0xa7a23a165f94a925,
+            0x600649facdd383bf,
+            0x2de2e391f51fb1c,
+        ])),
+        field_new!(Fq, BigInteger320([
+            0x4b3c32b0df0b5989,
+            0xd706da800132bc9a,
+            0x68d23e9ddbfe3f64,
+            0xa10dd5c31ad19412,
+            0x2aed41b56bb7d78,
+        ])),
+    ),
+    field_new!(Fq2,
+        field_new!(Fq, BigInteger320([
+            0xb280c5c3b1e31b13,
+            0xb368a8938cb37274,
+            0x663f2ebccf53a8fb,
+            0x17ee7894579f6815,
+            0xfe73da4d0a1c18,
+        ])),
+        field_new!(Fq, BigInteger320([
+            0x12cd55a21ebffe67,
+            0xa77fc0aacc1dbb5,
+            0x918470de8a8a9a76,
+            0xa1f6e9831feb77bb,
+            0x150e1fdc7854634,
+        ])),
+    ),
+);
+
+/// G2_GENERATOR_Y = [
+///     [31740092748246070457677943092194030978994615503726570180895475408200863271773078192139722193079,
+///      30261413948955264769241509843031153941332801192447678605718183215275065425758214858190865971597],
+///     [14195825602561496219090410113749222574308144851497375443809100117082380611212823440674391088885,
+///      2391152940984805871402135750194189812615420966694899795235607856168224901793030297133493038211],
+/// ]
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y: Fq4 = field_new!(Fq4,
+    field_new!(Fq2,
+        field_new!(Fq, BigInteger320([
+            0xbff705a606333e22,
+            0x31b578492fbb7edf,
+            0x3bece42b7d243542,
+            0x762efe160160165a,
+            0xac21ecf59c1015,
+        ])),
+        field_new!(Fq, BigInteger320([
+            0xfde7f179108ce94c,
+            0x2d9725abeccd6f95,
+            0xf1fc94f2ed7389fc,
+            0xcfa21e635db9d16f,
+            0x1074f89e2a514a8,
+        ])),
+    ),
+    field_new!(Fq2,
+        field_new!(Fq, BigInteger320([
+            0xcee3f6f218b9a481,
+            0x97206f095e126514,
+            0xc6c7b128c789592b,
+            0x1cba6143b6ccb376,
+            0x438c76587b9a35e,
+        ])),
+        field_new!(Fq, BigInteger320([
+            0x719f519309ffb5a9,
+            0xb1fb56ac75bde00b,
+            0x96965ce85de9b072,
+            0x64eb2ed4550422e1,
+            0x2f3d8d9af1e9bef,
+        ])),
+    ),
+);
@@ -0,0 +1,31 @@
+use algebra_core::curves::bls24::{Bls24, Bls24Parameters, TwistType};
+
+use crate::bls24_315::{Fq, Fq12Parameters, Fq24Parameters, Fq2Parameters, Fq4Parameters};
+
+pub mod g1;
+pub mod g2;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::{
+    g1::{G1Affine, G1Projective},
+    g2::{G2Affine, G2Projective},
+};
+
+pub type Bls24_315 = Bls24<Parameters>;
+
+pub struct Parameters;
+
+impl Bls24Parameters for Parameters {
+    const X: &'static [u64] = &[0xbfcfffff];
+    const X_IS_NEGATIVE: bool = true;
+    const TWIST_TYPE: TwistType = TwistType::D;
+    type Fp = Fq;
+    type Fp2Params = Fq2Parameters;
+    type Fp4Params = Fq4Parameters;
+    type Fp12Params = Fq12Parameters;
+    type Fp24Params = Fq24Parameters;
+    type G1Parameters = g1::Parameters;
+    type G2Parameters = g2::Parameters;
+}
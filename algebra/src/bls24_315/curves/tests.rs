@@ -0,0 +1,153 @@
+#![allow(unused_imports)]
+use algebra_core::{
+    curves::{models::SWModelParameters, AffineCurve, PairingEngine, ProjectiveCurve},
+    fields::{Field, FpParameters, PrimeField, SquareRootField, TorusCompression},
+    test_rng, One, Zero,
+};
+use core::ops::{AddAssign, MulAssign};
+use rand::Rng;
+
+use crate::{
+    bls24_315::{
+        g1, g2, Bls24_315, Fq, Fq24, Fq4, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+    },
+    tests::{
+        curves::{
+            curve_tests, pairing_output_test, pairing_torus_compression_test, sw_tests,
+            sw_xyzz_tests,
+        },
+        groups::group_test,
+    },
+};
+
+#[test]
+fn test_g1_projective_curve() {
+    curve_tests::<G1Projective>();
+
+    sw_tests::<g1::Parameters>();
+    sw_xyzz_tests::<g1::Parameters>();
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let mut rng = test_rng();
+    let a: G1Projective = rng.gen();
+    let b: G1Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    curve_tests::<G2Projective>();
+
+    sw_tests::<g2::Parameters>();
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let mut rng = test_rng();
+    let a: G2Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bilinearity() {
+    let mut rng = test_rng();
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+
+    let mut sa = a;
+    sa.mul_assign(s);
+    let mut sb = b;
+    sb.mul_assign(s);
+
+    let ans1 = Bls24_315::pairing(sa, b);
+    let ans2 = Bls24_315::pairing(a, sb);
+    let ans3 = Bls24_315::pairing(a, b).pow(s.into_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq24::one());
+    assert_ne!(ans2, Fq24::one());
+    assert_ne!(ans3, Fq24::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq24::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq24::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq24::one());
+}
+
+#[test]
+fn test_product_of_pairings() {
+    let mut rng = test_rng();
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let c: G1Projective = rng.gen();
+    let d: G2Projective = rng.gen();
+
+    let ans = Bls24_315::product_of_pairings(&[
+        (a.into_affine().into(), b.into_affine().into()),
+        (c.into_affine().into(), d.into_affine().into()),
+    ]);
+    assert_eq!(ans, Bls24_315::pairing(a, b) * &Bls24_315::pairing(c, d));
+
+    // e(P, Q) * e(-P, Q) = 1
+    let ans = Bls24_315::product_of_pairings(&[
+        (a.into_affine().into(), b.into_affine().into()),
+        ((-a).into_affine().into(), b.into_affine().into()),
+    ]);
+    assert_eq!(ans, Fq24::one());
+}
+
+#[test]
+fn test_torus_compression() {
+    pairing_torus_compression_test::<Bls24_315>();
+
+    // The compressed forms (0, c) are invalid, except for (0, 0) which is one.
+    assert_eq!(Fq24::torus_decompress(&(Fq4::zero(), Fq4::one())), None);
+}
+
+#[test]
+fn test_pairing_output() {
+    pairing_output_test::<Bls24_315>();
+}
+
+#[test]
+fn test_g1_generator_raw() {
+    let mut x = Fq::one();
+    loop {
+        // y^2 = x^3 + b
+        let mut rhs = x;
+        rhs.square_in_place();
+        rhs.mul_assign(&x);
+        rhs.add_assign(&g1::Parameters::COEFF_B);
+
+        if let Some(y) = rhs.sqrt() {
+            let p = G1Affine::new(x, if y < -y { y } else { -y }, false);
+            assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+
+            let g1 = G1Affine::from(p.scale_by_cofactor());
+            assert!(g1.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(g1, G1Affine::prime_subgroup_generator());
+            break;
+        }
+
+        x.add_assign(&Fq::one());
+    }
+}
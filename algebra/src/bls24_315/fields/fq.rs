@@ -0,0 +1,15 @@
+use algebra_core::{
+    biginteger::BigInteger320 as BigInteger,
+    field_new,
+    fields::{Fp320, FpParameters},
+};
+
+pub type Fq = Fp320<FqParameters>;
+
+#[derive(FpParameters)]
+#[modulus = "39705142709513438335025689890408969744933502416914749335064285505637884093126342347073617133569"]
+#[generator = "13"]
+pub struct FqParameters;
+
+pub const FQ_ONE: Fq = field_new!(Fq, FqParameters::R);
+pub const FQ_ZERO: Fq = field_new!(Fq, BigInteger([0, 0, 0, 0, 0]));
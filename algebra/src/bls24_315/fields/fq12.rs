@@ -0,0 +1,339 @@
+use crate::bls24_315::*;
+use algebra_core::{
+    biginteger::BigInteger320 as BigInteger,
+    field_new,
+    fields::{
+        fp12_3over4::{Fp12, Fp12Parameters},
+        Fp4Parameters,
+    },
+};
+
+pub type Fq12 = Fp12<Fq12Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq12Parameters;
+
+impl Fp12Parameters for Fq12Parameters {
+    type Fp4Params = Fq4Parameters;
+
+    /// NONRESIDUE = V
+    const NONRESIDUE: Fq4 = field_new!(Fq4, FQ2_ZERO, FQ2_ONE);
+
+    /// Coefficients for the Frobenius automorphism.
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP12_C1: &'static [Fq4] = &[
+        // Fq4(V)**(((q^0) - 1) / 3)
+        FQ4_ONE,
+        // Fq4(V)**(((q^1) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x21908ab21445494b,
+                    0x452f57f17fb2c12a,
+                    0xc38cb31938303c15,
+                    0x752bd6552457eefc,
+                    0x1615f503b2b5891,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^2) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xe1be0c8b8f56a44e,
+                    0x302694e5149a6c85,
+                    0x1542fcd80e4c5954,
+                    0xefd29f4fe442aa2e,
+                    0x2f85a44730b7d56,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^3) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xfae42bb398270bd0,
+                    0x1b8be1012ab8e38f,
+                    0x1cf98e018e23bb2f,
+                    0x901cd931ff164736,
+                    0xdcaf44f7689727,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^4) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x7caeae631976a484,
+                    0x22ab10f288a8bb09,
+                    0x1224e276540e04a2,
+                    0xa5fc22d3247ea347,
+                    0x3f096d6bd7cb263,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^5) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x493ba400c411c286,
+                    0x187b6ee9fdc40768,
+                    0x382eab02f86d99fa,
+                    0xeee84c68c43ab6e9,
+                    0x43d89f771c414e6,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^6) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x9af0a1d78a200036,
+                    0xf2847c0d740e4e83,
+                    0xfce1e59e45c1ab4d,
+                    0xb6298383403bf918,
+                    0xf83c924a71350c,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^7) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x4e57784d2beab6b6,
+                    0xfcef8de8d30b23d8,
+                    0x1b351d016a49deca,
+                    0x5ecb7336c5246fb3,
+                    0x360dab27a5b7dbf,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^8) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x8e29f673b0d95bb3,
+                    0x11f850f53e23787c,
+                    0xc97ed342942dc18c,
+                    0xe424aa3c0539b481,
+                    0x1c9dfbe427b58f9,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^9) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x7503d74ba808f431,
+                    0x269304d928050172,
+                    0xc1c8421914565fb1,
+                    0x43da7059ea661779,
+                    0x3e58abdbe1e3f29,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^10) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xf339549c26b95b7d,
+                    0x1f73d4e7ca1529f8,
+                    0xcc9ceda44e6c163e,
+                    0x2dfb26b8c4fdbb68,
+                    0xd1a32bf80a23ed,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**(((q^11) - 1) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x26ac5efe7c1e3d7b,
+                    0x29a376f054f9dd9a,
+                    0xa6932517aa0c80e6,
+                    0xe50efd232541a7c6,
+                    0x84b00b43c2c169,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+    ];
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP12_C2: &'static [Fq4] = &[
+        // Fq4(V)**((2 * (q^0) - 2) / 3)
+        FQ4_ONE,
+        // Fq4(V)**((2 * (q^1) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xe1be0c8b8f56a44e,
+                    0x302694e5149a6c85,
+                    0x1542fcd80e4c5954,
+                    0xefd29f4fe442aa2e,
+                    0x2f85a44730b7d56,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**((2 * (q^2) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x7caeae631976a484,
+                    0x22ab10f288a8bb09,
+                    0x1224e276540e04a2,
+                    0xa5fc22d3247ea347,
+                    0x3f096d6bd7cb263,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**((2 * (q^3) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x9af0a1d78a200036,
+                    0xf2847c0d740e4e83,
+                    0xfce1e59e45c1ab4d,
+                    0xb6298383403bf918,
+                    0xf83c924a71350c,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**((2 * (q^4) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x8e29f673b0d95bb3,
+                    0x11f850f53e23787c,
+                    0xc97ed342942dc18c,
+                    0xe424aa3c0539b481,
+                    0x1c9dfbe427b58f9,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**((2 * (q^5) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xf339549c26b95b7d,
+                    0x1f73d4e7ca1529f8,
+                    0xcc9ceda44e6c163e,
+                    0x2dfb26b8c4fdbb68,
+                    0xd1a32bf80a23ed,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**((2 * (q^6) - 2) / 3)
+        FQ4_ONE,
+        // Fq4(V)**((2 * (q^7) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xe1be0c8b8f56a44e,
+                    0x302694e5149a6c85,
+                    0x1542fcd80e4c5954,
+                    0xefd29f4fe442aa2e,
+                    0x2f85a44730b7d56,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**((2 * (q^8) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x7caeae631976a484,
+                    0x22ab10f288a8bb09,
+                    0x1224e276540e04a2,
+                    0xa5fc22d3247ea347,
+                    0x3f096d6bd7cb263,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**((2 * (q^9) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x9af0a1d78a200036,
+                    0xf2847c0d740e4e83,
+                    0xfce1e59e45c1ab4d,
+                    0xb6298383403bf918,
+                    0xf83c924a71350c,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**((2 * (q^10) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x8e29f673b0d95bb3,
+                    0x11f850f53e23787c,
+                    0xc97ed342942dc18c,
+                    0xe424aa3c0539b481,
+                    0x1c9dfbe427b58f9,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq4(V)**((2 * (q^11) - 2) / 3)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xf339549c26b95b7d,
+                    0x1f73d4e7ca1529f8,
+                    0xcc9ceda44e6c163e,
+                    0x2dfb26b8c4fdbb68,
+                    0xd1a32bf80a23ed,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+    ];
+
+    /// Multiply this element by the quadratic nonresidue V, using V^2 = U.
+    #[inline(always)]
+    fn mul_fp4_by_nonresidue(fe: &Fq4) -> Fq4 {
+        field_new!(Fq4, Fq4Parameters::mul_fp2_by_nonresidue(&fe.c1), fe.c0)
+    }
+}
+
+pub const FQ12_ZERO: Fq12 = field_new!(Fq12, FQ4_ZERO, FQ4_ZERO, FQ4_ZERO);
+pub const FQ12_ONE: Fq12 = field_new!(Fq12, FQ4_ONE, FQ4_ZERO, FQ4_ZERO);
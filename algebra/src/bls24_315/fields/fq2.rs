@@ -0,0 +1,41 @@
+use crate::bls24_315::*;
+use algebra_core::{biginteger::BigInteger320 as BigInteger, field_new, fields::*};
+
+pub type Fq2 = Fp2<Fq2Parameters>;
+
+pub struct Fq2Parameters;
+
+impl Fp2Parameters for Fq2Parameters {
+    type Fp = Fq;
+
+    /// NONRESIDUE = 13
+    #[rustfmt::skip]
+    const NONRESIDUE: Fq = field_new!(Fq, BigInteger([
+        0x717fd10bbceffd45,
+        0x75a264e0137fb258,
+        0xc4ccc7465c989dab,
+        0x3bca2ef9796975ca,
+        0x1ab9a9a58d4d14d,
+    ]));
+
+    /// QUADRATIC_NONRESIDUE = U
+    const QUADRATIC_NONRESIDUE: (Fq, Fq) = (FQ_ZERO, FQ_ONE);
+
+    /// Coefficients for the Frobenius automorphism.
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP2_C1: &'static [Fq] = &[
+        // Fq(13)**(((q^0) - 1) / 2)
+        FQ_ONE,
+        // Fq(13)**(((q^1) - 1) / 2)
+        field_new!(Fq, BigInteger([
+            0x9af0a1d78a200036,
+            0xf2847c0d740e4e83,
+            0xfce1e59e45c1ab4d,
+            0xb6298383403bf918,
+            0xf83c924a71350c,
+        ])),
+    ];
+}
+
+pub const FQ2_ZERO: Fq2 = field_new!(Fq2, FQ_ZERO, FQ_ZERO);
+pub const FQ2_ONE: Fq2 = field_new!(Fq2, FQ_ONE, FQ_ZERO);
@@ -0,0 +1,341 @@
+use crate::bls24_315::*;
+use algebra_core::{biginteger::BigInteger320 as BigInteger, field_new, fields::*};
+
+pub type Fq24 = Fp24<Fq24Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq24Parameters;
+
+impl Fp24Parameters for Fq24Parameters {
+    type Fp12Params = Fq12Parameters;
+
+    const NONRESIDUE: Fq12 = field_new!(Fq12, FQ4_ZERO, FQ4_ONE, FQ4_ZERO);
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP24_C1: &'static [Fq4] = &[
+        // Fq12(W)**(((q^0) - 1) / 2)
+        FQ4_ONE,
+        // Fq12(W)**(((q^1) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x65d085e82d0bb9fa,
+                    0x242274a4b78a6efc,
+                    0x5b12d15185577258,
+                    0x7d5452ae392c613c,
+                    0xae5b91a42576ec,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^2) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x21908ab21445494b,
+                    0x452f57f17fb2c12a,
+                    0xc38cb31938303c15,
+                    0x752bd6552457eefc,
+                    0x1615f503b2b5891,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^3) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xe2400c1be4dbe41a,
+                    0xfb7ad046541af6eb,
+                    0xb5558e7766406a0e,
+                    0x10688ef2cc34d984,
+                    0x37a4ff5b43b9f3d,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^4) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xe1be0c8b8f56a44e,
+                    0x302694e5149a6c85,
+                    0x1542fcd80e4c5954,
+                    0xefd29f4fe442aa2e,
+                    0x2f85a44730b7d56,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^5) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x6a4015f469c8eb31,
+                    0xd76a8094821db5d4,
+                    0xbd54aad441fd3d4d,
+                    0xcd4ab750952bad94,
+                    0x3c93fb84d7e0cfa,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^6) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xfae42bb398270bd0,
+                    0x1b8be1012ab8e38f,
+                    0x1cf98e018e23bb2f,
+                    0x901cd931ff164736,
+                    0xdcaf44f7689727,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^7) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x27927e7939ca2c5f,
+                    0x12897b796cd920a7,
+                    0x4a30b01b69d6af40,
+                    0xe9ef707337954968,
+                    0x342efde597f6755,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^8) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x7caeae631976a484,
+                    0x22ab10f288a8bb09,
+                    0x1224e276540e04a2,
+                    0xa5fc22d3247ea347,
+                    0x3f096d6bd7cb263,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^9) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x46f900c3cbd3137,
+                    0xb3480befca9346d8,
+                    0x6241d982bca5caf5,
+                    0x4ff664a25bff4c58,
+                    0x31ae426a958960e,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^10) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x493ba400c411c286,
+                    0x187b6ee9fdc40768,
+                    0x382eab02f86d99fa,
+                    0xeee84c68c43ab6e9,
+                    0x43d89f771c414e6,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^11) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xb53a755c951e4846,
+                    0x592d910d6b7c0ebd,
+                    0x739cf1bea6106011,
+                    0xad7e2b0c54dcce93,
+                    0x48ad9eb5aca9e69,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^12) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x9af0a1d78a200036,
+                    0xf2847c0d740e4e83,
+                    0xfce1e59e45c1ab4d,
+                    0xb6298383403bf918,
+                    0xf83c924a71350c,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^13) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xa177d1713244607,
+                    0x1dfc71359b337606,
+                    0x83aefec91d22a888,
+                    0x56a2f6ddb04ffd73,
+                    0x413de7111615f64,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^14) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x4e57784d2beab6b6,
+                    0xfcef8de8d30b23d8,
+                    0x1b351d016a49deca,
+                    0x5ecb7336c5246fb3,
+                    0x360dab27a5b7dbf,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^15) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x8da7f6e35b541be7,
+                    0x46a41593fea2ee16,
+                    0x296c41a33c39b0d1,
+                    0xc38eba991d47852b,
+                    0x147ea0d014b3713,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^16) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x8e29f673b0d95bb3,
+                    0x11f850f53e23787c,
+                    0xc97ed342942dc18c,
+                    0xe424aa3c0539b481,
+                    0x1c9dfbe427b58f9,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^17) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x5a7ed0ad66714d0,
+                    0x6ab46545d0a02f2e,
+                    0x216d2546607cdd92,
+                    0x6ac923b5450b11b,
+                    0xf8fa4a6808c956,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^18) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x7503d74ba808f431,
+                    0x269304d928050172,
+                    0xc1c8421914565fb1,
+                    0x43da7059ea661779,
+                    0x3e58abdbe1e3f29,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^19) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x485584860665d3a2,
+                    0x2f956a60e5e4c45b,
+                    0x94911fff38a36ba0,
+                    0xea07d918b1e71547,
+                    0x17f4a245c076efa,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^20) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xf339549c26b95b7d,
+                    0x1f73d4e7ca1529f8,
+                    0xcc9ceda44e6c163e,
+                    0x2dfb26b8c4fdbb68,
+                    0xd1a32bf80a23ed,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^21) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x6b7872f30372ceca,
+                    0x8ed6d9ea882a9e2a,
+                    0x7c7ff697e5d44fea,
+                    0x8400e4e98d7d1257,
+                    0x1a755dc0c2e4042,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^22) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0x26ac5efe7c1e3d7b,
+                    0x29a376f054f9dd9a,
+                    0xa6932517aa0c80e6,
+                    0xe50efd232541a7c6,
+                    0x84b00b43c2c169,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+        // Fq12(W)**(((q^23) - 1) / 2)
+        field_new!(Fq4,
+            field_new!(Fq2,
+                field_new!(Fq, BigInteger([
+                    0xbaad8da2ab11b7bb,
+                    0xe8f154cce741d644,
+                    0x6b24de5bfc69bace,
+                    0x26791e7f949f901c,
+                    0x3760175abc37e7,
+                ])),
+                FQ_ZERO,
+            ),
+            FQ2_ZERO,
+        ),
+    ];
+}
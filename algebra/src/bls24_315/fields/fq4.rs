@@ -0,0 +1,47 @@
+use crate::bls24_315::*;
+use algebra_core::{biginteger::BigInteger320 as BigInteger, field_new, fields::*};
+
+pub type Fq4 = Fp4<Fq4Parameters>;
+
+pub struct Fq4Parameters;
+
+impl Fp4Parameters for Fq4Parameters {
+    type Fp2Params = Fq2Parameters;
+
+    /// NONRESIDUE = U
+    const NONRESIDUE: Fq2 = field_new!(Fq2, FQ_ZERO, FQ_ONE);
+
+    /// Coefficients for the Frobenius automorphism.
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP4_C1: &'static [Fq] = &[
+        // Fq(13)**(((q^0) - 1) / 4)
+        FQ_ONE,
+        // Fq(13)**(((q^1) - 1) / 4)
+        field_new!(Fq, BigInteger([
+            0xfae42bb398270bd0,
+            0x1b8be1012ab8e38f,
+            0x1cf98e018e23bb2f,
+            0x901cd931ff164736,
+            0xdcaf44f7689727,
+        ])),
+        // Fq(13)**(((q^2) - 1) / 4)
+        field_new!(Fq, BigInteger([
+            0x9af0a1d78a200036,
+            0xf2847c0d740e4e83,
+            0xfce1e59e45c1ab4d,
+            0xb6298383403bf918,
+            0xf83c924a71350c,
+        ])),
+        // Fq(13)**(((q^3) - 1) / 4)
+        field_new!(Fq, BigInteger([
+            0x7503d74ba808f431,
+            0x269304d928050172,
+            0xc1c8421914565fb1,
+            0x43da7059ea661779,
+            0x3e58abdbe1e3f29,
+        ])),
+    ];
+}
+
+pub const FQ4_ZERO: Fq4 = field_new!(Fq4, FQ2_ZERO, FQ2_ZERO);
+pub const FQ4_ONE: Fq4 = field_new!(Fq4, FQ2_ONE, FQ2_ZERO);
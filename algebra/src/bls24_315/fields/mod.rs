@@ -0,0 +1,20 @@
+pub mod fr;
+pub use self::fr::*;
+
+pub mod fq;
+pub use self::fq::*;
+
+pub mod fq2;
+pub use self::fq2::*;
+
+pub mod fq4;
+pub use self::fq4::*;
+
+pub mod fq12;
+pub use self::fq12::*;
+
+pub mod fq24;
+pub use self::fq24::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,379 @@
+use algebra_core::{
+    biginteger::{BigInteger256, BigInteger320},
+    fields::{FftParameters, Field, Fp24ParamsWrapper, FpParameters, SquareRootField},
+    One, UniformRand, Zero,
+};
+use core::ops::MulAssign;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+use crate::{
+    bls24_315::{Fq, Fq12, Fq2, Fq24, Fq24Parameters, Fq4, FqParameters, Fr, FrParameters},
+    tests::fields::{
+        cyclotomic_compressed_test, field_test, fp_parameters_test, frobenius_test,
+        primefield_test, sqrt_field_test,
+    },
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fr() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let a: Fr = UniformRand::rand(&mut rng);
+        let b: Fr = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+    }
+}
+
+#[test]
+fn test_fq() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let a: Fq = UniformRand::rand(&mut rng);
+        let b: Fq = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+    }
+}
+
+#[test]
+fn test_fq2() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = UniformRand::rand(&mut rng);
+        let b: Fq2 = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_fq4() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let a: Fq4 = UniformRand::rand(&mut rng);
+        let b: Fq4 = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq4, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_fq12() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let g: Fq12 = UniformRand::rand(&mut rng);
+        let h: Fq12 = UniformRand::rand(&mut rng);
+        field_test(g, h);
+    }
+    frobenius_test::<Fq12, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_fq24() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let g: Fq24 = UniformRand::rand(&mut rng);
+        let h: Fq24 = UniformRand::rand(&mut rng);
+        field_test(g, h);
+    }
+    frobenius_test::<Fq24, _>(Fq::characteristic(), 25);
+    cyclotomic_compressed_test::<Fp24ParamsWrapper<Fq24Parameters>, _>(4);
+}
+
+#[test]
+fn test_fq24_cyclotomic_square() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        // Raise a random element to the power `(p^12 - 1) * (p^4 + 1)` to land
+        // in the cyclotomic subgroup.
+        let f: Fq24 = UniformRand::rand(&mut rng);
+        let mut g = f.unitary_inverse() * &f.inverse().unwrap();
+        let mut g_p4 = g;
+        g_p4.frobenius_map(4);
+        g *= &g_p4;
+        assert_eq!(g.cyclotomic_square(), g.square());
+    }
+}
+
+#[test]
+fn test_fq12_mul_by_1() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let c1 = Fq4::rand(&mut rng);
+        let mut a = Fq12::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_1(&c1);
+        b.mul_assign(&Fq12::new(Fq4::zero(), c1, Fq4::zero()));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq12_mul_by_01() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let c0 = Fq4::rand(&mut rng);
+        let c1 = Fq4::rand(&mut rng);
+        let mut a = Fq12::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_01(&c0, &c1);
+        b.mul_assign(&Fq12::new(c0, c1, Fq4::zero()));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq24_mul_by_014() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let c0 = Fq4::rand(&mut rng);
+        let c1 = Fq4::rand(&mut rng);
+        let c5 = Fq4::rand(&mut rng);
+        let mut a = Fq24::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_014(&c0, &c1, &c5);
+        b.mul_assign(&Fq24::new(
+            Fq12::new(c0, c1, Fq4::zero()),
+            Fq12::new(Fq4::zero(), c5, Fq4::zero()),
+        ));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq24_mul_by_034() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let c0 = Fq4::rand(&mut rng);
+        let c3 = Fq4::rand(&mut rng);
+        let c4 = Fq4::rand(&mut rng);
+        let mut a = Fq24::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_034(&c0, &c3, &c4);
+        b.mul_assign(&Fq24::new(
+            Fq12::new(c0, Fq4::zero(), Fq4::zero()),
+            Fq12::new(c3, c4, Fq4::zero()),
+        ));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq2_nonresidue() {
+    // Fq2 = Fq[u]/(u^2 - 13), so 13 must not be a square in Fq.
+    assert!(Fq::from(13u64).sqrt().is_none());
+    // Fq4 = Fq2[v]/(v^2 - u), so u must not be a square in Fq2.
+    assert!(Fq2::new(Fq::zero(), Fq::one()).sqrt().is_none());
+}
+
+/// The hand-written `FqParameters` that `#[derive(FpParameters)]` replaced.
+struct HandWrittenFqParameters;
+
+impl FftParameters for HandWrittenFqParameters {
+    type BigInt = BigInteger320;
+
+    const TWO_ADICITY: u32 = 20;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger320 = BigInteger320([
+        0x9b5d1647037ca81a,
+        0x12dd386125a33a28,
+        0xd3e15f6e5bd15edc,
+        0x91f8b99440a9df59,
+        0x42c8c47696901ac,
+    ]);
+}
+
+impl FpParameters for HandWrittenFqParameters {
+    /// MODULUS = 39705142709513438335025689890408969744933502416914749335064285505637884093126342347073617133569
+    #[rustfmt::skip]
+    const MODULUS: BigInteger320 = BigInteger320([
+        0x6fe802ff40300001,
+        0x421ee5da52bde502,
+        0xdec1d01aa27a1ae0,
+        0xd3f7498be97c5eaf,
+        0x4c23a02b586d650,
+    ]);
+
+    const MODULUS_BITS: u32 = 315;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 5;
+
+    #[rustfmt::skip]
+    const R: BigInteger320 = BigInteger320([
+        0xd4f76127b60fffcb,
+        0x4f9a69ccdeaf967e,
+        0xe1dfea7c5cb86f92,
+        0x1dcdc608a9406596,
+        0x3c9fd706b15a144,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger320 = BigInteger320([
+        0x6b817891fe329c16,
+        0x599ce86eec6e2c35,
+        0xc338890f540d5ad6,
+        0xcc160f6924c81f32,
+        0x215d8d4607a88d5,
+    ]);
+
+    const INV: u64 = 0x702ff9ff402fffff;
+
+    // GENERATOR = 13
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger320 = BigInteger320([
+        0x717fd10bbceffd45,
+        0x75a264e0137fb258,
+        0xc4ccc7465c989dab,
+        0x3bca2ef9796975ca,
+        0x1ab9a9a58d4d14d,
+    ]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger320 = BigInteger320([
+        0x37f4017fa0180000,
+        0x210f72ed295ef281,
+        0xef60e80d513d0d70,
+        0x69fba4c5f4be2f57,
+        0x2611d015ac36b28,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    #[rustfmt::skip]
+    const T: BigInteger320 = BigInteger320([
+        0xde5026fe802ff403,
+        0xa1ae0421ee5da52b,
+        0xc5eafdec1d01aa27,
+        0x6d650d3f7498be97,
+        0x4c23a02b58,
+    ]);
+
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger320 = BigInteger320([
+        0xef28137f4017fa01,
+        0xd0d70210f72ed295,
+        0xe2f57ef60e80d513,
+        0x36b2869fba4c5f4b,
+        0x2611d015ac,
+    ]);
+}
+
+#[test]
+fn test_fq_derived_parameters() {
+    fp_parameters_test::<HandWrittenFqParameters, FqParameters>();
+}
+
+/// The hand-written `FrParameters` that `#[derive(FpParameters)]` replaced.
+struct HandWrittenFrParameters;
+
+impl FftParameters for HandWrittenFrParameters {
+    type BigInt = BigInteger256;
+
+    const TWO_ADICITY: u32 = 22;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger256 = BigInteger256([
+        0x25207d1f93553fa6,
+        0xfc4fd5481e590848,
+        0x80ec632543b1e463,
+        0x2e1c91cf663e7c,
+    ]);
+}
+
+impl FpParameters for HandWrittenFrParameters {
+    /// MODULUS = 11502027791375260645628074404575422495959608200132055716665986169834464870401
+    #[rustfmt::skip]
+    const MODULUS: BigInteger256 = BigInteger256([
+        0x19d0c5fd00c00001,
+        0xc8c480ece644e364,
+        0x25fc7ec9cf927a98,
+        0x196deac24a9da12b,
+    ]);
+
+    const MODULUS_BITS: u32 = 253;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 3;
+
+    #[rustfmt::skip]
+    const R: BigInteger256 = BigInteger256([
+        0xfdd8441df87ffff6,
+        0x2852f6bf014f1e16,
+        0x84230c1de4473608,
+        0x1b4d46915d7b450,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger256 = BigInteger256([
+        0x56a1ff2e50fc8851,
+        0xeb3f198d55a12c3f,
+        0x9799359271b08283,
+        0x81d245007d35a5a,
+    ]);
+
+    const INV: u64 = 0x1e5035fd00bfffff;
+
+    // GENERATOR = 7
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger256 = BigInteger256([
+        0xf0e9dcd1cb7fffba,
+        0x1a44bf390929d2a0,
+        0x9cf554d13df27a39,
+        0xbf1cedf98e5ee33,
+    ]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        0xce862fe80600000,
+        0x64624076732271b2,
+        0x92fe3f64e7c93d4c,
+        0xcb6f561254ed095,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    #[rustfmt::skip]
+    const T: BigInteger256 = BigInteger256([
+        0x138d90674317f403,
+        0x49ea63231203b399,
+        0x7684ac97f1fb273e,
+        0x65b7ab092a,
+    ]);
+
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        0x89c6c833a18bfa01,
+        0x24f531918901d9cc,
+        0x3b42564bf8fd939f,
+        0x32dbd58495,
+    ]);
+}
+
+#[test]
+fn test_fr_derived_parameters() {
+    fp_parameters_test::<HandWrittenFrParameters, FrParameters>();
+}
@@ -0,0 +1,26 @@
+//! This module implements the BLS24_315 curve of [[EG21]](https://eprint.iacr.org/2021/1359).
+//! The name denotes that it is a Barreto--Lynn--Scott curve of embedding degree 24,
+//! defined over a 315-bit (prime) field.
+//! Its 253-bit scalar field makes it suitable as the inner curve of a 2-chain
+//! with a BW6 outer curve.
+//!
+//!
+//! Curve information:
+//! * Base field: q = 39705142709513438335025689890408969744933502416914749335064285505637884093126342347073617133569
+//! * Scalar field: r = 11502027791375260645628074404575422495959608200132055716665986169834464870401
+//! * valuation(q - 1, 2) = 20
+//! * valuation(r - 1, 2) = 22
+//! * G1 curve equation: y^2 = x^3 + 1
+//! * G2 curve equation: y^2 = x^3 + 1 / v, over Fq4 = Fq2[v]/(v^2 - u)
+//!
+//! The extension tower is
+//! * Fq2 = Fq[u]/(u^2 - 13)
+//! * Fq4 = Fq2[v]/(v^2 - u)
+//! * Fq12 = Fq4[w]/(w^3 - v)
+//! * Fq24 = Fq12[z]/(z^2 - w)
+
+mod curves;
+mod fields;
+
+pub use curves::*;
+pub use fields::*;
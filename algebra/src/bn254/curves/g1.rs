@@ -1,7 +1,13 @@
 use algebra_core::{
     biginteger::BigInteger256,
-    curves::models::{ModelParameters, SWModelParameters},
-    field_new, Zero,
+    curves::{
+        glv::GLVParameters,
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::GroupProjective,
+    },
+    field_new,
+    hashing::curve_maps::svdw::SVDWParameters,
+    Zero,
 };
 
 use crate::bn254::{Fq, Fr};
@@ -43,12 +49,147 @@ impl SWModelParameters for Parameters {
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
         (G1_GENERATOR_X, G1_GENERATOR_Y);
 
+    const GLV: Option<GLVParameters<Self>> = Some(GLVParameters {
+        lambda: GLV_LAMBDA,
+        basis: GLV_BASIS,
+        rounding: GLV_ROUNDING,
+        endomorphism,
+    });
+
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
 }
 
+/// The endomorphism `(x, y) -> (BETA * x, y)`.
+fn endomorphism(p: &GroupProjective<Parameters>) -> GroupProjective<Parameters> {
+    let mut res = *p;
+    res.x *= &BETA;
+    res
+}
+
+/// BETA = 2203960485148121921418603742825762020974279258880205651966,
+/// a primitive cube root of unity in Fq.
+#[rustfmt::skip]
+pub const BETA: Fq = field_new!(Fq, BigInteger256([
+    0x71930c11d782e155,
+    0xa6bb947cffbe3323,
+    0xaa303344d4741444,
+    0x2c3b3f0d26594943,
+]));
+
+/// GLV_LAMBDA = 4407920970296243842393367215006156084916469457145843978461,
+/// the eigenvalue of `endomorphism` on G1.
+#[rustfmt::skip]
+const GLV_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0x93e7cede4a0329b3,
+    0x7d4fdca77a96c167,
+    0x8be4ba08b19a750a,
+    0x1cbd5653a5661c25,
+]));
+
+/// GLV_BASIS = [
+///     (9931322734385697763, -147946756881789319000765030803803410728),
+///     (147946756881789319010696353538189108491, 9931322734385697763),
+/// ]
+#[rustfmt::skip]
+const GLV_BASIS: [(Fr, Fr); 2] = [
+    (
+        field_new!(Fr, BigInteger256([
+            0x24652cc2a81f4c29,
+            0xb67a59f4814d4d84,
+            0xf71dfeba0ae59e71,
+            0x14ee37d3e52329df,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xd4637ad3591eb18e,
+            0xf91bd72c3b060c6d,
+            0x97318e89386c6da1,
+            0x1108a44ae70a3861,
+        ])),
+    ),
+    (
+        field_new!(Fr, BigInteger256([
+            0x5001b1ef4f009a9b,
+            0xbd5e82c846474116,
+            0x5fec7030d27930cf,
+            0x3e59388fe18f17e,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x24652cc2a81f4c29,
+            0xb67a59f4814d4d84,
+            0xf71dfeba0ae59e71,
+            0x14ee37d3e52329df,
+        ])),
+    ),
+];
+
+/// GLV_ROUNDING = [round(2^256 * b2 / d), round(-2^256 * b1 / d)], where
+/// d = a1 * b2 - a2 * b1 = r
+#[rustfmt::skip]
+const GLV_ROUNDING: [BigInteger256; 2] = [
+    BigInteger256([
+        0xd91d232ec7e0b3d7,
+        0x2,
+        0x0,
+        0x0,
+    ]),
+    BigInteger256([
+        0x7a7bd9d4391eb18e,
+        0x4ccef014a773d2cf,
+        0x2,
+        0x0,
+    ]),
+];
+
+impl SVDWParameters for Parameters {
+    /// ZETA = 1
+    #[rustfmt::skip]
+    const ZETA: Fq = field_new!(Fq, BigInteger256([
+        0xd35d438dc58f0d9d,
+        0xa78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0xe0a77c19a07df2f,
+    ]));
+
+    /// C1 = g(ZETA) = ZETA^3 + b
+    #[rustfmt::skip]
+    const C1: Fq = field_new!(Fq, BigInteger256([
+        0x115482203dbf392d,
+        0x926242126eaa626a,
+        0xe16a48076063c052,
+        0x7c5909386eddc93,
+    ]));
+
+    /// C2 = -ZETA / 2
+    #[rustfmt::skip]
+    const C2: Fq = field_new!(Fq, BigInteger256([
+        0xb461a4448976f7d5,
+        0xc6843fb439555fa7,
+        0x28f0d12384840918,
+        0x112ceb58a394e07d,
+    ]));
+
+    /// C3 = sqrt(-g(ZETA) * 3 * ZETA^2)
+    #[rustfmt::skip]
+    const C3: Fq = field_new!(Fq, BigInteger256([
+        0x7c8487078735ab72,
+        0x51da7e0048bfb8d4,
+        0x945cfd183cbd7bf4,
+        0xb70b1ec48ae62c6,
+    ]));
+
+    /// C4 = -4 * g(ZETA) / (3 * ZETA^2)
+    #[rustfmt::skip]
+    const C4: Fq = field_new!(Fq, BigInteger256([
+        0xa79a2bdca0800831,
+        0x19fd7617e49815a1,
+        0xbb8d0c885550c7b1,
+        0x5c4aeb6ec7e0f48,
+    ]));
+}
+
 /// G1_GENERATOR_X =
 /// 1
 #[rustfmt::skip]
@@ -1,10 +1,17 @@
 use algebra_core::{
     biginteger::BigInteger256,
-    curves::models::{ModelParameters, SWModelParameters},
-    field_new, Zero,
+    curves::{
+        bn,
+        glv::GLSParameters,
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    field_new,
+    hashing::curve_maps::svdw::SVDWParameters,
+    BitIteratorBE, PrimeField, Zero,
 };
 
-use crate::bn254::{g1, Fq, Fq2, Fr};
+use crate::bn254::{self, g1, Fq, Fq2, Fr};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Parameters;
@@ -63,10 +70,303 @@ impl SWModelParameters for Parameters {
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
         (G2_GENERATOR_X, G2_GENERATOR_Y);
 
+    const GLS: Option<GLSParameters<Self>> = Some(GLSParameters {
+        lambda: GLS_LAMBDA,
+        basis: GLS_BASIS,
+        rounding: GLS_ROUNDING,
+        endomorphism,
+    });
+
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    fn clear_cofactor(p: &GroupAffine<Self>) -> GroupAffine<Self> {
+        bn::g2::clear_cofactor::<bn254::Parameters>(p)
+    }
+
+    /// Tests membership of G2 by checking that `endomorphism(p) = GLS_LAMBDA * p`,
+    /// where `GLS_LAMBDA = 6 * x^2`, as in Section 4.3 of
+    /// <https://eprint.iacr.org/2022/352>. The kernel of
+    /// `endomorphism - GLS_LAMBDA` is exactly G2, as its degree is
+    /// `GLS_LAMBDA^2 - t * GLS_LAMBDA + p = r`, where `t` is the trace of Frobenius.
+    fn is_in_correct_subgroup_assuming_on_curve(p: &GroupAffine<Self>) -> bool {
+        let lambda_times_p = p.mul_bits(BitIteratorBE::new(GLS_LAMBDA.into_repr()));
+        endomorphism(&GroupProjective::from(*p)) == lambda_times_p
+    }
+}
+
+/// The untwist-Frobenius-twist endomorphism `psi`, which acts on G2 as
+/// multiplication by `p`.
+fn endomorphism(p: &GroupProjective<Parameters>) -> GroupProjective<Parameters> {
+    let mut res = *p;
+    res.x.conjugate();
+    res.x *= &PSI_COEFF_X;
+    res.y.conjugate();
+    res.y *= &PSI_COEFF_Y;
+    res.z.conjugate();
+    res
+}
+
+/// PSI_COEFF_X = (21575463638280843010398324269430826099269044274347216827212613867836435027261, 10307601595873709700152284273816112264069230130616436755625194854815875713954)
+#[rustfmt::skip]
+const PSI_COEFF_X: Fq2 = field_new!(Fq2,
+    field_new!(Fq, BigInteger256([
+        0xb5773b104563ab30,
+        0x347f91c8a9aa6454,
+        0x7a007127242e0991,
+        0x1956bcd8118214ec,
+    ])),
+    field_new!(Fq, BigInteger256([
+        0x6e849f1ea0aa4757,
+        0xaa1c7b6d89f89141,
+        0xb6e713cdfae0ca3a,
+        0x26694fbb4e82ebc3,
+    ])),
+);
+
+/// PSI_COEFF_Y = (2821565182194536844548159561693502659359617185244120367078079554186484126554, 3505843767911556378687030309984248845540243509899259641013678093033130930403)
+#[rustfmt::skip]
+const PSI_COEFF_Y: Fq2 = field_new!(Fq2,
+    field_new!(Fq, BigInteger256([
+        0xe4bbdd0c2936b629,
+        0xbb30f162e133bacb,
+        0x31a9d1b6f9645366,
+        0x253570bea500f8dd,
+    ])),
+    field_new!(Fq, BigInteger256([
+        0xa1d77ce45ffe77c7,
+        0x7affd117826d1db,
+        0x6d16bd27bb7edc6b,
+        0x2c87200285defecc,
+    ])),
+);
+
+/// GLS_LAMBDA = 147946756881789318990833708069417712966,
+/// the eigenvalue of `endomorphism` on G2.
+#[rustfmt::skip]
+const GLS_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0xf7af821a3ec20245,
+    0xaf9a2dbd5cc6e3c8,
+    0x906f5be2b6a89277,
+    0x1877ea15af0c1d17,
+]));
+
+/// GLS_BASIS = [
+///     (9931322734385697763, 0, 9931322734385697762, 1),
+///     (9931322734385697762, 4965661367192848882, -4965661367192848881, 4965661367192848881),
+///     (4965661367192848882, 4965661367192848881, 4965661367192848881, -9931322734385697762),
+///     (9931322734385697763, -4965661367192848881, -4965661367192848882, -4965661367192848881),
+/// ]
+#[rustfmt::skip]
+const GLS_BASIS: [[Fr; 4]; 4] = [
+    [
+        field_new!(Fr, BigInteger256([
+            0x24652cc2a81f4c29,
+            0xb67a59f4814d4d84,
+            0xf71dfeba0ae59e71,
+            0x14ee37d3e52329df,
+        ])),
+        field_new!(Fr, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+        field_new!(Fr, BigInteger256([
+            0x77cef8a6581f4c2e,
+            0x7f7de35ee1ec805a,
+            0x90af5b4a926c5843,
+            0x6e3c0124b1b4ab0,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xac96341c4ffffffb,
+            0x36fc76959f60cd29,
+            0x666ea36f7879462e,
+            0xe0a77c19a07df2f,
+        ])),
+    ],
+    [
+        field_new!(Fr, BigInteger256([
+            0x77cef8a6581f4c2e,
+            0x7f7de35ee1ec805a,
+            0x90af5b4a926c5843,
+            0x6e3c0124b1b4ab0,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xe87db06f7c0fa612,
+            0xf6bb684510570d56,
+            0xaec65114c1af724f,
+            0x117c57cabf958487,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x7fa7940c3f059ea,
+            0x6874f69908c33064,
+            0x6ff89811384b2c3b,
+            0x2cf26e69bba3fad1,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x3be77c532c0fa617,
+            0xbfbef1af70f6402d,
+            0x4857ada549362c21,
+            0x371e009258da558,
+        ])),
+    ],
+    [
+        field_new!(Fr, BigInteger256([
+            0xe87db06f7c0fa612,
+            0xf6bb684510570d56,
+            0xaec65114c1af724f,
+            0x117c57cabf958487,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x3be77c532c0fa617,
+            0xbfbef1af70f6402d,
+            0x4857ada549362c21,
+            0x371e009258da558,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x3be77c532c0fa617,
+            0xbfbef1af70f6402d,
+            0x4857ada549362c21,
+            0x371e009258da558,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xcc12fced97e0b3d3,
+            0xa8b604e997ccf036,
+            0x27a0ea6bef150019,
+            0x29808e6096165579,
+        ])),
+    ],
+    [
+        field_new!(Fr, BigInteger256([
+            0x24652cc2a81f4c29,
+            0xb67a59f4814d4d84,
+            0xf71dfeba0ae59e71,
+            0x14ee37d3e52329df,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x7fa7940c3f059ea,
+            0x6874f69908c33064,
+            0x6ff89811384b2c3b,
+            0x2cf26e69bba3fad1,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x5b64452473f059ef,
+            0x317880036962633a,
+            0x989f4a1bfd1e60d,
+            0x1ee7f6a8219c1ba2,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x7fa7940c3f059ea,
+            0x6874f69908c33064,
+            0x6ff89811384b2c3b,
+            0x2cf26e69bba3fad1,
+        ])),
+    ],
+];
+
+/// GLS_ROUNDING = [round(2^256 * c_1), ..., round(2^256 * c_4)], where
+/// (c_1, c_2, c_3, c_4) are the coordinates of (1, 0, 0, 0) in GLS_BASIS
+#[rustfmt::skip]
+const GLS_ROUNDING: [BigInteger256; 4] = [
+    BigInteger256([
+        0x2dff291532e42728,
+        0x55b4ca7ba3e5577f,
+        0x9e80318ab0d92b95,
+        0x0,
+    ]),
+    BigInteger256([
+        0x46f4bda995d51bb1,
+        0x8e5da66fc7184ae,
+        0x9e80318ab0d92b93,
+        0x0,
+    ]),
+    BigInteger256([
+        0xd91d232ec7e0b3d7,
+        0x2,
+        0x0,
+        0x0,
+    ]),
+    BigInteger256([
+        0xc170977dcef3cd3f,
+        0x55b4ca7ba3e5577d,
+        0x9e80318ab0d92b95,
+        0x0,
+    ]),
+];
+
+impl SVDWParameters for Parameters {
+    /// ZETA = 1
+    #[rustfmt::skip]
+    const ZETA: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger256([
+            0xd35d438dc58f0d9d,
+            0xa78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0xe0a77c19a07df2f,
+        ])),
+        field_new!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+    );
+
+    /// C1 = g(ZETA) = ZETA^3 + b
+    #[rustfmt::skip]
+    const C1: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger256([
+            0xd335f05a64ca12fe,
+            0x75029bbec388940d,
+            0xd4d64ba9406d402e,
+            0x2baef80fc5ae772,
+        ])),
+        field_new!(Fq, BigInteger256([
+            0x38e7ecccd1dcff67,
+            0x65f0b37d93ce0d3e,
+            0xd749d0dd22ac00aa,
+            0x141b9ce4a688d4d,
+        ])),
+    );
+
+    /// C2 = -ZETA / 2
+    #[rustfmt::skip]
+    const C2: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger256([
+            0xb461a4448976f7d5,
+            0xc6843fb439555fa7,
+            0x28f0d12384840918,
+            0x112ceb58a394e07d,
+        ])),
+        field_new!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0])),
+    );
+
+    /// C3 = sqrt(-g(ZETA) * 3 * ZETA^2)
+    #[rustfmt::skip]
+    const C3: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger256([
+            0xaaad0cab9a24277f,
+            0xf2209f5b7e5b757a,
+            0xc3a46b7e850013a7,
+            0x1f9e7f3768c5c9af,
+        ])),
+        field_new!(Fq, BigInteger256([
+            0x412278c8de85d863,
+            0xfe3e4c7f559d375a,
+            0x5e44b9da0a96ad23,
+            0x297d818d387725c8,
+        ])),
+    );
+
+    /// C4 = -4 * g(ZETA) / (3 * ZETA^2)
+    #[rustfmt::skip]
+    const C4: Fq2 = field_new!(Fq2,
+        field_new!(Fq, BigInteger256([
+            0x63cdc796b49b3a32,
+            0x73a8220d40eb16f6,
+            0xb46d1eed55c49000,
+            0x1c9ef4f5f0528b82,
+        ])),
+        field_new!(Fq, BigInteger256([
+            0x9aeb505b1600fe13,
+            0x64eb25e9f8b4638f,
+            0x43edd9e4fdf1577a,
+            0x2eb756b528a63917,
+        ])),
+    );
 }
 
 #[rustfmt::skip]
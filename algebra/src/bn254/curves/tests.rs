@@ -1,17 +1,29 @@
 #![allow(unused_imports)]
 use algebra_core::{
-    curves::{models::SWModelParameters, AffineCurve, PairingEngine, ProjectiveCurve},
+    curves::{
+        bn::BnParameters, models::SWModelParameters, AffineCurve, PairingEngine, ProjectiveCurve,
+    },
     fields::{Field, FpParameters, PrimeField, SquareRootField},
+    hashing::curve_maps::svdw::SVDWMap,
     test_rng, CanonicalSerialize, One, Zero,
 };
-use core::ops::{AddAssign, MulAssign};
+use core::{
+    ops::{AddAssign, MulAssign},
+    str::FromStr,
+};
 use rand::Rng;
 
 use crate::{
-    bn254::{g1, g2, Bn254, Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective},
+    bn254::{
+        self, g1, g2, Bn254, Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+    },
     tests::{
-        curves::{curve_tests, sw_tests},
+        curves::{
+            curve_tests, pairing_output_test, pairing_torus_compression_test,
+            sw_clear_cofactor_test, sw_tests, sw_xyzz_tests,
+        },
         groups::group_test,
+        hashing::{hash_to_curve_test, sw_map_to_curve_test},
     },
 };
 
@@ -20,6 +32,7 @@ fn test_g1_projective_curve() {
     curve_tests::<G1Projective>();
 
     sw_tests::<g1::Parameters>();
+    sw_xyzz_tests::<g1::Parameters>();
 }
 
 #[test]
@@ -59,6 +72,19 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g2_clear_cofactor() {
+    // The method of Fuentes-Castañeda et al. evaluates `x + 3 * x * psi +
+    // x * psi^2 + psi^3`, and `psi` acts on G2 as `p = 6 * x^2 mod r`.
+    let x = Fr::from(<bn254::Parameters as BnParameters>::X[0]);
+    let lambda = Fr::from(6u64) * &x.square();
+    let h = x
+        + &(Fr::from(3u64) * &x * &lambda)
+        + &(x * &lambda.square())
+        + &(lambda.square() * &lambda);
+    sw_clear_cofactor_test::<g2::Parameters>(h * &g2::Parameters::COFACTOR_INV);
+}
+
 #[test]
 fn test_bilinearity() {
     let mut rng = test_rng();
@@ -86,3 +112,105 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
     assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
 }
+
+#[test]
+fn test_torus_compression() {
+    pairing_torus_compression_test::<Bn254>();
+}
+
+#[test]
+fn test_pairing_output() {
+    pairing_output_test::<Bn254>();
+}
+
+/// Regression vectors: the coordinates of the outputs of the
+/// BN254G1_XMD:SHA-256_SVDW_RO_ suite for each of `messages()`, as computed by
+/// this library. There are no published vectors for this suite, so these only
+/// detect changes to its output.
+const G1_HASH_TO_CURVE_VECTORS: [[&str; 2]; 5] = [
+    [
+        "4790658965958450548702669593570794336562317867247372723806336874591549759110",
+        "1163238807669877429342450210709044731909255047583162173012265677391336920021",
+    ],
+    [
+        "16267524812466668166267883771992486438338357688076900798565538061554532963281",
+        "1844916233815282837483764409618609279507070495361570126601873459268232811805",
+    ],
+    [
+        "11077683243901808951859264683654586764079462418577485658911541848692394044746",
+        "4858124309270455482359664916577923636817363175462672327824733704859450489677",
+    ],
+    [
+        "449076125358095157945547407089359408531318284903480972761046551095956160348",
+        "3427911873443593747709927415036866402371639925174562008506349359915732032632",
+    ],
+    [
+        "763925112321939766609678334678065587309331741428777416269918389033192485838",
+        "12636771015364464547273606234110225240317241569495907283228710706019336772016",
+    ],
+];
+
+#[test]
+fn test_g1_hash_to_curve() {
+    sw_map_to_curve_test::<g1::Parameters, SVDWMap<g1::Parameters>>();
+
+    let points = hash_to_curve_test::<G1Affine, SVDWMap<g1::Parameters>>(
+        b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_",
+    );
+    for (p, [x, y]) in points.iter().zip(G1_HASH_TO_CURVE_VECTORS.iter()) {
+        assert_eq!(p.x, Fq::from_str(x).unwrap());
+        assert_eq!(p.y, Fq::from_str(y).unwrap());
+    }
+}
+
+/// Regression vectors: the coordinates of the outputs of the
+/// BN254G2_XMD:SHA-256_SVDW_RO_ suite for each of `messages()`, as computed by
+/// this library, which clears the cofactor with the method of
+/// Fuentes-Castañeda, Knapp and Rodríguez-Henríquez. There are no published
+/// vectors for this suite, so these only detect changes to its output.
+const G2_HASH_TO_CURVE_VECTORS: [[&str; 4]; 5] = [
+    [
+        "7947280525355502288245767042139433332619084425813891508679326584140902765312",
+        "10530141512348869141982713319207053343182583313484148698392330696376288318261",
+        "2079515028849057274649333561166551431956364880890028320215862191123161285080",
+        "20169147323092870078028771345234445157617856249189458168875341276090072581620",
+    ],
+    [
+        "10305213714312555419584685236164610766057227018997600762219755820581571775698",
+        "5140998983273781645596043003996621170933075714207210952317183701750931672829",
+        "12782657610222102886506935265351398708799194735435757564502179253917869011884",
+        "15746452850775091549966312821847336261590899319279618339578671846526379873840",
+    ],
+    [
+        "9141649584568251133435811655082820452253999683001609355083509727807340928112",
+        "19241337378620754008094815492162488101811979191715181531381201352430992486769",
+        "18149222514336885092356998491550186845822771992585824025266466238465484336696",
+        "9129360097802525322055823374454170177267012396640126715240529872313988489338",
+    ],
+    [
+        "20353650816686918912609727598093385895712524005202794071238544969713808081729",
+        "17684256473523682464984867199875609280081365245056171175421469718260504681254",
+        "15896902550098660794387123920782326368527887924690142904247213645779094259076",
+        "15390867031388969173331373188576779664345770454778413558467452103273727102977",
+    ],
+    [
+        "16357539726107897952076989795377840344861047311782727672153303061989952217690",
+        "10844839375884734385955874223756004111213539742547007380520745461640534925130",
+        "20703414994053186684664027241143511234937261254193650036949701479117819278515",
+        "11278285373922966720757356129051535273988981659843897570823718288010165493815",
+    ],
+];
+
+#[test]
+fn test_g2_hash_to_curve() {
+    sw_map_to_curve_test::<g2::Parameters, SVDWMap<g2::Parameters>>();
+
+    let points = hash_to_curve_test::<G2Affine, SVDWMap<g2::Parameters>>(
+        b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_",
+    );
+    let fq2 = |c0: &str, c1: &str| Fq2::new(Fq::from_str(c0).unwrap(), Fq::from_str(c1).unwrap());
+    for (p, [x0, x1, y0, y1]) in points.iter().zip(G2_HASH_TO_CURVE_VECTORS.iter()) {
+        assert_eq!(p.x, fq2(x0, x1));
+        assert_eq!(p.y, fq2(y0, y1));
+    }
+}
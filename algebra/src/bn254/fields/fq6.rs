@@ -19,10 +19,10 @@ impl Fp6Parameters for Fq6Parameters {
             0x1d9598e8a7e39857,
         ])),
         field_new!(Fq, BigInteger256([
-            202099033278250856u64,
-            8885205928937022213u64,
-            5545221690922665192u64,
-            39800542322357402u64,
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
         ])),
     );
 
@@ -1,11 +1,11 @@
-#[cfg(any(feature = "bn254", feature = "ed_on_bn254"))]
+#[cfg(any(feature = "bn254", feature = "ed_on_bn254", feature = "grumpkin"))]
 pub mod fr;
-#[cfg(any(feature = "bn254", feature = "ed_on_bn254"))]
+#[cfg(any(feature = "bn254", feature = "ed_on_bn254", feature = "grumpkin"))]
 pub use self::fr::*;
 
-#[cfg(feature = "bn254")]
+#[cfg(any(feature = "bn254", feature = "grumpkin"))]
 pub mod fq;
-#[cfg(feature = "bn254")]
+#[cfg(any(feature = "bn254", feature = "grumpkin"))]
 pub use self::fq::*;
 
 #[cfg(feature = "bn254")]
@@ -2,8 +2,8 @@ use algebra_core::{
     biginteger::{BigInteger, BigInteger256},
     buffer_bit_byte_size,
     fields::{
-        fp6_3over2::Fp6Parameters, FftField, FftParameters, Field, FpParameters, PrimeField,
-        SquareRootField,
+        fp6_3over2::Fp6Parameters, FftField, FftParameters, Field, Fp12ParamsWrapper, FpParameters,
+        PrimeField, SquareRootField,
     },
     test_rng, CanonicalSerialize, One, UniformRand, Zero,
 };
@@ -15,9 +15,10 @@ use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
 use crate::{
-    bn254::{Fq, Fq12, Fq2, Fq6, Fq6Parameters, FqParameters, Fr},
+    bn254::{Fq, Fq12, Fq12Parameters, Fq2, Fq6, Fq6Parameters, FqParameters, Fr, FrParameters},
     tests::fields::{
-        field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test,
+        cyclotomic_compressed_test, field_serialization_test, field_test, fp_parameters_test,
+        frobenius_test, primefield_test, sqrt_field_test,
     },
 };
 
@@ -89,6 +90,7 @@ fn test_fq12() {
         field_test(g, h);
     }
     frobenius_test::<Fq12, _>(Fq::characteristic(), 13);
+    cyclotomic_compressed_test::<Fp12ParamsWrapper<Fq12Parameters>, _>(2);
     let byte_size = Fq12::zero().serialized_size();
     field_serialization_test::<Fq12>(byte_size);
 }
@@ -432,6 +434,19 @@ fn test_fq2_legendre() {
     assert_eq!(QuadraticNonResidue, m1.legendre());
 }
 
+#[test]
+fn test_fq6_nonresidue() {
+    let nonresidue = Fq2::new(Fq::from(9u64), Fq::one());
+    assert_eq!(Fq6Parameters::NONRESIDUE, nonresidue);
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let a = Fq2::rand(&mut rng);
+        assert_eq!(Fq6Parameters::mul_fp2_by_nonresidue(&a), a * &nonresidue);
+    }
+}
+
 #[test]
 fn test_fq6_mul_by_1() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -506,3 +521,197 @@ fn test_fq12_mul_by_034() {
         assert_eq!(a, b);
     }
 }
+
+/// The hand-written `FqParameters` that `#[derive(FpParameters)]` replaced.
+struct HandWrittenFqParameters;
+
+impl FftParameters for HandWrittenFqParameters {
+    type BigInt = BigInteger256;
+
+    const TWO_ADICITY: u32 = 1;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger256 = BigInteger256([
+        0x68c3488912edefaa,
+        0x8d087f6872aabf4f,
+        0x51e1a24709081231,
+        0x2259d6b14729c0fa,
+    ]);
+}
+
+impl FpParameters for HandWrittenFqParameters {
+    /// MODULUS = 258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177
+    #[rustfmt::skip]
+    const MODULUS: BigInteger256 = BigInteger256([
+        0x3c208c16d87cfd47,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+
+    const MODULUS_BITS: u32 = 254;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 2;
+
+    #[rustfmt::skip]
+    const R: BigInteger256 = BigInteger256([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0xe0a77c19a07df2f,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger256 = BigInteger256([
+        0xf32cfc5b538afa89,
+        0xb5e71911d44501fb,
+        0x47ab1eff0a417ff6,
+        0x6d89f71cab8351f,
+    ]);
+
+    const INV: u64 = 9786893198990664585u64;
+
+    // GENERATOR = 3
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger256 = BigInteger256([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ]);
+
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        0x9e10460b6c3e7ea3,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    // T = (MODULUS - 1) // 2^S =
+    // 10944121435919637611123202872628637544348155578648911831344518947322613104291
+    #[rustfmt::skip]
+    const T: BigInteger256 = BigInteger256([
+        0x9e10460b6c3e7ea3,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+
+    // (T - 1) // 2 =
+    // 1837921289030710838195067919506396475074392872918698035817074744121558668640693829665401097909504529
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        0x4f082305b61f3f51,
+        0x65e05aa45a1c72a3,
+        0x6e14116da0605617,
+        0xc19139cb84c680a,
+    ]);
+}
+
+#[test]
+fn test_fq_derived_parameters() {
+    fp_parameters_test::<HandWrittenFqParameters, FqParameters>();
+}
+
+/// The hand-written `FrParameters` that `#[derive(FpParameters)]` replaced.
+struct HandWrittenFrParameters;
+
+impl FftParameters for HandWrittenFrParameters {
+    type BigInt = BigInteger256;
+
+    const TWO_ADICITY: u32 = 28;
+
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger256 = BigInteger256([
+        7164790868263648668u64,
+        11685701338293206998u64,
+        6216421865291908056u64,
+        1756667274303109607u64,
+    ]);
+}
+
+impl FpParameters for HandWrittenFrParameters {
+    /// MODULUS = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+    #[rustfmt::skip]
+    const MODULUS: BigInteger256 = BigInteger256([
+        4891460686036598785u64,
+        2896914383306846353u64,
+        13281191951274694749u64,
+        3486998266802970665u64,
+    ]);
+
+    const MODULUS_BITS: u32 = 254;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 2;
+
+    #[rustfmt::skip]
+    const R: BigInteger256 = BigInteger256([
+        12436184717236109307u64,
+        3962172157175319849u64,
+        7381016538464732718u64,
+        1011752739694698287u64,
+    ]);
+
+    #[rustfmt::skip]
+    const R2: BigInteger256 = BigInteger256([
+        1997599621687373223u64,
+        6052339484930628067u64,
+        10108755138030829701u64,
+        150537098327114917u64,
+    ]);
+
+    const INV: u64 = 14042775128853446655u64;
+
+    // GENERATOR = 5
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger256 = BigInteger256([
+        1949230679015292902u64,
+        16913946402569752895u64,
+        5177146667339417225u64,
+        1571765431670520771u64,
+    ]);
+
+    /// (r - 1)/2 =
+    /// 10944121435919637611123202872628637544274182200208017171849102093287904247808
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        0xa1f0fac9f8000000,
+        0x9419f4243cdcb848,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 81540058820840996586704275553141814055101440848469862132140264610111
+    #[rustfmt::skip]
+    const T: BigInteger256 = BigInteger256([
+        0x9b9709143e1f593f,
+        0x181585d2833e8487,
+        0x131a029b85045b68,
+        0x30644e72e,
+    ]);
+
+    /// (t - 1) / 2 =
+    /// 40770029410420498293352137776570907027550720424234931066070132305055
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        0xcdcb848a1f0fac9f,
+        0x0c0ac2e9419f4243,
+        0x098d014dc2822db4,
+        0x183227397,
+    ]);
+}
+
+#[test]
+fn test_fr_derived_parameters() {
+    fp_parameters_test::<HandWrittenFrParameters, FrParameters>();
+}
@@ -5,7 +5,7 @@ use crate::{
         models::{ModelParameters, SWModelParameters},
         short_weierstrass_jacobian::{GroupAffine, GroupProjective},
     },
-    field_new,
+    field_new, Zero,
 };
 
 pub type G1Affine = GroupAffine<Parameters>;
@@ -71,11 +71,52 @@ impl SWModelParameters for Parameters {
         (G1_GENERATOR_X, G1_GENERATOR_Y);
     #[inline(always)]
     fn mul_by_a(_elem: &Self::BaseField) -> Self::BaseField {
-        use crate::Zero;
         Self::BaseField::zero()
     }
+
+    /// Tests membership of G1 by checking that `a * p + b * endomorphism(p)` is
+    /// zero, where `a = SUBGROUP_CHECK_A` and `b = SUBGROUP_CHECK_B` satisfy
+    /// `a + b * lambda = 0 mod r` for the eigenvalue `lambda` of `endomorphism`
+    /// on G1. The kernel of `a + b * endomorphism` is exactly G1, as its degree
+    /// `a^2 - a * b + b^2` is `r`.
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        let p = G1Projective::from(*p);
+        p.mul_bits_joint(SUBGROUP_CHECK_A, &endomorphism(&p), SUBGROUP_CHECK_B)
+            .is_zero()
+    }
 }
 
+/// The endomorphism `(x, y) -> (BETA * x, y)`.
+fn endomorphism(p: &G1Projective) -> G1Projective {
+    let mut res = *p;
+    res.x *= &BETA;
+    res
+}
+
+/// BETA = 4922464560225523242118178942575080391082002530232324381063048548642823052024664478336818169867474395270858391911405337707247735739826664939444490469542109391530482826728203582549674992333383150446779312029624171857054392282775648,
+/// a primitive cube root of unity in Fq.
+#[rustfmt::skip]
+pub const BETA: Fq = field_new!(Fq, BigInteger768([
+    0x7f96b51bd840c549,
+    0xd59782096496171f,
+    0x49b046fd9ce14bbc,
+    0x4b6163bba7527a56,
+    0xef6c92fb771d59f1,
+    0x425bedbac1dfdc7,
+    0xd3ac39de759c0ffd,
+    0x9f43ed0e063a81d0,
+    0x5bd7d20b4f9a3ce2,
+    0x411f03c36cf5c3c,
+    0x2d658fd49661c472,
+    0x1100249ae760b93,
+]));
+
+/// SUBGROUP_CHECK_A = 587269870971281361444171168277668240640243801025419411456
+const SUBGROUP_CHECK_A: &[u64] = &[0x2c58400000000000, 0x5c2d7510c0000000, 0x17f3622fba094800];
+
+/// SUBGROUP_CHECK_B = 293634935485640680722085584138834120324914961969255022593
+const SUBGROUP_CHECK_B: &[u64] = &[0x58b0800000000001, 0x2e16ba8860000000, 0xbf9b117dd04a400];
+
 /// G1_GENERATOR_X =
 /// 6238772257594679368032145693622812838779005809760824733138787810501188623461307351759238099287535516224314149266511977132140828635950940021790489507611754366317801811090811367945064510304504157188661901055903167026722666149426237
 #[rustfmt::skip]
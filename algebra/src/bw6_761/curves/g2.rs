@@ -1,11 +1,11 @@
 use crate::{
     biginteger::{BigInteger384, BigInteger768},
-    bw6_761::{Fq, Fr},
+    bw6_761::{g1, Fq, Fr},
     curves::{
         models::{ModelParameters, SWModelParameters},
         short_weierstrass_jacobian::{GroupAffine, GroupProjective},
     },
-    field_new,
+    field_new, Zero,
 };
 
 pub type G2Affine = GroupAffine<Parameters>;
@@ -71,11 +71,34 @@ impl SWModelParameters for Parameters {
         (G2_GENERATOR_X, G2_GENERATOR_Y);
     #[inline(always)]
     fn mul_by_a(_elem: &Self::BaseField) -> Self::BaseField {
-        use crate::Zero;
         Self::BaseField::zero()
     }
+
+    /// Tests membership of G2 by checking that `a * p + b * endomorphism(p)` is
+    /// zero, where `a = SUBGROUP_CHECK_A` and `b = SUBGROUP_CHECK_B` satisfy
+    /// `a + b * lambda = 0 mod r` for the eigenvalue `lambda` of `endomorphism`
+    /// on G2. The kernel of `a + b * endomorphism` is exactly G2, as its degree
+    /// `a^2 - a * b + b^2` is `r`.
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        let p = G2Projective::from(*p);
+        p.mul_bits_joint(SUBGROUP_CHECK_A, &endomorphism(&p), SUBGROUP_CHECK_B)
+            .is_zero()
+    }
+}
+
+/// The endomorphism `(x, y) -> (BETA * x, y)`, with `BETA` from G1.
+fn endomorphism(p: &G2Projective) -> G2Projective {
+    let mut res = *p;
+    res.x *= &g1::BETA;
+    res
 }
 
+/// SUBGROUP_CHECK_A = 293634935485640680722085584138834120324914961969255022593
+const SUBGROUP_CHECK_A: &[u64] = &[0x58b0800000000001, 0x2e16ba8860000000, 0xbf9b117dd04a400];
+
+/// SUBGROUP_CHECK_B = 587269870971281361444171168277668240640243801025419411456
+const SUBGROUP_CHECK_B: &[u64] = &[0x2c58400000000000, 0x5c2d7510c0000000, 0x17f3622fba094800];
+
 /// G2_GENERATOR_X =
 ///  6445332910596979336035888152774071626898886139774101364933948236926875073754470830732273879639675437155036544153105017729592600560631678554299562762294743927912429096636156401171909259073181112518725201388196280039960074422214428
 #[rustfmt::skip]
@@ -74,3 +74,13 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fr::characteristic()), Fq6::one());
     assert_eq!(ans3.pow(Fr::characteristic()), Fq6::one());
 }
+
+#[test]
+fn test_torus_compression() {
+    pairing_torus_compression_test::<BW6_761>();
+}
+
+#[test]
+fn test_pairing_output() {
+    pairing_output_test::<BW6_761>();
+}
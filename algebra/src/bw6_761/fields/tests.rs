@@ -1,10 +1,14 @@
-use algebra_core::{buffer_bit_byte_size, test_rng, CanonicalSerialize, Field, PrimeField};
+use algebra_core::{
+    buffer_bit_byte_size, fields::fp6_2over3::Fp6ParamsWrapper, test_rng, CanonicalSerialize,
+    Field, PrimeField,
+};
 use rand::Rng;
 
 use crate::bw6_761::*;
 
 use crate::tests::fields::{
-    field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test,
+    cyclotomic_compressed_test, field_serialization_test, field_test, frobenius_test,
+    primefield_test, sqrt_field_test,
 };
 
 #[test]
@@ -49,4 +53,5 @@ fn test_fq6() {
     let b: Fq6 = rng.gen();
     field_test(a, b);
     frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
+    cyclotomic_compressed_test::<Fp6ParamsWrapper<Fq6Parameters>, _>(1);
 }
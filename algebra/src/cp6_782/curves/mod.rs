@@ -1,6 +1,6 @@
 use crate::{
     biginteger::BigInteger832,
-    curves::{models::SWModelParameters, PairingEngine},
+    curves::{models::SWModelParameters, PairingEngine, PairingOutputEngine},
     field_new,
     fields::{BitIteratorBE, Field, FpParameters},
     One,
@@ -50,6 +50,10 @@ impl PairingEngine for CP6_782 {
     }
 }
 
+impl PairingOutputEngine for CP6_782 {
+    type TargetField = Fq6;
+}
+
 impl CP6_782 {
     pub fn ate_pairing(p: &G1Affine, q: &G2Affine) -> GT {
         CP6_782::final_exponentiation(&CP6_782::ate_miller_loop(p, q))
@@ -0,0 +1,133 @@
+use crate::curve25519::{Fq, Fr};
+use algebra_core::{
+    biginteger::BigInteger256,
+    curves::{
+        models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+        montgomery,
+        twisted_edwards_extended::{GroupAffine, GroupProjective},
+    },
+    field_new,
+    hashing::curve_maps::elligator2::Elligator2Parameters,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub type EdwardsAffine = GroupAffine<EdwardsParameters>;
+pub type EdwardsProjective = GroupProjective<EdwardsParameters>;
+
+pub type MontgomeryAffine = montgomery::GroupAffine<EdwardsParameters>;
+pub type MontgomeryProjective = montgomery::GroupProjective<EdwardsParameters>;
+
+/// GENERATOR_X =
+/// 15112221349535400772501151409588531511454012693041857206046113283949847762202
+#[rustfmt::skip]
+const GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
+    0xe2cabc553f9da287,
+    0x9ca598562396e489,
+    0x9879936bade4b5b7,
+    0x759e23707e6077d0,
+]));
+/// GENERATOR_Y = 4/5 =
+/// 46316835694926478169428394003475163141307993866256225615783033603165251855960
+#[rustfmt::skip]
+const GENERATOR_Y: Fq = field_new!(Fq, BigInteger256([
+    0x333333333333334a,
+    0x3333333333333333,
+    0x3333333333333333,
+    0x3333333333333333,
+]));
+
+/// edwards25519 is the twisted Edwards curve -x² + y² = 1 - (121665/121666)x²y²
+/// over Fq, which is birationally equivalent to the Montgomery curve
+/// Curve25519: y² = x³ + 486662x² + x. Its group of points has order 8r, and
+/// the generator is the base point of RFC 8032, whose y-coordinate is 4/5 and
+/// whose x-coordinate is even.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct EdwardsParameters;
+
+impl ModelParameters for EdwardsParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl TEModelParameters for EdwardsParameters {
+    /// COEFF_A = -1
+    #[rustfmt::skip]
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([
+        0xffffffffffffffc7,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]));
+
+    /// COEFF_D = -(121665/121666) mod q
+    ///         = 37095705934669439343138083508754565189542113879843219016388785533085940283555
+    #[rustfmt::skip]
+    const COEFF_D: Fq = field_new!(Fq, BigInteger256([
+        0x80ed8bfedf47e9fa,
+        0x10a18777afc62973,
+        0xe5939207bc188690,
+        0x2c822b5a729fc526,
+    ]));
+
+    /// COFACTOR = 8
+    const COFACTOR: &'static [u64] = &[8];
+
+    /// COFACTOR^(-1) mod r =
+    /// 2713877091499598330239944961141122840321418634767465352250731601857045344121
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, BigInteger256([
+        0xa7ed9ce5a30a2c13,
+        0xeb2106215d086329,
+        0xffffffffffffffff,
+        0xfffffffffffffff,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+
+    type MontgomeryModelParameters = EdwardsParameters;
+
+    /// Multiplication by `a` is simply negation here.
+    #[inline(always)]
+    fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
+        -(*elem)
+    }
+}
+
+impl MontgomeryModelParameters for EdwardsParameters {
+    /// COEFF_A = 486662
+    #[rustfmt::skip]
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([
+        0x11a2ee4,
+        0x0,
+        0x0,
+        0x0,
+    ]));
+    /// COEFF_B = -486664, which gives a curve isomorphic to Curve25519 since
+    /// -486664 is a square in Fq.
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, BigInteger256([
+        0xfffffffffee5d0bd,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]));
+
+    type TEModelParameters = EdwardsParameters;
+}
+
+/// Elligator 2 runs on the Montgomery model above rather than on Curve25519
+/// with the scaled rational map of the edwards25519_XMD:SHA-512_ELL2 suites, so
+/// its points agree with those suites only up to the sign of `x`.
+impl Elligator2Parameters for EdwardsParameters {
+    /// ZETA = 2, the first non-square in the sequence 1, -1, 2, -2, ...
+    #[rustfmt::skip]
+    const ZETA: Fq = field_new!(Fq, BigInteger256([
+        0x4c,
+        0x0,
+        0x0,
+        0x0,
+    ]));
+}
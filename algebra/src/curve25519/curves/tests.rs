@@ -0,0 +1,143 @@
+use algebra_core::{
+    biginteger::BigInteger256,
+    bytes::FromBytes,
+    curves::{rfc8032::Rfc8032Affine, AffineCurve, ProjectiveCurve},
+    hashing::{curve_maps::elligator2::Elligator2Map, MapToCurve},
+    test_rng, CanonicalDeserialize, CanonicalSerialize,
+};
+use core::str::FromStr;
+use rand::Rng;
+use sha2::{Digest, Sha512};
+
+use crate::curve25519::*;
+
+use crate::tests::{curves::*, groups::*, hashing::*};
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<EdwardsProjective>();
+
+    edwards_tests::<EdwardsParameters>();
+    rfc8032_serialization_test::<EdwardsParameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = test_rng();
+    let a = rng.gen();
+    let b = rng.gen();
+    for _i in 0..100 {
+        group_test::<EdwardsProjective>(a, b);
+    }
+}
+
+#[test]
+fn test_affine_group() {
+    let mut rng = test_rng();
+    let a: EdwardsAffine = rng.gen();
+    let b: EdwardsAffine = rng.gen();
+    for _i in 0..100 {
+        group_test::<EdwardsAffine>(a, b);
+    }
+}
+
+#[test]
+fn test_generator() {
+    let generator = EdwardsAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+
+    let mut serialized = vec![];
+    Rfc8032Affine(generator).serialize(&mut serialized).unwrap();
+    assert_eq!(
+        hex::encode(serialized),
+        "5866666666666666666666666666666666666666666666666666666666666666"
+    );
+}
+
+/// Secret keys of the Ed25519 test vectors of section 7.1 of RFC 8032, with
+/// their public keys.
+const RFC8032_VECTORS: [[&str; 2]; 2] = [
+    [
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+    ],
+    [
+        "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+        "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+    ],
+];
+
+#[test]
+fn test_rfc8032_vectors() {
+    let generator = EdwardsAffine::prime_subgroup_generator();
+    for [secret_key, public_key] in RFC8032_VECTORS.iter() {
+        // The secret scalar is the clamped first half of the hash of the key.
+        let mut h = Sha512::digest(&hex::decode(secret_key).unwrap()[..]);
+        h[0] &= 248;
+        h[31] &= 127;
+        h[31] |= 64;
+        let s = BigInteger256::read(&h[..32]).unwrap();
+        let p = Rfc8032Affine(generator.mul(s).into_affine());
+
+        let mut serialized = vec![];
+        p.serialize(&mut serialized).unwrap();
+        assert_eq!(hex::encode(&serialized), *public_key);
+        assert_eq!(Rfc8032Affine::deserialize(&serialized[..]).unwrap(), p);
+    }
+}
+
+#[test]
+fn test_montgomery_conversion() {
+    montgomery_conversion_test::<EdwardsParameters>();
+}
+
+#[test]
+fn test_montgomery_curve() {
+    curve_tests::<MontgomeryProjective>();
+
+    montgomery_tests::<EdwardsParameters>();
+}
+
+#[test]
+fn test_elligator2() {
+    elligator2_test::<EdwardsParameters>();
+}
+
+/// The coordinates of the outputs of the edwards25519_XMD:SHA-512_ELL2_NU_ suite
+/// of Appendix J.5.2 of RFC 9380, for each of `messages()`.
+const ENCODE_TO_CURVE_VECTORS: [[&str; 2]; 5] = [
+    [
+        "14450538714633175475175770327362428123916966252897750763253128433471644923610",
+        "15460252078786806841316243372988160953412189280087542430384807789961490141083",
+    ],
+    [
+        "43004701517812697156164072817278842337174890666792995168206718959289818403496",
+        "46791723578196873800227641477805378690896909937736162301355352936415402392130",
+    ],
+    [
+        "13489870354556367243714452793744222488648274004765532210057663792315095192561",
+        "21503275160801539512431388214773289293595366612997866487635071154846349035259",
+    ],
+    [
+        "24417580163276102164408696035267383325099804601114119601210905398628612190067",
+        "19433546955243719695505458501685564009650274228922919183111865235112816817232",
+    ],
+    [
+        "49920712429780636035607884514301030562679767334515503213170096756424132946943",
+        "20157542860790566335677871196716560863059758970891007939332780717715596319287",
+    ],
+];
+
+/// The map works on the Montgomery model with `B = -486664` instead of on
+/// Curve25519, so its points only agree with the suite up to the sign of `x`.
+#[test]
+fn test_encode_to_curve() {
+    let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_";
+    for (msg, [x, y]) in messages().iter().zip(ENCODE_TO_CURVE_VECTORS.iter()) {
+        let p = Elligator2Map::<EdwardsParameters>::encode_to_curve::<Sha512>(msg, dst).unwrap();
+        let expected = EdwardsAffine::new(Fq::from_str(x).unwrap(), Fq::from_str(y).unwrap());
+        assert!(expected.is_on_curve());
+        assert!(p == expected || p == -expected);
+    }
+}
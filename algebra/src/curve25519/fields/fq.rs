@@ -0,0 +1,8 @@
+use algebra_core::fields::{Fp256, FpParameters};
+
+pub type Fq = Fp256<FqParameters>;
+
+#[derive(FpParameters)]
+#[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
+#[generator = "2"]
+pub struct FqParameters;
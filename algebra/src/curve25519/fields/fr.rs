@@ -0,0 +1,8 @@
+use algebra_core::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+#[derive(FpParameters)]
+#[modulus = "7237005577332262213973186563042994240857116359379907606001950938285454250989"]
+#[generator = "2"]
+pub struct FrParameters;
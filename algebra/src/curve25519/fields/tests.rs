@@ -0,0 +1,106 @@
+use algebra_core::{
+    buffer_bit_byte_size, fields::FpParameters, test_rng, CanonicalSerialize, FftField, Field, One,
+    PrimeField, SquareRootField,
+};
+use core::str::FromStr;
+use rand::Rng;
+
+use crate::{
+    curve25519::{Fq, FqParameters, Fr, FrParameters},
+    tests::fields::{
+        fft_field_test, field_serialization_test, field_test, primefield_test, sqrt_field_test,
+    },
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fr() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+        let byte_size = a.serialized_size();
+        field_serialization_test::<Fr>(byte_size);
+    }
+    fft_field_test::<Fr>();
+}
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        let byte_size = a.serialized_size();
+        let (_, buffer_size) = buffer_bit_byte_size(Fq::size_in_bits());
+        assert_eq!(byte_size, buffer_size);
+        field_serialization_test::<Fq>(byte_size);
+    }
+    fft_field_test::<Fq>();
+}
+
+#[test]
+fn test_fq_num_bits() {
+    assert_eq!(FqParameters::MODULUS_BITS, 255);
+    assert_eq!(FqParameters::CAPACITY, 254);
+}
+
+#[test]
+fn test_fr_num_bits() {
+    assert_eq!(FrParameters::MODULUS_BITS, 253);
+    assert_eq!(FrParameters::CAPACITY, 252);
+}
+
+#[test]
+fn test_fq_modulus() {
+    // p = 2^255 - 19
+    assert_eq!(Fq::from(2u64).pow([255]), Fq::from(19u64));
+    // l = 2^252 + 27742317777372353535851937790883648493
+    assert_eq!(
+        Fr::from(2u64).pow([252]),
+        -Fr::from_str("27742317777372353535851937790883648493").unwrap()
+    );
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    assert_eq!(Fq::two_adicity(), 2);
+    assert_eq!(Fr::two_adicity(), 2);
+
+    // sqrt(-1) = 2^((p - 1) / 4), as in RFC 8032.
+    let sqrt_m1 = Fq::from_str(
+        "19681161376707505956807079304988542015446066515923890162744021073123829784752",
+    )
+    .unwrap();
+    assert_eq!(sqrt_m1.square(), -Fq::one());
+    assert!(Fq::two_adic_root_of_unity() == sqrt_m1 || Fq::two_adic_root_of_unity() == -sqrt_m1);
+    let sqrt = (-Fq::one()).sqrt().unwrap();
+    assert!(sqrt == sqrt_m1 || sqrt == -sqrt_m1);
+}
+
+#[test]
+fn test_fq_legendre() {
+    use crate::fields::LegendreSymbol::*;
+
+    // p = 5 mod 8, so 2 is not a square.
+    assert_eq!(QuadraticNonResidue, Fq::from(2u64).legendre());
+    assert_eq!(QuadraticResidue, (-Fq::one()).legendre());
+    assert_eq!(QuadraticResidue, Fq::from(4u64).legendre());
+}
+
+#[test]
+fn test_fq_edwards_d() {
+    // d = -121665 / 121666, as in RFC 8032.
+    let d = Fq::from_str(
+        "37095705934669439343138083508754565189542113879843219016388785533085940283555",
+    )
+    .unwrap();
+    assert_eq!(d, -Fq::from(121665u64) / Fq::from(121666u64));
+}
@@ -0,0 +1,24 @@
+//! This module implements Curve25519 in the twisted Edwards form used by
+//! Ed25519 signatures, that is, the curve edwards25519 of
+//! [RFC 7748](https://www.rfc-editor.org/rfc/rfc7748). Points can be
+//! serialized with the encoding of [RFC 8032](https://www.rfc-editor.org/rfc/rfc8032)
+//! through `algebra_core::curves::rfc8032::Rfc8032Affine`, and the module also
+//! provides the prime order group Ristretto255 of
+//! [RFC 9496](https://www.rfc-editor.org/rfc/rfc9496) built on top of the curve.
+//!
+//! Curve information:
+//! * Base field: q = 57896044618658097711785492504343953926634992332820282019728792003956564819949
+//! * Scalar field: r = 7237005577332262213973186563042994240857116359379907606001950938285454250989
+//! * Valuation(q - 1, 2) = 2
+//! * Valuation(r - 1, 2) = 2
+//! * Curve equation: ax^2 + y^2 =1 + dx^2y^2, where
+//!    * a = -1
+//!    * d = -(121665/121666)
+
+mod curves;
+mod fields;
+mod ristretto;
+
+pub use curves::*;
+pub use fields::*;
+pub use ristretto::*;
@@ -0,0 +1,444 @@
+//! The prime order group Ristretto255 of [RFC 9496](https://www.rfc-editor.org/rfc/rfc9496).
+//!
+//! Ristretto255 is the quotient of the even points of edwards25519 by its
+//! 4-torsion subgroup. An element is stored as any of its four representatives
+//! on the curve, and its encoding and equality do not depend on the choice of
+//! representative, so protocols built on the group do not have to deal with
+//! the cofactor of the curve.
+
+use crate::{
+    curve25519::{EdwardsParameters, EdwardsProjective, Fq, FqParameters, Fr},
+    io::{self, Read, Result as IoResult, Write},
+    serialize::{
+        CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize, SerializationError,
+    },
+};
+use algebra_core::{
+    biginteger::{BigInteger, BigInteger256},
+    bytes::{FromBytes, ToBytes},
+    curves::{models::TEModelParameters, ProjectiveCurve},
+    field_new,
+    fields::{Field, FpParameters, PrimeField},
+    groups::Group,
+    Choice, ConditionallySelectable, ConstantTimeEq, One, Zero,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    iter::Sum,
+    ops::{Add, AddAssign, MulAssign, Neg, Sub, SubAssign},
+};
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// SQRT_M1 = sqrt(-1) =
+/// 19681161376707505956807079304988542015446066515923890162744021073123829784752
+#[rustfmt::skip]
+const SQRT_M1: Fq = field_new!(Fq, BigInteger256([
+    0x3b5807d4fe2bdb04,
+    0x3f590fdb51be9ed,
+    0x6d6e16bf336202d1,
+    0x75776b0bd6c71ba8,
+]));
+
+/// INVSQRT_A_MINUS_D = 1/sqrt(a - d) =
+/// 54469307008909316920995813868745141605393597292927456921205312896311721017578
+#[rustfmt::skip]
+const INVSQRT_A_MINUS_D: Fq = field_new!(Fq, BigInteger256([
+    0xd3d5a74f0dd7a555,
+    0x54fd477765b7084a,
+    0x60de57abb4461997,
+    0x601c56dcd41f800f,
+]));
+
+/// SQRT_AD_MINUS_ONE = sqrt(a * d - 1) =
+/// 25063068953384623474111414158702152701244531502492656460079210482610430750235
+#[rustfmt::skip]
+const SQRT_AD_MINUS_ONE: Fq = field_new!(Fq, BigInteger256([
+    0xca8e9bcae848d932,
+    0x116315cc13dc9420,
+    0x430d85dd6a7d2ba8,
+    0x399d6260757cc98a,
+]));
+
+/// ONE_MINUS_D_SQ = 1 - d^2 =
+/// 1159843021668779879193775521855586647937357759715417654439879720876111806838
+#[rustfmt::skip]
+const ONE_MINUS_D_SQ: Fq = field_new!(Fq, BigInteger256([
+    0x9e6972bc0636b784,
+    0x9b3dee6e7bfbe05b,
+    0xcc1182ea44c13bde,
+    0x6171050a86b36000,
+]));
+
+/// D_MINUS_ONE_SQ = (d - 1)^2 =
+/// 40440834346308536858101042469323190826248399146238708352240133220865137265952
+#[rustfmt::skip]
+const D_MINUS_ONE_SQ: Fq = field_new!(Fq, BigInteger256([
+    0x5fbb75463b3974ae,
+    0x437f02a22477ccbd,
+    0x68c75906430db701,
+    0x458aa440940d15b1,
+]));
+
+/// (q - 5) / 8, the exponent used to compute square roots of ratios.
+const Q_MINUS_FIVE_DIV_EIGHT: [u64; 4] = [
+    0xfffffffffffffffd,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0x0fffffffffffffff,
+];
+
+/// The number of bytes of the encoding of an element.
+const ENCODED_SIZE: usize = 32;
+
+/// An element of Ristretto255.
+#[derive(Copy, Clone, Debug)]
+pub struct RistrettoPoint(EdwardsProjective);
+
+impl RistrettoPoint {
+    /// The generator of the group, whose representative is the generator of
+    /// edwards25519.
+    pub fn generator() -> Self {
+        Self(EdwardsProjective::prime_subgroup_generator())
+    }
+
+    /// Maps 64 uniformly random bytes to an element, with the element
+    /// derivation function of section 4.3.4 of RFC 9496. The output is
+    /// uniformly distributed, so the function can be composed with a hash
+    /// function with a 64-byte output to hash to the group.
+    pub fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        let p1 = map(field_element_mod_order(&bytes[..32]));
+        let p2 = map(field_element_mod_order(&bytes[32..]));
+        Self(p1 + &p2)
+    }
+
+    /// Returns the field element whose little-endian encoding is the encoding
+    /// of `self`, as in section 4.3.2 of RFC 9496.
+    fn encode(&self) -> Fq {
+        let EdwardsProjective {
+            x: x0,
+            y: y0,
+            z: z0,
+            t: t0,
+            ..
+        } = self.0;
+
+        let u1 = (z0 + &y0) * &(z0 - &y0);
+        let u2 = x0 * &y0;
+        let (_, invsqrt) = sqrt_ratio_m1(&Fq::one(), &(u1 * &u2.square()));
+        let den1 = invsqrt * &u1;
+        let den2 = invsqrt * &u2;
+        let z_inv = den1 * &den2 * &t0;
+        let ix0 = x0 * &SQRT_M1;
+        let iy0 = y0 * &SQRT_M1;
+        let enchanted_denominator = den1 * &INVSQRT_A_MINUS_D;
+        let rotate = is_negative(&(t0 * &z_inv));
+        let x = Fq::conditional_select(&x0, &iy0, rotate);
+        let mut y = Fq::conditional_select(&y0, &ix0, rotate);
+        let den_inv = Fq::conditional_select(&den2, &enchanted_denominator, rotate);
+        y = Fq::conditional_select(&y, &-y, is_negative(&(x * &z_inv)));
+        abs(&(den_inv * &(z0 - &y)))
+    }
+
+    /// Decodes the field element `s`, as in section 4.3.1 of RFC 9496.
+    fn decode(s: &Fq) -> Result<Self, SerializationError> {
+        if bool::from(is_negative(s)) {
+            return Err(SerializationError::InvalidData);
+        }
+        let ss = s.square();
+        let u1 = Fq::one() - &ss;
+        let u2 = Fq::one() + &ss;
+        let u2_sqr = u2.square();
+        let v = -(EdwardsParameters::COEFF_D * &u1.square()) - &u2_sqr;
+        let (was_square, invsqrt) = sqrt_ratio_m1(&Fq::one(), &(v * &u2_sqr));
+        let den_x = invsqrt * &u2;
+        let den_y = invsqrt * &den_x * &v;
+        let x = abs(&(s.double() * &den_x));
+        let y = u1 * &den_y;
+        let t = x * &y;
+        if !bool::from(was_square) || bool::from(is_negative(&t)) || y.is_zero() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self(EdwardsProjective::new(x, y, t, Fq::one())))
+    }
+}
+
+/// Returns whether the canonical representative of `f` is odd, which RFC 9496
+/// calls negative.
+#[inline]
+fn is_negative(f: &Fq) -> Choice {
+    Choice::from(f.into_repr().is_odd() as u8)
+}
+
+/// Returns the element among `f` and `-f` that is not negative.
+#[inline]
+fn abs(f: &Fq) -> Fq {
+    Fq::conditional_select(f, &-*f, is_negative(f))
+}
+
+/// Computes the non-negative square root of `u / v` if it exists, and of
+/// `SQRT_M1 * u / v` otherwise, as in section 4.2 of RFC 9496. The returned
+/// choice is set if `u / v` is a square.
+fn sqrt_ratio_m1(u: &Fq, v: &Fq) -> (Choice, Fq) {
+    let v3 = v.square() * v;
+    let v7 = v3.square() * v;
+    let mut r = (*u * &v3) * &(*u * &v7).pow(Q_MINUS_FIVE_DIV_EIGHT);
+    let check = *v * &r.square();
+
+    let correct_sign_sqrt = check.ct_eq(u);
+    let flipped_sign_sqrt = check.ct_eq(&-*u);
+    let flipped_sign_sqrt_i = check.ct_eq(&(-*u * &SQRT_M1));
+
+    let r_prime = SQRT_M1 * &r;
+    r = Fq::conditional_select(&r, &r_prime, flipped_sign_sqrt | flipped_sign_sqrt_i);
+    (correct_sign_sqrt | flipped_sign_sqrt, abs(&r))
+}
+
+/// Maps a field element to a representative of an element, as in section
+/// 4.3.4 of RFC 9496.
+fn map(t: Fq) -> EdwardsProjective {
+    let d = EdwardsParameters::COEFF_D;
+    let one = Fq::one();
+    let r = SQRT_M1 * &t.square();
+    let u = (r + &one) * &ONE_MINUS_D_SQ;
+    let v = (-one - &(r * &d)) * &(r + &d);
+
+    let (was_square, mut s) = sqrt_ratio_m1(&u, &v);
+    let s_prime = -abs(&(s * &t));
+    s = Fq::conditional_select(&s_prime, &s, was_square);
+    let c = Fq::conditional_select(&r, &-one, was_square);
+
+    let n = c * &(r - &one) * &D_MINUS_ONE_SQ - &v;
+    let w0 = s.double() * &v;
+    let w1 = n * &SQRT_AD_MINUS_ONE;
+    let w2 = one - &s.square();
+    let w3 = one + &s.square();
+    EdwardsProjective::new(w0 * &w3, w2 * &w1, w0 * &w2, w1 * &w3)
+}
+
+/// Interprets 32 bytes as a little-endian integer, masks its most significant
+/// bit and reduces it modulo q.
+fn field_element_mod_order(bytes: &[u8]) -> Fq {
+    let mut repr = BigInteger256::read(bytes).unwrap();
+    repr.0[3] &= 0x7fff_ffff_ffff_ffff;
+    if let Some(f) = Fq::from_repr(repr) {
+        return f;
+    }
+    // The masked integer is smaller than 2q.
+    repr.sub_noborrow(&FqParameters::MODULUS);
+    Fq::from_repr(repr).unwrap()
+}
+
+#[cfg(feature = "std")]
+fn invalid_encoding() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "invalid Ristretto255 encoding")
+}
+
+#[cfg(not(feature = "std"))]
+fn invalid_encoding() -> io::Error {
+    io::Error
+}
+
+impl PartialEq for RistrettoPoint {
+    /// Compares two elements as in section 4.3.3 of RFC 9496.
+    fn eq(&self, other: &Self) -> bool {
+        let (x1, y1) = (self.0.x, self.0.y);
+        let (x2, y2) = (other.0.x, other.0.y);
+        bool::from((x1 * &y2).ct_eq(&(y1 * &x2)) | (y1 * &y2).ct_eq(&(x1 * &x2)))
+    }
+}
+
+impl Eq for RistrettoPoint {}
+
+impl Hash for RistrettoPoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encode().hash(state);
+    }
+}
+
+impl Display for RistrettoPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "RistrettoPoint({})", self.encode())
+    }
+}
+
+impl Default for RistrettoPoint {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl Zero for RistrettoPoint {
+    #[inline]
+    fn zero() -> Self {
+        Self(EdwardsProjective::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.x.is_zero() || self.0.y.is_zero()
+    }
+}
+
+impl Distribution<RistrettoPoint> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RistrettoPoint {
+        RistrettoPoint(rng.gen())
+    }
+}
+
+impl Neg for RistrettoPoint {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<'a> Add<&'a Self> for RistrettoPoint {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &'a Self) -> Self {
+        Self(self.0 + &other.0)
+    }
+}
+
+impl Add<Self> for RistrettoPoint {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self + &other
+    }
+}
+
+impl<'a> AddAssign<&'a Self> for RistrettoPoint {
+    #[inline]
+    fn add_assign(&mut self, other: &'a Self) {
+        self.0 += &other.0;
+    }
+}
+
+impl AddAssign<Self> for RistrettoPoint {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self += &other;
+    }
+}
+
+impl<'a> Sub<&'a Self> for RistrettoPoint {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &'a Self) -> Self {
+        Self(self.0 - &other.0)
+    }
+}
+
+impl Sub<Self> for RistrettoPoint {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self - &other
+    }
+}
+
+impl<'a> SubAssign<&'a Self> for RistrettoPoint {
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Self) {
+        self.0 -= &other.0;
+    }
+}
+
+impl SubAssign<Self> for RistrettoPoint {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self -= &other;
+    }
+}
+
+impl MulAssign<Fr> for RistrettoPoint {
+    #[inline]
+    fn mul_assign(&mut self, other: Fr) {
+        self.0 *= other;
+    }
+}
+
+impl Sum<Self> for RistrettoPoint {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for RistrettoPoint {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl Group for RistrettoPoint {
+    type ScalarField = Fr;
+
+    #[inline]
+    fn double(&self) -> Self {
+        Self(ProjectiveCurve::double(&self.0))
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        ProjectiveCurve::double_in_place(&mut self.0);
+        self
+    }
+}
+
+impl ToBytes for RistrettoPoint {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.encode().write(writer)
+    }
+}
+
+impl FromBytes for RistrettoPoint {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Self::decode(&Fq::read(reader)?).map_err(|_| invalid_encoding())
+    }
+}
+
+impl CanonicalSerialize for RistrettoPoint {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        Ok(self.encode().write(writer)?)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        ENCODED_SIZE
+    }
+}
+
+impl ConstantSerializedSize for RistrettoPoint {
+    const SERIALIZED_SIZE: usize = ENCODED_SIZE;
+    const UNCOMPRESSED_SIZE: usize = ENCODED_SIZE;
+}
+
+impl CanonicalDeserialize for RistrettoPoint {
+    /// Decodes an element, rejecting any non-canonical encoding.
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; ENCODED_SIZE];
+        reader.read_exact(&mut bytes)?;
+        let s = Fq::from_repr(BigInteger256::read(&bytes[..])?)
+            .ok_or(SerializationError::InvalidData)?;
+        Self::decode(&s)
+    }
+}
@@ -0,0 +1,123 @@
+use algebra_core::{test_rng, CanonicalDeserialize, CanonicalSerialize, One, Zero};
+use rand::Rng;
+use sha2::{Digest, Sha512};
+
+use crate::{
+    curve25519::{ristretto::SQRT_M1, EdwardsProjective, Fq, RistrettoPoint},
+    tests::groups::group_test,
+};
+
+#[test]
+fn test_ristretto_group() {
+    let mut rng = test_rng();
+    let a: RistrettoPoint = rng.gen();
+    let b: RistrettoPoint = rng.gen();
+    group_test(a, b);
+}
+
+/// The encodings of the multiples 0, 1, ..., 15 of the generator, from
+/// appendix A.1 of RFC 9496.
+const GENERATOR_MULTIPLES: [&str; 16] = [
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+    "6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919",
+    "94741f5d5d52755ece4f23f044ee27d5d1ea1e2bd196b462166b16152a9d0259",
+    "da80862773358b466ffadfe0b3293ab3d9fd53c5ea6c955358f568322daf6a57",
+    "e882b131016b52c1d3337080187cf768423efccbb517bb495ab812c4160ff44e",
+    "f64746d3c92b13050ed8d80236a7f0007c3b3f962f5ba793d19a601ebb1df403",
+    "44f53520926ec81fbd5a387845beb7df85a96a24ece18738bdcfa6a7822a176d",
+    "903293d8f2287ebe10e2374dc1a53e0bc887e592699f02d077d5263cdd55601c",
+    "02622ace8f7303a31cafc63f8fc48fdc16e1c8c8d234b2f0d6685282a9076031",
+    "20706fd788b2720a1ed2a5dad4952b01f413bcf0e7564de8cdc816689e2db95f",
+    "bce83f8ba5dd2fa572864c24ba1810f9522bc6004afe95877ac73241cafdab42",
+    "e4549ee16b9aa03099ca208c67adafcafa4c3f3e4e5303de6026e3ca8ff84460",
+    "aa52e000df2e16f55fb1032fc33bc42742dad6bd5a8fc0be0167436c5948501f",
+    "46376b80f409b29dc2b5f6f0c52591990896e5716f41477cd30085ab7f10301e",
+    "e0c418f7c8d9c4cdd7395b93ea124f3ad99021bb681dfc3302a9d99a2e53e64e",
+];
+
+#[test]
+fn test_generator_multiples() {
+    let mut p = RistrettoPoint::zero();
+    for encoding in GENERATOR_MULTIPLES.iter() {
+        let mut serialized = vec![];
+        p.serialize(&mut serialized).unwrap();
+        assert_eq!(hex::encode(&serialized), *encoding);
+        assert_eq!(RistrettoPoint::deserialize(&serialized[..]).unwrap(), p);
+        p += &RistrettoPoint::generator();
+    }
+}
+
+/// Encodings that must be rejected, from appendix A.2 of RFC 9496.
+const INVALID_ENCODINGS: [&str; 9] = [
+    // Non-canonical field encodings.
+    "00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    "f3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    // Negative field elements.
+    "0100000000000000000000000000000000000000000000000000000000000000",
+    "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    // Non-square x^2.
+    "26948d35ca62e643e26a83177332e6b6afeb9d08e4268b650f1f5bbd8d81d371",
+    // Negative xy value.
+    "3eb858e78f5a7254d8c9731174a94f76755fd3941c0ac93735c07ba14579630e",
+    // s = -1, which causes y = 0.
+    "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+];
+
+#[test]
+fn test_invalid_encodings() {
+    for encoding in INVALID_ENCODINGS.iter() {
+        let bytes = hex::decode(encoding).unwrap();
+        assert!(RistrettoPoint::deserialize(&bytes[..]).is_err());
+    }
+}
+
+/// Labels whose SHA-512 hashes are mapped to the group, with the encodings of
+/// the resulting elements, from appendix A.3 of RFC 9496.
+const HASH_TO_GROUP_VECTORS: [[&str; 2]; 2] = [
+    [
+        "Ristretto is traditionally a short shot of espresso coffee",
+        "3066f82a1a747d45120d1740f14358531a8f04bbffe6a819f86dfe50f44a0a46",
+    ],
+    [
+        "If you use Ristretto, it generally makes a sweeter, more potent drink",
+        "6cc74def564b1d13fd86342d006aef367a18bb6001c74395cdf9011f731b0c1a",
+    ],
+];
+
+#[test]
+fn test_from_uniform_bytes() {
+    for [label, encoding] in HASH_TO_GROUP_VECTORS.iter() {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(&Sha512::digest(label.as_bytes()));
+        let p = RistrettoPoint::from_uniform_bytes(&bytes);
+
+        let mut serialized = vec![];
+        p.serialize(&mut serialized).unwrap();
+        assert_eq!(hex::encode(&serialized), *encoding);
+    }
+}
+
+#[test]
+fn test_torsion_representatives() {
+    // (sqrt(-1), 0) is a point of order 4 of edwards25519, so adding it to a
+    // representative gives another representative of the same element.
+    let torsion = EdwardsProjective::new(SQRT_M1, Fq::zero(), Fq::zero(), Fq::one());
+
+    let mut rng = test_rng();
+    let a: RistrettoPoint = rng.gen();
+    let mut b = a;
+    for _ in 0..4 {
+        b.0 += &torsion;
+        assert_eq!(a, b);
+
+        let mut a_serialized = vec![];
+        a.serialize(&mut a_serialized).unwrap();
+        let mut b_serialized = vec![];
+        b.serialize(&mut b_serialized).unwrap();
+        assert_eq!(a_serialized, b_serialized);
+    }
+    assert!(a != a + &RistrettoPoint::generator());
+}
@@ -3,9 +3,11 @@ use algebra_core::{
     biginteger::BigInteger256,
     curves::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+        montgomery,
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
     field_new,
+    hashing::curve_maps::elligator2::Elligator2Parameters,
 };
 
 #[cfg(test)]
@@ -14,6 +16,9 @@ mod tests;
 pub type EdwardsAffine = GroupAffine<EdwardsParameters>;
 pub type EdwardsProjective = GroupProjective<EdwardsParameters>;
 
+pub type MontgomeryAffine = montgomery::GroupAffine<EdwardsParameters>;
+pub type MontgomeryProjective = montgomery::GroupProjective<EdwardsParameters>;
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct EdwardsParameters;
 
@@ -88,6 +93,17 @@ impl MontgomeryModelParameters for EdwardsParameters {
     type TEModelParameters = EdwardsParameters;
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// ZETA = 11, the first non-square in the sequence 1, -1, 2, -2, ...
+    #[rustfmt::skip]
+    const ZETA: Fq = field_new!(Fq, BigInteger256([
+        1855201571499933546u64,
+        8511318076631809892u64,
+        6222514765367795509u64,
+        1122129207579058019u64,
+    ]));
+}
+
 /// GENERATOR_X =
 /// 7810607721416582242904415504650443951498042435501746664987470571546413371306
 #[rustfmt::skip]
@@ -1,4 +1,4 @@
-use crate::tests::fields::{field_test, primefield_test};
+use crate::tests::fields::{field_test, primefield_test, sqrt_field_test};
 use algebra_core::test_rng;
 use rand::Rng;
 
@@ -10,6 +10,7 @@ fn test_fr() {
     let a: Fr = rng.gen();
     let b: Fr = rng.gen();
     field_test(a, b);
+    sqrt_field_test(a);
     primefield_test::<Fr>();
 }
 
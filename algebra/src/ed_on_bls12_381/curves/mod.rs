@@ -3,9 +3,11 @@ use algebra_core::{
     biginteger::BigInteger256,
     curves::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+        montgomery,
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
     field_new,
+    hashing::curve_maps::elligator2::Elligator2Parameters,
 };
 
 #[cfg(test)]
@@ -14,6 +16,9 @@ mod tests;
 pub type EdwardsAffine = GroupAffine<EdwardsParameters>;
 pub type EdwardsProjective = GroupProjective<EdwardsParameters>;
 
+pub type MontgomeryAffine = montgomery::GroupAffine<EdwardsParameters>;
+pub type MontgomeryProjective = montgomery::GroupProjective<EdwardsParameters>;
+
 #[rustfmt::skip]
 const GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
     14080349899812819339,
@@ -122,3 +127,14 @@ impl MontgomeryModelParameters for EdwardsParameters {
 
     type TEModelParameters = EdwardsParameters;
 }
+
+impl Elligator2Parameters for EdwardsParameters {
+    /// ZETA = 5, the first non-square in the sequence 1, -1, 2, -2, ...
+    #[rustfmt::skip]
+    const ZETA: Fq = field_new!(Fq, BigInteger256([
+        47244640245u64,
+        7411222800919301131u64,
+        14736824336091166661u64,
+        345034914438815205u64,
+    ]));
+}
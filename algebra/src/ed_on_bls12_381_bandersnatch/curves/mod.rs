@@ -0,0 +1,381 @@
+use crate::ed_on_bls12_381_bandersnatch::{Fq, Fr};
+use algebra_core::{
+    biginteger::BigInteger256,
+    curves::{
+        glv::GLVParameters,
+        models::{
+            ModelParameters, MontgomeryModelParameters, SWModelParameters, TEModelParameters,
+        },
+        montgomery, short_weierstrass_jacobian, twisted_edwards_extended,
+    },
+    field_new,
+    fields::Field,
+    hashing::curve_maps::elligator2::Elligator2Parameters,
+    Zero,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub type EdwardsAffine = twisted_edwards_extended::GroupAffine<EdwardsParameters>;
+pub type EdwardsProjective = twisted_edwards_extended::GroupProjective<EdwardsParameters>;
+
+pub type MontgomeryAffine = montgomery::GroupAffine<EdwardsParameters>;
+pub type MontgomeryProjective = montgomery::GroupProjective<EdwardsParameters>;
+
+pub type SWAffine = short_weierstrass_jacobian::GroupAffine<SWParameters>;
+pub type SWProjective = short_weierstrass_jacobian::GroupProjective<SWParameters>;
+
+/// GENERATOR_X =
+/// 18886178867200960497001835917649091219057080094937609519140440539760939937304
+#[rustfmt::skip]
+const GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
+    0xec2627e1e7ab47f5,
+    0x3e63de484f01aa9c,
+    0xfe0f5c3b53946dc4,
+    0x2d71920baeb2cfcd,
+]));
+/// GENERATOR_Y =
+/// 19188667384257783945677642223292697773471335439753913231509108946878080696678
+#[rustfmt::skip]
+const GENERATOR_Y: Fq = field_new!(Fq, BigInteger256([
+    0x4e30593e1895bd34,
+    0x156d738f32afbe4b,
+    0x45ef0b1ccdeb75f4,
+    0x6a7cca0037d2e71f,
+]));
+
+/// `Bandersnatch` is the twisted Edwards curve -5x² + y² = 1 + dx²y² over Fq,
+/// where d = 45022363124591815672509500913686876175488063829319466900776701791074614335719.
+/// Its group of points has order 4r.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct EdwardsParameters;
+
+impl ModelParameters for EdwardsParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl TEModelParameters for EdwardsParameters {
+    /// COEFF_A = -5
+    #[rustfmt::skip]
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([
+        0xfffffff40000000c,
+        0xece3b023ffec4ff3,
+        0x66b620607396203f,
+        0x6f23d7e5f361df62,
+    ]));
+
+    /// COEFF_D =
+    /// 45022363124591815672509500913686876175488063829319466900776701791074614335719
+    #[rustfmt::skip]
+    const COEFF_D: Fq = field_new!(Fq, BigInteger256([
+        0xa8dced1b47a2c730,
+        0x381c065aad3cccc7,
+        0x53ff52e1188351f8,
+        0x362e8d63990fe940,
+    ]));
+
+    /// COFACTOR = 4
+    const COFACTOR: &'static [u64] = &[4];
+
+    /// COFACTOR^(-1) mod r =
+    /// 9831726595336160714896451345284868594481866920080427688839802480047265754601
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, BigInteger256([
+        0x1605f295af12303e,
+        0xe0f1ff17cdf71d,
+        0xe66313fbfb2f13fe,
+        0x6092c566b31415b,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+
+    type MontgomeryModelParameters = EdwardsParameters;
+
+    const GLV: Option<GLVParameters<Self, EdwardsProjective>> = Some(GLVParameters {
+        lambda: GLV_LAMBDA,
+        basis: GLV_BASIS,
+        rounding: GLV_ROUNDING,
+        endomorphism: edwards_endomorphism,
+    });
+
+    /// Multiplication by `a` is multiplication by -5.
+    #[inline(always)]
+    fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
+        let t = elem.double().double();
+        -(t + elem)
+    }
+}
+
+impl MontgomeryModelParameters for EdwardsParameters {
+    /// COEFF_A = 2 * (a + d) / (a - d)
+    ///         = 29978822694968839326280996386011761570173833766074948509196803838190355340952
+    #[rustfmt::skip]
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([
+        0xbb5f62a4db208e37,
+        0xf2534a00bc48e02d,
+        0x4e16c53199d5f845,
+        0x6a73b83193a4f19d,
+    ]));
+    /// COEFF_B = 4 / (a - d)
+    ///         = 25465760566081946422412445027709227188579564747101592991722834452325077642517
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, BigInteger256([
+        0x40ecec43d42cb05d,
+        0x88b85e04a721fff5,
+        0x758b323623d827fa,
+        0x3dcb807b250e31ba,
+    ]));
+
+    type TEModelParameters = EdwardsParameters;
+}
+
+impl Elligator2Parameters for EdwardsParameters {
+    /// ZETA = 5, the first non-square in the sequence 1, -1, 2, -2, ...
+    #[rustfmt::skip]
+    const ZETA: Fq = field_new!(Fq, BigInteger256([
+        0xafffffff5,
+        0x66d9f3df00120c0b,
+        0xcc83b7a7960bb7c5,
+        0x4c9cf6d363b9de5,
+    ]));
+}
+
+/// The short Weierstrass form y² = x³ + Ax + B of Bandersnatch, obtained from
+/// its Montgomery form By² = x³ + Ax² + x. Its generator is the image of the
+/// generator of the twisted Edwards form.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SWParameters;
+
+impl ModelParameters for SWParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for SWParameters {
+    /// COEFF_A = (3 - A^2) / (3 * B^2)
+    ///         = 10773120815616481058602537765553212789256758185246796157495669123169359657269
+    #[rustfmt::skip]
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([
+        0x4a76edf8d53dc9a4,
+        0x1f8fb7e79393fff5,
+        0x453ecaa4866eac5,
+        0x70e0fbe4d4367b46,
+    ]));
+
+    /// COEFF_B = (2 * A^3 - 9 * A) / (27 * B^3)
+    ///         = 29569587568322301171008055308580903175558631321415017492731745847794083609535
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, BigInteger256([
+        0x67274ef7dc31ae59,
+        0x1dc0dfe74138818f,
+        0xe1c48857b66332b6,
+        0x1a2b947fb45cb597,
+    ]));
+
+    /// COFACTOR = 4
+    const COFACTOR: &'static [u64] = &[4];
+
+    /// COFACTOR^(-1) mod r =
+    /// 9831726595336160714896451345284868594481866920080427688839802480047265754601
+    const COFACTOR_INV: Fr = EdwardsParameters::COFACTOR_INV;
+
+    /// AFFINE_GENERATOR_COEFFS = (SW_GENERATOR_X, SW_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (SW_GENERATOR_X, SW_GENERATOR_Y);
+
+    const GLV: Option<GLVParameters<Self>> = Some(GLVParameters {
+        lambda: GLV_LAMBDA,
+        basis: GLV_BASIS,
+        rounding: GLV_ROUNDING,
+        endomorphism,
+    });
+}
+
+/// SW_GENERATOR_X =
+/// 30900340493481298850216505686589334086208278925799850409469406976849338430199
+#[rustfmt::skip]
+const SW_GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
+    0x92d18a4480a544cf,
+    0xa6d45d6e625c1e92,
+    0x718fc055474e2aa3,
+    0x24436d0f0fb7db01,
+]));
+
+/// SW_GENERATOR_Y =
+/// 12663882780877899054958035777720958383845500985908634476792678820121468453298
+#[rustfmt::skip]
+const SW_GENERATOR_Y: Fq = field_new!(Fq, BigInteger256([
+    0x6f5bc874b59098d3,
+    0xfedbb34951a95d63,
+    0x8e26837fa461c4f0,
+    0x1fcede15cbff86b8,
+]));
+
+/// The endomorphism of degree 2 of Bandersnatch, which is the composition of
+/// the 2-isogeny whose kernel is the point `(ENDO_X0, 0)`, given by Vélu's
+/// formulas as `(x, y) -> (x + t / (x - x0), y * (1 - t / (x - x0)^2))`, with
+/// the isomorphism `(x, y) -> (u^2 * x, u^3 * y)` back to the curve.
+fn endomorphism(p: &SWProjective) -> SWProjective {
+    // With x = X / Z^2 and e = X - x0 * Z^2, the image of (X, Y, Z) is
+    // (u^2 * (X * e + t * Z^4) * e, u^3 * Y * (e^2 - t * Z^4) * e, Z * e).
+    let z2 = p.z.square();
+    let e = p.x - &(ENDO_X0 * &z2);
+    let tz4 = ENDO_T * &z2.square();
+    let x = ENDO_U2 * &(p.x * &e + &tz4) * &e;
+    let y = ENDO_U3 * &p.y * &(e.square() - &tz4) * &e;
+    SWProjective::new(x, y, p.z * &e)
+}
+
+/// The endomorphism of the short Weierstrass form, carried over to the twisted
+/// Edwards form, where it is given by
+/// `(x, y) -> (c * (1 - y^2) / (x * y), b * (y^2 + b) / (y^2 - b))`
+/// (see [[MS21]](https://eprint.iacr.org/2021/1152)).
+fn edwards_endomorphism(p: &EdwardsProjective) -> EdwardsProjective {
+    // With x = X / Z and y = Y / Z, the image of (X, Y, T, Z) is
+    // (f * h, g * w, f * g, w * h) in extended coordinates, where
+    // f = c * (Z^2 - Y^2), g = b * (Y^2 + b * Z^2), h = Y^2 - b * Z^2 and
+    // w = X * Y. Only points with x = 0 have w = 0, and the only such point of
+    // the prime order subgroup is the identity.
+    if p.x.is_zero() {
+        return *p;
+    }
+    let y2 = p.y.square();
+    let bz2 = ENDO_B * &p.z.square();
+    let f = ENDO_C * &(p.z.square() - &y2);
+    let g = ENDO_B * &(y2 + &bz2);
+    let h = y2 - &bz2;
+    let w = p.x * &p.y;
+    EdwardsProjective::new(f * &h, g * &w, f * &g, w * &h)
+}
+
+/// ENDO_B = b =
+/// 37446463827641770816307242315180085052603635617490163568005256780843403514036,
+/// a constant of the endomorphism of the twisted Edwards form.
+#[rustfmt::skip]
+pub const ENDO_B: Fq = field_new!(Fq, BigInteger256([
+    0xa2504eaa126fb8e8,
+    0xabee190a21d5d1e5,
+    0xc3f118354cb77ef,
+    0x6085ed8a47d4bdae,
+]));
+
+/// ENDO_C = c =
+/// 49199877423542878313146170939139662862850515542392585932876811575731455068989,
+/// a constant of the endomorphism of the twisted Edwards form.
+#[rustfmt::skip]
+pub const ENDO_C: Fq = field_new!(Fq, BigInteger256([
+    0xc2780b526ccbe0c8,
+    0x448f3bf473880956,
+    0x6249f6e302014353,
+    0x304c14afac09e900,
+]));
+
+/// ENDO_X0 = A / (3 * B) =
+/// 42460977304182762931716743824405123254375045638571669698531889431804823178961,
+/// the x-coordinate of the point of order 2 of the short Weierstrass form.
+#[rustfmt::skip]
+const ENDO_X0: Fq = field_new!(Fq, BigInteger256([
+    0xf17a2782369b2135,
+    0x22147fc0c7864e1e,
+    0x303185e2efe4db0b,
+    0x423248a7fa9ccb33,
+]));
+
+/// ENDO_T = 3 * x0^2 + A =
+/// 35484827650731063748396669747216844996598387089274032563585525486049249153249
+#[rustfmt::skip]
+const ENDO_T: Fq = field_new!(Fq, BigInteger256([
+    0x6cebd79ce6748705,
+    0xd9a9c11a7725c05,
+    0xca3ab06eaafdb18f,
+    0x30337696aa4999b7,
+]));
+
+/// ENDO_U2 = u^2 = -1/2 =
+/// 26217937587563095239723870254092982918845276250263818911301829349969290592256
+#[rustfmt::skip]
+const ENDO_U2: Fq = field_new!(Fq, BigInteger256([
+    0xfffffffe00000002,
+    0xa77b4805fffcb7fd,
+    0x6673b0101343b00a,
+    0x67db4ea6533afa90,
+]));
+
+/// ENDO_U3 = u^3 =
+/// 8089994378958280414021351509578931277711957629993758335181991576135134307306,
+/// where the square root u of -1/2 is chosen so that the eigenvalue of
+/// `endomorphism` is GLV_LAMBDA.
+#[rustfmt::skip]
+const ENDO_U3: Fq = field_new!(Fq, BigInteger256([
+    0x1ffbb8761abd6635,
+    0xce9edf812f2615a6,
+    0xefd0d3f4f6bc5ea4,
+    0xe8fd542f9a6b3b0,
+]));
+
+/// GLV_LAMBDA = 8913659658109529928382530854484400854125314752504019737736543920008458395397,
+/// a square root of -2 in Fr and the eigenvalue of `endomorphism`.
+#[rustfmt::skip]
+const GLV_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0x367418621cbd6544,
+    0xe876a237efb3098f,
+    0x42ee867d918755ae,
+    0xc083cc734dd2e28,
+]));
+
+/// GLV_BASIS = [
+///     (113482231691339203864511368254957623327, 10741319382058138887739339959866629956),
+///     (21482638764116277775478679919733259912, -113482231691339203864511368254957623327),
+/// ]
+#[rustfmt::skip]
+const GLV_BASIS: [(Fr, Fr); 2] = [
+    (
+        field_new!(Fr, BigInteger256([
+            0xe0c9ceeed5a4c85f,
+            0xd5496cb79d7c6ffb,
+            0xbad61a170ef853b1,
+            0x14e5c8ea426abf1e,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x30a30ee0353f9c96,
+            0xa0cbf7017ae7203f,
+            0xd6975d53f0d11c21,
+            0x155d4e84bc741a67,
+        ])),
+    ),
+    (
+        field_new!(Fr, BigInteger256([
+            0xec49170b4208514b,
+            0x4208670281b53c0c,
+            0xa06044a5df39c242,
+            0xdbf3334ae80d57d,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x943337c652d21f82,
+            0x2a461a48d69c9475,
+            0x51f85beaf370224f,
+            0x815a0ea87fca033,
+        ])),
+    ),
+];
+
+/// GLV_ROUNDING = [round(2^256 * b2 / d), round(-2^256 * b1 / d)], where
+/// d = a1 * b2 - a2 * b1 = -r
+#[rustfmt::skip]
+const GLV_ROUNDING: [BigInteger256; 2] = [
+    BigInteger256([
+        0xdebac77a3f4747c2,
+        0xf21df5b0541cf632,
+        0x2,
+        0x0,
+    ]),
+    BigInteger256([
+        0x993b75e7547768ab,
+        0x4760f127d8767bde,
+        0x0,
+        0x0,
+    ]),
+];
@@ -0,0 +1,122 @@
+use algebra_core::{
+    curves::{AffineCurve, ProjectiveCurve},
+    fields::Field,
+    test_rng, MontgomeryModelParameters, One,
+};
+use rand::Rng;
+
+use crate::ed_on_bls12_381_bandersnatch::*;
+
+use crate::tests::{curves::*, groups::*, hashing::*};
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<EdwardsProjective>();
+
+    edwards_tests::<EdwardsParameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = test_rng();
+    let a = rng.gen();
+    let b = rng.gen();
+    for _i in 0..100 {
+        group_test::<EdwardsProjective>(a, b);
+    }
+}
+
+#[test]
+fn test_affine_group() {
+    let mut rng = test_rng();
+    let a: EdwardsAffine = rng.gen();
+    let b: EdwardsAffine = rng.gen();
+    for _i in 0..100 {
+        group_test::<EdwardsAffine>(a, b);
+    }
+}
+
+#[test]
+fn test_generator() {
+    let generator = EdwardsAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_montgomery_conversion() {
+    montgomery_conversion_test::<EdwardsParameters>();
+}
+
+#[test]
+fn test_montgomery_curve() {
+    curve_tests::<MontgomeryProjective>();
+
+    montgomery_tests::<EdwardsParameters>();
+}
+
+#[test]
+fn test_elligator2() {
+    elligator2_test::<EdwardsParameters>();
+}
+
+#[test]
+fn test_sw_curve() {
+    curve_tests::<SWProjective>();
+
+    sw_tests::<SWParameters>();
+    sw_xyzz_tests::<SWParameters>();
+}
+
+#[test]
+fn test_sw_group() {
+    let mut rng = test_rng();
+    let a: SWProjective = rng.gen();
+    let b: SWProjective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_sw_generator() {
+    let generator = SWAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+/// Maps a point of the twisted Edwards form to the short Weierstrass form,
+/// through the Montgomery form.
+fn edwards_to_sw(p: &EdwardsAffine) -> SWAffine {
+    let a = <EdwardsParameters as MontgomeryModelParameters>::COEFF_A;
+    let b = <EdwardsParameters as MontgomeryModelParameters>::COEFF_B;
+    let b_inv = b.inverse().unwrap();
+    let u = (Fq::one() + &p.y) * &(Fq::one() - &p.y).inverse().unwrap();
+    let v = u * &p.x.inverse().unwrap();
+    let three = Fq::one().double() + &Fq::one();
+    SWAffine::new(
+        (u + &(a * &three.inverse().unwrap())) * &b_inv,
+        v * &b_inv,
+        false,
+    )
+}
+
+#[test]
+fn test_sw_edwards_consistency() {
+    assert_eq!(
+        edwards_to_sw(&EdwardsAffine::prime_subgroup_generator()),
+        SWAffine::prime_subgroup_generator()
+    );
+
+    let mut rng = test_rng();
+    let a: EdwardsProjective = rng.gen();
+    let b: EdwardsProjective = rng.gen();
+    let s: Fr = rng.gen();
+    let (a, b) = (a.into_affine(), b.into_affine());
+    assert_eq!(
+        edwards_to_sw(&(a + &b)),
+        (edwards_to_sw(&a).into_projective() + &edwards_to_sw(&b).into_projective()).into_affine()
+    );
+    assert_eq!(
+        edwards_to_sw(&a.mul(s).into_affine()),
+        edwards_to_sw(&a).mul(s).into_affine()
+    );
+}
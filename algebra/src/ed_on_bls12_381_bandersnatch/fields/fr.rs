@@ -0,0 +1,8 @@
+use algebra_core::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+#[derive(FpParameters)]
+#[modulus = "13108968793781547619861935127046491459309155893440570251786403306729687672801"]
+#[generator = "7"]
+pub struct FrParameters;
@@ -0,0 +1,27 @@
+use algebra_core::test_rng;
+use rand::Rng;
+
+use crate::{
+    ed_on_bls12_381_bandersnatch::{Fq, Fr},
+    tests::fields::{field_test, primefield_test, sqrt_field_test},
+};
+
+#[test]
+fn test_fr() {
+    let mut rng = test_rng();
+    let a: Fr = rng.gen();
+    let b: Fr = rng.gen();
+    field_test(a, b);
+    primefield_test::<Fr>();
+    sqrt_field_test(b);
+}
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    let a: Fq = rng.gen();
+    let b: Fq = rng.gen();
+    field_test(a, b);
+    primefield_test::<Fq>();
+    sqrt_field_test(a);
+}
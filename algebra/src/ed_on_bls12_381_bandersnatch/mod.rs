@@ -0,0 +1,26 @@
+//! This module implements Bandersnatch, a twisted Edwards curve whose base
+//! field is the scalar field of the curve BLS12-381, like
+//! [Jubjub](crate::ed_on_bls12_381). It was generated by Simon Masson and
+//! Antonio Sanso, and is described in [MS21](https://eprint.iacr.org/2021/1152).
+//! Unlike Jubjub, Bandersnatch admits an endomorphism of degree 2, so that
+//! scalar multiplications of points of the prime order subgroup can use the
+//! GLV method, through `mul_glv` on both the twisted Edwards form and the
+//! short Weierstrass form, which is provided alongside it.
+//!
+//! Curve information:
+//! * Base field: q = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+//! * Scalar field: r = 13108968793781547619861935127046491459309155893440570251786403306729687672801
+//! * Valuation(q - 1, 2) = 32
+//! * Valuation(r - 1, 2) = 5
+//! * Curve equation: ax^2 + y^2 =1 + dx^2y^2, where
+//!    * a = -5
+//!    * d = 45022363124591815672509500913686876175488063829319466900776701791074614335719
+//! * Short Weierstrass form: y^2 = x^3 + Ax + B, where
+//!    * A = 10773120815616481058602537765553212789256758185246796157495669123169359657269
+//!    * B = 29569587568322301171008055308580903175558631321415017492731745847794083609535
+
+mod curves;
+mod fields;
+
+pub use curves::*;
+pub use fields::*;
@@ -3,9 +3,11 @@ use algebra_core::{
     biginteger::BigInteger256,
     curves::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+        montgomery,
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
     field_new,
+    hashing::curve_maps::elligator2::Elligator2Parameters,
 };
 
 #[cfg(test)]
@@ -14,6 +16,9 @@ mod tests;
 pub type EdwardsAffine = GroupAffine<EdwardsParameters>;
 pub type EdwardsProjective = GroupProjective<EdwardsParameters>;
 
+pub type MontgomeryAffine = montgomery::GroupAffine<EdwardsParameters>;
+pub type MontgomeryProjective = montgomery::GroupProjective<EdwardsParameters>;
+
 #[rustfmt::skip]
 const GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
     0x3db6612c2863cc99,
@@ -109,3 +114,14 @@ impl MontgomeryModelParameters for EdwardsParameters {
 
     type TEModelParameters = EdwardsParameters;
 }
+
+impl Elligator2Parameters for EdwardsParameters {
+    /// ZETA = 5, the first non-square in the sequence 1, -1, 2, -2, ...
+    #[rustfmt::skip]
+    const ZETA: Fq = field_new!(Fq, BigInteger256([
+        1949230679015292902u64,
+        16913946402569752895u64,
+        5177146667339417225u64,
+        1571765431670520771u64,
+    ]));
+}
@@ -8,7 +8,7 @@ use rand::Rng;
 
 use crate::ed_on_bn254::*;
 
-use crate::tests::{curves::*, groups::*};
+use crate::tests::{curves::*, groups::*, hashing::*};
 
 #[test]
 fn test_projective_curve() {
@@ -111,3 +111,15 @@ fn test_bytes() {
 fn test_montgomery_conversion() {
     montgomery_conversion_test::<EdwardsParameters>();
 }
+
+#[test]
+fn test_montgomery_curve() {
+    curve_tests::<MontgomeryProjective>();
+
+    montgomery_tests::<EdwardsParameters>();
+}
+
+#[test]
+fn test_elligator2() {
+    elligator2_test::<EdwardsParameters>();
+}
@@ -2,9 +2,11 @@ use crate::{
     biginteger::BigInteger384 as BigInteger,
     curves::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+        montgomery,
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
     field_new,
+    hashing::curve_maps::elligator2::Elligator2Parameters,
 };
 
 use crate::ed_on_cp6_782::{fq::Fq, fr::Fr};
@@ -15,6 +17,9 @@ mod tests;
 pub type EdwardsAffine = GroupAffine<EdwardsParameters>;
 pub type EdwardsProjective = GroupProjective<EdwardsParameters>;
 
+pub type MontgomeryAffine = montgomery::GroupAffine<EdwardsParameters>;
+pub type MontgomeryProjective = montgomery::GroupProjective<EdwardsParameters>;
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct EdwardsParameters;
 
@@ -99,6 +104,19 @@ impl MontgomeryModelParameters for EdwardsParameters {
     type TEModelParameters = EdwardsParameters;
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// ZETA = 5, the first non-square in the sequence 1, -1, 2, -2, ...
+    #[rustfmt::skip]
+    const ZETA: Fq = field_new!(Fq, BigInteger([
+        9871116327010172167u64,
+        9167007004823125620u64,
+        18338974479346628539u64,
+        5649234265355377548u64,
+        13442091487463296847u64,
+        77904398905292312u64,
+    ]));
+}
+
 /// GENERATOR_X =
 /// 174701772324485506941690903512423551998294352968833659960042362742684869862495746426366187462669992073196420267127
 #[rustfmt::skip]
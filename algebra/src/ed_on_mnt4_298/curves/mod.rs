@@ -3,9 +3,11 @@ use algebra_core::{
     biginteger::BigInteger320,
     curves::{
         models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+        montgomery,
         twisted_edwards_extended::{GroupAffine, GroupProjective},
     },
     field_new,
+    hashing::curve_maps::elligator2::Elligator2Parameters,
 };
 
 #[cfg(test)]
@@ -14,6 +16,9 @@ mod tests;
 pub type EdwardsAffine = GroupAffine<EdwardsParameters>;
 pub type EdwardsProjective = GroupProjective<EdwardsParameters>;
 
+pub type MontgomeryAffine = montgomery::GroupAffine<EdwardsParameters>;
+pub type MontgomeryProjective = montgomery::GroupProjective<EdwardsParameters>;
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct EdwardsParameters;
 
@@ -112,6 +117,18 @@ impl MontgomeryModelParameters for EdwardsParameters {
     type TEModelParameters = EdwardsParameters;
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// ZETA = 5, the first non-square in the sequence 1, -1, 2, -2, ...
+    #[rustfmt::skip]
+    const ZETA: Fq = field_new!(Fq, BigInteger320([
+        6408337942890780106u64,
+        1364954624287809540u64,
+        5152173214042605211u64,
+        9848521082393849561u64,
+        1424783596008u64,
+    ]));
+}
+
 /// GENERATOR_X =
 /// 282406820114868156776872298252698015906762052916420164316497572033519876761239463633892227
 /// Needs to be in the Montgomery residue form in Fq
@@ -6,7 +6,7 @@ use rand::Rng;
 
 use crate::ed_on_mnt4_298::*;
 
-use crate::tests::{curves::*, groups::*};
+use crate::tests::{curves::*, groups::*, hashing::*};
 
 #[test]
 fn test_projective_curve() {
@@ -63,3 +63,15 @@ fn test_conversion() {
 fn test_montgomery_conversion() {
     montgomery_conversion_test::<EdwardsParameters>();
 }
+
+#[test]
+fn test_montgomery_curve() {
+    curve_tests::<MontgomeryProjective>();
+
+    montgomery_tests::<EdwardsParameters>();
+}
+
+#[test]
+fn test_elligator2() {
+    elligator2_test::<EdwardsParameters>();
+}
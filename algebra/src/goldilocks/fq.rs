@@ -0,0 +1,64 @@
+use algebra_core::{
+    biginteger::BigInteger64 as BigInteger,
+    fields::{FftParameters, Fp64, Fp64Parameters},
+};
+
+pub type Fq = Fp64<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp64Parameters for FqParameters {
+    /// MODULUS = 18446744069414584321
+    const MODULUS: BigInteger = BigInteger([18446744069414584321u64]);
+
+    const MODULUS_BITS: u32 = 64;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 0;
+
+    const GENERATOR: BigInteger = BigInteger([7u64]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([9223372034707292160u64]);
+
+    const T: BigInteger = BigInteger([4294967295u64]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([2147483647u64]);
+
+    /// Reduces `x` with 2^64 = 2^32 - 1 and 2^96 = -1.
+    #[inline(always)]
+    fn reduce(x: u128) -> u64 {
+        const EPSILON: u64 = (1 << 32) - 1;
+        let lo = x as u64;
+        let hi = (x >> 64) as u64;
+
+        // lo - hi_hi, where adding 2^64 on a borrow must be corrected by
+        // subtracting 2^64 - p = EPSILON. This cannot underflow again.
+        let (mut t0, borrow) = lo.overflowing_sub(hi >> 32);
+        if borrow {
+            t0 = t0.wrapping_sub(EPSILON);
+        }
+        // t0 + hi_lo * EPSILON, where dropping 2^64 on a carry must be
+        // corrected likewise. This cannot overflow again.
+        let t1 = (hi & EPSILON) * EPSILON;
+        let (mut t2, carry) = t0.overflowing_add(t1);
+        if carry {
+            t2 = t2.wrapping_add(EPSILON);
+        }
+
+        if t2 >= Self::MODULUS.0[0] {
+            t2 - Self::MODULUS.0[0]
+        } else {
+            t2
+        }
+    }
+}
+
+impl FftParameters for FqParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 32;
+
+    /// 7^t
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([1753635133440165772u64]);
+}
@@ -0,0 +1,47 @@
+use super::*;
+use algebra_core::{
+    biginteger::BigInteger64 as BigInteger,
+    field_new,
+    fields::fp3::{Fp3, Fp3Parameters},
+};
+
+pub type Fq3 = Fp3<Fq3Parameters>;
+
+pub struct Fq3Parameters;
+
+impl Fp3Parameters for Fq3Parameters {
+    type Fp = Fq;
+
+    /// NONRESIDUE = 7
+    const NONRESIDUE: Fq = field_new!(Fq, BigInteger([7u64]));
+
+    const TWO_ADICITY: u32 = 32;
+
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: &'static [u64] = &[
+        0x80000002fffffffe,
+        0x80000002fffffffc,
+        0x7ffffffe,
+    ];
+
+    /// (7^t, 0, 0)
+    const QUADRATIC_NONRESIDUE_TO_T: (Fq, Fq, Fq) = (
+        field_new!(Fq, BigInteger([3607031617444012685u64])),
+        field_new!(Fq, BigInteger([0u64])),
+        field_new!(Fq, BigInteger([0u64])),
+    );
+
+    /// NONRESIDUE**(((q^i) - 1) / 3)
+    const FROBENIUS_COEFF_FP3_C1: &'static [Fq] = &[
+        field_new!(Fq, BigInteger([1u64])),
+        field_new!(Fq, BigInteger([18446744065119617025u64])),
+        field_new!(Fq, BigInteger([4294967295u64])),
+    ];
+
+    /// NONRESIDUE**(((2q^i) - 2) / 3)
+    const FROBENIUS_COEFF_FP3_C2: &'static [Fq] = &[
+        field_new!(Fq, BigInteger([1u64])),
+        field_new!(Fq, BigInteger([4294967295u64])),
+        field_new!(Fq, BigInteger([18446744065119617025u64])),
+    ];
+}
@@ -0,0 +1,23 @@
+//! This module implements the Goldilocks field, the prime field of order
+//! p = 2^64 - 2^32 + 1, along with its quadratic and cubic extensions.
+//!
+//! Products reduce cheaply as 2^64 = 2^32 - 1 and 2^96 = -1 modulo p, and the
+//! multiplicative group has a subgroup of order 2^32 for FFTs.
+//!
+//! Field information:
+//! * Field: p = 18446744069414584321
+//! * valuation(p - 1, 2) = 32
+//! * Quadratic extension: Fq2 = Fq[u]/(u^2 - 7)
+//! * Cubic extension: Fq3 = Fq[u]/(u^3 - 7)
+
+mod fq;
+pub use fq::*;
+
+mod fq2;
+pub use fq2::*;
+
+mod fq3;
+pub use fq3::*;
+
+#[cfg(test)]
+mod tests;
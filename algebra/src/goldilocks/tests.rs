@@ -0,0 +1,92 @@
+use algebra_core::{test_rng, CanonicalSerialize, FftField, Field, One, Zero};
+use rand::Rng;
+
+use crate::goldilocks::*;
+
+use crate::tests::fields::{
+    fft_field_test, field_serialization_test, field_test, fp64_reduce_test, frobenius_test,
+    primefield_test, sqrt_field_test,
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    primefield_test::<Fq>();
+    fft_field_test::<Fq>();
+    fp64_reduce_test::<FqParameters>();
+    let byte_size = Fq::zero().serialized_size();
+    assert_eq!(byte_size, 8);
+    field_serialization_test::<Fq>(byte_size);
+}
+
+#[test]
+fn test_fq2() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = rng.gen();
+        let b: Fq2 = rng.gen();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
+    let byte_size = Fq2::zero().serialized_size();
+    field_serialization_test::<Fq2>(byte_size);
+}
+
+#[test]
+fn test_fq3() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq3 = rng.gen();
+        let b: Fq3 = rng.gen();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq3, _>(Fq::characteristic(), 13);
+    let byte_size = Fq3::zero().serialized_size();
+    field_serialization_test::<Fq3>(byte_size);
+}
+
+#[test]
+fn test_fq_modulus() {
+    // p = 2^64 - 2^32 + 1, so 2^64 = 2^32 - 1 and 2^96 = -1.
+    let two = Fq::from(2u64);
+    assert_eq!(two.pow([64]), Fq::from((1u64 << 32) - 1));
+    assert_eq!(two.pow([96]), -Fq::one());
+    assert_eq!(-Fq::one(), Fq::from(18446744069414584320u64));
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    // 7^t, the generator of the subgroup of order 2^32 used by Plonky2.
+    assert_eq!(Fq::two_adicity(), 32);
+    assert_eq!(
+        Fq::two_adic_root_of_unity(),
+        Fq::from(1753635133440165772u64)
+    );
+    let mut root = Fq::two_adic_root_of_unity();
+    for _ in 1..Fq::two_adicity() {
+        root.square_in_place();
+    }
+    assert_eq!(root, -Fq::one());
+}
+
+#[test]
+fn test_extension_nonresidues() {
+    // Fq2 = Fq[u]/(u^2 - 7) and Fq3 = Fq[u]/(u^3 - 7).
+    let u = Fq2::new(Fq::zero(), Fq::one());
+    assert_eq!(u.square(), Fq2::new(Fq::from(7u64), Fq::zero()));
+    let u = Fq3::new(Fq::zero(), Fq::one(), Fq::zero());
+    assert_eq!(
+        u.square() * u,
+        Fq3::new(Fq::from(7u64), Fq::zero(), Fq::zero())
+    );
+}
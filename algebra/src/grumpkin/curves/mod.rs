@@ -0,0 +1,180 @@
+use crate::grumpkin::{Fq, Fr};
+use algebra_core::{
+    biginteger::BigInteger256,
+    curves::{
+        glv::GLVParameters,
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    field_new, Zero,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub type Affine = GroupAffine<GrumpkinParameters>;
+pub type Projective = GroupProjective<GrumpkinParameters>;
+
+/// The cycle formed by BN254 and Grumpkin.
+#[cfg(feature = "bn254")]
+#[derive(Copy, Clone, Debug)]
+pub struct Bn254GrumpkinCycle;
+
+#[cfg(feature = "bn254")]
+impl algebra_core::curves::CurveCycle for Bn254GrumpkinCycle {
+    type E1 = crate::bn254::G1Projective;
+    type E2 = Projective;
+}
+
+/// Grumpkin is the short Weierstrass curve y² = x³ - 17 over Fq, whose group
+/// of points has prime order r. Its base and scalar fields are respectively the
+/// scalar and base fields of BN254.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct GrumpkinParameters;
+
+impl ModelParameters for GrumpkinParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for GrumpkinParameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+
+    /// COEFF_B = -17
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, BigInteger256([
+        0xdd7056026000005a,
+        0x223fa97acb319311,
+        0xcc388229877910c0,
+        0x34394632b724eaa,
+    ]));
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, BigInteger256([
+        0xd35d438dc58f0d9d,
+        0xa78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0xe0a77c19a07df2f,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (GENERATOR_X, GENERATOR_Y);
+
+    const GLV: Option<GLVParameters<Self>> = Some(GLVParameters {
+        lambda: GLV_LAMBDA,
+        basis: GLV_BASIS,
+        rounding: GLV_ROUNDING,
+        endomorphism,
+    });
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// The endomorphism `(x, y) -> (BETA * x, y)`.
+fn endomorphism(p: &Projective) -> Projective {
+    let mut res = *p;
+    res.x *= &BETA;
+    res
+}
+
+/// BETA = 4407920970296243842393367215006156084916469457145843978461,
+/// a primitive cube root of unity in Fq.
+#[rustfmt::skip]
+pub const BETA: Fq = field_new!(Fq, BigInteger256([
+    0x93e7cede4a0329b3,
+    0x7d4fdca77a96c167,
+    0x8be4ba08b19a750a,
+    0x1cbd5653a5661c25,
+]));
+
+/// GLV_LAMBDA = 2203960485148121921418603742825762020974279258880205651966,
+/// the eigenvalue of `endomorphism`.
+#[rustfmt::skip]
+const GLV_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0x71930c11d782e155,
+    0xa6bb947cffbe3323,
+    0xaa303344d4741444,
+    0x2c3b3f0d26594943,
+]));
+
+/// GLV_BASIS = [
+///     (9931322734385697762, -147946756881789319000765030803803410729),
+///     (147946756881789319010696353538189108491, 9931322734385697762),
+/// ]
+#[rustfmt::skip]
+const GLV_BASIS: [(Fr, Fr); 2] = [
+    (
+        field_new!(Fr, BigInteger256([
+            0xb7f14de1353f96c2,
+            0x7d8add758ea26e9d,
+            0x53aef83530ba011d,
+            0x6e3c0124b1b4aaf,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x1d1aa130fdc49462,
+            0x16453b9c0bad4b83,
+            0x1d30c85e7507bf4f,
+            0x1108a44ae70a385f,
+        ])),
+    ),
+    (
+        field_new!(Fr, BigInteger256([
+            0xd6f738c70ff7ffa7,
+            0xfec70c6aeb66eda7,
+            0xeece758d3d339a2b,
+            0x263f6a3a4542b279,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xb7f14de1353f96c2,
+            0x7d8add758ea26e9d,
+            0x53aef83530ba011d,
+            0x6e3c0124b1b4aaf,
+        ])),
+    ),
+];
+
+/// GLV_ROUNDING = [round(2^256 * b2 / d), round(-2^256 * b1 / d)], where
+/// d = a1 * b2 - a2 * b1 = r
+#[rustfmt::skip]
+const GLV_ROUNDING: [BigInteger256; 2] = [
+    BigInteger256([
+        0xd91d232ec7e0b3d2,
+        0x2,
+        0x0,
+        0x0,
+    ]),
+    BigInteger256([
+        0x7a7bd9d4391eb18e,
+        0x4ccef014a773d2cf,
+        0x2,
+        0x0,
+    ]),
+];
+
+/// GENERATOR_X = 1
+#[rustfmt::skip]
+pub const GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
+    0xac96341c4ffffffb,
+    0x36fc76959f60cd29,
+    0x666ea36f7879462e,
+    0xe0a77c19a07df2f,
+]));
+
+/// GENERATOR_Y = 17631683881184975370165255887551781615748388533673675138860,
+/// a square root of -16 in Fq.
+#[rustfmt::skip]
+pub const GENERATOR_Y: Fq = field_new!(Fq, BigInteger256([
+    0x11b2dff1448c41d8,
+    0x23d3446f21c77dc3,
+    0xaa7b8cf435dfafbb,
+    0x14b34cf69dc25d68,
+]));
@@ -0,0 +1 @@
+pub use crate::bn254::{Fr as Fq, FrParameters as FqParameters};
@@ -0,0 +1 @@
+pub use crate::bn254::{Fq as Fr, FqParameters as FrParameters};
@@ -0,0 +1,20 @@
+//! This module implements Grumpkin, the curve that forms a cycle with the
+//! [BN254 curve](crate::bn254). Its base field is the scalar field of BN254
+//! and its scalar field is the base field of BN254, so that the arithmetic
+//! of Grumpkin is native to circuits over BN254 and vice versa. This allows
+//! cheap in-circuit Pedersen hashing and signature verification on proof
+//! systems over BN254. Grumpkin is not pairing-friendly, but it admits a GLV
+//! endomorphism.
+//!
+//! Curve information:
+//! * Base field: q = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+//! * Scalar field: r = 21888242871839275222246405745257275088696311157297823662689037894645226208583
+//! * valuation(q - 1, 2) = 28
+//! * valuation(r - 1, 2) = 1
+//! * Curve equation: y^2 = x^3 - 17
+
+mod curves;
+mod fields;
+
+pub use curves::*;
+pub use fields::*;
@@ -40,11 +40,17 @@ pub use bn254::Bn254;
 ///////////////////////////////////////////////////////////////////////////////
 
 ///////////////////////////////////////////////////////////////////////////////
-#[cfg(all(not(feature = "bn254"), feature = "ed_on_bn254"))]
+#[cfg(all(
+    not(feature = "bn254"),
+    any(feature = "ed_on_bn254", feature = "grumpkin")
+))]
 pub(crate) mod bn254;
 
 #[cfg(feature = "ed_on_bn254")]
 pub mod ed_on_bn254;
+
+#[cfg(feature = "grumpkin")]
+pub mod grumpkin;
 ///////////////////////////////////////////////////////////////////////////////
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -79,11 +85,24 @@ pub use bls12_381::Bls12_381;
 ///////////////////////////////////////////////////////////////////////////////
 
 ///////////////////////////////////////////////////////////////////////////////
-#[cfg(all(not(feature = "bls12_381"), feature = "ed_on_bls12_381"))]
+#[cfg(all(
+    not(feature = "bls12_381"),
+    any(feature = "ed_on_bls12_381", feature = "ed_on_bls12_381_bandersnatch")
+))]
 pub(crate) mod bls12_381;
 
 #[cfg(feature = "ed_on_bls12_381")]
 pub mod ed_on_bls12_381;
+
+#[cfg(feature = "ed_on_bls12_381_bandersnatch")]
+pub mod ed_on_bls12_381_bandersnatch;
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "bls24_315")]
+pub mod bls24_315;
+#[cfg(feature = "bls24_315")]
+pub use bls24_315::Bls24_315;
 ///////////////////////////////////////////////////////////////////////////////
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -175,5 +194,46 @@ pub mod ed_on_bw6_761;
 pub(crate) mod bw6_761;
 ///////////////////////////////////////////////////////////////////////////////
 
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "pallas")]
+pub mod pallas;
+
+#[cfg(all(not(feature = "pallas"), feature = "vesta"))]
+pub(crate) mod pallas;
+
+#[cfg(feature = "vesta")]
+pub mod vesta;
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "secp256k1")]
+pub mod secp256k1;
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "secp256r1")]
+pub mod secp256r1;
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "curve25519")]
+pub mod curve25519;
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "goldilocks")]
+pub mod goldilocks;
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "baby_bear")]
+pub mod baby_bear;
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "mersenne31")]
+pub mod mersenne31;
+///////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 pub(crate) mod tests;
@@ -0,0 +1,51 @@
+use algebra_core::{
+    biginteger::BigInteger64 as BigInteger,
+    fields::{FftParameters, Fp64, Fp64Parameters},
+};
+
+pub type Fq = Fp64<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp64Parameters for FqParameters {
+    /// MODULUS = 2147483647
+    const MODULUS: BigInteger = BigInteger([2147483647u64]);
+
+    const MODULUS_BITS: u32 = 31;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 33;
+
+    const GENERATOR: BigInteger = BigInteger([7u64]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([1073741823u64]);
+
+    const T: BigInteger = BigInteger([1073741823u64]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([536870911u64]);
+
+    /// Reduces `x < MODULUS^2 < 2^62` with 2^31 = 1.
+    #[inline(always)]
+    fn reduce(x: u128) -> u64 {
+        let modulus = Self::MODULUS.0[0];
+        let x = x as u64;
+        // Folding twice leaves at most `MODULUS + 1`.
+        let x = (x & modulus) + (x >> 31);
+        let x = (x & modulus) + (x >> 31);
+        if x >= modulus {
+            x - modulus
+        } else {
+            x
+        }
+    }
+}
+
+impl FftParameters for FqParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 1;
+
+    /// 7^t = -1
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([2147483646u64]);
+}
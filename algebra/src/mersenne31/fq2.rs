@@ -0,0 +1,49 @@
+use super::*;
+use algebra_core::{biginteger::BigInteger64 as BigInteger, field_new, fields::*};
+
+pub type Fq2 = Fp2<Fq2Parameters>;
+
+pub struct Fq2Parameters;
+
+impl Fp2Parameters for Fq2Parameters {
+    type Fp = Fq;
+
+    /// NONRESIDUE = -1
+    const NONRESIDUE: Fq = field_new!(Fq, BigInteger([2147483646u64]));
+
+    /// QUADRATIC_NONRESIDUE = U+2
+    const QUADRATIC_NONRESIDUE: (Fq, Fq) = (
+        field_new!(Fq, BigInteger([2u64])),
+        field_new!(Fq, BigInteger([1u64])),
+    );
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: &'static [Fq] = &[
+        // NONRESIDUE**(((q^0) - 1) / 2)
+        field_new!(Fq, BigInteger([1u64])),
+        // NONRESIDUE**(((q^1) - 1) / 2)
+        field_new!(Fq, BigInteger([2147483646u64])),
+    ];
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue(fe: &Self::Fp) -> Self::Fp {
+        -(*fe)
+    }
+}
+
+impl Fp2FftParameters for Fq2Parameters {
+    /// p^2 - 1 = 2^32 * 3^2 * 7 * 11 * 31 * 151 * 331
+    const TWO_ADICITY: u32 = 32;
+
+    /// GENERATOR = 7 + 2 * U
+    const GENERATOR: (Fq, Fq) = (
+        field_new!(Fq, BigInteger([7u64])),
+        field_new!(Fq, BigInteger([2u64])),
+    );
+
+    /// GENERATOR^((p^2 - 1) / 2^32)
+    const TWO_ADIC_ROOT_OF_UNITY: (Fq, Fq) = (
+        field_new!(Fq, BigInteger([299267213u64])),
+        field_new!(Fq, BigInteger([2121177069u64])),
+    );
+}
@@ -0,0 +1,28 @@
+//! This module implements the Mersenne-31 field, the prime field of order
+//! p = 2^31 - 1, along with its quadratic and cubic extensions.
+//!
+//! Products reduce with shifts and additions as 2^31 = 1 modulo p. As
+//! valuation(p - 1, 2) = 1, the field itself only has FFT domains of size 2,
+//! but the multiplicative group of the quadratic extension, the complex
+//! extension Fq[i]/(i^2 + 1), has order divisible by 2^32. FFTs over M31 data
+//! should therefore be run over `Fq2`, which implements `FftField`. `Fq3` does
+//! not, as valuation(p^3 - 1, 2) = 1 as well.
+//!
+//! Field information:
+//! * Field: p = 2147483647
+//! * valuation(p - 1, 2) = 1
+//! * Quadratic extension: Fq2 = Fq[u]/(u^2 + 1)
+//! * valuation(p^2 - 1, 2) = 32
+//! * Cubic extension: Fq3 = Fq[u]/(u^3 - 5)
+
+mod fq;
+pub use fq::*;
+
+mod fq2;
+pub use fq2::*;
+
+mod fq3;
+pub use fq3::*;
+
+#[cfg(test)]
+mod tests;
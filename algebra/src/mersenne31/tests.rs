@@ -0,0 +1,85 @@
+use algebra_core::{test_rng, CanonicalSerialize, FftField, Field, One, Zero};
+use rand::Rng;
+
+use crate::mersenne31::*;
+
+use crate::tests::fields::{
+    fft_field_test, field_serialization_test, field_test, fp64_reduce_test, frobenius_test,
+    primefield_test, sqrt_field_test,
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    primefield_test::<Fq>();
+    fp64_reduce_test::<FqParameters>();
+    let byte_size = Fq::zero().serialized_size();
+    assert_eq!(byte_size, 4);
+    field_serialization_test::<Fq>(byte_size);
+}
+
+#[test]
+fn test_fq2() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = rng.gen();
+        let b: Fq2 = rng.gen();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
+    fft_field_test::<Fq2>();
+    let byte_size = Fq2::zero().serialized_size();
+    field_serialization_test::<Fq2>(byte_size);
+
+    // The 2-adic root of unity is primitive.
+    let mut root = Fq2::two_adic_root_of_unity();
+    for _ in 1..Fq2::two_adicity() {
+        root.square_in_place();
+    }
+    assert_eq!(root, -Fq2::one());
+}
+
+#[test]
+fn test_fq3() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq3 = rng.gen();
+        let b: Fq3 = rng.gen();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq3, _>(Fq::characteristic(), 13);
+    let byte_size = Fq3::zero().serialized_size();
+    field_serialization_test::<Fq3>(byte_size);
+}
+
+#[test]
+fn test_fq_modulus() {
+    // p = 2^31 - 1
+    assert_eq!(Fq::from(2u64).pow([31]), Fq::one());
+    assert_eq!(-Fq::one(), Fq::from(2147483646u64));
+    assert_eq!(Fq::two_adicity(), 1);
+}
+
+#[test]
+fn test_extension_nonresidues() {
+    // Fq2 = Fq[i]/(i^2 + 1), whose multiplicative group has order
+    // p^2 - 1 = 2^32 * (2^30 - 1), and Fq3 = Fq[u]/(u^3 - 5).
+    let i = Fq2::new(Fq::zero(), Fq::one());
+    assert_eq!(i.square(), -Fq2::one());
+    assert_eq!(Fq2::two_adicity(), 32);
+    let u = Fq3::new(Fq::zero(), Fq::one(), Fq::zero());
+    assert_eq!(
+        u.square() * u,
+        Fq3::new(Fq::from(5u64), Fq::zero(), Fq::zero())
+    );
+}
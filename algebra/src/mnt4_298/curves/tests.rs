@@ -12,6 +12,7 @@ fn test_g1_projective_curve() {
     curve_tests::<G1Projective>();
 
     sw_tests::<g1::Parameters>();
+    sw_xyzz_tests::<g1::Parameters>();
 }
 
 #[test]
@@ -77,6 +78,16 @@ fn test_bilinearity() {
     assert_eq!(ans3.pow(Fr::characteristic()), Fq4::one());
 }
 
+#[test]
+fn test_torus_compression() {
+    pairing_torus_compression_test::<MNT4_298>();
+}
+
+#[test]
+fn test_pairing_output() {
+    pairing_output_test::<MNT4_298>();
+}
+
 #[test]
 fn test_product_of_pairings() {
     let rng = &mut test_rng();
@@ -21,6 +21,17 @@ pub use self::{
 
 pub type MNT6_298 = MNT6<Parameters>;
 
+/// The cycle of pairing-friendly curves formed by MNT4-298 and MNT6-298.
+#[cfg(feature = "mnt4_298")]
+#[derive(Copy, Clone, Debug)]
+pub struct MNT298Cycle;
+
+#[cfg(feature = "mnt4_298")]
+impl algebra_core::curves::CycleEngine for MNT298Cycle {
+    type E1 = crate::mnt4_298::MNT4_298;
+    type E2 = MNT6_298;
+}
+
 pub struct Parameters;
 
 impl MNT6Parameters for Parameters {
@@ -77,6 +77,16 @@ fn test_bilinearity() {
     assert_eq!(ans3.pow(Fr::characteristic()), Fq6::one());
 }
 
+#[test]
+fn test_torus_compression() {
+    pairing_torus_compression_test::<MNT6_298>();
+}
+
+#[test]
+fn test_pairing_output() {
+    pairing_output_test::<MNT6_298>();
+}
+
 #[test]
 fn test_product_of_pairings() {
     let rng = &mut test_rng();
@@ -89,3 +99,10 @@ fn test_product_of_pairings() {
     let ans2 = MNT6_298::product_of_pairings(&[(a.into(), b.into()), (c.into(), d.into())]);
     assert_eq!(ans1, ans2);
 }
+
+#[cfg(feature = "mnt4_298")]
+#[test]
+fn test_mnt298_cycle() {
+    curve_cycle_test::<MNT298Cycle>();
+    cycle_engine_test::<MNT298Cycle>();
+}
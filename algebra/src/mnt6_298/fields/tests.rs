@@ -1,11 +1,14 @@
-use algebra_core::fields::models::fp6_2over3::*;
-use algebra_core::fields::quadratic_extension::QuadExtParameters;
-use algebra_core::{test_rng, Field};
+use algebra_core::{
+    fields::{models::fp6_2over3::*, quadratic_extension::QuadExtParameters},
+    test_rng, Field,
+};
 use rand::Rng;
 
 use crate::mnt6_298::*;
 
-use crate::tests::fields::{field_test, frobenius_test, primefield_test, sqrt_field_test};
+use crate::tests::fields::{
+    cyclotomic_compressed_test, field_test, frobenius_test, primefield_test, sqrt_field_test,
+};
 
 #[test]
 fn test_fr() {
@@ -48,4 +51,5 @@ fn test_fq6() {
     let b: Fq6 = rng.gen();
     field_test(a, b);
     frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
+    cyclotomic_compressed_test::<Fp6ParamsWrapper<Fq6Parameters>, _>(1);
 }
@@ -0,0 +1,168 @@
+use crate::pallas::{Fq, Fr};
+use algebra_core::{
+    biginteger::BigInteger256,
+    curves::{
+        glv::GLVParameters,
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    field_new, Zero,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub type Affine = GroupAffine<PallasParameters>;
+pub type Projective = GroupProjective<PallasParameters>;
+
+/// Pallas is the short Weierstrass curve y² = x³ + 5 over Fq, whose group of
+/// points has prime order r. Its base and scalar fields are respectively the
+/// scalar and base fields of Vesta.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct PallasParameters;
+
+impl ModelParameters for PallasParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for PallasParameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+
+    /// COEFF_B = 5
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, BigInteger256([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ]));
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, BigInteger256([
+        0x5b2b3e9cfffffffd,
+        0x992c350be3420567,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (GENERATOR_X, GENERATOR_Y);
+
+    const GLV: Option<GLVParameters<Self>> = Some(GLVParameters {
+        lambda: GLV_LAMBDA,
+        basis: GLV_BASIS,
+        rounding: GLV_ROUNDING,
+        endomorphism,
+    });
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// The endomorphism `(x, y) -> (BETA * x, y)`.
+fn endomorphism(p: &Projective) -> Projective {
+    let mut res = *p;
+    res.x *= &BETA;
+    res
+}
+
+/// BETA = 8503465768106391777493614032514048814691664078728891710322960303815233784505,
+/// a primitive cube root of unity in Fq.
+#[rustfmt::skip]
+pub const BETA: Fq = field_new!(Fq, BigInteger256([
+    0x2021cf6619a153d,
+    0x9e8c26974980b78e,
+    0x2a676d5cc87a4666,
+    0x15d8049da7a17876,
+]));
+
+/// GLV_LAMBDA = 2942865608506852014473558576493638302197734138389222805617480874486368177743,
+/// the eigenvalue of `endomorphism`.
+#[rustfmt::skip]
+const GLV_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0x7c541a8480111122,
+    0x40630b9c56ed29da,
+    0x2c275fb135b2b29,
+    0x121d29f888245b10,
+]));
+
+/// GLV_BASIS = [
+///     (98231058071186745657228807397848383489, -98231058071100081932162823354453065728),
+///     (98231058071100081932162823354453065728, 196462116142286827589391630752301449217),
+/// ]
+#[rustfmt::skip]
+const GLV_BASIS: [(Fr, Fr); 2] = [
+    (
+        field_new!(Fr, BigInteger256([
+            0x5bffb780fffffffd,
+            0xc0d38969c435c275,
+            0xb1a27161e7e62937,
+            0x186bf7a9a0f9da22,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x32c49e4c00000000,
+            0x143c3bf8e412911a,
+            0xad5a9749a37a3893,
+            0x279408565edfc16a,
+        ])),
+    ),
+    (
+        field_new!(Fr, BigInteger256([
+            0x59824cd500000001,
+            0xe0a5d03258217c3,
+            0x52a568b65c85c76d,
+            0x186bf7a9a1203e95,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xb5820455fffffffe,
+            0xcedde66ce9b7da38,
+            0x447da18446bf0a4,
+            0x30d7ef53421a18b8,
+        ])),
+    ),
+];
+
+/// GLV_ROUNDING = [round(2^256 * b2 / d), round(-2^256 * b1 / d)], where
+/// d = a1 * b2 - a2 * b1 = r
+#[rustfmt::skip]
+const GLV_ROUNDING: [BigInteger256; 2] = [
+    BigInteger256([
+        0x31f0256800000003,
+        0x4f34e8b2066389a4,
+        0x2,
+        0x0,
+    ]),
+    BigInteger256([
+        0x32c49e4bffffffff,
+        0x279a745902a2654e,
+        0x1,
+        0x0,
+    ]),
+];
+
+/// GENERATOR_X = -1
+#[rustfmt::skip]
+pub const GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
+    0x64b4c3b400000004,
+    0x891a63f02533e46e,
+    0x0,
+    0x0,
+]));
+
+/// GENERATOR_Y = 2
+#[rustfmt::skip]
+pub const GENERATOR_Y: Fq = field_new!(Fq, BigInteger256([
+    0xcfc3a984fffffff9,
+    0x1011d11bbee5303e,
+    0xffffffffffffffff,
+    0x3fffffffffffffff,
+]));
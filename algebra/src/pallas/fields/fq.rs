@@ -0,0 +1,8 @@
+use algebra_core::fields::{Fp256, FpParameters};
+
+pub type Fq = Fp256<FqParameters>;
+
+#[derive(FpParameters)]
+#[modulus = "28948022309329048855892746252171976963363056481941560715954676764349967630337"]
+#[generator = "5"]
+pub struct FqParameters;
@@ -0,0 +1,8 @@
+use algebra_core::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+#[derive(FpParameters)]
+#[modulus = "28948022309329048855892746252171976963363056481941647379679742748393362948097"]
+#[generator = "5"]
+pub struct FrParameters;
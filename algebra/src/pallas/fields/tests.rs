@@ -0,0 +1,100 @@
+use algebra_core::{
+    buffer_bit_byte_size, fields::FpParameters, test_rng, CanonicalSerialize, FftField, Field, One,
+    PrimeField, SquareRootField,
+};
+use rand::Rng;
+
+use crate::{
+    pallas::{Fq, FqParameters, Fr, FrParameters},
+    tests::fields::{
+        fft_field_test, field_serialization_test, field_test, primefield_test, sqrt_field_test,
+    },
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fr() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+        let byte_size = a.serialized_size();
+        field_serialization_test::<Fr>(byte_size);
+    }
+    fft_field_test::<Fr>();
+}
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        let byte_size = a.serialized_size();
+        let (_, buffer_size) = buffer_bit_byte_size(Fq::size_in_bits());
+        assert_eq!(byte_size, buffer_size);
+        field_serialization_test::<Fq>(byte_size);
+    }
+    fft_field_test::<Fq>();
+}
+
+#[test]
+fn test_fq_num_bits() {
+    assert_eq!(FqParameters::MODULUS_BITS, 255);
+    assert_eq!(FqParameters::CAPACITY, 254);
+}
+
+#[test]
+fn test_fr_num_bits() {
+    assert_eq!(FrParameters::MODULUS_BITS, 255);
+    assert_eq!(FrParameters::CAPACITY, 254);
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    // p = 2^32 * t + 1, with t = 0x40000000000000000000000000000000224698fc094cf91b992d30ed
+    assert_eq!(Fq::two_adicity(), 32);
+    assert_eq!(Fq::multiplicative_generator(), Fq::from(5u64));
+    assert_eq!(
+        Fq::multiplicative_generator().pow([
+            0x094cf91b992d30ed,
+            0x00000000224698fc,
+            0x0000000000000000,
+            0x0000000040000000,
+        ]),
+        Fq::two_adic_root_of_unity()
+    );
+    assert_eq!(
+        Fq::two_adic_root_of_unity().pow([1 << Fq::two_adicity()]),
+        Fq::one()
+    );
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fr_root_of_unity() {
+    // q = 2^32 * t + 1, with t = 0x40000000000000000000000000000000224698fc0994a8dd8c46eb21
+    assert_eq!(Fr::two_adicity(), 32);
+    assert_eq!(Fr::multiplicative_generator(), Fr::from(5u64));
+    assert_eq!(
+        Fr::multiplicative_generator().pow([
+            0x0994a8dd8c46eb21,
+            0x00000000224698fc,
+            0x0000000000000000,
+            0x0000000040000000,
+        ]),
+        Fr::two_adic_root_of_unity()
+    );
+    assert_eq!(
+        Fr::two_adic_root_of_unity().pow([1 << Fr::two_adicity()]),
+        Fr::one()
+    );
+    assert!(Fr::multiplicative_generator().sqrt().is_none());
+}
@@ -0,0 +1,21 @@
+//! This module implements the Pallas curve of the
+//! [Pasta cycle](https://electriccoin.co/blog/the-pasta-curves-for-halo-2-and-beyond/)
+//! of Hopwood. Pallas and Vesta are prime order short Weierstrass curves
+//! such that the scalar field of each is the base field of the other, which
+//! allows recursive proof composition without pairings or a trusted setup.
+//! Both fields are highly 2-adic, and both curves admit a GLV endomorphism.
+//!
+//! Curve information:
+//! * Base field: q = 28948022309329048855892746252171976963363056481941560715954676764349967630337
+//! * Scalar field: r = 28948022309329048855892746252171976963363056481941647379679742748393362948097
+//! * valuation(q - 1, 2) = 32
+//! * valuation(r - 1, 2) = 32
+//! * Curve equation: y^2 = x^3 + 5
+
+#[cfg(feature = "pallas")]
+mod curves;
+mod fields;
+
+#[cfg(feature = "pallas")]
+pub use curves::*;
+pub use fields::*;
@@ -0,0 +1,169 @@
+use crate::secp256k1::{Fq, Fr};
+use algebra_core::{
+    biginteger::BigInteger256,
+    curves::{
+        glv::GLVParameters,
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    field_new, Zero,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub type Affine = GroupAffine<Secp256k1Parameters>;
+pub type Projective = GroupProjective<Secp256k1Parameters>;
+
+/// secp256k1 is the short Weierstrass curve y² = x³ + 7 over Fq, whose group
+/// of points has prime order r.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secp256k1Parameters;
+
+impl ModelParameters for Secp256k1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Secp256k1Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+
+    /// COEFF_B = 7
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, BigInteger256([
+        0x700001ab7,
+        0x0,
+        0x0,
+        0x0,
+    ]));
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, BigInteger256([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x1,
+        0x0,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (GENERATOR_X, GENERATOR_Y);
+
+    const GLV: Option<GLVParameters<Self>> = Some(GLVParameters {
+        lambda: GLV_LAMBDA,
+        basis: GLV_BASIS,
+        rounding: GLV_ROUNDING,
+        endomorphism,
+    });
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// The endomorphism `(x, y) -> (BETA * x, y)`.
+fn endomorphism(p: &Projective) -> Projective {
+    let mut res = *p;
+    res.x *= &BETA;
+    res
+}
+
+/// BETA = 55594575648329892869085402983802832744385952214688224221778511981742606582254,
+/// a primitive cube root of unity in Fq.
+#[rustfmt::skip]
+pub const BETA: Fq = field_new!(Fq, BigInteger256([
+    0x58a4361c8e81894e,
+    0x3fde1631c4b80af,
+    0xf8e98978d02e3905,
+    0x7a4a36aebcbb3d53,
+]));
+
+/// GLV_LAMBDA = 37718080363155996902926221483475020450927657555482586988616620542887997980018,
+/// the eigenvalue of `endomorphism`.
+#[rustfmt::skip]
+const GLV_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0xf07deb3dc9926c9e,
+    0x2c93e7ad83c6944c,
+    0x73a9660652697d91,
+    0x532840178558d639,
+]));
+
+/// GLV_BASIS = [
+///     (64502973549206556628585045361533709077, -303414439467246543595250775667605759171),
+///     (367917413016453100223835821029139468248, 64502973549206556628585045361533709077),
+/// ]
+#[rustfmt::skip]
+const GLV_BASIS: [(Fr, Fr); 2] = [
+    (
+        field_new!(Fr, BigInteger256([
+            0xb326003c6621faab,
+            0x9c244f20bb8e4702,
+            0xe893209a45dbb030,
+            0x3daa8a1471e8ca7f,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xfacdf5bcc55d1b05,
+            0x9f925ae0b4a1b2f8,
+            0xea8e4b51753b808d,
+            0xddedf753620af939,
+        ])),
+    ),
+    (
+        field_new!(Fr, BigInteger256([
+            0x782a690c70fb20e7,
+            0xb740d126b6353445,
+            0xfe04d548d0a02fa1,
+            0x5fbc92c10fddd145,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0xb326003c6621faab,
+            0x9c244f20bb8e4702,
+            0xe893209a45dbb030,
+            0x3daa8a1471e8ca7f,
+        ])),
+    ),
+];
+
+/// GLV_ROUNDING = [round(2^256 * b2 / d), round(-2^256 * b1 / d)], where
+/// d = a1 * b2 - a2 * b1 = r
+#[rustfmt::skip]
+const GLV_ROUNDING: [BigInteger256; 2] = [
+    BigInteger256([
+        0xe86c90e49284eb15,
+        0x3086d221a7d46bcd,
+        0x0,
+        0x0,
+    ]),
+    BigInteger256([
+        0x6f547fa90abfe4c4,
+        0xe4437ed6010e8828,
+        0x0,
+        0x0,
+    ]),
+];
+
+/// GENERATOR_X =
+/// 55066263022277343669578718895168534326250603453777594175500187360389116729240
+#[rustfmt::skip]
+pub const GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
+    0xd7362e5a487e2097,
+    0x231e295329bc66db,
+    0x979f48c033fd129c,
+    0x9981e643e9089f48,
+]));
+
+/// GENERATOR_Y =
+/// 32670510020758816978083085130507043184471273380659243275938904335757337482424
+#[rustfmt::skip]
+pub const GENERATOR_Y: Fq = field_new!(Fq, BigInteger256([
+    0xb15ea6d2d3dbabe2,
+    0x8dfc5d5d1f1dc64d,
+    0x70b6b59aac19c136,
+    0xcf3f851fd4a582d6,
+]));
@@ -0,0 +1,88 @@
+use algebra_core::{
+    curves::{sec1::Sec1Affine, AffineCurve, ProjectiveCurve},
+    test_rng, CanonicalDeserialize, CanonicalSerialize,
+};
+use core::str::FromStr;
+use rand::Rng;
+
+use crate::{
+    secp256k1::*,
+    tests::{
+        curves::{curve_tests, sec1_serialization_test, sw_tests, sw_xyzz_tests},
+        groups::group_test,
+    },
+};
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<Projective>();
+
+    sw_tests::<Secp256k1Parameters>();
+    sw_xyzz_tests::<Secp256k1Parameters>();
+    sec1_serialization_test::<Secp256k1Parameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = test_rng();
+    let a: Projective = rng.gen();
+    let b: Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_generator() {
+    let generator = Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+
+    let mut serialized = vec![];
+    Sec1Affine(generator).serialize(&mut serialized).unwrap();
+    assert_eq!(
+        hex::encode(serialized),
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+    );
+}
+
+/// Multiples of the generator by scalars `k`, with their compressed and
+/// uncompressed SEC 1 encodings.
+const SEC1_VECTORS: [[&str; 3]; 4] = [
+    [
+        "2",
+        "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+        "04c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee51ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+    ],
+    [
+        "3",
+        "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+        "04f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
+    ],
+    [
+        "112233445566778899",
+        "02a90cc3d3f3e146daadfc74ca1372207cb4b725ae708cef713a98edd73d99ef29",
+        "04a90cc3d3f3e146daadfc74ca1372207cb4b725ae708cef713a98edd73d99ef295a79d6b289610c68bc3b47f3d72f9788a26a06868b4d8e433e1e2ad76fb7dc76",
+    ],
+    [
+        "115792089237316195423570985008687907852837564279074904382605163141518161494336",
+        "0379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777",
+    ],
+];
+
+#[test]
+fn test_sec1_vectors() {
+    let generator = Affine::prime_subgroup_generator();
+    for [k, compressed, uncompressed] in SEC1_VECTORS.iter() {
+        let p = Sec1Affine(generator.mul(Fr::from_str(k).unwrap()).into_affine());
+
+        let mut serialized = vec![];
+        p.serialize(&mut serialized).unwrap();
+        assert_eq!(hex::encode(&serialized), *compressed);
+        assert_eq!(Sec1Affine::deserialize(&serialized[..]).unwrap(), p);
+
+        let mut serialized = vec![];
+        p.serialize_uncompressed(&mut serialized).unwrap();
+        assert_eq!(hex::encode(&serialized), *uncompressed);
+        assert_eq!(Sec1Affine::deserialize(&serialized[..]).unwrap(), p);
+    }
+}
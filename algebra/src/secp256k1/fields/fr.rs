@@ -0,0 +1,8 @@
+use algebra_core::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+#[derive(FpParameters)]
+#[modulus = "115792089237316195423570985008687907852837564279074904382605163141518161494337"]
+#[generator = "7"]
+pub struct FrParameters;
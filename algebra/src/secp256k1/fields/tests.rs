@@ -0,0 +1,113 @@
+use algebra_core::{
+    buffer_bit_byte_size, fields::FpParameters, test_rng, CanonicalSerialize, FftField, Field, One,
+    PrimeField, SquareRootField,
+};
+use core::str::FromStr;
+use rand::Rng;
+
+use crate::{
+    secp256k1::{Fq, FqParameters, Fr, FrParameters, BETA},
+    tests::fields::{
+        fft_field_test, field_serialization_test, field_test, primefield_test, sqrt_field_test,
+    },
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fr() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+        let byte_size = a.serialized_size();
+        field_serialization_test::<Fr>(byte_size);
+    }
+    fft_field_test::<Fr>();
+}
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        let byte_size = a.serialized_size();
+        let (_, buffer_size) = buffer_bit_byte_size(Fq::size_in_bits());
+        assert_eq!(byte_size, buffer_size);
+        field_serialization_test::<Fq>(byte_size);
+    }
+    fft_field_test::<Fq>();
+}
+
+#[test]
+fn test_fq_num_bits() {
+    assert_eq!(FqParameters::MODULUS_BITS, 256);
+    assert_eq!(FqParameters::CAPACITY, 255);
+}
+
+#[test]
+fn test_fr_num_bits() {
+    assert_eq!(FrParameters::MODULUS_BITS, 256);
+    assert_eq!(FrParameters::CAPACITY, 255);
+}
+
+#[test]
+fn test_fq_modulus() {
+    // p = 2^256 - 2^32 - 977
+    assert_eq!(Fq::from(2u64).pow([256]), Fq::from((1u64 << 32) + 977));
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    assert_eq!(Fq::two_adicity(), 1);
+    assert_eq!(Fq::two_adic_root_of_unity(), -Fq::one());
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+
+    assert_eq!(Fr::two_adicity(), 6);
+    assert_eq!(
+        Fr::two_adic_root_of_unity().pow([1 << Fr::two_adicity()]),
+        Fr::one()
+    );
+    assert_ne!(
+        Fr::two_adic_root_of_unity().pow([1 << (Fr::two_adicity() - 1)]),
+        Fr::one()
+    );
+    assert!(Fr::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq_legendre() {
+    use crate::fields::LegendreSymbol::*;
+
+    // p = 3 mod 4, so -1 is not a square.
+    assert_eq!(QuadraticNonResidue, (-Fq::one()).legendre());
+    assert_eq!(QuadraticResidue, Fq::from(4u64).legendre());
+    // b = 7 is not a square, so the curve has no point with x = 0.
+    assert_eq!(QuadraticNonResidue, Fq::from(7u64).legendre());
+}
+
+#[test]
+fn test_cube_roots_of_unity() {
+    // The cube roots of unity of the endomorphism, as given in libsecp256k1.
+    let beta = Fq::from_str(
+        "55594575648329892869085402983802832744385952214688224221778511981742606582254",
+    )
+    .unwrap();
+    assert_eq!(beta, BETA);
+    assert_ne!(beta, Fq::one());
+    assert_eq!(beta.pow([3]), Fq::one());
+
+    let lambda = Fr::from_str(
+        "37718080363155996902926221483475020450927657555482586988616620542887997980018",
+    )
+    .unwrap();
+    assert_ne!(lambda, Fr::one());
+    assert_eq!(lambda.pow([3]), Fr::one());
+}
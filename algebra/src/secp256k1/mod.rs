@@ -0,0 +1,18 @@
+//! This module implements the secp256k1 curve of
+//! [SEC 2](https://www.secg.org/sec2-v2.pdf), which is used for signatures in
+//! Bitcoin and Ethereum. It is a prime order short Weierstrass curve that
+//! admits a GLV endomorphism. Points can be serialized with the SEC 1 encoding
+//! of other implementations through `algebra_core::curves::sec1::Sec1Affine`.
+//!
+//! Curve information:
+//! * Base field: q = 115792089237316195423570985008687907853269984665640564039457584007908834671663
+//! * Scalar field: r = 115792089237316195423570985008687907852837564279074904382605163141518161494337
+//! * valuation(q - 1, 2) = 1
+//! * valuation(r - 1, 2) = 6
+//! * Curve equation: y^2 = x^3 + 7
+
+mod curves;
+mod fields;
+
+pub use curves::*;
+pub use fields::*;
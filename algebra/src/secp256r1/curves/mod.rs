@@ -0,0 +1,81 @@
+use crate::secp256r1::{Fq, Fr};
+use algebra_core::{
+    biginteger::BigInteger256,
+    curves::{
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    field_new,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub type Affine = GroupAffine<Secp256r1Parameters>;
+pub type Projective = GroupProjective<Secp256r1Parameters>;
+
+/// secp256r1 is the short Weierstrass curve y² = x³ - 3x + b over Fq, whose
+/// group of points has prime order r.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secp256r1Parameters;
+
+impl ModelParameters for Secp256r1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Secp256r1Parameters {
+    /// COEFF_A = -3
+    #[rustfmt::skip]
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([
+        0xfffffffffffffffc,
+        0x3ffffffff,
+        0x0,
+        0xfffffffc00000004,
+    ]));
+
+    /// COEFF_B = 41058363725152142129326129780047268409114441015993725554835256314039467401291
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, BigInteger256([
+        0xd89cdf6229c4bddf,
+        0xacf005cd78843090,
+        0xe5a220abf7212ed6,
+        0xdc30061d04874834,
+    ]));
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, BigInteger256([
+        0xc46353d039cdaaf,
+        0x4319055258e8617b,
+        0x0,
+        0xffffffff,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (GENERATOR_X, GENERATOR_Y);
+}
+
+/// GENERATOR_X =
+/// 48439561293906451759052585252797914202762949526041747995844080717082404635286
+#[rustfmt::skip]
+pub const GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
+    0x79e730d418a9143c,
+    0x75ba95fc5fedb601,
+    0x79fb732b77622510,
+    0x18905f76a53755c6,
+]));
+
+/// GENERATOR_Y =
+/// 36134250956749795798585127919587881956611106672985015071877198253568414405109
+#[rustfmt::skip]
+pub const GENERATOR_Y: Fq = field_new!(Fq, BigInteger256([
+    0xddf25357ce95560a,
+    0x8b4ab8e4ba19e45c,
+    0xd2e88688dd21f325,
+    0x8571ff1825885d85,
+]));
@@ -0,0 +1,88 @@
+use algebra_core::{
+    curves::{sec1::Sec1Affine, AffineCurve, ProjectiveCurve},
+    test_rng, CanonicalDeserialize, CanonicalSerialize,
+};
+use core::str::FromStr;
+use rand::Rng;
+
+use crate::{
+    secp256r1::*,
+    tests::{
+        curves::{curve_tests, sec1_serialization_test, sw_tests, sw_xyzz_tests},
+        groups::group_test,
+    },
+};
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<Projective>();
+
+    sw_tests::<Secp256r1Parameters>();
+    sw_xyzz_tests::<Secp256r1Parameters>();
+    sec1_serialization_test::<Secp256r1Parameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = test_rng();
+    let a: Projective = rng.gen();
+    let b: Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_generator() {
+    let generator = Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+
+    let mut serialized = vec![];
+    Sec1Affine(generator).serialize(&mut serialized).unwrap();
+    assert_eq!(
+        hex::encode(serialized),
+        "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+    );
+}
+
+/// Multiples of the generator by scalars `k`, with their compressed and
+/// uncompressed SEC 1 encodings.
+const SEC1_VECTORS: [[&str; 3]; 4] = [
+    [
+        "2",
+        "037cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+        "047cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc4766997807775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+    ],
+    [
+        "3",
+        "025ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c",
+        "045ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032",
+    ],
+    [
+        "112233445566778899",
+        "03339150844ec15234807fe862a86be77977dbfb3ae3d96f4c22795513aeaab82f",
+        "04339150844ec15234807fe862a86be77977dbfb3ae3d96f4c22795513aeaab82fb1c14ddfdc8ec1b2583f51e85a5eb3a155840f2034730e9b5ada38b674336a21",
+    ],
+    [
+        "115792089210356248762697446949407573529996955224135760342422259061068512044368",
+        "026b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296b01cbd1c01e58065711814b583f061e9d431cca994cea1313449bf97c840ae0a",
+    ],
+];
+
+#[test]
+fn test_sec1_vectors() {
+    let generator = Affine::prime_subgroup_generator();
+    for [k, compressed, uncompressed] in SEC1_VECTORS.iter() {
+        let p = Sec1Affine(generator.mul(Fr::from_str(k).unwrap()).into_affine());
+
+        let mut serialized = vec![];
+        p.serialize(&mut serialized).unwrap();
+        assert_eq!(hex::encode(&serialized), *compressed);
+        assert_eq!(Sec1Affine::deserialize(&serialized[..]).unwrap(), p);
+
+        let mut serialized = vec![];
+        p.serialize_uncompressed(&mut serialized).unwrap();
+        assert_eq!(hex::encode(&serialized), *uncompressed);
+        assert_eq!(Sec1Affine::deserialize(&serialized[..]).unwrap(), p);
+    }
+}
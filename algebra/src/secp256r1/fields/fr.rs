@@ -0,0 +1,8 @@
+use algebra_core::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+#[derive(FpParameters)]
+#[modulus = "115792089210356248762697446949407573529996955224135760342422259061068512044369"]
+#[generator = "7"]
+pub struct FrParameters;
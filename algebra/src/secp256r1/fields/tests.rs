@@ -0,0 +1,97 @@
+use algebra_core::{
+    buffer_bit_byte_size, fields::FpParameters, test_rng, CanonicalSerialize, FftField, Field, One,
+    PrimeField, SquareRootField,
+};
+use rand::Rng;
+
+use crate::{
+    secp256r1::{Fq, FqParameters, Fr, FrParameters},
+    tests::fields::{
+        fft_field_test, field_serialization_test, field_test, primefield_test, sqrt_field_test,
+    },
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fr() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+        let byte_size = a.serialized_size();
+        field_serialization_test::<Fr>(byte_size);
+    }
+    fft_field_test::<Fr>();
+}
+
+#[test]
+fn test_fq() {
+    let mut rng = test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        let byte_size = a.serialized_size();
+        let (_, buffer_size) = buffer_bit_byte_size(Fq::size_in_bits());
+        assert_eq!(byte_size, buffer_size);
+        field_serialization_test::<Fq>(byte_size);
+    }
+    fft_field_test::<Fq>();
+}
+
+#[test]
+fn test_fq_num_bits() {
+    assert_eq!(FqParameters::MODULUS_BITS, 256);
+    assert_eq!(FqParameters::CAPACITY, 255);
+}
+
+#[test]
+fn test_fr_num_bits() {
+    assert_eq!(FrParameters::MODULUS_BITS, 256);
+    assert_eq!(FrParameters::CAPACITY, 255);
+}
+
+#[test]
+fn test_fq_modulus() {
+    // p = 2^256 - 2^224 + 2^192 + 2^96 - 1
+    let two = Fq::from(2u64);
+    assert_eq!(
+        two.pow([256]),
+        two.pow([224]) - two.pow([192]) - two.pow([96]) + Fq::one()
+    );
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    assert_eq!(Fq::two_adicity(), 1);
+    assert_eq!(Fq::two_adic_root_of_unity(), -Fq::one());
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+
+    assert_eq!(Fr::two_adicity(), 4);
+    assert_eq!(
+        Fr::two_adic_root_of_unity().pow([1 << Fr::two_adicity()]),
+        Fr::one()
+    );
+    assert_ne!(
+        Fr::two_adic_root_of_unity().pow([1 << (Fr::two_adicity() - 1)]),
+        Fr::one()
+    );
+    assert!(Fr::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq_legendre() {
+    use crate::fields::LegendreSymbol::*;
+
+    // p = 3 mod 4, so -1 is not a square, but p = 1 mod 3, so -3 is.
+    assert_eq!(QuadraticNonResidue, (-Fq::one()).legendre());
+    assert_eq!(QuadraticResidue, (-Fq::from(3u64)).legendre());
+    assert_eq!(QuadraticResidue, Fq::from(2u64).legendre());
+    assert_eq!(QuadraticResidue, Fq::from(4u64).legendre());
+}
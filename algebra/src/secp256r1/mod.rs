@@ -0,0 +1,19 @@
+//! This module implements the secp256r1 curve of
+//! [SEC 2](https://www.secg.org/sec2-v2.pdf), also known as NIST P-256, which
+//! is used for signatures in WebAuthn and TLS. It is a prime order short
+//! Weierstrass curve. Points can be serialized with the SEC 1 encoding of other
+//! implementations through `algebra_core::curves::sec1::Sec1Affine`.
+//!
+//! Curve information:
+//! * Base field: q = 115792089210356248762697446949407573530086143415290314195533631308867097853951
+//! * Scalar field: r = 115792089210356248762697446949407573529996955224135760342422259061068512044369
+//! * valuation(q - 1, 2) = 1
+//! * valuation(r - 1, 2) = 4
+//! * Curve equation: y^2 = x^3 - 3x + b, where
+//!    * b = 41058363725152142129326129780047268409114441015993725554835256314039467401291
+
+mod curves;
+mod fields;
+
+pub use curves::*;
+pub use fields::*;
@@ -1,11 +1,16 @@
 #![allow(unused)]
 use algebra_core::{
-    curves::{AffineCurve, ProjectiveCurve},
+    curves::{
+        AffineCurve, ConstantTimeCurve, CurveCycle, CycleEngine, PairingEngine, PairingOutput,
+        PairingOutputEngine, ProjectiveCurve,
+    },
+    fields::{CyclotomicMultSubgroup, TorusCompression},
     io::Cursor,
-    CanonicalDeserialize, CanonicalSerialize, Field, MontgomeryModelParameters, One, PrimeField,
+    BigInteger, BitIteratorBE, CanonicalDeserialize, CanonicalSerialize, Choice,
+    ConditionallySelectable, ConstantTimeEq, Field, MontgomeryModelParameters, One, PrimeField,
     SWFlags, SWModelParameters, SerializationError, TEModelParameters, UniformRand, Vec, Zero,
 };
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
 pub const ITERATIONS: usize = 10;
@@ -50,17 +55,17 @@ fn random_addition_test<G: ProjectiveCurve>() {
         // Mixed addition
 
         // (a + b) + c
-        tmp[3] = a_affine.into_projective();
+        tmp[3] = G::from(a_affine);
         tmp[3].add_assign_mixed(&b_affine);
         tmp[3].add_assign_mixed(&c_affine);
 
         // a + (b + c)
-        tmp[4] = b_affine.into_projective();
+        tmp[4] = G::from(b_affine);
         tmp[4].add_assign_mixed(&c_affine);
         tmp[4].add_assign_mixed(&a_affine);
 
         // (a + c) + b
-        tmp[5] = a_affine.into_projective();
+        tmp[5] = G::from(a_affine);
         tmp[5].add_assign_mixed(&c_affine);
         tmp[5].add_assign_mixed(&b_affine);
 
@@ -114,10 +119,10 @@ fn random_multiplication_test<G: ProjectiveCurve>() {
 
         // Affine multiplication
         let mut tmp3 = a_affine.mul(s.into_repr());
-        tmp3.add_assign(&b_affine.mul(s.into_repr()));
+        tmp3 += &b_affine.mul(s.into_repr());
 
         assert_eq!(tmp1, tmp2);
-        assert_eq!(tmp1, tmp3);
+        assert_eq!(tmp1.into_affine(), tmp3.into());
     }
 }
 
@@ -183,7 +188,7 @@ fn random_transformation_test<G: ProjectiveCurve>() {
     for _ in 0..ITERATIONS {
         let g = G::rand(&mut rng);
         let g_affine = g.into_affine();
-        let g_projective = g_affine.into_projective();
+        let g_projective = G::from(g_affine);
         assert_eq!(g, g_projective);
     }
 
@@ -205,12 +210,12 @@ fn random_transformation_test<G: ProjectiveCurve>() {
         }
         for _ in 0..5 {
             let s = between.sample(&mut rng);
-            v[s] = v[s].into_affine().into_projective();
+            v[s] = G::from(v[s].into_affine());
         }
 
         let expected_v = v
             .iter()
-            .map(|v| v.into_affine().into_projective())
+            .map(|v| G::from(v.into_affine()))
             .collect::<Vec<_>>();
         G::batch_normalization(&mut v);
 
@@ -222,7 +227,73 @@ fn random_transformation_test<G: ProjectiveCurve>() {
     }
 }
 
-pub fn curve_tests<G: ProjectiveCurve>() {
+fn random_batch_addition_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = G::rand(&mut rng).into_affine();
+        let b = G::rand(&mut rng).into_affine();
+
+        // Sums of distinct points, equal points, a point and its negation,
+        // and sums involving zero.
+        let mut points = vec![a, b, a, a, a, -a, a, G::Affine::zero()];
+        points.extend(&[G::Affine::zero(), b, G::Affine::zero(), G::Affine::zero()]);
+        let pairs = [(0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11)];
+        let expected = pairs
+            .iter()
+            .map(|&(i, j)| {
+                (points[i].into_projective() + &points[j].into_projective()).into_affine()
+            })
+            .collect::<Vec<_>>();
+
+        G::Affine::batch_add_in_place(&mut points, &pairs);
+        for (&(i, _), expected) in pairs.iter().zip(expected) {
+            assert_eq!(points[i], expected);
+        }
+    }
+}
+
+fn random_constant_time_test<G: ConstantTimeCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let zero = G::zero();
+    assert!(zero.double_ct().is_zero());
+    assert!(zero.add_ct(&zero).is_zero());
+    assert!(bool::from(zero.ct_eq(&zero.double_ct())));
+    assert!(zero.mul_ct(&G::ScalarField::rand(&mut rng)).is_zero());
+    assert_eq!(zero.into_affine_ct(), zero.into_affine());
+
+    for _ in 0..ITERATIONS {
+        let a = G::rand(&mut rng);
+        let b = G::rand(&mut rng);
+        let s = G::ScalarField::rand(&mut rng);
+
+        assert_eq!(a.add_ct(&b), a + &b);
+        assert_eq!(a.add_ct(&a), a.double());
+        assert_eq!(a.double_ct(), a.double());
+        assert!(a.add_ct(&-a).is_zero());
+        assert_eq!(a.add_ct(&zero), a);
+        assert_eq!(zero.add_ct(&a), a);
+
+        assert_eq!(a.mul_ct(&s), a.mul(s.into_repr()));
+        assert!(a.mul_ct(&G::ScalarField::zero()).is_zero());
+        assert_eq!(a.mul_ct(&G::ScalarField::one()), a);
+        assert_eq!(a.mul_ct(&-G::ScalarField::one()), -a);
+
+        let c = a.mul_ct(&s);
+        assert_eq!(c.into_affine_ct(), c.into_affine());
+        assert_eq!(a.double().into_affine_ct(), a.double().into_affine());
+
+        assert!(bool::from(a.ct_eq(&G::from(a.into_affine()))));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert!(!bool::from(a.ct_eq(&zero)));
+        assert!(!bool::from(zero.ct_eq(&a)));
+        assert_eq!(G::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(G::conditional_select(&a, &b, Choice::from(1)), b);
+    }
+}
+
+pub fn curve_tests<G: ConstantTimeCurve>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
     // Negation edge case with zero.
@@ -265,12 +336,8 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     // Transformations
     {
         let a = G::rand(&mut rng);
-        let b = a.into_affine().into_projective();
-        let c = a
-            .into_affine()
-            .into_projective()
-            .into_affine()
-            .into_projective();
+        let b = G::from(a.into_affine());
+        let c = G::from(G::from(a.into_affine()).into_affine());
         assert_eq!(a, b);
         assert_eq!(b, c);
     }
@@ -288,17 +355,324 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     random_doubling_test::<G>();
     random_negation_test::<G>();
     random_transformation_test::<G>();
+    random_batch_addition_test::<G>();
+    random_constant_time_test::<G>();
 }
 
 pub fn sw_tests<P: SWModelParameters>() {
     sw_curve_serialization_test::<P>();
     sw_from_random_bytes::<P>();
+    sw_glv_test::<P>();
+    sw_gls_test::<P>();
+    sw_subgroup_check_test::<P>();
+}
+
+pub fn sw_xyzz_tests<P: SWModelParameters>() {
+    use algebra_core::{
+        curves::models::{
+            short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+            short_weierstrass_xyzz,
+        },
+        msm::VariableBaseMSM,
+    };
+
+    type Xyzz<P> = short_weierstrass_xyzz::GroupProjective<P>;
+
+    random_addition_test::<Xyzz<P>>();
+    random_multiplication_test::<Xyzz<P>>();
+    random_doubling_test::<Xyzz<P>>();
+    random_negation_test::<Xyzz<P>>();
+    random_transformation_test::<Xyzz<P>>();
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng);
+        let b = GroupProjective::<P>::rand(&mut rng);
+        let (a_affine, b_affine) = (a.into_affine(), b.into_affine());
+        let a_xyzz = Xyzz::<P>::from(a);
+
+        // Conversions from and to Jacobian coordinates.
+        assert_eq!(a_xyzz.into_affine(), a_affine);
+        assert_eq!(Xyzz::<P>::from(a_affine), a_xyzz);
+        assert_eq!(GroupProjective::from(a_xyzz.double()), a.double());
+        assert!(Xyzz::<P>::from(GroupProjective::zero()).is_zero());
+        assert!(Xyzz::<P>::from(GroupAffine::zero()).is_zero());
+        assert!(GroupProjective::<P>::from(Xyzz::zero()).is_zero());
+        assert!(Xyzz::<P>::zero().into_affine().is_zero());
+
+        // Addition, in particular with non-normalized points.
+        let mut c = a_xyzz.double();
+        c += &Xyzz::from(b).double();
+        assert_eq!(c, Xyzz::from(a.double() + &b.double()));
+        let mut c = a_xyzz.double();
+        c += &Xyzz::from(a.double());
+        assert_eq!(c, Xyzz::from(a.double().double()));
+        assert!((a_xyzz.double() - &Xyzz::from(a.double())).is_zero());
+        assert_eq!(a_xyzz + &Xyzz::zero(), a_xyzz);
+        assert_eq!(Xyzz::zero() + &a_xyzz, a_xyzz);
+
+        // Mixed addition, including the doubling and negation cases.
+        let mut c = a_xyzz.double();
+        c.add_assign_mixed(&b_affine);
+        assert_eq!(c, Xyzz::from(a.double() + &b));
+        let mut c = a_xyzz.double();
+        c.add_assign_mixed(&a.double().into_affine());
+        assert_eq!(c, Xyzz::from(a.double().double()));
+        let mut c = a_xyzz.double();
+        c.add_assign_mixed(&-a.double().into_affine());
+        assert!(c.is_zero());
+
+        // Bucket additions, including the doubling and negation cases for
+        // buckets with `ZZ = 1` and `ZZ != 1`.
+        let add_all = |bases: &[GroupAffine<P>]| {
+            let mut bucket = Xyzz::<P>::zero();
+            bases.iter().for_each(|base| bucket.add_assign_mixed(base));
+            bucket
+        };
+        let zero = GroupAffine::<P>::zero();
+        let buckets = [
+            add_all(&[a_affine, b_affine, a_affine, -b_affine, -b_affine, zero]),
+            add_all(&[a_affine, a_affine]),
+            add_all(&[a_affine, b_affine, -b_affine, a_affine]),
+            add_all(&[a_affine, -a_affine]),
+            add_all(&[a_affine, b_affine, -a_affine, -b_affine]),
+            add_all(&[]),
+        ];
+        assert_eq!(
+            Xyzz::batch_normalization_into_affine(&buckets),
+            vec![
+                (a.double() - &b).into_affine(),
+                a.double().into_affine(),
+                a.double().into_affine(),
+                zero,
+                zero,
+                zero,
+            ]
+        );
+    }
+
+    // Repeated and negated bases with equal scalars go into the same buckets.
+    let mut bases = (0..50)
+        .map(|_| GroupProjective::<P>::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    let mut scalars = (0..50)
+        .map(|_| P::ScalarField::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    let (repeated_bases, repeated_scalars) = (bases[..20].to_vec(), scalars[..20].to_vec());
+    bases.extend(repeated_bases[..10].iter().copied());
+    bases.extend(repeated_bases[10..].iter().map(|p| -*p));
+    scalars.extend(repeated_scalars);
+
+    // Small MSMs sum their buckets in XYZZ coordinates.
+    let expected = bases
+        .iter()
+        .zip(&scalars)
+        .map(|(base, scalar)| base.mul(*scalar))
+        .sum::<GroupProjective<P>>()
+        .into_affine();
+    assert_eq!(
+        VariableBaseMSM::multi_scalar_mul(&bases, &scalars).into_affine(),
+        expected
+    );
+}
+
+pub fn sw_glv_test<P: SWModelParameters>() {
+    use algebra_core::{
+        curves::models::short_weierstrass_jacobian::GroupProjective, BigInteger, BitIteratorBE,
+        FpParameters,
+    };
+
+    let glv = match P::GLV {
+        Some(glv) => glv,
+        None => return,
+    };
+    let signed = |(neg, k): (bool, _)| {
+        let k = P::ScalarField::from_repr(k).unwrap();
+        if neg {
+            -k
+        } else {
+            k
+        }
+    };
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng);
+        let a_affine = a.into_affine();
+        let mul_bits = |k: P::ScalarField| a_affine.mul_bits(BitIteratorBE::new(k.into_repr()));
+
+        // The endomorphism acts as multiplication by lambda.
+        assert_eq!((glv.endomorphism)(&a), mul_bits(glv.lambda));
+
+        let scalars = [
+            P::ScalarField::zero(),
+            P::ScalarField::one(),
+            -P::ScalarField::one(),
+            glv.lambda,
+            -glv.lambda,
+            P::ScalarField::rand(&mut rng),
+        ];
+        for k in scalars.iter() {
+            let [k1, k2] = glv.decompose(k);
+            let max_bits = <P::ScalarField as PrimeField>::size_in_bits() as u32 / 2 + 1;
+            assert!(k1.1.num_bits() <= max_bits);
+            assert!(k2.1.num_bits() <= max_bits);
+            assert_eq!(signed(k1) + &(signed(k2) * &glv.lambda), *k);
+
+            let expected = mul_bits(*k);
+            assert_eq!(a.mul_glv(k), expected);
+            assert_eq!(a_affine.mul_glv(k), expected);
+        }
+    }
+}
+
+pub fn sw_gls_test<P: SWModelParameters>() {
+    use algebra_core::{
+        curves::models::short_weierstrass_jacobian::GroupProjective, BigInteger, BitIteratorBE,
+    };
+
+    let gls = match P::GLS {
+        Some(gls) => gls,
+        None => return,
+    };
+    let signed = |(neg, k): (bool, _)| {
+        let k = P::ScalarField::from_repr(k).unwrap();
+        if neg {
+            -k
+        } else {
+            k
+        }
+    };
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // The basis vectors are in the lattice.
+    for b in gls.basis.iter() {
+        let mut sum = P::ScalarField::zero();
+        for b_i in b.iter().rev() {
+            sum = sum * &gls.lambda + b_i;
+        }
+        assert!(sum.is_zero());
+    }
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng);
+        let a_affine = a.into_affine();
+        let mul_bits = |k: P::ScalarField| a_affine.mul_bits(BitIteratorBE::new(k.into_repr()));
+
+        // The endomorphism acts as multiplication by lambda.
+        assert_eq!((gls.endomorphism)(&a), mul_bits(gls.lambda));
+
+        let scalars = [
+            P::ScalarField::zero(),
+            P::ScalarField::one(),
+            -P::ScalarField::one(),
+            gls.lambda,
+            -gls.lambda,
+            P::ScalarField::rand(&mut rng),
+        ];
+        for k in scalars.iter() {
+            let parts = gls.decompose(k);
+            let max_bits = <P::ScalarField as PrimeField>::size_in_bits() as u32 / 4 + 3;
+            let mut sum = P::ScalarField::zero();
+            for part in parts.iter().rev() {
+                assert!(part.1.num_bits() <= max_bits);
+                sum = sum * &gls.lambda + &signed(*part);
+            }
+            assert_eq!(sum, *k);
+
+            let expected = mul_bits(*k);
+            assert_eq!(a.mul_glv(k), expected);
+            assert_eq!(a_affine.mul_glv(k), expected);
+        }
+    }
+}
+
+/// Compares `is_in_correct_subgroup_assuming_on_curve` with multiplication by
+/// the order of the subgroup, on points of the subgroup, random points of the
+/// curve, and sums of those with points of small order.
+pub fn sw_subgroup_check_test<P: SWModelParameters>() {
+    use algebra_core::curves::models::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+
+    let r = || BitIteratorBE::new(P::ScalarField::characteristic());
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut points = vec![GroupAffine::<P>::zero()];
+    for _ in 0..ITERATIONS {
+        let g = GroupProjective::<P>::rand(&mut rng);
+        let p = loop {
+            let x = P::BaseField::rand(&mut rng);
+            if let Some(p) = GroupAffine::<P>::get_point_from_x(x, rng.gen()) {
+                break p;
+            }
+        };
+        // A point killed by the cofactor, and points of prime order dividing it.
+        let mut torsion = vec![p.mul_bits(r())];
+        for l in &[2, 3, 5, 7] {
+            if let Some(h) = div_exact(P::COFACTOR, *l) {
+                torsion.push(torsion[0].mul_bits(BitIteratorBE::new(h)));
+            }
+        }
+
+        points.push(g.into_affine());
+        points.push(p);
+        for t in torsion {
+            points.push(t.into_affine());
+            points.push((g + &t).into_affine());
+        }
+    }
+
+    for p in points {
+        let expected = p.mul_bits(r()).is_zero();
+        assert_eq!(p.is_in_correct_subgroup_assuming_on_curve(), expected);
+    }
+}
+
+/// Returns `n / d` if `d` divides `n`, where `n` is given by its limbs.
+fn div_exact(n: &[u64], d: u64) -> Option<Vec<u64>> {
+    let mut q = vec![0u64; n.len()];
+    let mut rem = 0u128;
+    for i in (0..n.len()).rev() {
+        let cur = (rem << 64) | u128::from(n[i]);
+        q[i] = (cur / u128::from(d)) as u64;
+        rem = cur % u128::from(d);
+    }
+    if rem == 0 {
+        Some(q)
+    } else {
+        None
+    }
+}
+
+/// Checks that `clear_cofactor` maps random points of the curve into the prime
+/// order subgroup, and agrees there with multiplication by the cofactor
+/// followed by multiplication by `c`.
+pub fn sw_clear_cofactor_test<P: SWModelParameters>(c: P::ScalarField) {
+    use algebra_core::curves::models::short_weierstrass_jacobian::GroupAffine;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let p = loop {
+            let x = P::BaseField::rand(&mut rng);
+            if let Some(p) = GroupAffine::<P>::get_point_from_x(x, rng.gen()) {
+                break p;
+            }
+        };
+        let cleared = p.clear_cofactor();
+        assert!(cleared.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(cleared, p.mul_by_cofactor().mul(c).into_affine());
+    }
 }
 
 pub fn sw_from_random_bytes<P: SWModelParameters>() {
     use algebra_core::curves::models::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
 
     let buf_size = GroupAffine::<P>::zero().serialized_size();
+    // Whether the flags are serialized in an additional byte.
+    let has_flags_byte = buf_size > P::BaseField::zero().serialized_size();
 
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -315,13 +689,33 @@ pub fn sw_from_random_bytes<P: SWModelParameters>() {
             let p2 = GroupAffine::<P>::from_random_bytes(&serialized).unwrap();
             assert_eq!(p1, p2);
         }
+
+        // The bits of the additional flags byte below the flags are ignored.
+        for p in &[a, -a, GroupAffine::<P>::zero()] {
+            let mut serialized = vec![0; buf_size];
+            let mut cursor = Cursor::new(&mut serialized[..]);
+            p.serialize(&mut cursor).unwrap();
+            if has_flags_byte {
+                serialized[buf_size - 1] |= 0x3f;
+            }
+            assert_eq!(
+                GroupAffine::<P>::from_random_bytes(&serialized).unwrap(),
+                *p
+            );
+        }
     }
 }
 
 pub fn sw_curve_serialization_test<P: SWModelParameters>() {
     use algebra_core::curves::models::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+    use algebra_core::ConstantSerializedSize;
 
     let buf_size = GroupAffine::<P>::zero().serialized_size();
+    assert_eq!(buf_size, GroupAffine::<P>::SERIALIZED_SIZE);
+    assert_eq!(
+        GroupAffine::<P>::zero().uncompressed_size(),
+        GroupAffine::<P>::UNCOMPRESSED_SIZE
+    );
 
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -403,6 +797,119 @@ pub fn sw_curve_serialization_test<P: SWModelParameters>() {
     }
 }
 
+pub fn sec1_serialization_test<P: SWModelParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    use algebra_core::curves::{
+        models::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        sec1::Sec1Affine,
+    };
+
+    let field_size = P::BaseField::zero().serialized_size();
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = Sec1Affine(GroupProjective::<P>::rand(&mut rng).into_affine());
+        for b in &[a, Sec1Affine(-a.0)] {
+            let mut serialized = vec![];
+            b.serialize(&mut serialized).unwrap();
+            assert_eq!(serialized.len(), 1 + field_size);
+            assert_eq!(serialized.len(), b.serialized_size());
+            assert_eq!(serialized[0], 2 + b.0.y.into_repr().is_odd() as u8);
+            assert_eq!(Sec1Affine::deserialize(&serialized[..]).unwrap(), *b);
+
+            let mut serialized = vec![];
+            b.serialize_uncompressed(&mut serialized).unwrap();
+            assert_eq!(serialized.len(), 1 + 2 * field_size);
+            assert_eq!(serialized.len(), b.uncompressed_size());
+            assert_eq!(serialized[0], 4);
+            assert_eq!(Sec1Affine::deserialize(&serialized[..]).unwrap(), *b);
+
+            // A point that is not on the curve.
+            serialized[1 + 2 * field_size - 1] ^= 1;
+            assert!(Sec1Affine::<P>::deserialize(&serialized[..]).is_err());
+
+            // The hybrid form of SEC 1 is not supported.
+            serialized[0] = 6;
+            assert!(Sec1Affine::<P>::deserialize(&serialized[..]).is_err());
+        }
+    }
+
+    // An x coordinate that is not reduced.
+    let mut serialized = vec![0xff; 1 + field_size];
+    serialized[0] = 2;
+    assert!(Sec1Affine::<P>::deserialize(&serialized[..]).is_err());
+
+    let zero = Sec1Affine(GroupAffine::<P>::zero());
+    let mut serialized = vec![];
+    zero.serialize(&mut serialized).unwrap();
+    assert_eq!(serialized, [0]);
+    let mut serialized = vec![];
+    zero.serialize_uncompressed(&mut serialized).unwrap();
+    assert_eq!(serialized, [0]);
+    assert_eq!(Sec1Affine::deserialize(&serialized[..]).unwrap(), zero);
+}
+
+pub fn rfc8032_serialization_test<P: TEModelParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    use algebra_core::{
+        curves::{
+            models::twisted_edwards_extended::{GroupAffine, GroupProjective},
+            rfc8032::Rfc8032Affine,
+        },
+        ConstantSerializedSize,
+    };
+
+    let size = Rfc8032Affine::<P>::SERIALIZED_SIZE;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = Rfc8032Affine(GroupProjective::<P>::rand(&mut rng).into_affine());
+        for b in &[a, Rfc8032Affine(-a.0)] {
+            let mut serialized = vec![];
+            b.serialize(&mut serialized).unwrap();
+            assert_eq!(serialized.len(), size);
+            assert_eq!(serialized.len(), b.serialized_size());
+            assert_eq!(serialized[size - 1] >> 7, b.0.x.into_repr().is_odd() as u8);
+            assert_eq!(Rfc8032Affine::deserialize(&serialized[..]).unwrap(), *b);
+        }
+    }
+
+    // A y coordinate that is not reduced.
+    let mut serialized = vec![0xff; size];
+    serialized[size - 1] = 0x7f;
+    assert!(Rfc8032Affine::<P>::deserialize(&serialized[..]).is_err());
+
+    let zero = Rfc8032Affine(GroupAffine::<P>::zero());
+    let mut serialized = vec![];
+    zero.serialize(&mut serialized).unwrap();
+    assert_eq!(serialized[0], 1);
+    assert!(serialized[1..].iter().all(|b| *b == 0));
+    assert_eq!(Rfc8032Affine::deserialize(&serialized[..]).unwrap(), zero);
+
+    // The x coordinate is zero, so its sign bit must not be set.
+    serialized[size - 1] |= 0x80;
+    assert!(Rfc8032Affine::<P>::deserialize(&serialized[..]).is_err());
+
+    // The point (0, -1) of order 2 is only accepted by unchecked deserialization.
+    let torsion = Rfc8032Affine(GroupAffine::<P>::new(
+        P::BaseField::zero(),
+        -P::BaseField::one(),
+    ));
+    let mut serialized = vec![];
+    torsion.serialize(&mut serialized).unwrap();
+    assert!(Rfc8032Affine::<P>::deserialize(&serialized[..]).is_err());
+    assert_eq!(
+        Rfc8032Affine::deserialize_unchecked(&serialized[..]).unwrap(),
+        torsion
+    );
+}
+
 pub(crate) fn montgomery_conversion_test<P>()
 where
     P: TEModelParameters,
@@ -418,12 +925,189 @@ where
     assert_eq!(b, P::MontgomeryModelParameters::COEFF_B);
 }
 
+pub fn montgomery_tests<P: MontgomeryModelParameters>() {
+    montgomery_birational_map_test::<P>();
+    montgomery_ladder_test::<P>();
+    montgomery_curve_serialization_test::<P>();
+}
+
+pub fn montgomery_birational_map_test<P: MontgomeryModelParameters>() {
+    use algebra_core::curves::models::{
+        montgomery::{GroupAffine, GroupProjective},
+        twisted_edwards_extended as te,
+    };
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let g = GroupAffine::<P>::prime_subgroup_generator();
+    assert!(g.is_on_curve());
+    assert!(g.is_in_correct_subgroup_assuming_on_curve());
+    assert_eq!(
+        g.to_twisted_edwards(),
+        te::GroupAffine::prime_subgroup_generator()
+    );
+
+    // The identity and the point of order two.
+    let te_zero = te::GroupAffine::<P::TEModelParameters>::zero();
+    let te_two_torsion =
+        te::GroupAffine::<P::TEModelParameters>::new(P::BaseField::zero(), -P::BaseField::one());
+    for &p in &[te_zero, te_two_torsion] {
+        let m = GroupAffine::<P>::from_twisted_edwards(&p);
+        assert!(m.is_on_curve());
+        assert_eq!(m.to_twisted_edwards(), p);
+        let m_proj = GroupProjective::<P>::from_twisted_edwards(&p.into_projective());
+        assert_eq!(m_proj.into_affine(), m);
+        assert_eq!(m_proj.to_twisted_edwards(), p.into_projective());
+    }
+
+    for _ in 0..ITERATIONS {
+        let a = te::GroupProjective::<P::TEModelParameters>::rand(&mut rng);
+        let b = te::GroupProjective::<P::TEModelParameters>::rand(&mut rng);
+
+        let a_m = GroupAffine::<P>::from_twisted_edwards(&a.into_affine());
+        let b_m = GroupAffine::<P>::from_twisted_edwards(&b.into_affine());
+        assert!(a_m.is_on_curve());
+        assert_eq!(a_m.to_twisted_edwards(), a.into_affine());
+
+        let a_m_proj = GroupProjective::<P>::from_twisted_edwards(&a);
+        assert_eq!(a_m_proj.into_affine(), a_m);
+        assert_eq!(a_m_proj.to_twisted_edwards(), a);
+
+        // The map is a group homomorphism.
+        let sum = GroupAffine::<P>::from_twisted_edwards(&(a + &b).into_affine());
+        assert_eq!(a_m + b_m, sum);
+        assert_eq!(a_m_proj + &b_m.into_projective(), sum);
+        assert_eq!(
+            GroupAffine::<P>::from_twisted_edwards(&a.double().into_affine()),
+            a_m_proj.double()
+        );
+        assert_eq!(
+            -a_m,
+            GroupAffine::<P>::from_twisted_edwards(&(-a).into_affine())
+        );
+    }
+}
+
+pub fn montgomery_ladder_test<P: MontgomeryModelParameters>() {
+    use algebra_core::curves::models::montgomery::{GroupAffine, GroupProjective, XZProjective};
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng);
+        let s = P::ScalarField::rand(&mut rng);
+
+        let expected = XZProjective::from(a.mul(s.into_repr()));
+        assert_eq!(XZProjective::from(a).mul(s.into_repr()), expected);
+        assert_eq!(
+            XZProjective::<P>::from(a.into_affine()).mul(s.into_repr()),
+            expected
+        );
+        assert_eq!(
+            XZProjective::<P>::from(a).double(),
+            XZProjective::from(a.double())
+        );
+        assert_eq!(
+            XZProjective::<P>::from(a).mul(P::ScalarField::zero().into_repr()),
+            XZProjective::zero()
+        );
+        // A point and its negation share their x-coordinate.
+        assert_eq!(XZProjective::from(a).mul((-s).into_repr()), expected);
+
+        let b = GroupProjective::<P>::rand(&mut rng);
+        assert_eq!(
+            XZProjective::from(a)
+                .differential_add(&XZProjective::from(b), &XZProjective::from(a - &b)),
+            XZProjective::from(a + &b)
+        );
+    }
+
+    // The point of order two.
+    let t = XZProjective::<P>::from(GroupAffine::new(
+        P::BaseField::zero(),
+        P::BaseField::zero(),
+        false,
+    ));
+    let two = P::ScalarField::one().double();
+    assert_eq!(t.mul(P::ScalarField::one().into_repr()), t);
+    assert!(t.mul(two.into_repr()).is_zero());
+    assert_eq!(t.mul((two + &P::ScalarField::one()).into_repr()), t);
+    assert_eq!(t.to_affine_x(), Some(P::BaseField::zero()));
+    assert!(XZProjective::<P>::zero().mul(two.into_repr()).is_zero());
+}
+
+pub fn montgomery_curve_serialization_test<P: MontgomeryModelParameters>() {
+    use algebra_core::curves::models::montgomery::{GroupAffine, GroupProjective};
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng).into_affine();
+        for &a in &[a, -a, GroupAffine::<P>::zero()] {
+            let mut serialized = vec![0; a.serialized_size()];
+            let mut cursor = Cursor::new(&mut serialized[..]);
+            a.serialize(&mut cursor).unwrap();
+            let mut cursor = Cursor::new(&serialized[..]);
+            let b = GroupAffine::<P>::deserialize(&mut cursor).unwrap();
+            assert_eq!(a, b);
+
+            let mut serialized = vec![0; a.uncompressed_size()];
+            let mut cursor = Cursor::new(&mut serialized[..]);
+            a.serialize_uncompressed(&mut cursor).unwrap();
+            let mut cursor = Cursor::new(&serialized[..]);
+            let b = GroupAffine::<P>::deserialize_uncompressed(&mut cursor).unwrap();
+            assert_eq!(a, b);
+        }
+
+        let serialized = vec![0; GroupAffine::<P>::zero().serialized_size() - 1];
+        let mut cursor = Cursor::new(&serialized[..]);
+        GroupAffine::<P>::deserialize(&mut cursor).unwrap_err();
+    }
+}
+
 pub fn edwards_tests<P: TEModelParameters>()
 where
     P::BaseField: PrimeField,
 {
     edwards_curve_serialization_test::<P>();
     edwards_from_random_bytes::<P>();
+    edwards_glv_test::<P>();
+}
+
+pub fn edwards_glv_test<P: TEModelParameters>() {
+    use algebra_core::curves::models::twisted_edwards_extended::GroupProjective;
+
+    let glv = match P::GLV {
+        Some(glv) => glv,
+        None => return,
+    };
+
+    // The endomorphism maps the identity to itself.
+    assert!((glv.endomorphism)(&GroupProjective::<P>::zero()).is_zero());
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng);
+        let a_affine = a.into_affine();
+
+        // The endomorphism acts as multiplication by lambda.
+        assert_eq!((glv.endomorphism)(&a), a_affine.mul(glv.lambda.into_repr()));
+
+        let scalars = [
+            P::ScalarField::zero(),
+            P::ScalarField::one(),
+            -P::ScalarField::one(),
+            glv.lambda,
+            -glv.lambda,
+            P::ScalarField::rand(&mut rng),
+        ];
+        for k in scalars.iter() {
+            let expected = a_affine.mul(k.into_repr());
+            assert_eq!(a.mul_glv(k), expected);
+            assert_eq!(a_affine.mul_glv(k), expected);
+        }
+    }
 }
 
 pub fn edwards_from_random_bytes<P: TEModelParameters>()
@@ -434,6 +1118,8 @@ where
     use algebra_core::{to_bytes, ToBytes};
 
     let buf_size = GroupAffine::<P>::zero().serialized_size();
+    // Whether the flags are serialized in an additional byte.
+    let has_flags_byte = buf_size > P::BaseField::zero().serialized_size();
 
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -450,6 +1136,20 @@ where
             let p2 = GroupAffine::<P>::from_random_bytes(&serialized).unwrap();
             assert_eq!(p1, p2);
         }
+
+        // The bits of the additional flags byte below the flags are ignored.
+        for p in &[a, -a, GroupAffine::<P>::zero()] {
+            let mut serialized = vec![0; buf_size];
+            let mut cursor = Cursor::new(&mut serialized[..]);
+            p.serialize(&mut cursor).unwrap();
+            if has_flags_byte {
+                serialized[buf_size - 1] |= 0x3f;
+            }
+            assert_eq!(
+                GroupAffine::<P>::from_random_bytes(&serialized).unwrap(),
+                *p
+            );
+        }
     }
 
     for _ in 0..ITERATIONS {
@@ -467,8 +1167,14 @@ where
 
 pub fn edwards_curve_serialization_test<P: TEModelParameters>() {
     use algebra_core::curves::models::twisted_edwards_extended::{GroupAffine, GroupProjective};
+    use algebra_core::ConstantSerializedSize;
 
     let buf_size = GroupAffine::<P>::zero().serialized_size();
+    assert_eq!(buf_size, GroupAffine::<P>::SERIALIZED_SIZE);
+    assert_eq!(
+        GroupAffine::<P>::zero().uncompressed_size(),
+        GroupAffine::<P>::UNCOMPRESSED_SIZE
+    );
 
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -529,3 +1235,168 @@ pub fn edwards_curve_serialization_test<P: TEModelParameters>() {
         }
     }
 }
+
+pub fn pairing_torus_compression_test<E: PairingOutputEngine>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let one = E::Fqk::one();
+    let compressed = one.torus_compress().unwrap();
+    assert_eq!(E::Fqk::torus_decompress(&compressed), Some(one));
+
+    for _ in 0..ITERATIONS {
+        let a = E::G1Projective::rand(&mut rng);
+        let b = E::G2Projective::rand(&mut rng);
+        let gt = E::pairing(a, b);
+
+        let compressed = gt.torus_compress().unwrap();
+        assert_eq!(E::Fqk::torus_decompress(&compressed), Some(gt));
+
+        let mut serialized = Vec::new();
+        gt.serialize_torus(&mut serialized).unwrap();
+        assert!(serialized.len() * 2 <= gt.serialized_size());
+        assert_eq!(E::Fqk::deserialize_torus(&serialized[..]).unwrap(), gt);
+
+        // Random elements are not in the cyclotomic subgroup.
+        let f = E::Fqk::rand(&mut rng);
+        assert!(f.torus_compress().is_none());
+        assert!(matches!(
+            f.serialize_torus(&mut Vec::new()),
+            Err(SerializationError::InvalidData)
+        ));
+    }
+}
+
+pub fn pairing_output_test<E: PairingOutputEngine>() {
+    use algebra_core::{to_bytes, FromBytes, ToBytes};
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let a = PairingOutput::<E>::rand(&mut rng);
+    let b = PairingOutput::<E>::rand(&mut rng);
+    crate::tests::groups::group_test(a, b);
+    assert!(a.is_in_correct_subgroup());
+
+    // e(s * P, Q) = s * e(P, Q)
+    let s = E::Fr::rand(&mut rng);
+    let p = E::G1Projective::rand(&mut rng);
+    let q = E::G2Projective::rand(&mut rng);
+    let mut sp = p;
+    sp *= s;
+    let mut expected = PairingOutput::<E>::pairing(p, q);
+    expected *= s;
+    assert_eq!(PairingOutput::<E>::pairing(sp, q), expected);
+
+    for size in [0, 1, 5, 20].iter().copied() {
+        let bases: Vec<_> = (0..size)
+            .map(|_| PairingOutput::<E>::rand(&mut rng))
+            .collect();
+        let scalars: Vec<_> = (0..size).map(|_| E::Fr::rand(&mut rng)).collect();
+        let mut naive = PairingOutput::<E>::zero();
+        for (base, scalar) in bases.iter().zip(&scalars) {
+            let mut term = *base;
+            term *= *scalar;
+            naive += &term;
+        }
+        let repr: Vec<_> = scalars.iter().map(|s| s.into_repr()).collect();
+        assert_eq!(PairingOutput::multi_scalar_mul(&bases, &repr), naive);
+    }
+
+    let mut serialized = Vec::new();
+    a.serialize(&mut serialized).unwrap();
+    assert_eq!(serialized.len(), a.serialized_size());
+    assert_eq!(PairingOutput::<E>::deserialize(&serialized[..]).unwrap(), a);
+
+    let mut serialized = Vec::new();
+    a.serialize_uncompressed(&mut serialized).unwrap();
+    assert_eq!(serialized.len(), a.uncompressed_size());
+    assert_eq!(
+        PairingOutput::<E>::deserialize_uncompressed(&serialized[..]).unwrap(),
+        a
+    );
+
+    // An element of the cyclotomic subgroup, which is much larger than the
+    // target group, can be compressed but is rejected on deserialization.
+    // This is f^((p^(k/2) - 1) * (p^(k/6) + 1)), with p^0 + 1 = 2 for k = 4.
+    let f = E::Fqk::rand(&mut rng);
+    let g = f.cyclotomic_inverse() * &f.inverse().unwrap();
+    let k = E::Fqk::one().serialized_size() / E::Fq::one().serialized_size();
+    let mut h = g;
+    h.frobenius_map(k / 6);
+    let outside = PairingOutput::<E>(g * &h);
+    assert!(!outside.is_in_correct_subgroup());
+    let mut serialized = Vec::new();
+    outside.serialize(&mut serialized).unwrap();
+    assert!(matches!(
+        PairingOutput::<E>::deserialize(&serialized[..]),
+        Err(SerializationError::InvalidData)
+    ));
+    let mut serialized = Vec::new();
+    outside.serialize_uncompressed(&mut serialized).unwrap();
+    assert!(matches!(
+        PairingOutput::<E>::deserialize_uncompressed(&serialized[..]),
+        Err(SerializationError::InvalidData)
+    ));
+    let serialized = to_bytes![outside].unwrap();
+    assert!(PairingOutput::<E>::read(&serialized[..]).is_err());
+    assert_eq!(
+        PairingOutput::<E>::from_bytes_unchecked(&serialized[..]).unwrap(),
+        outside
+    );
+    let serialized = to_bytes![a].unwrap();
+    assert_eq!(PairingOutput::<E>::read(&serialized[..]).unwrap(), a);
+}
+
+/// Checks that each curve of the cycle has the base field of the other as its
+/// scalar field, by using base field elements of one curve as scalars on the
+/// other.
+pub fn curve_cycle_test<C: CurveCycle>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = <C::E1 as ProjectiveCurve>::BaseField::rand(&mut rng);
+        let b = <C::E1 as ProjectiveCurve>::BaseField::rand(&mut rng);
+        let g = C::E2::rand(&mut rng);
+        assert_eq!(
+            g.mul((a * &b).into_repr()),
+            g.mul(a.into_repr()).mul(b.into_repr())
+        );
+        assert_eq!(
+            g.mul((a + &b).into_repr()),
+            g.mul(a.into_repr()) + &g.mul(b.into_repr())
+        );
+
+        let a = <C::E2 as ProjectiveCurve>::BaseField::rand(&mut rng);
+        let b = <C::E2 as ProjectiveCurve>::BaseField::rand(&mut rng);
+        let g = C::E1::rand(&mut rng);
+        assert_eq!(
+            g.mul((a * &b).into_repr()),
+            g.mul(a.into_repr()).mul(b.into_repr())
+        );
+        assert_eq!(
+            g.mul((a + &b).into_repr()),
+            g.mul(a.into_repr()) + &g.mul(b.into_repr())
+        );
+    }
+}
+
+/// Checks that the pairings of both engines of the cycle are bilinear in
+/// scalars taken from the base field of the other engine.
+pub fn cycle_engine_test<C: CycleEngine>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let a = <C::E1 as PairingEngine>::G1Projective::rand(&mut rng);
+    let b = <C::E1 as PairingEngine>::G2Projective::rand(&mut rng);
+    let s = <C::E2 as PairingEngine>::Fq::rand(&mut rng);
+    assert_eq!(
+        C::E1::pairing(a.mul(s.into_repr()), b),
+        C::E1::pairing(a, b.mul(s.into_repr()))
+    );
+
+    let a = <C::E2 as PairingEngine>::G1Projective::rand(&mut rng);
+    let b = <C::E2 as PairingEngine>::G2Projective::rand(&mut rng);
+    let s = <C::E1 as PairingEngine>::Fq::rand(&mut rng);
+    assert_eq!(
+        C::E2::pairing(a.mul(s.into_repr()), b),
+        C::E2::pairing(a, b.mul(s.into_repr()))
+    );
+}
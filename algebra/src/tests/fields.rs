@@ -1,10 +1,15 @@
 #![allow(unused)]
 use crate::{
-    fields::{FftField, FftParameters, Field, LegendreSymbol, PrimeField, SquareRootField},
+    fields::{
+        ConstantTimeField, CubicExtField, CubicExtParameters, FftField, FftParameters, Field,
+        Fp64Parameters, FpParameters, LegendreSymbol, PrimeField, QuadExtField, QuadExtParameters,
+        SquareRootField,
+    },
     io::Cursor,
-    Flags, SWFlags,
+    Choice, ConditionallySelectable, ConstantTimeEq, Flags, One, SWFlags, UniformRand,
 };
-use algebra_core::buffer_bit_byte_size;
+use algebra_core::{buffer_bit_byte_size, serialized_size_with_flags, BigInteger64};
+use core::marker::PhantomData;
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
@@ -89,6 +94,43 @@ fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_constant_time_tests<F: ConstantTimeField, R: Rng>(rng: &mut R) {
+    assert!(F::zero().ct_inverse().is_zero());
+    assert_eq!(F::one().ct_inverse(), F::one());
+    assert_eq!((-F::one()).ct_inverse(), -F::one());
+    assert!(F::zero().ct_neg().is_zero());
+    assert_eq!((-F::one()).ct_double(), -F::one().double());
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+        assert_eq!(a.ct_inverse(), a.inverse().unwrap());
+        assert_eq!((-a).ct_inverse(), (-a).inverse().unwrap());
+
+        assert_eq!(a.ct_add(&b), a + &b);
+        assert_eq!(a.ct_sub(&b), a - &b);
+        assert_eq!(b.ct_sub(&a), b - &a);
+        assert_eq!(a.ct_double(), a.double());
+        assert_eq!(a.ct_neg(), -a);
+        assert_eq!(a.ct_mul(&b), a * &b);
+        assert_eq!(a.ct_square(), a.square());
+        assert_eq!((-a).ct_add(&-b), -a - &b);
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(1)), b);
+    }
+
+    let mut v = (0..ITERATIONS)
+        .map(|i| if i % 3 == 0 { F::zero() } else { F::rand(rng) })
+        .collect::<Vec<_>>();
+    let mut expected = v.clone();
+    crate::fields::batch_inversion(&mut expected);
+    crate::fields::batch_inversion_ct(&mut v);
+    assert_eq!(v, expected);
+}
+
 fn random_doubling_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         let mut a = F::rand(rng);
@@ -156,7 +198,7 @@ fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
-fn random_field_tests<F: Field>() {
+fn random_field_tests<F: ConstantTimeField>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
     random_negation_tests::<F, _>(&mut rng);
@@ -164,6 +206,7 @@ fn random_field_tests<F: Field>() {
     random_subtraction_tests::<F, _>(&mut rng);
     random_multiplication_tests::<F, _>(&mut rng);
     random_inversion_tests::<F, _>(&mut rng);
+    random_constant_time_tests::<F, _>(&mut rng);
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
     random_expansion_tests::<F, _>(&mut rng);
@@ -224,8 +267,10 @@ pub fn from_str_test<F: PrimeField>() {
     {
         let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
+        // Keep `n` below the modulus of fields smaller than 64 bits.
+        let shift = 64usize.saturating_sub(F::size_in_bits() - 1);
         for _ in 0..ITERATIONS {
-            let n: u64 = rng.gen();
+            let n: u64 = rng.gen::<u64>() >> shift;
 
             let a = F::from_str(&crate::format!("{}", n))
                 .map_err(|_| ())
@@ -242,7 +287,7 @@ pub fn from_str_test<F: PrimeField>() {
     assert!(F::from_str("00000000000").is_err());
 }
 
-pub fn field_test<F: Field>(a: F, b: F) {
+pub fn field_test<F: ConstantTimeField>(a: F, b: F) {
     let zero = F::zero();
     assert_eq!(zero, zero);
     assert_eq!(zero.is_zero(), true);
@@ -310,20 +355,20 @@ pub fn field_test<F: Field>(a: F, b: F) {
 
 pub fn fft_field_test<F: FftField>() {
     assert_eq!(
-        F::two_adic_root_of_unity().pow([1 << F::FftParams::TWO_ADICITY]),
+        F::two_adic_root_of_unity().pow([1 << F::two_adicity()]),
         F::one()
     );
 
-    if let Some(small_subgroup_base) = F::FftParams::SMALL_SUBGROUP_BASE {
-        let small_subgroup_base_adicity = F::FftParams::SMALL_SUBGROUP_BASE_ADICITY.unwrap();
+    if let Some(small_subgroup_base) = F::small_subgroup_base() {
+        let small_subgroup_base_adicity = F::small_subgroup_base_adicity().unwrap();
         let large_subgroup_root_of_unity = F::large_subgroup_root_of_unity().unwrap();
         assert_eq!(
-            large_subgroup_root_of_unity.pow([(1 << F::FftParams::TWO_ADICITY)
+            large_subgroup_root_of_unity.pow([(1 << F::two_adicity())
                 * (small_subgroup_base as u64).pow(small_subgroup_base_adicity)]),
             F::one()
         );
 
-        for i in 0..F::FftParams::TWO_ADICITY {
+        for i in 0..F::two_adicity() {
             for j in 0..small_subgroup_base_adicity {
                 use core::convert::TryFrom;
                 let size = usize::try_from(1 << i as usize).unwrap()
@@ -333,7 +378,7 @@ pub fn fft_field_test<F: FftField>() {
             }
         }
     } else {
-        for i in 0..F::FftParams::TWO_ADICITY {
+        for i in 0..F::two_adicity() {
             let size = 1 << i;
             let root = F::get_root_of_unity(size).unwrap();
             assert_eq!(root.pow([size as u64]), F::one());
@@ -349,6 +394,74 @@ pub fn primefield_test<F: PrimeField>() {
     fft_field_test::<F>();
 }
 
+/// Checks that parameters produced by `#[derive(FpParameters)]` match the
+/// hand-written ones.
+pub fn fp_parameters_test<P: FpParameters, D: FpParameters<BigInt = P::BigInt>>() {
+    assert_eq!(P::MODULUS, D::MODULUS);
+    assert_eq!(P::MODULUS_BITS, D::MODULUS_BITS);
+    assert_eq!(P::CAPACITY, D::CAPACITY);
+    assert_eq!(P::REPR_SHAVE_BITS, D::REPR_SHAVE_BITS);
+    assert_eq!(P::R, D::R);
+    assert_eq!(P::R2, D::R2);
+    assert_eq!(P::INV, D::INV);
+    assert_eq!(P::GENERATOR, D::GENERATOR);
+    assert_eq!(P::MODULUS_MINUS_ONE_DIV_TWO, D::MODULUS_MINUS_ONE_DIV_TWO);
+    assert_eq!(P::T, D::T);
+    assert_eq!(P::T_MINUS_ONE_DIV_TWO, D::T_MINUS_ONE_DIV_TWO);
+    assert_eq!(P::TWO_ADICITY, D::TWO_ADICITY);
+    assert_eq!(P::TWO_ADIC_ROOT_OF_UNITY, D::TWO_ADIC_ROOT_OF_UNITY);
+    assert_eq!(P::SMALL_SUBGROUP_BASE, D::SMALL_SUBGROUP_BASE);
+    assert_eq!(P::SMALL_SUBGROUP_BASE_ADICITY, D::SMALL_SUBGROUP_BASE_ADICITY);
+    assert_eq!(
+        P::LARGE_SUBGROUP_ROOT_OF_UNITY,
+        D::LARGE_SUBGROUP_ROOT_OF_UNITY
+    );
+}
+
+/// Checks the reduction of `Fp64Parameters` against reducing with `%`.
+pub fn fp64_reduce_test<P: Fp64Parameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let modulus = u128::from(P::MODULUS.0[0]);
+    let max = (modulus - 1) * (modulus - 1);
+
+    let mut inputs = vec![0, 1, modulus - 1, modulus, modulus + 1, max];
+    for _ in 0..1000 {
+        inputs.push(rng.gen::<u128>() % (max + 1));
+        inputs.push(u128::from(rng.gen::<u64>()) % (max + 1));
+    }
+    for x in inputs {
+        assert_eq!(u128::from(P::reduce(x)), x % modulus, "{}", x);
+        assert_eq!(
+            u128::from(DefaultReduce::<P>::reduce(x)),
+            x % modulus,
+            "{}",
+            x
+        );
+    }
+}
+
+/// The parameters `P` with the default `Fp64Parameters::reduce`, so that
+/// `fp64_reduce_test` covers it for every modulus, overridden or not.
+struct DefaultReduce<P>(PhantomData<P>);
+
+impl<P: Fp64Parameters> FftParameters for DefaultReduce<P> {
+    type BigInt = BigInteger64;
+
+    const TWO_ADICITY: u32 = P::TWO_ADICITY;
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger64 = P::TWO_ADIC_ROOT_OF_UNITY;
+}
+
+impl<P: Fp64Parameters> Fp64Parameters for DefaultReduce<P> {
+    const MODULUS: BigInteger64 = P::MODULUS;
+    const MODULUS_BITS: u32 = P::MODULUS_BITS;
+    const REPR_SHAVE_BITS: u32 = P::REPR_SHAVE_BITS;
+    const GENERATOR: BigInteger64 = P::GENERATOR;
+    const CAPACITY: u32 = P::CAPACITY;
+    const T: BigInteger64 = P::T;
+    const T_MINUS_ONE_DIV_TWO: BigInteger64 = P::T_MINUS_ONE_DIV_TWO;
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger64 = P::MODULUS_MINUS_ONE_DIV_TWO;
+}
+
 pub fn sqrt_field_test<F: SquareRootField>(elem: F) {
     let square = elem.square();
     let sqrt = square.sqrt().unwrap();
@@ -380,6 +493,52 @@ pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: us
     }
 }
 
+/// Checks Karabina's compressed squaring and the cyclotomic exponentiation
+/// against squaring and `pow` in the cyclotomic subgroup of a sextic extension
+/// of a field of degree `base_degree` over the prime field.
+pub fn cyclotomic_compressed_test<P, Q>(base_degree: usize)
+where
+    P: QuadExtParameters<BaseField = CubicExtField<Q>>,
+    Q: CubicExtParameters,
+{
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let one = QuadExtField::<P>::one();
+    assert_eq!(
+        one.cyclotomic_square_compressed().decompress_karabina(),
+        one
+    );
+    assert_eq!(one.cyclotomic_exp(&[12345u64]), one);
+
+    for _ in 0..5 {
+        // Raise a random element to the power `(q^3 - 1) * (q + 1)`, where
+        // `q = p^base_degree`, to land in the cyclotomic subgroup.
+        let f = QuadExtField::<P>::rand(&mut rng);
+        let mut g = f.unitary_inverse() * &f.inverse().unwrap();
+        let mut g_q = g;
+        g_q.frobenius_map(base_degree);
+        g *= &g_q;
+        assert_eq!(g.decompress_karabina(), g);
+
+        let mut compressed = Vec::new();
+        let mut squares = Vec::new();
+        let (mut c, mut s) = (g, g);
+        for _ in 0..10 {
+            c = c.cyclotomic_square_compressed();
+            s.square_in_place();
+            assert_eq!(c.decompress_karabina(), s);
+            compressed.push(c);
+            squares.push(s);
+        }
+        QuadExtField::<P>::batch_decompress_karabina(&mut compressed);
+        assert_eq!(compressed, squares);
+
+        let exponent: [u64; 4] = rng.gen();
+        assert_eq!(g.cyclotomic_exp(&exponent), g.pow(&exponent));
+        assert_eq!(g.cyclotomic_exp(&[0u64]), one);
+        assert_eq!(g.cyclotomic_exp(&[1u64]), g);
+    }
+}
+
 pub fn field_serialization_test<F: Field>(buf_size: usize) {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -406,7 +565,7 @@ pub fn field_serialization_test<F: Field>(buf_size: usize) {
         }
 
         {
-            let mut serialized = vec![0u8; buf_size];
+            let mut serialized = vec![0u8; serialized_size_with_flags::<F>(SWFlags::len())];
             let mut cursor = Cursor::new(&mut serialized[..]);
             a.serialize_with_flags(&mut cursor, SWFlags::from_y_sign(true))
                 .unwrap();
@@ -467,3 +626,85 @@ pub fn field_serialization_test<F: Field>(buf_size: usize) {
         }
     }
 }
+
+#[cfg(feature = "bn254")]
+mod dyn_fp {
+    use super::*;
+    use crate::{
+        bn254::{Fr, FrParameters},
+        fields::{DynFp256, DynFpContext, DynFpContextCell, DynFpParameters},
+        BigInteger256, CanonicalSerialize,
+    };
+
+    static DYN_FR_CONTEXT: DynFpContextCell<BigInteger256> = DynFpContextCell::new();
+
+    struct DynFrParameters;
+
+    impl DynFpParameters for DynFrParameters {
+        type BigInt = BigInteger256;
+
+        fn context() -> &'static DynFpContext<BigInteger256> {
+            DYN_FR_CONTEXT.get()
+        }
+    }
+
+    type DynFr = DynFp256<DynFrParameters>;
+
+    /// Checks a `DynFp256` with the modulus of the BN254 scalar field against
+    /// that field.
+    #[test]
+    fn test_dyn_fp() {
+        let generator = Fr::multiplicative_generator().into_repr();
+        DYN_FR_CONTEXT
+            .set(DynFpContext::new(FrParameters::MODULUS, generator).unwrap())
+            .unwrap();
+        // The context can only be set once.
+        assert!(DYN_FR_CONTEXT
+            .set(DynFpContext::new(FrParameters::MODULUS, generator).unwrap())
+            .is_err());
+        assert_eq!(DynFr::characteristic(), Fr::characteristic());
+        assert_eq!(DynFr::modulus(), Fr::modulus());
+        assert_eq!(DynFr::size_in_bits(), Fr::size_in_bits());
+        assert_eq!(DynFr::capacity(), Fr::capacity());
+        assert_eq!(DynFr::two_adicity(), Fr::two_adicity());
+        assert_eq!(DynFr::small_subgroup_base(), Fr::small_subgroup_base());
+        assert_eq!(DynFr::trace(), Fr::trace());
+        assert_eq!(
+            DynFr::trace_minus_one_div_two(),
+            Fr::trace_minus_one_div_two()
+        );
+        assert_eq!(
+            DynFr::modulus_minus_one_div_two(),
+            Fr::modulus_minus_one_div_two()
+        );
+        assert_eq!(DynFr::one().0, FrParameters::R);
+        assert_eq!(DynFr::multiplicative_generator().0, FrParameters::GENERATOR);
+        assert_eq!(
+            DynFr::two_adic_root_of_unity().0,
+            FrParameters::TWO_ADIC_ROOT_OF_UNITY
+        );
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        for _ in 0..ITERATIONS {
+            let a: Fr = rng.gen();
+            let b: Fr = rng.gen();
+            let x = DynFr::from(a.into_repr());
+            let y = DynFr::from(b.into_repr());
+            // Both use Montgomery form with the same R.
+            assert_eq!(x.0, a.0);
+            assert_eq!((x + &y).0, (a + &b).0);
+            assert_eq!((x - &y).0, (a - &b).0);
+            assert_eq!((x * &y).0, (a * &b).0);
+            assert_eq!(x.inverse().unwrap().0, a.inverse().unwrap().0);
+            assert_eq!(x.sqrt().map(|s| s.0), a.sqrt().map(|s| s.0));
+
+            let x: DynFr = rng.gen();
+            let y: DynFr = rng.gen();
+            field_test(x, y);
+            primefield_test::<DynFr>();
+            fft_field_test::<DynFr>();
+            sqrt_field_test(x);
+            field_serialization_test::<DynFr>(x.serialized_size());
+        }
+    }
+}
@@ -0,0 +1,81 @@
+#![allow(unused)]
+use algebra_core::{
+    curves::{short_weierstrass_jacobian, twisted_edwards_extended, AffineCurve},
+    hashing::{
+        curve_maps::elligator2::{Elligator2Map, Elligator2Parameters},
+        HashToField, MapToCurve,
+    },
+    PrimeField, SWModelParameters, UniformRand, Vec, Zero,
+};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use sha2::Sha256;
+
+use crate::tests::curves::ITERATIONS;
+
+/// The messages hashed in the test vectors of the hash-to-curve specification.
+pub fn messages() -> Vec<Vec<u8>> {
+    vec![
+        b"".to_vec(),
+        b"abc".to_vec(),
+        b"abcdef0123456789".to_vec(),
+        [&b"q128_"[..], &[b'q'; 128][..]].concat(),
+        [&b"a512_"[..], &[b'a'; 512][..]].concat(),
+    ]
+}
+
+fn is_in_prime_order_subgroup<G: AffineCurve>(p: &G) -> bool {
+    p.mul(G::ScalarField::modulus()).is_zero()
+}
+
+/// Hashes each of `messages()` to `G` under `dst`, checking that the results
+/// are in the prime order subgroup and depend on the domain separation tag.
+/// Returns the points so that callers can compare them with test vectors.
+pub fn hash_to_curve_test<G: AffineCurve, M: MapToCurve<G>>(dst: &[u8]) -> Vec<G>
+where
+    G::BaseField: HashToField,
+{
+    messages()
+        .iter()
+        .map(|msg| {
+            let p = M::hash_to_curve::<Sha256>(msg, dst).unwrap();
+            assert!(!p.is_zero());
+            assert!(is_in_prime_order_subgroup(&p));
+            assert_eq!(p, M::hash_to_curve::<Sha256>(msg, dst).unwrap());
+            assert_ne!(p, M::hash_to_curve::<Sha256>(msg, b"another tag").unwrap());
+
+            let q = M::encode_to_curve::<Sha256>(msg, dst).unwrap();
+            assert!(is_in_prime_order_subgroup(&q));
+            p
+        })
+        .collect()
+}
+
+/// Checks that `M` sends random field elements to points of the curve.
+pub fn sw_map_to_curve_test<P, M>()
+where
+    P: SWModelParameters,
+    M: MapToCurve<short_weierstrass_jacobian::GroupAffine<P>>,
+{
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let p = M::map_to_curve(P::BaseField::rand(&mut rng));
+        assert!(p.is_on_curve());
+    }
+}
+
+pub fn elligator2_test<P: Elligator2Parameters>()
+where
+    P::BaseField: HashToField,
+{
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let p = Elligator2Map::<P>::map_to_curve(P::BaseField::rand(&mut rng));
+        assert!(p.is_on_curve());
+    }
+    hash_to_curve_test::<twisted_edwards_extended::GroupAffine<P>, Elligator2Map<P>>(
+        b"QUUX-V01-CS02-with-elligator2-test",
+    );
+}
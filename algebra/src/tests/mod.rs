@@ -1,4 +1,5 @@
 pub(crate) mod curves;
 pub(crate) mod fields;
 pub(crate) mod groups;
+pub(crate) mod hashing;
 pub(crate) mod msm;
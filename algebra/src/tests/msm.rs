@@ -1,9 +1,11 @@
 #![cfg(feature = "bls12_381")]
-use crate::bls12_381::{Fr, G1Projective};
+use crate::bls12_381::{Fr, G1Affine, G1Projective, G2Projective};
 use algebra_core::{
-    msm::VariableBaseMSM, AffineCurve, PrimeField, ProjectiveCurve, UniformRand, Zero,
+    msm::{CombTable, FixedBaseMSM, PreparedMSMBases, VariableBaseMSM},
+    AffineCurve, CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize, Field, One,
+    PrimeField, ProjectiveCurve, UniformRand, Zero,
 };
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
 fn naive_var_base_msm<G: AffineCurve>(
@@ -55,3 +57,288 @@ fn test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.into_affine(), fast.into_affine());
 }
+
+#[test]
+fn test_with_bls12_g2() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES)
+        .map(|_| Fr::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| G2Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let naive = naive_var_base_msm(g.as_slice(), v.as_slice());
+    let fast = VariableBaseMSM::multi_scalar_mul(g.as_slice(), v.as_slice());
+
+    assert_eq!(naive.into_affine(), fast.into_affine());
+}
+
+#[test]
+fn test_with_bls12_repeated_bases() {
+    const SAMPLES: usize = 1 << 10;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    // Few distinct bases, some of them negated, and scalars with long runs of
+    // equal digits, so that buckets see equal points and points that cancel.
+    let p = (0..4)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    let minus_one = -Fr::one();
+    let scalars = [
+        Fr::zero(),
+        Fr::one(),
+        minus_one,
+        Fr::from(2u64).pow(&[128]) - &Fr::one(),
+        Fr::from(2u64).pow(&[254]),
+    ];
+    let v = (0..SAMPLES)
+        .map(|i| {
+            if i % 3 == 0 {
+                scalars[rng.gen_range(0, scalars.len())].into_repr()
+            } else {
+                Fr::rand(&mut rng).into_repr()
+            }
+        })
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| {
+            let base = p[rng.gen_range(0, p.len())];
+            if rng.gen() {
+                -base
+            } else {
+                base
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let naive = naive_var_base_msm(g.as_slice(), v.as_slice());
+    let fast = VariableBaseMSM::multi_scalar_mul(g.as_slice(), v.as_slice());
+
+    assert_eq!(naive.into_affine(), fast.into_affine());
+}
+
+#[test]
+fn test_with_bls12_small_inputs() {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    for samples in 0..40 {
+        let v = (0..samples)
+            .map(|_| Fr::rand(&mut rng).into_repr())
+            .collect::<Vec<_>>();
+        let g = (0..samples)
+            .map(|_| G1Projective::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+
+        let naive = naive_var_base_msm(g.as_slice(), v.as_slice());
+        let fast = VariableBaseMSM::multi_scalar_mul(g.as_slice(), v.as_slice());
+
+        assert_eq!(naive.into_affine(), fast.into_affine());
+    }
+}
+
+#[test]
+fn test_window_size() {
+    // The window grows with the number of points, by about one bit for every
+    // doubling of it.
+    let mut prev = 2;
+    for log_size in 0..28 {
+        let c = G1Affine::msm_window_size(1 << log_size);
+        assert!(c >= prev && c <= prev + 3);
+        prev = c;
+    }
+    assert_eq!(G1Affine::msm_window_size(1 << 20), 16);
+}
+
+#[test]
+fn test_prepared_with_bls12() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES)
+        .map(|_| Fr::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let naive = naive_var_base_msm(g.as_slice(), v.as_slice());
+    for &num_copies in &[0, 1, 2, 3, 7, 1000] {
+        let prepared = PreparedMSMBases::new(g.as_slice(), num_copies);
+        assert_eq!(prepared.bases(), g.as_slice());
+
+        let fast = VariableBaseMSM::multi_scalar_mul_prepared(&prepared, v.as_slice());
+        assert_eq!(naive.into_affine(), fast.into_affine());
+
+        // Fewer scalars than bases.
+        let naive_prefix = naive_var_base_msm(&g[..100], &v[..100]);
+        let fast_prefix = VariableBaseMSM::multi_scalar_mul_prepared(&prepared, &v[..100]);
+        assert_eq!(naive_prefix.into_affine(), fast_prefix.into_affine());
+    }
+}
+
+#[test]
+fn test_prepared_serialization() {
+    const SAMPLES: usize = 1 << 6;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let g = (0..SAMPLES)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    let prepared = PreparedMSMBases::new(g.as_slice(), 4);
+
+    let mut serialized = vec![0; prepared.serialized_size()];
+    prepared.serialize(&mut serialized[..]).unwrap();
+    assert_eq!(
+        prepared,
+        PreparedMSMBases::deserialize(&serialized[..]).unwrap()
+    );
+
+    let mut serialized = vec![0; prepared.uncompressed_size()];
+    prepared
+        .serialize_uncompressed(&mut serialized[..])
+        .unwrap();
+    assert_eq!(
+        prepared,
+        PreparedMSMBases::deserialize_uncompressed(&serialized[..]).unwrap()
+    );
+
+    // A number of bases that does not divide the number of stored points.
+    serialized[8..16].copy_from_slice(&(SAMPLES as u64 + 1).to_le_bytes());
+    assert!(PreparedMSMBases::<G1Affine>::deserialize_uncompressed(&serialized[..]).is_err());
+}
+
+#[test]
+fn test_from_reader_with_bls12() {
+    const SAMPLES: usize = 1 << 7;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES)
+        .map(|_| Fr::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let mut serialized = vec![0; SAMPLES * G1Affine::UNCOMPRESSED_SIZE];
+    for (base, bytes) in g
+        .iter()
+        .zip(serialized.chunks_mut(G1Affine::UNCOMPRESSED_SIZE))
+    {
+        base.serialize_uncompressed(bytes).unwrap();
+    }
+
+    let naive = naive_var_base_msm(g.as_slice(), v.as_slice());
+    let naive_prefix = naive_var_base_msm(&g[..100], &v[..100]);
+    for &chunk_size in &[0, 1, 7, SAMPLES, 1000] {
+        let mut reader = &serialized[..];
+        let fast = VariableBaseMSM::multi_scalar_mul_from_reader::<G1Affine, _>(
+            &mut reader,
+            SAMPLES,
+            v.as_slice(),
+            chunk_size,
+        )
+        .unwrap();
+        assert_eq!(naive.into_affine(), fast.into_affine());
+        assert!(reader.is_empty());
+
+        // Fewer scalars than bases: all the bases are still read.
+        let mut reader = &serialized[..];
+        let fast = VariableBaseMSM::multi_scalar_mul_from_reader_unchecked::<G1Affine, _>(
+            &mut reader,
+            SAMPLES,
+            &v[..100],
+            chunk_size,
+        )
+        .unwrap();
+        assert_eq!(naive_prefix.into_affine(), fast.into_affine());
+        assert!(reader.is_empty());
+
+        // Fewer bases than scalars.
+        let fast = VariableBaseMSM::multi_scalar_mul_from_reader::<G1Affine, _>(
+            &serialized[..],
+            100,
+            v.as_slice(),
+            chunk_size,
+        )
+        .unwrap();
+        assert_eq!(naive_prefix.into_affine(), fast.into_affine());
+    }
+
+    // More bases than the reader holds.
+    assert!(
+        VariableBaseMSM::multi_scalar_mul_from_reader::<G1Affine, _>(
+            &serialized[..],
+            SAMPLES + 1,
+            v.as_slice(),
+            16,
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_comb_with_bls12() {
+    const SAMPLES: usize = 1 << 4;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES)
+        .map(|_| Fr::rand(&mut rng))
+        .chain(vec![Fr::zero(), Fr::one(), -Fr::one()])
+        .collect::<Vec<_>>();
+    let g = G1Projective::rand(&mut rng);
+    let h = G2Projective::rand(&mut rng);
+
+    for &(teeth, blocks) in &[(1, 1), (1, 300), (3, 2), (4, 255), (5, 5), (8, 1), (12, 3)] {
+        let g_table = CombTable::new(g, teeth, blocks);
+        assert_eq!(g_table.generator(), g);
+        assert!(g_table.blocks() <= blocks.max(1));
+        let fast = FixedBaseMSM::multi_scalar_mul_comb(&g_table, &v);
+        for (e, res) in v.iter().zip(fast) {
+            assert_eq!(g.mul(*e), res);
+        }
+
+        if teeth <= 8 {
+            let h_table = CombTable::new(h, teeth, blocks);
+            for e in &v {
+                assert_eq!(h.mul(*e), h_table.mul(e));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_comb_serialization() {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let table = CombTable::new(G1Projective::rand(&mut rng), 4, 3);
+
+    let mut serialized = vec![0; table.serialized_size()];
+    table.serialize(&mut serialized[..]).unwrap();
+    assert_eq!(table, CombTable::deserialize(&serialized[..]).unwrap());
+
+    let mut serialized = vec![0; table.uncompressed_size()];
+    table.serialize_uncompressed(&mut serialized[..]).unwrap();
+    assert_eq!(
+        table,
+        CombTable::deserialize_uncompressed(&serialized[..]).unwrap()
+    );
+
+    // A number of blocks that does not match the size of the table.
+    let mut invalid = serialized.clone();
+    invalid[8..16].copy_from_slice(&4u64.to_le_bytes());
+    assert!(CombTable::<G1Projective>::deserialize_uncompressed(&invalid[..]).is_err());
+
+    // Too many teeth.
+    let mut invalid = serialized;
+    invalid[0..8].copy_from_slice(&25u64.to_le_bytes());
+    assert!(CombTable::<G1Projective>::deserialize_uncompressed(&invalid[..]).is_err());
+}
@@ -0,0 +1,179 @@
+use crate::vesta::{Fq, Fr};
+use algebra_core::{
+    biginteger::BigInteger256,
+    curves::{
+        glv::GLVParameters,
+        models::{ModelParameters, SWModelParameters},
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    field_new, Zero,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub type Affine = GroupAffine<VestaParameters>;
+pub type Projective = GroupProjective<VestaParameters>;
+
+/// The cycle formed by Pallas and Vesta.
+#[cfg(feature = "pallas")]
+#[derive(Copy, Clone, Debug)]
+pub struct PastaCycle;
+
+#[cfg(feature = "pallas")]
+impl algebra_core::curves::CurveCycle for PastaCycle {
+    type E1 = crate::pallas::Projective;
+    type E2 = Projective;
+}
+
+/// Vesta is the short Weierstrass curve y² = x³ + 5 over Fq, whose group of
+/// points has prime order r. Its base and scalar fields are respectively the
+/// scalar and base fields of Pallas.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct VestaParameters;
+
+impl ModelParameters for VestaParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for VestaParameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+
+    /// COEFF_B = 5
+    #[rustfmt::skip]
+    const COEFF_B: Fq = field_new!(Fq, BigInteger256([
+        0x96bc8c8cffffffed,
+        0x74c2a54b49f7778e,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ]));
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, BigInteger256([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]));
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (GENERATOR_X, GENERATOR_Y);
+
+    const GLV: Option<GLVParameters<Self>> = Some(GLVParameters {
+        lambda: GLV_LAMBDA,
+        basis: GLV_BASIS,
+        rounding: GLV_ROUNDING,
+        endomorphism,
+    });
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// The endomorphism `(x, y) -> (BETA * x, y)`.
+fn endomorphism(p: &Projective) -> Projective {
+    let mut res = *p;
+    res.x *= &BETA;
+    res
+}
+
+/// BETA = 2942865608506852014473558576493638302197734138389222805617480874486368177743,
+/// a primitive cube root of unity in Fq.
+#[rustfmt::skip]
+pub const BETA: Fq = field_new!(Fq, BigInteger256([
+    0x7c541a8480111122,
+    0x40630b9c56ed29da,
+    0x2c275fb135b2b29,
+    0x121d29f888245b10,
+]));
+
+/// GLV_LAMBDA = 8503465768106391777493614032514048814691664078728891710322960303815233784505,
+/// the eigenvalue of `endomorphism`.
+#[rustfmt::skip]
+const GLV_LAMBDA: Fr = field_new!(Fr, BigInteger256([
+    0x2021cf6619a153d,
+    0x9e8c26974980b78e,
+    0x2a676d5cc87a4666,
+    0x15d8049da7a17876,
+]));
+
+/// GLV_BASIS = [
+///     (98231058071186745657228807397848383488, -98231058071100081932162823354453065729),
+///     (98231058071100081932162823354453065729, 196462116142286827589391630752301449217),
+/// ]
+#[rustfmt::skip]
+const GLV_BASIS: [(Fr, Fr); 2] = [
+    (
+        field_new!(Fr, BigInteger256([
+            0x9a01a9d100000001,
+            0x291d06d324fe7fd9,
+            0x80025e27f75d1eb8,
+            0x186bf7a9a14ca0eb,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x9779620000000004,
+            0x6266586244e71618,
+            0xdefafacf6d01995f,
+            0x279408565e8cfaa1,
+        ])),
+    ),
+    (
+        field_new!(Fr, BigInteger256([
+            0x1b3ceecfffffffd,
+            0xbfe04099c465e303,
+            0x2105053092fe66a0,
+            0x186bf7a9a173055e,
+        ])),
+        field_new!(Fr, BigInteger256([
+            0x9bb578bdfffffffe,
+            0xe8fd476ce96462dc,
+            0xa10763588a5b8558,
+            0x30d7ef5342bfa649,
+        ])),
+    ),
+];
+
+/// GLV_ROUNDING = [round(2^256 * b2 / d), round(-2^256 * b1 / d)], where
+/// d = a1 * b2 - a2 * b1 = r
+#[rustfmt::skip]
+const GLV_ROUNDING: [BigInteger256; 2] = [
+    BigInteger256([
+        0x31f0256800000003,
+        0x4f34e8b2066389a4,
+        0x2,
+        0x0,
+    ]),
+    BigInteger256([
+        0x32c49e4c00000003,
+        0x279a745902a2654e,
+        0x1,
+        0x0,
+    ]),
+];
+
+/// GENERATOR_X = -1
+#[rustfmt::skip]
+pub const GENERATOR_X: Fq = field_new!(Fq, BigInteger256([
+    0x311bac8400000004,
+    0x891a63f02652a376,
+    0x0,
+    0x0,
+]));
+
+/// GENERATOR_Y = 2
+#[rustfmt::skip]
+pub const GENERATOR_Y: Fq = field_new!(Fq, BigInteger256([
+    0x2a0f9218fffffff9,
+    0x1011d11bbcef61f1,
+    0xffffffffffffffff,
+    0x3fffffffffffffff,
+]));
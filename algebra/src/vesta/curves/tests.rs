@@ -0,0 +1,42 @@
+use algebra_core::{
+    curves::AffineCurve,
+    test_rng,
+};
+use rand::Rng;
+
+use crate::{
+    vesta::*,
+    tests::{
+        curves::{curve_tests, sw_tests, sw_xyzz_tests},
+        groups::group_test,
+    },
+};
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<Projective>();
+
+    sw_tests::<VestaParameters>();
+    sw_xyzz_tests::<VestaParameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = test_rng();
+    let a: Projective = rng.gen();
+    let b: Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_generator() {
+    let generator = Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[cfg(feature = "pallas")]
+#[test]
+fn test_pasta_cycle() {
+    crate::tests::curves::curve_cycle_test::<PastaCycle>();
+}
@@ -0,0 +1 @@
+pub use crate::pallas::{Fr as Fq, FrParameters as FqParameters};
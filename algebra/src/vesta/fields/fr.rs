@@ -0,0 +1 @@
+pub use crate::pallas::{Fq as Fr, FqParameters as FrParameters};
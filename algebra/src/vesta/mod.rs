@@ -0,0 +1,17 @@
+//! This module implements the Vesta curve of the
+//! [Pasta cycle](https://electriccoin.co/blog/the-pasta-curves-for-halo-2-and-beyond/)
+//! of Hopwood. Its base field and scalar field respectively equal the scalar
+//! field and base field of Pallas.
+//!
+//! Curve information:
+//! * Base field: q = 28948022309329048855892746252171976963363056481941647379679742748393362948097
+//! * Scalar field: r = 28948022309329048855892746252171976963363056481941560715954676764349967630337
+//! * valuation(q - 1, 2) = 32
+//! * valuation(r - 1, 2) = 32
+//! * Curve equation: y^2 = x^3 + 5
+
+mod curves;
+mod fields;
+
+pub use curves::*;
+pub use fields::*;
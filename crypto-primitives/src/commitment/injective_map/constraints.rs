@@ -7,7 +7,7 @@ use crate::commitment::{
 };
 
 pub use crate::crh::injective_map::constraints::InjectiveMapGadget;
-use algebra_core::{Field, PrimeField, ProjectiveCurve};
+use algebra_core::{ConstantTimeCurve, Field, PrimeField, ProjectiveCurve};
 use r1cs_core::SynthesisError;
 use r1cs_std::{
     groups::{CurveVar, GroupOpsBounds},
@@ -36,7 +36,7 @@ impl<C, I, GG, IG, W>
     crate::commitment::CommitmentGadget<PedersenCommCompressor<C, I, W>, ConstraintF<C>>
     for CommitmentCompressorGadget<C, I, W, GG, IG>
 where
-    C: ProjectiveCurve,
+    C: ConstantTimeCurve,
     I: InjectiveMap<C>,
     GG: CurveVar<C, ConstraintF<C>>,
     ConstraintF<C>: PrimeField,
@@ -4,7 +4,7 @@ use rand::Rng;
 
 use super::{pedersen, CommitmentScheme};
 pub use crate::crh::injective_map::InjectiveMap;
-use algebra_core::ProjectiveCurve;
+use algebra_core::{ConstantTimeCurve, ProjectiveCurve};
 
 #[cfg(feature = "r1cs")]
 pub mod constraints;
@@ -15,7 +15,7 @@ pub struct PedersenCommCompressor<C: ProjectiveCurve, I: InjectiveMap<C>, W: ped
     _comm: pedersen::Commitment<C, W>,
 }
 
-impl<C: ProjectiveCurve, I: InjectiveMap<C>, W: pedersen::Window> CommitmentScheme
+impl<C: ConstantTimeCurve, I: InjectiveMap<C>, W: pedersen::Window> CommitmentScheme
     for PedersenCommCompressor<C, I, W>
 {
     type Output = I::Output;
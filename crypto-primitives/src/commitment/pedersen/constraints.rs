@@ -5,7 +5,7 @@ use crate::{
 };
 use algebra_core::{
     fields::{Field, PrimeField},
-    to_bytes, ProjectiveCurve, Zero,
+    to_bytes, ConstantTimeCurve, ProjectiveCurve, Zero,
 };
 use r1cs_core::{Namespace, SynthesisError};
 
@@ -43,7 +43,7 @@ where
 impl<C, GG, W> crate::commitment::CommitmentGadget<Commitment<C, W>, ConstraintF<C>>
     for CommGadget<C, GG, W>
 where
-    C: ProjectiveCurve,
+    C: ConstantTimeCurve,
     GG: CurveVar<C, ConstraintF<C>>,
     W: Window,
     for<'a> &'a GG: GroupOpsBounds<'a, C, GG>,
@@ -2,8 +2,8 @@ use crate::{Error, Vec};
 use algebra_core::{
     bytes::ToBytes,
     io::{Result as IoResult, Write},
-    BitIteratorLE, Field, FpParameters, PrimeField, ProjectiveCurve, ToConstraintField,
-    UniformRand,
+    BitIteratorLE, Choice, ConstantTimeCurve, Field, PrimeField, ProjectiveCurve,
+    ToConstraintField, UniformRand,
 };
 
 use core::marker::PhantomData;
@@ -45,7 +45,7 @@ impl<C: ProjectiveCurve> ToBytes for Randomness<C> {
     }
 }
 
-impl<C: ProjectiveCurve, W: Window> CommitmentScheme for Commitment<C, W> {
+impl<C: ConstantTimeCurve, W: Window> CommitmentScheme for Commitment<C, W> {
     type Parameters = Parameters<C>;
     type Randomness = Randomness<C>;
     type Output = C::Affine;
@@ -57,7 +57,7 @@ impl<C: ProjectiveCurve, W: Window> CommitmentScheme for Commitment<C, W> {
             W::WINDOW_SIZE,
             W::NUM_WINDOWS * W::WINDOW_SIZE
         ));
-        let num_powers = <C::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        let num_powers = <C::ScalarField as PrimeField>::size_in_bits();
         let randomness_generator = pedersen::CRH::<C, W>::generator_powers(num_powers, rng);
         let generators = pedersen::CRH::<C, W>::create_generators(rng);
         end_timer!(time);
@@ -74,29 +74,13 @@ impl<C: ProjectiveCurve, W: Window> CommitmentScheme for Commitment<C, W> {
         randomness: &Self::Randomness,
     ) -> Result<Self::Output, Error> {
         let commit_time = start_timer!(|| "PedersenCOMM::Commit");
-        // If the input is too long, return an error.
-        if input.len() > W::WINDOW_SIZE * W::NUM_WINDOWS {
-            panic!("incorrect input length: {:?}", input.len());
-        }
-        // Pad the input to the necessary length.
-        let mut padded_input = Vec::with_capacity(input.len());
-        let mut input = input;
-        if (input.len() * 8) < W::WINDOW_SIZE * W::NUM_WINDOWS {
-            let current_length = input.len();
-            padded_input.extend_from_slice(input);
-            for _ in current_length..((W::WINDOW_SIZE * W::NUM_WINDOWS) / 8) {
-                padded_input.push(0u8);
-            }
-            input = padded_input.as_slice();
-        }
-        assert_eq!(parameters.generators.len(), W::NUM_WINDOWS);
-
-        // Invoke Pedersen CRH here, to prevent code duplication.
+        // Invoke Pedersen CRH here, to prevent code duplication. The input and
+        // the randomness may be secret, so both are handled in constant time.
 
         let crh_parameters = pedersen::Parameters {
             generators: parameters.generators.clone(),
         };
-        let mut result: C = pedersen::CRH::<C, W>::evaluate(&crh_parameters, &input)?.into();
+        let mut result = pedersen::CRH::<C, W>::evaluate_ct(&crh_parameters, &input)?;
         let randomize_time = start_timer!(|| "Randomize");
 
         // Compute h^r.
@@ -104,14 +88,13 @@ impl<C: ProjectiveCurve, W: Window> CommitmentScheme for Commitment<C, W> {
             .into_iter()
             .zip(&parameters.randomness_generator)
         {
-            if bit {
-                result += power
-            }
+            let term = C::conditional_select(&C::zero(), power, Choice::from(bit as u8));
+            result = result.add_ct(&term);
         }
         end_timer!(randomize_time);
         end_timer!(commit_time);
 
-        Ok(result.into())
+        Ok(result.into_affine_ct())
     }
 }
 
@@ -8,7 +8,7 @@ use rand::Rng;
 use rayon::prelude::*;
 
 use crate::crh::FixedLengthCRH;
-use algebra_core::{Field, ProjectiveCurve, ToConstraintField};
+use algebra_core::{Choice, ConstantTimeCurve, Field, ProjectiveCurve, ToConstraintField};
 use ff_fft::cfg_chunks;
 
 #[cfg(feature = "r1cs")]
@@ -47,28 +47,33 @@ impl<C: ProjectiveCurve, W: Window> CRH<C, W> {
         }
         cur_gen_powers
     }
-}
 
-impl<C: ProjectiveCurve, W: Window> FixedLengthCRH for CRH<C, W> {
-    const INPUT_SIZE_BITS: usize = W::WINDOW_SIZE * W::NUM_WINDOWS;
-    type Output = C::Affine;
-    type Parameters = Parameters<C>;
+    /// Evaluates the hash like `evaluate`, but in time independent of the
+    /// input, for inputs that are secret. The result is left in projective
+    /// form, since converting it to affine form is not constant-time.
+    pub fn evaluate_ct(parameters: &Parameters<C>, input: &[u8]) -> Result<C, Error>
+    where
+        C: ConstantTimeCurve,
+    {
+        let eval_time = start_timer!(|| "PedersenCRH::EvalCT");
+
+        let bits = Self::input_bits(parameters, input);
+        let mut result = C::zero();
+        for (bits, generator_powers) in bits.chunks(W::WINDOW_SIZE).zip(&parameters.generators) {
+            for (bit, base) in bits.iter().zip(generator_powers) {
+                let term = C::conditional_select(&C::zero(), base, Choice::from(*bit as u8));
+                result = result.add_ct(&term);
+            }
+        }
 
-    fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, Error> {
-        let time = start_timer!(|| format!(
-            "PedersenCRH::Setup: {} {}-bit windows; {{0,1}}^{{{}}} -> C",
-            W::NUM_WINDOWS,
-            W::WINDOW_SIZE,
-            W::NUM_WINDOWS * W::WINDOW_SIZE
-        ));
-        let generators = Self::create_generators(rng);
-        end_timer!(time);
-        Ok(Self::Parameters { generators })
-    }
+        end_timer!(eval_time);
 
-    fn evaluate(parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
-        let eval_time = start_timer!(|| "PedersenCRH::Eval");
+        Ok(result)
+    }
 
+    /// Checks the lengths of `input` and `parameters`, and returns the bits of
+    /// `input` padded with zeros to the input size.
+    fn input_bits(parameters: &Parameters<C>, input: &[u8]) -> Vec<bool> {
         if (input.len() * 8) > W::WINDOW_SIZE * W::NUM_WINDOWS {
             panic!(
                 "incorrect input length {:?} for window params {:?}✕{:?}",
@@ -100,8 +105,32 @@ impl<C: ProjectiveCurve, W: Window> FixedLengthCRH for CRH<C, W> {
             W::NUM_WINDOWS
         );
 
+        bytes_to_bits(input)
+    }
+}
+
+impl<C: ProjectiveCurve, W: Window> FixedLengthCRH for CRH<C, W> {
+    const INPUT_SIZE_BITS: usize = W::WINDOW_SIZE * W::NUM_WINDOWS;
+    type Output = C::Affine;
+    type Parameters = Parameters<C>;
+
+    fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, Error> {
+        let time = start_timer!(|| format!(
+            "PedersenCRH::Setup: {} {}-bit windows; {{0,1}}^{{{}}} -> C",
+            W::NUM_WINDOWS,
+            W::WINDOW_SIZE,
+            W::NUM_WINDOWS * W::WINDOW_SIZE
+        ));
+        let generators = Self::create_generators(rng);
+        end_timer!(time);
+        Ok(Self::Parameters { generators })
+    }
+
+    fn evaluate(parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
+        let eval_time = start_timer!(|| "PedersenCRH::Eval");
+
         // Compute sum of h_i^{m_i} for all i.
-        let bits = bytes_to_bits(input);
+        let bits = Self::input_bits(parameters, input);
         let result = cfg_chunks!(bits, W::WINDOW_SIZE)
             .zip(&parameters.generators)
             .map(|(bits, generator_powers)| {
@@ -150,3 +179,31 @@ impl<ConstraintF: Field, C: ProjectiveCurve + ToConstraintField<ConstraintF>>
         Ok(Vec::new())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crh::pedersen::{Window, CRH},
+        FixedLengthCRH,
+    };
+    use algebra::{ed_on_bls12_381::EdwardsProjective, test_rng, ProjectiveCurve};
+
+    #[test]
+    fn test_evaluate_ct() {
+        #[derive(Clone)]
+        struct TestWindow {}
+        impl Window for TestWindow {
+            const WINDOW_SIZE: usize = 128;
+            const NUM_WINDOWS: usize = 8;
+        }
+        type H = CRH<EdwardsProjective, TestWindow>;
+
+        let rng = &mut test_rng();
+        let params = <H as FixedLengthCRH>::setup(rng).unwrap();
+        for input in &[&[][..], &[1, 2, 3], &[0xff; 128]] {
+            let expected = <H as FixedLengthCRH>::evaluate(&params, input).unwrap();
+            let result = H::evaluate_ct(&params, input).unwrap();
+            assert_eq!(result.into_affine(), expected);
+        }
+    }
+}
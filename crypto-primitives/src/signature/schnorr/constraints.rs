@@ -1,5 +1,5 @@
 use crate::Vec;
-use algebra_core::{Field, ProjectiveCurve};
+use algebra_core::{ConstantTimeCurve, ConstantTimeField, Field, ProjectiveCurve};
 use r1cs_core::{Namespace, SynthesisError};
 use r1cs_std::prelude::*;
 
@@ -48,7 +48,8 @@ where
 impl<C, GC, D> SigRandomizePkGadget<Schnorr<C, D>, ConstraintF<C>>
     for SchnorrRandomizePkGadget<C, GC>
 where
-    C: ProjectiveCurve,
+    C: ConstantTimeCurve,
+    C::ScalarField: ConstantTimeField,
     GC: CurveVar<C, ConstraintF<C>>,
     D: Digest + Send + Sync,
     for<'a> &'a GC: GroupOpsBounds<'a, C, GC>,
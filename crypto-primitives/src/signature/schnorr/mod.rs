@@ -1,9 +1,10 @@
 use crate::{Error, SignatureScheme, Vec};
 use algebra_core::{
     bytes::ToBytes,
-    fields::{Field, PrimeField},
+    fields::{ConstantTimeField, Field, PrimeField},
     io::{Result as IoResult, Write},
-    to_bytes, AffineCurve, One, ProjectiveCurve, ToConstraintField, UniformRand, Zero,
+    to_bytes, AffineCurve, Choice, ConditionallySelectable, ConstantTimeCurve, One,
+    ProjectiveCurve, ToConstraintField, UniformRand, Zero,
 };
 use core::{hash::Hash, marker::PhantomData};
 use digest::Digest;
@@ -43,9 +44,9 @@ pub struct Signature<C: ProjectiveCurve> {
     pub verifier_challenge: C::ScalarField,
 }
 
-impl<C: ProjectiveCurve + Hash, D: Digest + Send + Sync> SignatureScheme for Schnorr<C, D>
+impl<C: ConstantTimeCurve + Hash, D: Digest + Send + Sync> SignatureScheme for Schnorr<C, D>
 where
-    C::ScalarField: PrimeField,
+    C::ScalarField: PrimeField + ConstantTimeField,
 {
     type Parameters = Parameters<C, D>;
     type PublicKey = PublicKey<C>;
@@ -74,7 +75,9 @@ where
         let keygen_time = start_timer!(|| "SchnorrSig::KeyGen");
 
         let secret_key = C::ScalarField::rand(rng);
-        let public_key = parameters.generator.mul(secret_key).into();
+        let public_key = C::from(parameters.generator)
+            .mul_ct(&secret_key)
+            .into_affine_ct();
 
         end_timer!(keygen_time);
         Ok((public_key, SecretKey(secret_key)))
@@ -93,7 +96,9 @@ where
             let random_scalar: C::ScalarField = C::ScalarField::rand(rng);
             // Commit to the random scalar via r := k · G.
             // This is the prover's first msg in the Sigma protocol.
-            let prover_commitment = parameters.generator.mul(random_scalar).into_affine();
+            let prover_commitment = C::from(parameters.generator)
+                .mul_ct(&random_scalar)
+                .into_affine_ct();
 
             // Hash everything to get verifier challenge.
             let mut hash_input = Vec::new();
@@ -110,7 +115,7 @@ where
         };
 
         // k - xe;
-        let prover_response = random_scalar - &(verifier_challenge * &sk.0);
+        let prover_response = random_scalar.ct_sub(&verifier_challenge.ct_mul(&sk.0));
         let signature = Signature {
             prover_response,
             verifier_challenge,
@@ -161,23 +166,19 @@ where
         let rand_pk_time = start_timer!(|| "SchnorrSig::RandomizePubKey");
 
         let randomized_pk = *public_key;
-        let base = parameters.generator;
+        let base = C::from(parameters.generator);
+        // Go through every bit of the randomness, in constant time.
         let mut encoded = C::zero();
-        for bit in bytes_to_bits(randomness)
-            .into_iter()
-            .rev()
-            .skip_while(|b| !b)
-        {
-            encoded.double_in_place();
-            if bit {
-                encoded.add_assign_mixed(&base)
-            }
+        for bit in bytes_to_bits(randomness).into_iter().rev() {
+            encoded = encoded.double_ct();
+            let term = C::conditional_select(&C::zero(), &base, Choice::from(bit as u8));
+            encoded = encoded.add_ct(&term);
         }
-        encoded.add_assign_mixed(&randomized_pk);
+        encoded = encoded.add_ct(&C::from(randomized_pk));
 
         end_timer!(rand_pk_time);
 
-        Ok(encoded.into())
+        Ok(encoded.into_affine_ct())
     }
 
     fn randomize_signature(
@@ -193,14 +194,17 @@ where
         let mut base = C::ScalarField::one();
         let mut multiplier = C::ScalarField::zero();
         for bit in bytes_to_bits(randomness) {
-            if bit {
-                multiplier += &base;
-            }
+            let term = C::ScalarField::conditional_select(
+                &C::ScalarField::zero(),
+                &base,
+                Choice::from(bit as u8),
+            );
+            multiplier = multiplier.ct_add(&term);
             base.double_in_place();
         }
 
         let new_sig = Signature {
-            prover_response: *prover_response - &(*verifier_challenge * &multiplier),
+            prover_response: prover_response.ct_sub(&verifier_challenge.ct_mul(&multiplier)),
             verifier_challenge: *verifier_challenge,
         };
         end_timer!(rand_signature_time);
@@ -224,6 +228,6 @@ impl<ConstraintF: Field, C: ProjectiveCurve + ToConstraintField<ConstraintF>, D:
 {
     #[inline]
     fn to_field_elements(&self) -> Result<Vec<ConstraintF>, Error> {
-        self.generator.into_projective().to_field_elements()
+        C::from(self.generator).to_field_elements()
     }
 }
@@ -10,7 +10,7 @@ use crate::dpc::{
     },
     Record,
 };
-use algebra::{to_bytes, FpParameters, PrimeField, ToConstraintField};
+use algebra::{to_bytes, PrimeField, ToConstraintField};
 use r1cs_core::{ConstraintSystemRef, SynthesisError};
 use r1cs_std::{boolean::Boolean, prelude::*};
 
@@ -686,7 +686,7 @@ where
         &local_data_bytes,
     )?;
 
-    let e_fr_num_bits = <C::CoreCheckF as PrimeField>::Params::MODULUS_BITS as usize;
+    let e_fr_num_bits = <C::CoreCheckF as PrimeField>::size_in_bits();
     let e_fr_num_bits_nearest_64 =
         e_fr_num_bits / 64 * 64 + (if e_fr_num_bits % 64 == 0 { 0 } else { 64 });
 
@@ -11,7 +11,7 @@ use crate::domain::{
     DomainCoeff, EvaluationDomain, MixedRadixEvaluationDomain, Radix2EvaluationDomain,
 };
 use crate::Vec;
-use algebra_core::{FftField, FftParameters};
+use algebra_core::FftField;
 
 /// Defines a domain over which finite field (I)FFTs can be performed.
 /// Generally tries to build a radix-2 domain and falls back to a mixed-radix
@@ -39,7 +39,7 @@ impl<F: FftField> EvaluationDomain<F> for GeneralEvaluationDomain<F> {
             return Some(GeneralEvaluationDomain::Radix2(domain));
         }
 
-        if F::FftParams::SMALL_SUBGROUP_BASE.is_some() {
+        if F::small_subgroup_base().is_some() {
             return Some(GeneralEvaluationDomain::MixedRadix(
                 MixedRadixEvaluationDomain::new(num_coeffs)?,
             ));
@@ -54,7 +54,7 @@ impl<F: FftField> EvaluationDomain<F> for GeneralEvaluationDomain<F> {
             return Some(domain_size);
         }
 
-        if F::FftParams::SMALL_SUBGROUP_BASE.is_some() {
+        if F::small_subgroup_base().is_some() {
             return Some(MixedRadixEvaluationDomain::<F>::compute_size_of_domain(
                 num_coeffs,
             )?);
@@ -15,7 +15,7 @@ use crate::domain::{
     DomainCoeff, EvaluationDomain,
 };
 use crate::Vec;
-use algebra_core::{fields::utils::k_adicity, FftField, FftParameters};
+use algebra_core::{fields::utils::k_adicity, FftField};
 use core::cmp::min;
 use core::convert::TryFrom;
 use core::fmt;
@@ -59,7 +59,7 @@ impl<F: FftField> EvaluationDomain<F> for MixedRadixEvaluationDomain<F> {
     /// Construct a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
     fn new(num_coeffs: usize) -> Option<Self> {
-        let small_subgroup_base = F::FftParams::SMALL_SUBGROUP_BASE?;
+        let small_subgroup_base = F::small_subgroup_base()?;
 
         // Compute the best size of our evaluation domain.
         let num_coeffs = best_mixed_domain_size::<F>(num_coeffs);
@@ -99,7 +99,7 @@ impl<F: FftField> EvaluationDomain<F> for MixedRadixEvaluationDomain<F> {
     }
 
     fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
-        let small_subgroup_base = F::FftParams::SMALL_SUBGROUP_BASE?;
+        let small_subgroup_base = F::small_subgroup_base()?;
 
         // Compute the best size of our evaluation domain.
         let num_coeffs = best_mixed_domain_size::<F>(num_coeffs);
@@ -250,8 +250,8 @@ fn mixed_radix_fft_permute(
 
 fn best_mixed_domain_size<F: FftField>(min_size: usize) -> usize {
     let mut best = usize::max_value();
-    let small_subgroup_base_adicity = F::FftParams::SMALL_SUBGROUP_BASE_ADICITY.unwrap();
-    let small_subgroup_base = usize::try_from(F::FftParams::SMALL_SUBGROUP_BASE.unwrap()).unwrap();
+    let small_subgroup_base_adicity = F::small_subgroup_base_adicity().unwrap();
+    let small_subgroup_base = usize::try_from(F::small_subgroup_base().unwrap()).unwrap();
 
     for b in 0..=small_subgroup_base_adicity {
         let mut r = small_subgroup_base.pow(b);
@@ -262,7 +262,7 @@ fn best_mixed_domain_size<F: FftField>(min_size: usize) -> usize {
             two_adicity += 1;
         }
 
-        if two_adicity <= F::FftParams::TWO_ADICITY {
+        if two_adicity <= F::two_adicity() {
             best = min(best, r);
         }
     }
@@ -279,7 +279,7 @@ pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
     // and then splits into q sub-arrays q_adicity many times.
 
     let n = a.len();
-    let q = usize::try_from(F::FftParams::SMALL_SUBGROUP_BASE.unwrap()).unwrap();
+    let q = usize::try_from(F::small_subgroup_base().unwrap()).unwrap();
 
     let q_adicity = k_adicity(q, n);
     let q_part = q.pow(q_adicity);
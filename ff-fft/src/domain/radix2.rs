@@ -9,7 +9,7 @@ use crate::domain::{
     DomainCoeff, EvaluationDomain,
 };
 use crate::Vec;
-use algebra_core::{FftField, FftParameters};
+use algebra_core::FftField;
 use core::convert::TryFrom;
 use core::fmt;
 #[cfg(feature = "parallel")]
@@ -53,7 +53,7 @@ impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
         let log_size_of_group = size.trailing_zeros();
 
         // libfqfft uses > https://github.com/scipr-lab/libfqfft/blob/e0183b2cef7d4c5deb21a6eaf3fe3b586d738fe0/libfqfft/evaluation_domain/domains/basic_radix2_domain.tcc#L33
-        if log_size_of_group > F::FftParams::TWO_ADICITY {
+        if log_size_of_group > F::two_adicity() {
             return None;
         }
 
@@ -78,7 +78,7 @@ impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
 
     fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
         let size = num_coeffs.next_power_of_two();
-        if size.trailing_zeros() > F::FftParams::TWO_ADICITY {
+        if size.trailing_zeros() > F::two_adicity() {
             None
         } else {
             Some(size)
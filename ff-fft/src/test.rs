@@ -1,16 +1,39 @@
 use crate::domain::*;
 use algebra::{
+    baby_bear::Fq as BabyBearFq,
     bls12_381::{Fr, G1Projective},
+    goldilocks::Fq as GoldilocksFq,
+    mersenne31::Fq2 as M31Fq2,
     mnt6_753::{Fr as MNT6Fr, G1Projective as MNT6G1Projective},
 };
-use algebra_core::{test_rng, PrimeField, UniformRand};
+use algebra_core::{test_rng, FftField, UniformRand};
+#[cfg(feature = "std")]
+use algebra_core::{BigInteger64, DynFp64, DynFpContext, DynFpContextCell, DynFpParameters};
+
+#[cfg(feature = "std")]
+static DYN_CONTEXT: DynFpContextCell<BigInteger64> = DynFpContextCell::new();
+
+#[cfg(feature = "std")]
+struct DynParameters;
+
+#[cfg(feature = "std")]
+impl DynFpParameters for DynParameters {
+    type BigInt = BigInteger64;
+
+    fn context() -> &'static DynFpContext<BigInteger64> {
+        DYN_CONTEXT.get()
+    }
+}
+
+#[cfg(feature = "std")]
+type DynF = DynFp64<DynParameters>;
 
 // Test multiplying various (low degree) polynomials together and
 // comparing with naive evaluations.
 #[test]
 fn fft_composition() {
     fn test_fft_composition<
-        F: PrimeField,
+        F: FftField,
         T: DomainCoeff<F> + UniformRand + core::fmt::Debug + Eq,
         R: rand::Rng,
         D: EvaluationDomain<F>,
@@ -56,4 +79,20 @@ fn fft_composition() {
     // This will result in a mixed-radix domain being used.
     test_fft_composition::<MNT6Fr, MNT6Fr, _, MixedRadixEvaluationDomain<MNT6Fr>>(rng, 17);
     test_fft_composition::<MNT6Fr, MNT6G1Projective, _, MixedRadixEvaluationDomain<MNT6Fr>>(rng, 5);
+    // Single-limb fields.
+    test_fft_composition::<GoldilocksFq, GoldilocksFq, _, Radix2EvaluationDomain<GoldilocksFq>>(
+        rng, 12,
+    );
+    test_fft_composition::<BabyBearFq, BabyBearFq, _, Radix2EvaluationDomain<BabyBearFq>>(rng, 12);
+    // The complex extension of Mersenne-31, as M31 itself has two-adicity 1.
+    test_fft_composition::<M31Fq2, M31Fq2, _, Radix2EvaluationDomain<M31Fq2>>(rng, 12);
+
+    // A field whose modulus, 15 * 2^27 + 1, is only set at runtime.
+    #[cfg(feature = "std")]
+    {
+        let context =
+            DynFpContext::new(BigInteger64::from(2013265921), BigInteger64::from(31)).unwrap();
+        DYN_CONTEXT.set(context).unwrap();
+        test_fft_composition::<DynF, DynF, _, GeneralEvaluationDomain<DynF>>(rng, 10);
+    }
 }
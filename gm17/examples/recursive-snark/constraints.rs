@@ -1,7 +1,4 @@
-use algebra::{
-    fields::{FftParameters, FpParameters},
-    BigInteger, Field, PrimeField,
-};
+use algebra::{BigInteger, Field, PrimeField};
 use algebra_core::{PairingEngine, ToConstraintField};
 use core::ops::MulAssign;
 use crypto_primitives::nizk::{
@@ -201,7 +198,7 @@ where
             let input_bytes = UInt8::new_input_vec(r1cs_core::ns!(cs, "Input"), &input_bytes[..])?;
             // 40 byte
             let element_size =
-                <<<C::TickGroup as PairingEngine>::Fr as PrimeField>::Params as FftParameters>::BigInt::NUM_LIMBS * 8;
+                <<C::TickGroup as PairingEngine>::Fr as PrimeField>::BigInt::NUM_LIMBS * 8;
             input_gadgets = input_bytes
                 .chunks(element_size)
                 .map(|c| {
@@ -319,11 +316,10 @@ where
             }
 
             // Pack input bits into field elements of the underlying circuit.
-            let max_size = 8
-                * (<<<C::TockGroup as PairingEngine>::Fr as PrimeField>::Params as FpParameters>::CAPACITY / 8)
-                    as usize;
+            let max_size =
+                8 * (<<C::TockGroup as PairingEngine>::Fr as PrimeField>::capacity() / 8);
             let bigint_size =
-                <<<C::TockGroup as PairingEngine>::Fr as PrimeField>::Params as FftParameters>::BigInt::NUM_LIMBS * 64;
+                <<C::TockGroup as PairingEngine>::Fr as PrimeField>::BigInt::NUM_LIMBS * 64;
             for chunk in input_bits.chunks(max_size) {
                 let mut chunk = chunk.to_vec();
                 let len = chunk.len();
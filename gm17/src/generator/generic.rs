@@ -1,5 +1,6 @@
 use algebra_core::{
-    msm::FixedBaseMSM, Field, One, PairingEngine, PrimeField, ProjectiveCurve, UniformRand, Zero,
+    msm::{CombTable, FixedBaseMSM},
+    Field, One, PairingEngine, PrimeField, ProjectiveCurve, UniformRand, Zero,
 };
 use ff_fft::{cfg_into_iter, cfg_iter, EvaluationDomain};
 
@@ -8,7 +9,7 @@ use rand::Rng;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use crate::{r1cs_to_sap::R1CStoSAP, Parameters, Vec, VerifyingKey};
+use crate::{r1cs_to_sap::R1CStoSAP, GeneratorTables, Parameters, Vec, VerifyingKey};
 
 /// Generates a random common reference string for
 /// a circuit.
@@ -31,6 +32,26 @@ where
     generate_parameters::<E, C, D, R>(circuit, alpha, beta, gamma, g, h, rng)
 }
 
+/// Generates a random common reference string for a circuit, using the
+/// generators of the given tables.
+pub fn generate_random_parameters_with_tables<E, C, D, R>(
+    circuit: C,
+    tables: &GeneratorTables<E>,
+    rng: &mut R,
+) -> Result<Parameters<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    R: Rng,
+{
+    let alpha = E::Fr::rand(rng);
+    let beta = E::Fr::rand(rng);
+    let gamma = E::Fr::one();
+
+    generate_parameters_with_tables::<E, C, D, R>(circuit, alpha, beta, gamma, tables, rng)
+}
+
 /// Create parameters for a circuit, given some toxic waste.
 pub fn generate_parameters<E, C, D, R>(
     circuit: C,
@@ -41,6 +62,44 @@ pub fn generate_parameters<E, C, D, R>(
     h: E::G2Projective,
     rng: &mut R,
 ) -> Result<Parameters<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    R: Rng,
+{
+    generate_parameters_inner::<E, C, D, R>(circuit, alpha, beta, gamma, Err((g, h)), rng)
+}
+
+/// Create parameters for a circuit, given some toxic waste, using the
+/// generators `g` and `h` of the given tables.
+pub fn generate_parameters_with_tables<E, C, D, R>(
+    circuit: C,
+    alpha: E::Fr,
+    beta: E::Fr,
+    gamma: E::Fr,
+    tables: &GeneratorTables<E>,
+    rng: &mut R,
+) -> Result<Parameters<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    R: Rng,
+{
+    generate_parameters_inner::<E, C, D, R>(circuit, alpha, beta, gamma, Ok(tables), rng)
+}
+
+/// Create parameters for a circuit, given some toxic waste and either the
+/// tables of the generators or the generators themselves.
+fn generate_parameters_inner<E, C, D, R>(
+    circuit: C,
+    alpha: E::Fr,
+    beta: E::Fr,
+    gamma: E::Fr,
+    tables: Result<&GeneratorTables<E>, (E::G1Projective, E::G2Projective)>,
+    rng: &mut R,
+) -> Result<Parameters<E>, SynthesisError>
 where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
@@ -85,32 +144,47 @@ where
         .sum();
     let scalar_bits = E::Fr::size_in_bits();
 
-    // Compute G window table
-    let g_window_time = start_timer!(|| "Compute G window table");
-    let g_window = FixedBaseMSM::get_mul_window_size(
-        // Verifier query
-        num_inputs
-        // A query
-        + non_zero_a
-        // C query 1
-        + (sap_num_variables - (num_inputs - 1))
-        // C query 2
-        + sap_num_variables + 1
-        // G gamma2 Z t
-        + m_raw + 1,
-    );
-    let g_table = FixedBaseMSM::get_window_table::<E::G1Projective>(scalar_bits, g_window, g);
-    end_timer!(g_window_time);
+    // Compute the G and H window tables, unless they are given. A comb
+    // with one block per bit of a row is a plain window table.
+    let new_tables;
+    let tables = match tables {
+        Ok(tables) => tables,
+        Err((g, h)) => {
+            let g_window_time = start_timer!(|| "Compute G window table");
+            let g_window = FixedBaseMSM::get_mul_window_size(
+                // Verifier query
+                num_inputs
+                // A query
+                + non_zero_a
+                // C query 1
+                + (sap_num_variables - (num_inputs - 1))
+                // C query 2
+                + sap_num_variables + 1
+                // G gamma2 Z t
+                + m_raw + 1,
+            );
+            let g1_table = CombTable::new(g, g_window, scalar_bits);
+            end_timer!(g_window_time);
+
+            let h_window_time = start_timer!(|| "Compute H window table");
+            let h_window = FixedBaseMSM::get_mul_window_size(non_zero_a);
+            let g2_table = CombTable::new(h, h_window, scalar_bits);
+            end_timer!(h_window_time);
+
+            new_tables = GeneratorTables { g1_table, g2_table };
+            &new_tables
+        },
+    };
+    let g = tables.g1_table.generator();
+    let h = tables.g2_table.generator();
 
     // Generate the R1CS proving key
     let proving_key_time = start_timer!(|| "Generate the R1CS proving key");
 
     // Compute the A-query
     let a_time = start_timer!(|| "Calculate A");
-    let mut a_query = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
-        g_window,
-        &g_table,
+    let mut a_query = FixedBaseMSM::multi_scalar_mul_comb(
+        &tables.g1_table,
         &cfg_iter!(a).map(|a| *a * &gamma).collect::<Vec<_>>(),
     );
     end_timer!(a_time);
@@ -129,10 +203,8 @@ where
 
     // Compute the vector G_gamma2_z_t := Z(t) * t^i * gamma^2 * G
     let gamma2_z_t = gamma_z * &gamma;
-    let mut g_gamma2_z_t = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
-        g_window,
-        &g_table,
+    let mut g_gamma2_z_t = FixedBaseMSM::multi_scalar_mul_comb(
+        &tables.g1_table,
         &cfg_into_iter!(0..m_raw + 1)
             .map(|i| gamma2_z_t * &(t.pow([i as u64])))
             .collect::<Vec<_>>(),
@@ -141,10 +213,8 @@ where
 
     // Compute the C_1-query
     let c1_time = start_timer!(|| "Calculate C1");
-    let result = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
-        g_window,
-        &g_table,
+    let result = FixedBaseMSM::multi_scalar_mul_comb(
+        &tables.g1_table,
         &cfg_into_iter!(0..sap_num_variables + 1)
             .map(|i| c[i] * &gamma + &(a[i] * &alpha_beta))
             .collect::<Vec<_>>(),
@@ -155,31 +225,19 @@ where
     // Compute the C_2-query
     let c2_time = start_timer!(|| "Calculate C2");
     let double_gamma2_z = (zt * &gamma.square()).double();
-    let mut c_query_2 = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
-        g_window,
-        &g_table,
+    let mut c_query_2 = FixedBaseMSM::multi_scalar_mul_comb(
+        &tables.g1_table,
         &cfg_into_iter!(0..sap_num_variables + 1)
             .map(|i| a[i] * &double_gamma2_z)
             .collect::<Vec<_>>(),
     );
-    drop(g_table);
     end_timer!(c2_time);
 
-    // Compute H_gamma window table
-    let h_gamma_time = start_timer!(|| "Compute H table");
-    let h_gamma_window = FixedBaseMSM::get_mul_window_size(non_zero_a);
-    let h_gamma_table =
-        FixedBaseMSM::get_window_table::<E::G2Projective>(scalar_bits, h_gamma_window, h_gamma);
-    end_timer!(h_gamma_time);
-
-    // Compute the B-query
+    // Compute the B-query, the multiples of H_gamma
     let b_time = start_timer!(|| "Calculate B");
-    let mut b_query = FixedBaseMSM::multi_scalar_mul::<E::G2Projective>(
-        scalar_bits,
-        h_gamma_window,
-        &h_gamma_table,
-        &a,
+    let mut b_query = FixedBaseMSM::multi_scalar_mul_comb(
+        &tables.g2_table,
+        &cfg_iter!(a).map(|a| *a * &gamma).collect::<Vec<_>>(),
     );
     end_timer!(b_time);
 
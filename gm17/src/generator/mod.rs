@@ -1,4 +1,4 @@
-use crate::Parameters;
+use crate::{GeneratorTables, Parameters};
 use algebra_core::PairingEngine;
 use ff_fft::GeneralEvaluationDomain;
 use r1cs_core::{ConstraintSynthesizer, SynthesisError};
@@ -43,3 +43,42 @@ where
         circuit, alpha, beta, gamma, g, h, rng,
     )
 }
+
+/// Generates a random common reference string for a circuit, using the
+/// generators of the given tables.
+#[inline]
+pub fn generate_random_parameters_with_tables<E, C, R>(
+    circuit: C,
+    tables: &GeneratorTables<E>,
+    rng: &mut R,
+) -> Result<Parameters<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    self::generic::generate_random_parameters_with_tables::<E, C, GeneralEvaluationDomain<E::Fr>, R>(
+        circuit, tables, rng,
+    )
+}
+
+/// Create parameters for a circuit, given some toxic waste, using the
+/// generators `g` and `h` of the given tables.
+#[inline]
+pub fn generate_parameters_with_tables<E, C, R>(
+    circuit: C,
+    alpha: E::Fr,
+    beta: E::Fr,
+    gamma: E::Fr,
+    tables: &GeneratorTables<E>,
+    rng: &mut R,
+) -> Result<Parameters<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    self::generic::generate_parameters_with_tables::<E, C, GeneralEvaluationDomain<E::Fr>, R>(
+        circuit, alpha, beta, gamma, tables, rng,
+    )
+}
@@ -26,6 +26,7 @@ use std::vec::Vec;
 use algebra_core::{
     bytes::ToBytes,
     io::{self, Result as IoResult},
+    msm::{CombTable, PreparedMSMBases},
     serialize::*,
     PairingEngine,
 };
@@ -113,6 +114,25 @@ impl<E: PairingEngine> Default for VerifyingKey<E> {
     }
 }
 
+/// Comb tables of the generators `g` of G1 and `h` of G2 of a setup. Setups
+/// for different circuits can reuse the same tables.
+#[derive(PartialEq, Eq, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct GeneratorTables<E: PairingEngine> {
+    pub g1_table: CombTable<E::G1Projective>,
+    pub g2_table: CombTable<E::G2Projective>,
+}
+
+impl<E: PairingEngine> GeneratorTables<E> {
+    /// Builds the tables for the given generators, as combs with `teeth` rows
+    /// split into `blocks` blocks (see `CombTable`).
+    pub fn new(g: E::G1Projective, h: E::G2Projective, teeth: usize, blocks: usize) -> Self {
+        Self {
+            g1_table: CombTable::new(g, teeth, blocks),
+            g2_table: CombTable::new(h, teeth, blocks),
+        }
+    }
+}
+
 /// Full public (prover and verifier) parameters for the GM17 zkSNARK.
 #[derive(PartialEq, Eq, Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Parameters<E: PairingEngine> {
@@ -128,6 +148,104 @@ pub struct Parameters<E: PairingEngine> {
     pub g_gamma2_z_t: Vec<E::G1Affine>,
 }
 
+/// Proving parameters whose query vectors are prepared for multi-scalar
+/// multiplication, which makes proving faster at the expense of larger size
+/// in memory. The first elements of the A, B and second C queries are left
+/// out of the prepared queries.
+///
+/// Only `params` and `num_copies` are serialized; the prepared queries are
+/// recomputed on deserialization, so they always match `params`.
+#[derive(PartialEq, Eq, Clone)]
+pub struct PreparedParameters<E: PairingEngine> {
+    pub params: Parameters<E>,
+    pub num_copies: usize,
+    pub a_query: PreparedMSMBases<E::G1Affine>,
+    pub b_query: PreparedMSMBases<E::G2Affine>,
+    pub c_query_1: PreparedMSMBases<E::G1Affine>,
+    pub c_query_2: PreparedMSMBases<E::G1Affine>,
+    pub g_gamma2_z_t: PreparedMSMBases<E::G1Affine>,
+}
+
+impl<E: PairingEngine> PreparedParameters<E> {
+    /// Prepares the query vectors of `params`, storing `num_copies` shifted
+    /// copies of each of them (see `PreparedMSMBases`).
+    pub fn new(params: Parameters<E>, num_copies: usize) -> Self {
+        let a_query = PreparedMSMBases::new(&params.a_query[1..], num_copies);
+        let b_query = PreparedMSMBases::new(&params.b_query[1..], num_copies);
+        let c_query_1 = PreparedMSMBases::new(&params.c_query_1, num_copies);
+        let c_query_2 = PreparedMSMBases::new(&params.c_query_2[1..], num_copies);
+        let g_gamma2_z_t = PreparedMSMBases::new(&params.g_gamma2_z_t, num_copies);
+        Self {
+            params,
+            num_copies,
+            a_query,
+            b_query,
+            c_query_1,
+            c_query_2,
+            g_gamma2_z_t,
+        }
+    }
+}
+
+impl<E: PairingEngine> CanonicalSerialize for PreparedParameters<E> {
+    #[inline]
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.params.serialize(&mut writer)?;
+        self.num_copies.serialize(&mut writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.params.serialized_size() + self.num_copies.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.params.serialize_uncompressed(&mut writer)?;
+        self.num_copies.serialize(&mut writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.params.serialize_unchecked(&mut writer)?;
+        self.num_copies.serialize(&mut writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.params.uncompressed_size() + self.num_copies.serialized_size()
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for PreparedParameters<E> {
+    #[inline]
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let params = Parameters::deserialize(&mut reader)?;
+        let num_copies = usize::deserialize(&mut reader)?;
+        Ok(Self::new(params, num_copies))
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let params = Parameters::deserialize_uncompressed(&mut reader)?;
+        let num_copies = usize::deserialize(&mut reader)?;
+        Ok(Self::new(params, num_copies))
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let params = Parameters::deserialize_unchecked(&mut reader)?;
+        let num_copies = usize::deserialize(&mut reader)?;
+        Ok(Self::new(params, num_copies))
+    }
+}
+
+impl<E: PairingEngine> From<PreparedParameters<E>> for Parameters<E> {
+    fn from(other: PreparedParameters<E>) -> Self {
+        other.params
+    }
+}
+
 /// Preprocessed verification key parameters that enable faster verification
 /// at the expense of larger size in memory.
 #[derive(PartialEq, Eq, Clone)]
@@ -6,12 +6,12 @@ use algebra_core::{
     msm::VariableBaseMSM, AffineCurve, PairingEngine, PrimeField, ProjectiveCurve, UniformRand,
 };
 
-use crate::{r1cs_to_sap::R1CStoSAP, Parameters, Proof, Vec};
+use crate::{r1cs_to_sap::R1CStoSAP, Parameters, PreparedParameters, Proof, Vec};
 use ff_fft::{cfg_into_iter, EvaluationDomain};
 
 use r1cs_core::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
 
-use core::ops::{AddAssign, MulAssign};
+use core::ops::AddAssign;
 
 pub fn create_random_proof<E, C, D, R>(
     circuit: C,
@@ -42,6 +42,126 @@ where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
     D: EvaluationDomain<E::Fr>,
+{
+    create_proof_with_msm::<E, C, D, _>(circuit, params, d1, d2, r)
+}
+
+pub fn create_random_proof_with_prepared_parameters<E, C, D, R>(
+    circuit: C,
+    params: &PreparedParameters<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    R: Rng,
+{
+    let d1 = E::Fr::rand(rng);
+    let d2 = E::Fr::rand(rng);
+    let r = E::Fr::rand(rng);
+
+    create_proof_with_prepared_parameters::<E, C, D>(circuit, params, d1, d2, r)
+}
+
+pub fn create_proof_with_prepared_parameters<E, C, D>(
+    circuit: C,
+    params: &PreparedParameters<E>,
+    d1: E::Fr,
+    d2: E::Fr,
+    r: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+{
+    create_proof_with_msm::<E, C, D, _>(circuit, params, d1, d2, r)
+}
+
+type BigInt<E> = <<E as PairingEngine>::Fr as PrimeField>::BigInt;
+
+/// The parameters of the prover. The multi-scalar multiplications over the A,
+/// B and second C queries leave out their first elements, and take the input
+/// and auxiliary assignments together.
+trait QueryMSM<E: PairingEngine> {
+    fn params(&self) -> &Parameters<E>;
+
+    fn a_query_msm(&self, scalars: &[BigInt<E>]) -> E::G1Projective;
+
+    fn b_query_msm(&self, scalars: &[BigInt<E>]) -> E::G2Projective;
+
+    fn c_query_1_msm(&self, aux_scalars: &[BigInt<E>]) -> E::G1Projective;
+
+    fn c_query_2_msm(&self, scalars: &[BigInt<E>]) -> E::G1Projective;
+
+    fn g_gamma2_z_t_msm(&self, h: &[BigInt<E>]) -> E::G1Projective;
+}
+
+impl<'a, E: PairingEngine> QueryMSM<E> for &'a Parameters<E> {
+    fn params(&self) -> &Parameters<E> {
+        self
+    }
+
+    fn a_query_msm(&self, scalars: &[BigInt<E>]) -> E::G1Projective {
+        VariableBaseMSM::multi_scalar_mul(&self.a_query[1..], scalars)
+    }
+
+    fn b_query_msm(&self, scalars: &[BigInt<E>]) -> E::G2Projective {
+        VariableBaseMSM::multi_scalar_mul(&self.b_query[1..], scalars)
+    }
+
+    fn c_query_1_msm(&self, aux_scalars: &[BigInt<E>]) -> E::G1Projective {
+        VariableBaseMSM::multi_scalar_mul(&self.c_query_1, aux_scalars)
+    }
+
+    fn c_query_2_msm(&self, scalars: &[BigInt<E>]) -> E::G1Projective {
+        VariableBaseMSM::multi_scalar_mul(&self.c_query_2[1..], scalars)
+    }
+
+    fn g_gamma2_z_t_msm(&self, h: &[BigInt<E>]) -> E::G1Projective {
+        VariableBaseMSM::multi_scalar_mul(&self.g_gamma2_z_t, h)
+    }
+}
+
+impl<'a, E: PairingEngine> QueryMSM<E> for &'a PreparedParameters<E> {
+    fn params(&self) -> &Parameters<E> {
+        &self.params
+    }
+
+    fn a_query_msm(&self, scalars: &[BigInt<E>]) -> E::G1Projective {
+        VariableBaseMSM::multi_scalar_mul_prepared(&self.a_query, scalars)
+    }
+
+    fn b_query_msm(&self, scalars: &[BigInt<E>]) -> E::G2Projective {
+        VariableBaseMSM::multi_scalar_mul_prepared(&self.b_query, scalars)
+    }
+
+    fn c_query_1_msm(&self, aux_scalars: &[BigInt<E>]) -> E::G1Projective {
+        VariableBaseMSM::multi_scalar_mul_prepared(&self.c_query_1, aux_scalars)
+    }
+
+    fn c_query_2_msm(&self, scalars: &[BigInt<E>]) -> E::G1Projective {
+        VariableBaseMSM::multi_scalar_mul_prepared(&self.c_query_2, scalars)
+    }
+
+    fn g_gamma2_z_t_msm(&self, h: &[BigInt<E>]) -> E::G1Projective {
+        VariableBaseMSM::multi_scalar_mul_prepared(&self.g_gamma2_z_t, h)
+    }
+}
+
+fn create_proof_with_msm<E, C, D, Q>(
+    circuit: C,
+    queries: Q,
+    d1: E::Fr,
+    d2: E::Fr,
+    r: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    Q: QueryMSM<E>,
 {
     let prover_time = start_timer!(|| "GrothMaller17::Prover");
     let cs = ConstraintSystem::new_ref();
@@ -61,56 +181,40 @@ where
     let prover = cs.borrow().unwrap();
     let num_inputs = prover.num_instance_variables;
 
-    let input_assignment = full_input_assignment[1..num_inputs]
-        .iter()
-        .map(|s| s.into_repr())
-        .collect::<Vec<_>>();
-
-    let aux_assignment = cfg_into_iter!(full_input_assignment[num_inputs..])
+    let assignment = cfg_into_iter!(full_input_assignment[1..])
         .map(|s| s.into_repr())
         .collect::<Vec<_>>();
+    let aux_assignment = &assignment[num_inputs - 1..];
     drop(full_input_assignment);
 
-    let h_input = h[0..num_inputs]
-        .iter()
-        .map(|s| s.into_repr())
-        .collect::<Vec<_>>();
-    let h_aux = cfg_into_iter!(h[num_inputs..])
-        .map(|s| s.into_repr())
-        .collect::<Vec<_>>();
-    drop(h);
+    let h = cfg_into_iter!(h).map(|s| s.into_repr()).collect::<Vec<_>>();
+
+    let params = queries.params();
 
     // Compute A
     let a_acc_time = start_timer!(|| "Compute A");
-    let (a_inputs_source, a_aux_source) = params.get_a_query(num_inputs)?;
-    let a_inputs_acc = VariableBaseMSM::multi_scalar_mul(a_inputs_source, &input_assignment);
-    let a_aux_acc = VariableBaseMSM::multi_scalar_mul(a_aux_source, &aux_assignment);
+    let a_acc = queries.a_query_msm(&assignment);
 
-    let r_g = params.get_g_gamma_z()?.mul(r);
-    let d1_g = params.get_g_gamma_z()?.mul(d1);
+    let r_g = params.get_g_gamma_z()?.mul_glv(&r);
+    let d1_g = params.get_g_gamma_z()?.mul_glv(&d1);
 
     let mut g_a = r_g;
     g_a.add_assign(&params.get_a_query_full()?[0].into_projective());
     g_a.add_assign(&d1_g);
-    g_a.add_assign(&a_inputs_acc);
-    g_a.add_assign(&a_aux_acc);
+    g_a.add_assign(&a_acc);
     end_timer!(a_acc_time);
 
     // Compute B
     let b_acc_time = start_timer!(|| "Compute B");
+    let b_acc = queries.b_query_msm(&assignment);
 
-    let (b_inputs_source, b_aux_source) = params.get_b_query(num_inputs)?;
-    let b_inputs_acc = VariableBaseMSM::multi_scalar_mul(b_inputs_source, &input_assignment);
-    let b_aux_acc = VariableBaseMSM::multi_scalar_mul(b_aux_source, &aux_assignment);
-
-    let r_h = params.get_h_gamma_z()?.mul(r);
-    let d1_h = params.get_h_gamma_z()?.mul(d1);
+    let r_h = params.get_h_gamma_z()?.mul_glv(&r);
+    let d1_h = params.get_h_gamma_z()?.mul_glv(&d1);
 
     let mut g_b = r_h;
     g_b.add_assign(&params.get_b_query_full()?[0].into_projective());
     g_b.add_assign(&d1_h);
-    g_b.add_assign(&b_inputs_acc);
-    g_b.add_assign(&b_aux_acc);
+    g_b.add_assign(&b_acc);
     end_timer!(b_acc_time);
 
     // Compute C
@@ -120,37 +224,25 @@ where
     let d1_r_2 = d1 * &r_2;
 
     let c1_acc_time = start_timer!(|| "Compute C1");
-    let (_, c1_aux_source) = params.get_c_query_1(0)?;
-    let c1_acc = VariableBaseMSM::multi_scalar_mul(c1_aux_source, &aux_assignment);
+    let c1_acc = queries.c_query_1_msm(aux_assignment);
     end_timer!(c1_acc_time);
 
     let c2_acc_time = start_timer!(|| "Compute C2");
-
-    let (c2_inputs_source, c2_aux_source) = params.get_c_query_2(num_inputs)?;
-    let c2_inputs_acc = VariableBaseMSM::multi_scalar_mul(c2_inputs_source, &input_assignment);
-    let c2_aux_acc = VariableBaseMSM::multi_scalar_mul(c2_aux_source, &aux_assignment);
-
-    let c2_acc = c2_inputs_acc + &c2_aux_acc;
+    let c2_acc = queries.c_query_2_msm(&assignment);
     end_timer!(c2_acc_time);
 
     // Compute G
     let g_acc_time = start_timer!(|| "Compute G");
-
-    let (g_inputs_source, g_aux_source) = params.get_g_gamma2_z_t(num_inputs)?;
-    let g_inputs_acc = VariableBaseMSM::multi_scalar_mul(g_inputs_source, &h_input);
-    let g_aux_acc = VariableBaseMSM::multi_scalar_mul(g_aux_source, &h_aux);
-
-    let g_acc = g_inputs_acc + &g_aux_acc;
+    let g_acc = queries.g_gamma2_z_t_msm(&h);
     end_timer!(g_acc_time);
 
-    let r2_g_gamma2_z2 = params.get_g_gamma2_z2()?.mul(r2);
-    let r_g_ab_gamma_z = params.get_g_ab_gamma_z()?.mul(r);
-    let d1_g_ab_gamma_z = params.get_g_ab_gamma_z()?.mul(d1);
-    let r_c0 = params.get_c_query_2_full()?[0].mul(r);
-    let r2_d1_g_gamma2_z2 = params.get_g_gamma2_z2()?.mul(d1_r_2);
-    let d2_g_gamma2_z_t0 = params.get_g_gamma2_z_t_full()?[0].mul(d2);
-    let mut r_c2_exp = c2_acc;
-    r_c2_exp.mul_assign(r);
+    let r2_g_gamma2_z2 = params.get_g_gamma2_z2()?.mul_glv(&r2);
+    let r_g_ab_gamma_z = params.get_g_ab_gamma_z()?.mul_glv(&r);
+    let d1_g_ab_gamma_z = params.get_g_ab_gamma_z()?.mul_glv(&d1);
+    let r_c0 = params.get_c_query_2_full()?[0].mul_glv(&r);
+    let r2_d1_g_gamma2_z2 = params.get_g_gamma2_z2()?.mul_glv(&d1_r_2);
+    let d2_g_gamma2_z_t0 = params.get_g_gamma2_z_t_full()?[0].mul_glv(&d2);
+    let r_c2_exp = c2_acc.mul_glv(&r);
 
     let mut g_c = c1_acc;
     g_c.add_assign(&r2_g_gamma2_z2);
@@ -1,4 +1,4 @@
-use crate::{Parameters, Proof};
+use crate::{Parameters, PreparedParameters, Proof};
 use algebra_core::PairingEngine;
 use ff_fft::GeneralEvaluationDomain;
 use r1cs_core::{ConstraintSynthesizer, SynthesisError};
@@ -36,3 +36,48 @@ where
 {
     self::generic::create_proof::<E, C, GeneralEvaluationDomain<E::Fr>>(circuit, params, d1, d2, r)
 }
+
+/// Prepares the query vectors of `params` for faster proving, storing
+/// `num_copies` shifted copies of each of them (see `PreparedMSMBases`).
+pub fn prepare_parameters<E: PairingEngine>(
+    params: &Parameters<E>,
+    num_copies: usize,
+) -> PreparedParameters<E> {
+    PreparedParameters::new(params.clone(), num_copies)
+}
+
+#[inline]
+pub fn create_random_proof_with_prepared_parameters<E, C, R>(
+    circuit: C,
+    params: &PreparedParameters<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    self::generic::create_random_proof_with_prepared_parameters::<
+        E,
+        C,
+        GeneralEvaluationDomain<E::Fr>,
+        R,
+    >(circuit, params, rng)
+}
+
+#[inline]
+pub fn create_proof_with_prepared_parameters<E, C>(
+    circuit: C,
+    params: &PreparedParameters<E>,
+    d1: E::Fr,
+    d2: E::Fr,
+    r: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+{
+    self::generic::create_proof_with_prepared_parameters::<E, C, GeneralEvaluationDomain<E::Fr>>(
+        circuit, params, d1, d2, r,
+    )
+}
@@ -35,11 +35,15 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
 mod bls12_377 {
     use super::*;
     use crate::{
-        create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+        create_proof, create_proof_with_prepared_parameters, create_random_proof,
+        generate_random_parameters, generate_random_parameters_with_tables, prepare_parameters,
+        prepare_verifying_key, verify_proof, GeneratorTables, PreparedParameters,
+    };
+    use algebra_core::{
+        test_rng, CanonicalDeserialize, CanonicalSerialize, ProjectiveCurve, UniformRand,
     };
-    use algebra_core::{test_rng, UniformRand};
 
-    use algebra::bls12_377::{Bls12_377, Fr};
+    use algebra::bls12_377::{Bls12_377, Fr, G1Projective, G2Projective};
     use core::ops::MulAssign;
 
     #[test]
@@ -72,6 +76,94 @@ mod bls12_377 {
             assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
         }
     }
+
+    #[test]
+    fn prove_and_verify_with_prepared_parameters() {
+        let rng = &mut test_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+        let prepared_params = prepare_parameters(&params, 4);
+
+        let mut serialized = vec![0; prepared_params.serialized_size()];
+        prepared_params.serialize(&mut serialized[..]).unwrap();
+        let deserialized = PreparedParameters::deserialize(&serialized[..]).unwrap();
+        assert!(deserialized == prepared_params);
+        let prepared_params = deserialized;
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+            let d1 = Fr::rand(rng);
+            let d2 = Fr::rand(rng);
+            let r = Fr::rand(rng);
+
+            let circuit = MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            };
+            let proof = create_proof_with_prepared_parameters(circuit, &prepared_params, d1, d2, r)
+                .unwrap();
+
+            let circuit = MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            };
+            assert!(proof == create_proof(circuit, &params, d1, d2, r).unwrap());
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+        }
+    }
+
+    #[test]
+    fn prove_and_verify_with_generator_tables() {
+        let rng = &mut test_rng();
+
+        let tables = GeneratorTables::<Bls12_377>::new(
+            G1Projective::rand(rng),
+            G2Projective::rand(rng),
+            6,
+            4,
+        );
+        let mut serialized = vec![0; tables.serialized_size()];
+        tables.serialize(&mut serialized[..]).unwrap();
+        let tables = GeneratorTables::deserialize(&serialized[..]).unwrap();
+
+        for _ in 0..2 {
+            let params = generate_random_parameters_with_tables::<Bls12_377, _, _>(
+                MySillyCircuit { a: None, b: None },
+                &tables,
+                rng,
+            )
+            .unwrap();
+            assert_eq!(params.vk.h_g2, tables.g2_table.generator().into_affine());
+
+            let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+
+            let proof = create_random_proof(
+                MySillyCircuit {
+                    a: Some(a),
+                    b: Some(b),
+                },
+                &params,
+                rng,
+            )
+            .unwrap();
+
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+        }
+    }
 }
 
 mod cp6_782 {
@@ -33,7 +33,7 @@ pub fn verify_proof<E: PairingEngine>(
 
     let mut g_psi = pvk.query[0].into_projective();
     for (i, b) in public_inputs.iter().zip(pvk.query.iter().skip(1)) {
-        g_psi += &b.mul(*i);
+        g_psi += &b.mul_glv(i);
     }
 
     let mut test1_a_g_alpha = proof.a.into_projective();
@@ -1,5 +1,6 @@
 use algebra_core::{
-    msm::FixedBaseMSM, Field, PairingEngine, PrimeField, ProjectiveCurve, UniformRand, Zero,
+    msm::{CombTable, FixedBaseMSM},
+    Field, PairingEngine, PrimeField, ProjectiveCurve, UniformRand, Zero,
 };
 use ff_fft::{cfg_into_iter, cfg_iter, EvaluationDomain};
 
@@ -9,7 +10,7 @@ use rand::Rng;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use crate::{r1cs_to_qap::R1CStoQAP, Parameters, Vec, VerifyingKey};
+use crate::{r1cs_to_qap::R1CStoQAP, GeneratorTables, Parameters, Vec, VerifyingKey};
 
 /// Generates a random common reference string for
 /// a circuit.
@@ -31,6 +32,27 @@ where
     generate_parameters::<E, C, D, R>(circuit, alpha, beta, gamma, delta, rng)
 }
 
+/// Generates a random common reference string for a circuit, using the
+/// generators of the given tables.
+pub fn generate_random_parameters_with_tables<E, C, D, R>(
+    circuit: C,
+    tables: &GeneratorTables<E>,
+    rng: &mut R,
+) -> Result<Parameters<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    R: Rng,
+{
+    let alpha = E::Fr::rand(rng);
+    let beta = E::Fr::rand(rng);
+    let gamma = E::Fr::rand(rng);
+    let delta = E::Fr::rand(rng);
+
+    generate_parameters_with_tables::<E, C, D, R>(circuit, alpha, beta, gamma, delta, tables, rng)
+}
+
 /// Create parameters for a circuit, given some toxic waste.
 pub fn generate_parameters<E, C, D, R>(
     circuit: C,
@@ -40,6 +62,44 @@ pub fn generate_parameters<E, C, D, R>(
     delta: E::Fr,
     rng: &mut R,
 ) -> Result<Parameters<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    R: Rng,
+{
+    generate_parameters_inner::<E, C, D, R>(circuit, alpha, beta, gamma, delta, None, rng)
+}
+
+/// Create parameters for a circuit, given some toxic waste, using the
+/// generators of the given tables.
+pub fn generate_parameters_with_tables<E, C, D, R>(
+    circuit: C,
+    alpha: E::Fr,
+    beta: E::Fr,
+    gamma: E::Fr,
+    delta: E::Fr,
+    tables: &GeneratorTables<E>,
+    rng: &mut R,
+) -> Result<Parameters<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    R: Rng,
+{
+    generate_parameters_inner::<E, C, D, R>(circuit, alpha, beta, gamma, delta, Some(tables), rng)
+}
+
+fn generate_parameters_inner<E, C, D, R>(
+    circuit: C,
+    alpha: E::Fr,
+    beta: E::Fr,
+    gamma: E::Fr,
+    delta: E::Fr,
+    tables: Option<&GeneratorTables<E>>,
+    rng: &mut R,
+) -> Result<Parameters<E>, SynthesisError>
 where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
@@ -101,16 +161,33 @@ where
         .map(|((a, b), c)| (beta * a + &(alpha * b) + c) * &delta_inverse)
         .collect::<Vec<_>>();
 
-    let g1_generator = E::G1Projective::rand(rng);
-    let g2_generator = E::G2Projective::rand(rng);
-
-    // Compute G window table
-    let g1_window_time = start_timer!(|| "Compute G1 window table");
-    let g1_window =
-        FixedBaseMSM::get_mul_window_size(non_zero_a + non_zero_b + qap_num_variables + m_raw + 1);
-    let g1_table =
-        FixedBaseMSM::get_window_table::<E::G1Projective>(scalar_bits, g1_window, g1_generator);
-    end_timer!(g1_window_time);
+    // Compute the G1 and G2 window tables, unless they are given. A comb
+    // with one block per bit of a row is a plain window table.
+    let new_tables;
+    let tables = match tables {
+        Some(tables) => tables,
+        None => {
+            let g1_generator = E::G1Projective::rand(rng);
+            let g2_generator = E::G2Projective::rand(rng);
+
+            let g1_window_time = start_timer!(|| "Compute G1 window table");
+            let g1_window = FixedBaseMSM::get_mul_window_size(
+                non_zero_a + non_zero_b + qap_num_variables + m_raw + 1,
+            );
+            let g1_table = CombTable::new(g1_generator, g1_window, scalar_bits);
+            end_timer!(g1_window_time);
+
+            let g2_window_time = start_timer!(|| "Compute G2 window table");
+            let g2_window = FixedBaseMSM::get_mul_window_size(non_zero_b);
+            let g2_table = CombTable::new(g2_generator, g2_window, scalar_bits);
+            end_timer!(g2_window_time);
+
+            new_tables = GeneratorTables { g1_table, g2_table };
+            &new_tables
+        },
+    };
+    let g1_generator = tables.g1_table.generator();
+    let g2_generator = tables.g2_table.generator();
 
     // Generate the R1CS proving key
     let proving_key_time = start_timer!(|| "Generate the R1CS proving key");
@@ -123,35 +200,23 @@ where
 
     // Compute the A-query
     let a_time = start_timer!(|| "Calculate A");
-    let mut a_query =
-        FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &a);
+    let mut a_query = FixedBaseMSM::multi_scalar_mul_comb(&tables.g1_table, &a);
     end_timer!(a_time);
 
     // Compute the B-query in G1
     let b_g1_time = start_timer!(|| "Calculate B G1");
-    let mut b_g1_query =
-        FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &b);
+    let mut b_g1_query = FixedBaseMSM::multi_scalar_mul_comb(&tables.g1_table, &b);
     end_timer!(b_g1_time);
 
-    // Compute B window table
-    let g2_time = start_timer!(|| "Compute G2 table");
-    let g2_window = FixedBaseMSM::get_mul_window_size(non_zero_b);
-    let g2_table =
-        FixedBaseMSM::get_window_table::<E::G2Projective>(scalar_bits, g2_window, g2_generator);
-    end_timer!(g2_time);
-
     // Compute the B-query in G2
     let b_g2_time = start_timer!(|| "Calculate B G2");
-    let mut b_g2_query =
-        FixedBaseMSM::multi_scalar_mul::<E::G2Projective>(scalar_bits, g2_window, &g2_table, &b);
+    let mut b_g2_query = FixedBaseMSM::multi_scalar_mul_comb(&tables.g2_table, &b);
     end_timer!(b_g2_time);
 
     // Compute the H-query
     let h_time = start_timer!(|| "Calculate H");
-    let mut h_query = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
-        g1_window,
-        &g1_table,
+    let mut h_query = FixedBaseMSM::multi_scalar_mul_comb(
+        &tables.g1_table,
         &cfg_into_iter!(0..m_raw - 1)
             .map(|i| zt * &delta_inverse * &t.pow([i as u64]))
             .collect::<Vec<_>>(),
@@ -161,8 +226,7 @@ where
 
     // Compute the L-query
     let l_time = start_timer!(|| "Calculate L");
-    let l_query =
-        FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &l);
+    let l_query = FixedBaseMSM::multi_scalar_mul_comb(&tables.g1_table, &l);
     let mut l_query = l_query[cs.num_instance_variables()..].to_vec();
     end_timer!(l_time);
 
@@ -171,14 +235,7 @@ where
     // Generate R1CS verification key
     let verifying_key_time = start_timer!(|| "Generate the R1CS verification key");
     let gamma_g2 = g2_generator.mul(gamma);
-    let gamma_abc_g1 = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
-        g1_window,
-        &g1_table,
-        &gamma_abc,
-    );
-
-    drop(g1_table);
+    let gamma_abc_g1 = FixedBaseMSM::multi_scalar_mul_comb(&tables.g1_table, &gamma_abc);
 
     end_timer!(verifying_key_time);
 
@@ -26,6 +26,7 @@ use std::vec::Vec;
 use algebra_core::{
     bytes::ToBytes,
     io::{self, Result as IoResult},
+    msm::{CombTable, PreparedMSMBases},
     serialize::*,
     PairingEngine,
 };
@@ -123,6 +124,203 @@ pub struct Parameters<E: PairingEngine> {
     pub l_query: Vec<E::G1Affine>,
 }
 
+/// Comb tables of the G1 and G2 generators of a setup. Setups for different
+/// circuits can reuse the same tables.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct GeneratorTables<E: PairingEngine> {
+    pub g1_table: CombTable<E::G1Projective>,
+    pub g2_table: CombTable<E::G2Projective>,
+}
+
+impl<E: PairingEngine> GeneratorTables<E> {
+    /// Builds the tables for the given generators, as combs with `teeth` rows
+    /// split into `blocks` blocks (see `CombTable`).
+    pub fn new(
+        g1_generator: E::G1Projective,
+        g2_generator: E::G2Projective,
+        teeth: usize,
+        blocks: usize,
+    ) -> Self {
+        Self {
+            g1_table: CombTable::new(g1_generator, teeth, blocks),
+            g2_table: CombTable::new(g2_generator, teeth, blocks),
+        }
+    }
+}
+
+/// Proving parameters whose query vectors are prepared for multi-scalar
+/// multiplication, which makes proving faster at the expense of larger size
+/// in memory. The first elements of the A and B queries are left out of the
+/// prepared queries.
+///
+/// The prepared queries are serialized as they are, together with the parts
+/// of `params` they leave out, so deserializing does not redo the doublings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreparedParameters<E: PairingEngine> {
+    pub params: Parameters<E>,
+    pub num_copies: usize,
+    pub a_query: PreparedMSMBases<E::G1Affine>,
+    pub b_g1_query: PreparedMSMBases<E::G1Affine>,
+    pub b_g2_query: PreparedMSMBases<E::G2Affine>,
+    pub h_query: PreparedMSMBases<E::G1Affine>,
+    pub l_query: PreparedMSMBases<E::G1Affine>,
+}
+
+impl<E: PairingEngine> PreparedParameters<E> {
+    /// Prepares the query vectors of `params`, storing `num_copies` shifted
+    /// copies of each of them (see `PreparedMSMBases`).
+    pub fn new(params: Parameters<E>, num_copies: usize) -> Self {
+        let a_query = PreparedMSMBases::new(&params.a_query[1..], num_copies);
+        let b_g1_query = PreparedMSMBases::new(&params.b_g1_query[1..], num_copies);
+        let b_g2_query = PreparedMSMBases::new(&params.b_g2_query[1..], num_copies);
+        let h_query = PreparedMSMBases::new(&params.h_query, num_copies);
+        let l_query = PreparedMSMBases::new(&params.l_query, num_copies);
+        Self {
+            params,
+            num_copies,
+            a_query,
+            b_g1_query,
+            b_g2_query,
+            h_query,
+            l_query,
+        }
+    }
+}
+
+/// Returns `first` followed by `rest`.
+fn with_first<G: Clone>(first: G, rest: &[G]) -> Vec<G> {
+    let mut query = Vec::with_capacity(rest.len() + 1);
+    query.push(first);
+    query.extend_from_slice(rest);
+    query
+}
+
+// Writes the parts of `params` left out of the prepared queries, then
+// `num_copies` and the prepared queries.
+macro_rules! serialize_prepared_parameters {
+    ($self:ident, $writer:ident, $serialize:ident) => {{
+        let params = &$self.params;
+        params.vk.$serialize(&mut $writer)?;
+        params.beta_g1.$serialize(&mut $writer)?;
+        params.delta_g1.$serialize(&mut $writer)?;
+        params.a_query[0].$serialize(&mut $writer)?;
+        params.b_g1_query[0].$serialize(&mut $writer)?;
+        params.b_g2_query[0].$serialize(&mut $writer)?;
+        $self.num_copies.$serialize(&mut $writer)?;
+        $self.a_query.$serialize(&mut $writer)?;
+        $self.b_g1_query.$serialize(&mut $writer)?;
+        $self.b_g2_query.$serialize(&mut $writer)?;
+        $self.h_query.$serialize(&mut $writer)?;
+        $self.l_query.$serialize(&mut $writer)
+    }};
+}
+
+macro_rules! prepared_parameters_size {
+    ($self:ident, $size:ident) => {{
+        let params = &$self.params;
+        params.vk.$size()
+            + params.beta_g1.$size()
+            + params.delta_g1.$size()
+            + params.a_query[0].$size()
+            + params.b_g1_query[0].$size()
+            + params.b_g2_query[0].$size()
+            + $self.num_copies.$size()
+            + $self.a_query.$size()
+            + $self.b_g1_query.$size()
+            + $self.b_g2_query.$size()
+            + $self.h_query.$size()
+            + $self.l_query.$size()
+    }};
+}
+
+// Reads what `serialize_prepared_parameters!` writes and rebuilds the query
+// vectors of `params` from the prepared queries.
+macro_rules! deserialize_prepared_parameters {
+    ($reader:ident, $deserialize:ident) => {{
+        let vk = VerifyingKey::$deserialize(&mut $reader)?;
+        let beta_g1 = E::G1Affine::$deserialize(&mut $reader)?;
+        let delta_g1 = E::G1Affine::$deserialize(&mut $reader)?;
+        let a_first = E::G1Affine::$deserialize(&mut $reader)?;
+        let b_g1_first = E::G1Affine::$deserialize(&mut $reader)?;
+        let b_g2_first = E::G2Affine::$deserialize(&mut $reader)?;
+        let num_copies = usize::$deserialize(&mut $reader)?;
+        let a_query = PreparedMSMBases::$deserialize(&mut $reader)?;
+        let b_g1_query = PreparedMSMBases::$deserialize(&mut $reader)?;
+        let b_g2_query = PreparedMSMBases::$deserialize(&mut $reader)?;
+        let h_query = PreparedMSMBases::$deserialize(&mut $reader)?;
+        let l_query = PreparedMSMBases::$deserialize(&mut $reader)?;
+        let params = Parameters {
+            vk,
+            beta_g1,
+            delta_g1,
+            a_query: with_first(a_first, a_query.bases()),
+            b_g1_query: with_first(b_g1_first, b_g1_query.bases()),
+            b_g2_query: with_first(b_g2_first, b_g2_query.bases()),
+            h_query: h_query.bases().to_vec(),
+            l_query: l_query.bases().to_vec(),
+        };
+        Ok(Self {
+            params,
+            num_copies,
+            a_query,
+            b_g1_query,
+            b_g2_query,
+            h_query,
+            l_query,
+        })
+    }};
+}
+
+impl<E: PairingEngine> CanonicalSerialize for PreparedParameters<E> {
+    #[inline]
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        serialize_prepared_parameters!(self, writer, serialize)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        prepared_parameters_size!(self, serialized_size)
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        serialize_prepared_parameters!(self, writer, serialize_uncompressed)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        serialize_prepared_parameters!(self, writer, serialize_unchecked)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        prepared_parameters_size!(self, uncompressed_size)
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for PreparedParameters<E> {
+    #[inline]
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        deserialize_prepared_parameters!(reader, deserialize)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        deserialize_prepared_parameters!(reader, deserialize_uncompressed)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        deserialize_prepared_parameters!(reader, deserialize_unchecked)
+    }
+}
+
+impl<E: PairingEngine> From<PreparedParameters<E>> for Parameters<E> {
+    fn from(other: PreparedParameters<E>) -> Self {
+        other.params
+    }
+}
+
 /// Preprocessed verification key parameters that enable faster verification
 /// at the expense of larger size in memory.
 #[derive(Clone, Debug, PartialEq)]
@@ -1,11 +1,11 @@
 use rand::Rng;
 
 use algebra_core::{
-    msm::VariableBaseMSM, AffineCurve, PairingEngine, PrimeField, ProjectiveCurve, UniformRand,
-    Zero,
+    io::Read, msm::VariableBaseMSM, AffineCurve, CanonicalDeserialize, PairingEngine, PrimeField,
+    ProjectiveCurve, SerializationError, UniformRand, Zero,
 };
 
-use crate::{r1cs_to_qap::R1CStoQAP, Parameters, Proof, Vec};
+use crate::{r1cs_to_qap::R1CStoQAP, Parameters, PreparedParameters, Proof, Vec, VerifyingKey};
 
 use r1cs_core::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
 
@@ -53,6 +53,377 @@ where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
     D: EvaluationDomain<E::Fr>,
+{
+    create_proof_with_msm::<E, C, D, _>(circuit, params, r, s)
+}
+
+pub fn create_random_proof_with_prepared_parameters<E, C, D, R>(
+    circuit: C,
+    params: &PreparedParameters<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    R: Rng,
+{
+    let r = E::Fr::rand(rng);
+    let s = E::Fr::rand(rng);
+
+    create_proof_with_prepared_parameters::<E, C, D>(circuit, params, r, s)
+}
+
+pub fn create_proof_with_prepared_parameters<E, C, D>(
+    circuit: C,
+    params: &PreparedParameters<E>,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+{
+    create_proof_with_msm::<E, C, D, _>(circuit, params, r, s)
+}
+
+/// Creates a proof, reading the parameters from `reader` as written by
+/// `Parameters::serialize_uncompressed`. At most `chunk_size` elements of
+/// each query are held in memory at a time. As with `deserialize_unchecked`,
+/// the parameters are not checked.
+pub fn create_random_proof_from_reader<E, C, D, R, P>(
+    circuit: C,
+    reader: P,
+    chunk_size: usize,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    R: Rng,
+    P: Read + Send,
+{
+    let r = E::Fr::rand(rng);
+    let s = E::Fr::rand(rng);
+
+    create_proof_from_reader::<E, C, D, P>(circuit, reader, chunk_size, r, s)
+}
+
+/// Creates a proof, reading the parameters from `reader` as in
+/// `create_random_proof_from_reader`.
+pub fn create_proof_from_reader<E, C, D, P>(
+    circuit: C,
+    reader: P,
+    chunk_size: usize,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    P: Read + Send,
+{
+    create_proof_with_msm::<E, C, D, _>(circuit, ParametersReader::new(reader, chunk_size), r, s)
+}
+
+type BigInt<E> = <<E as PairingEngine>::Fr as PrimeField>::BigInt;
+
+/// The parameters of the prover. The multi-scalar multiplications over the
+/// query vectors are computed in the order of the queries in `Parameters`,
+/// and return the first elements of the A and B queries separately.
+trait QueryMSM<E: PairingEngine> {
+    fn vk(&mut self) -> Result<&VerifyingKey<E>, SynthesisError>;
+
+    fn beta_g1(&mut self) -> Result<E::G1Affine, SynthesisError>;
+
+    fn delta_g1(&mut self) -> Result<E::G1Affine, SynthesisError>;
+
+    fn a_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G1Affine, E::G1Projective), SynthesisError>;
+
+    fn b_g1_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G1Affine, E::G1Projective), SynthesisError>;
+
+    /// Skips the B-query in G1, which is not needed for proofs without zero
+    /// knowledge.
+    fn skip_b_g1_query(&mut self) -> Result<(), SynthesisError> {
+        Ok(())
+    }
+
+    fn b_g2_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G2Affine, E::G2Projective), SynthesisError>;
+
+    fn h_query_msm(&mut self, scalars: &[BigInt<E>]) -> Result<E::G1Projective, SynthesisError>;
+
+    fn l_query_msm(&mut self, scalars: &[BigInt<E>]) -> Result<E::G1Projective, SynthesisError>;
+}
+
+impl<'a, E: PairingEngine> QueryMSM<E> for &'a Parameters<E> {
+    fn vk(&mut self) -> Result<&VerifyingKey<E>, SynthesisError> {
+        Ok(&self.vk)
+    }
+
+    fn beta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.beta_g1)
+    }
+
+    fn delta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.delta_g1)
+    }
+
+    fn a_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G1Affine, E::G1Projective), SynthesisError> {
+        let query = self.get_a_query_full()?;
+        Ok((
+            query[0],
+            VariableBaseMSM::multi_scalar_mul(&query[1..], scalars),
+        ))
+    }
+
+    fn b_g1_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G1Affine, E::G1Projective), SynthesisError> {
+        let query = self.get_b_g1_query_full()?;
+        Ok((
+            query[0],
+            VariableBaseMSM::multi_scalar_mul(&query[1..], scalars),
+        ))
+    }
+
+    fn b_g2_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G2Affine, E::G2Projective), SynthesisError> {
+        let query = self.get_b_g2_query_full()?;
+        Ok((
+            query[0],
+            VariableBaseMSM::multi_scalar_mul(&query[1..], scalars),
+        ))
+    }
+
+    fn h_query_msm(&mut self, scalars: &[BigInt<E>]) -> Result<E::G1Projective, SynthesisError> {
+        let query = self.get_h_query_full()?;
+        Ok(VariableBaseMSM::multi_scalar_mul(query, scalars))
+    }
+
+    fn l_query_msm(&mut self, scalars: &[BigInt<E>]) -> Result<E::G1Projective, SynthesisError> {
+        let query = self.get_l_query_full()?;
+        Ok(VariableBaseMSM::multi_scalar_mul(query, scalars))
+    }
+}
+
+impl<'a, E: PairingEngine> QueryMSM<E> for &'a PreparedParameters<E> {
+    fn vk(&mut self) -> Result<&VerifyingKey<E>, SynthesisError> {
+        Ok(&self.params.vk)
+    }
+
+    fn beta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.params.beta_g1)
+    }
+
+    fn delta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.params.delta_g1)
+    }
+
+    fn a_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G1Affine, E::G1Projective), SynthesisError> {
+        Ok((
+            self.params.get_a_query_full()?[0],
+            VariableBaseMSM::multi_scalar_mul_prepared(&self.a_query, scalars),
+        ))
+    }
+
+    fn b_g1_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G1Affine, E::G1Projective), SynthesisError> {
+        Ok((
+            self.params.get_b_g1_query_full()?[0],
+            VariableBaseMSM::multi_scalar_mul_prepared(&self.b_g1_query, scalars),
+        ))
+    }
+
+    fn b_g2_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G2Affine, E::G2Projective), SynthesisError> {
+        Ok((
+            self.params.get_b_g2_query_full()?[0],
+            VariableBaseMSM::multi_scalar_mul_prepared(&self.b_g2_query, scalars),
+        ))
+    }
+
+    fn h_query_msm(&mut self, scalars: &[BigInt<E>]) -> Result<E::G1Projective, SynthesisError> {
+        Ok(VariableBaseMSM::multi_scalar_mul_prepared(
+            &self.h_query,
+            scalars,
+        ))
+    }
+
+    fn l_query_msm(&mut self, scalars: &[BigInt<E>]) -> Result<E::G1Projective, SynthesisError> {
+        Ok(VariableBaseMSM::multi_scalar_mul_prepared(
+            &self.l_query,
+            scalars,
+        ))
+    }
+}
+
+/// Parameters streamed from a reader, as written by
+/// `Parameters::serialize_uncompressed`. The parts that are not queries are
+/// read on first use.
+struct ParametersReader<E: PairingEngine, P> {
+    reader: P,
+    chunk_size: usize,
+    header: Option<(VerifyingKey<E>, E::G1Affine, E::G1Affine)>,
+}
+
+impl<E: PairingEngine, P: Read + Send> ParametersReader<E, P> {
+    fn new(reader: P, chunk_size: usize) -> Self {
+        Self {
+            reader,
+            chunk_size,
+            header: None,
+        }
+    }
+
+    fn header(&mut self) -> Result<&(VerifyingKey<E>, E::G1Affine, E::G1Affine), SynthesisError> {
+        if self.header.is_none() {
+            let vk = VerifyingKey::deserialize_unchecked(&mut self.reader).map_err(malformed)?;
+            let beta_g1 =
+                E::G1Affine::deserialize_unchecked(&mut self.reader).map_err(malformed)?;
+            let delta_g1 =
+                E::G1Affine::deserialize_unchecked(&mut self.reader).map_err(malformed)?;
+            self.header = Some((vk, beta_g1, delta_g1));
+        }
+        Ok(self.header.as_ref().unwrap())
+    }
+
+    /// Reads the length of the next query, and the MSM of its elements with
+    /// `scalars`.
+    fn query_msm<G: AffineCurve>(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<G::Projective, SynthesisError>
+    where
+        G::ScalarField: PrimeField<BigInt = BigInt<E>>,
+    {
+        self.header()?;
+        let len = u64::deserialize(&mut self.reader).map_err(malformed)?;
+        self.msm::<G>(len as usize, scalars)
+    }
+
+    /// Reads the length of the next query and its first element, and the MSM
+    /// of the remaining elements with `scalars`.
+    fn query_msm_with_first<G: AffineCurve>(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(G, G::Projective), SynthesisError>
+    where
+        G::ScalarField: PrimeField<BigInt = BigInt<E>>,
+    {
+        self.header()?;
+        let len = u64::deserialize(&mut self.reader).map_err(malformed)?;
+        if len == 0 {
+            return Err(SynthesisError::MalformedProvingKey);
+        }
+        let first = G::deserialize_unchecked(&mut self.reader).map_err(malformed)?;
+        Ok((first, self.msm::<G>(len as usize - 1, scalars)?))
+    }
+
+    fn msm<G: AffineCurve>(
+        &mut self,
+        num_bases: usize,
+        scalars: &[BigInt<E>],
+    ) -> Result<G::Projective, SynthesisError>
+    where
+        G::ScalarField: PrimeField<BigInt = BigInt<E>>,
+    {
+        VariableBaseMSM::multi_scalar_mul_from_reader_unchecked::<G, _>(
+            &mut self.reader,
+            num_bases,
+            scalars,
+            self.chunk_size,
+        )
+        .map_err(malformed)
+    }
+}
+
+impl<E: PairingEngine, P: Read + Send> QueryMSM<E> for ParametersReader<E, P> {
+    fn vk(&mut self) -> Result<&VerifyingKey<E>, SynthesisError> {
+        Ok(&self.header()?.0)
+    }
+
+    fn beta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.header()?.1)
+    }
+
+    fn delta_g1(&mut self) -> Result<E::G1Affine, SynthesisError> {
+        Ok(self.header()?.2)
+    }
+
+    fn a_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G1Affine, E::G1Projective), SynthesisError> {
+        self.query_msm_with_first(scalars)
+    }
+
+    fn b_g1_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G1Affine, E::G1Projective), SynthesisError> {
+        self.query_msm_with_first(scalars)
+    }
+
+    fn skip_b_g1_query(&mut self) -> Result<(), SynthesisError> {
+        self.b_g1_query_msm(&[]).map(|_| ())
+    }
+
+    fn b_g2_query_msm(
+        &mut self,
+        scalars: &[BigInt<E>],
+    ) -> Result<(E::G2Affine, E::G2Projective), SynthesisError> {
+        self.query_msm_with_first(scalars)
+    }
+
+    fn h_query_msm(&mut self, scalars: &[BigInt<E>]) -> Result<E::G1Projective, SynthesisError> {
+        self.query_msm::<E::G1Affine>(scalars)
+    }
+
+    fn l_query_msm(&mut self, scalars: &[BigInt<E>]) -> Result<E::G1Projective, SynthesisError> {
+        self.query_msm::<E::G1Affine>(scalars)
+    }
+}
+
+fn malformed(_: SerializationError) -> SynthesisError {
+    SynthesisError::MalformedProvingKey
+}
+
+fn create_proof_with_msm<E, C, D, Q>(
+    circuit: C,
+    mut queries: Q,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    D: EvaluationDomain<E::Fr>,
+    Q: QueryMSM<E>,
 {
     let prover_time = start_timer!(|| "Groth16::Prover");
     let cs = ConstraintSystem::new_ref();
@@ -88,48 +459,50 @@ where
 
     // Compute A
     let a_acc_time = start_timer!(|| "Compute A");
-    let a_query = params.get_a_query_full()?;
-    let r_g1 = params.delta_g1.mul(r);
+    let alpha_g1 = queries.vk()?.alpha_g1;
+    let delta_g1 = queries.delta_g1()?;
+    let r_g1 = delta_g1.mul_glv(&r);
+    let (a_query_0, a_acc) = queries.a_query_msm(&assignment)?;
 
-    let g_a = calculate_coeff(r_g1, a_query, params.vk.alpha_g1, &assignment);
+    let g_a = calculate_coeff(r_g1, a_query_0, a_acc, alpha_g1);
 
     end_timer!(a_acc_time);
 
     // Compute B in G1 if needed
     let g1_b = if r != E::Fr::zero() {
         let b_g1_acc_time = start_timer!(|| "Compute B in G1");
-        let s_g1 = params.delta_g1.mul(s);
-        let b_query = params.get_b_g1_query_full()?;
+        let s_g1 = delta_g1.mul_glv(&s);
+        let beta_g1 = queries.beta_g1()?;
+        let (b_query_0, b_acc) = queries.b_g1_query_msm(&assignment)?;
 
-        let g1_b = calculate_coeff(s_g1, b_query, params.beta_g1, &assignment);
+        let g1_b = calculate_coeff(s_g1, b_query_0, b_acc, beta_g1);
 
         end_timer!(b_g1_acc_time);
 
         g1_b
     } else {
+        queries.skip_b_g1_query()?;
         E::G1Projective::zero()
     };
 
     // Compute B in G2
     let b_g2_acc_time = start_timer!(|| "Compute B in G2");
-    let b_query = params.get_b_g2_query_full()?;
-    let s_g2 = params.vk.delta_g2.mul(s);
-    let g2_b = calculate_coeff(s_g2, b_query, params.vk.beta_g2, &assignment);
+    let vk = queries.vk()?;
+    let (beta_g2, s_g2) = (vk.beta_g2, vk.delta_g2.mul_glv(&s));
+    let (b_query_0, b_acc) = queries.b_g2_query_msm(&assignment)?;
+    let g2_b = calculate_coeff(s_g2, b_query_0, b_acc, beta_g2);
 
     end_timer!(b_g2_acc_time);
 
     // Compute C
     let c_acc_time = start_timer!(|| "Compute C");
 
-    let h_query = params.get_h_query_full()?;
-    let h_acc = VariableBaseMSM::multi_scalar_mul(&h_query, &h_assignment);
+    let h_acc = queries.h_query_msm(&h_assignment)?;
+    let l_aux_acc = queries.l_query_msm(&aux_assignment)?;
 
-    let l_aux_source = params.get_l_query_full()?;
-    let l_aux_acc = VariableBaseMSM::multi_scalar_mul(l_aux_source, &aux_assignment);
-
-    let s_g_a = g_a.mul(s);
-    let r_g1_b = g1_b.mul(r);
-    let r_s_delta_g1 = params.delta_g1.into_projective().mul(r).mul(s);
+    let s_g_a = g_a.mul_glv(&s);
+    let r_g1_b = g1_b.mul_glv(&r);
+    let r_s_delta_g1 = delta_g1.mul_glv(&(r * &s));
 
     let mut g_c = s_g_a;
     g_c += &r_g1_b;
@@ -149,13 +522,10 @@ where
 
 fn calculate_coeff<G: AffineCurve>(
     initial: G::Projective,
-    query: &[G],
+    el: G,
+    acc: G::Projective,
     vk_param: G,
-    assignment: &[<G::ScalarField as PrimeField>::BigInt],
 ) -> G::Projective {
-    let el = query[0];
-    let acc = VariableBaseMSM::multi_scalar_mul(&query[1..], assignment);
-
     let mut res = initial;
     res.add_assign_mixed(&el);
     res += &acc;
@@ -1,5 +1,5 @@
-use crate::{Parameters, Proof};
-use algebra_core::PairingEngine;
+use crate::{Parameters, PreparedParameters, Proof};
+use algebra_core::{io::Read, PairingEngine};
 use ff_fft::GeneralEvaluationDomain;
 use r1cs_core::{ConstraintSynthesizer, SynthesisError};
 use rand::Rng;
@@ -47,3 +47,85 @@ where
 {
     self::generic::create_proof::<E, C, GeneralEvaluationDomain<E::Fr>>(circuit, params, r, s)
 }
+
+/// Prepares the query vectors of `params` for faster proving, storing
+/// `num_copies` shifted copies of each of them (see `PreparedMSMBases`).
+pub fn prepare_parameters<E: PairingEngine>(
+    params: &Parameters<E>,
+    num_copies: usize,
+) -> PreparedParameters<E> {
+    PreparedParameters::new(params.clone(), num_copies)
+}
+
+#[inline]
+pub fn create_random_proof_with_prepared_parameters<E, C, R>(
+    circuit: C,
+    params: &PreparedParameters<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    self::generic::create_random_proof_with_prepared_parameters::<
+        E,
+        C,
+        GeneralEvaluationDomain<E::Fr>,
+        R,
+    >(circuit, params, rng)
+}
+
+#[inline]
+pub fn create_proof_with_prepared_parameters<E, C>(
+    circuit: C,
+    params: &PreparedParameters<E>,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+{
+    self::generic::create_proof_with_prepared_parameters::<E, C, GeneralEvaluationDomain<E::Fr>>(
+        circuit, params, r, s,
+    )
+}
+
+/// Creates a proof, streaming the parameters from `reader` (see
+/// `generic::create_random_proof_from_reader`).
+#[inline]
+pub fn create_random_proof_from_reader<E, C, R, P>(
+    circuit: C,
+    reader: P,
+    chunk_size: usize,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+    P: Read + Send,
+{
+    self::generic::create_random_proof_from_reader::<E, C, GeneralEvaluationDomain<E::Fr>, R, P>(
+        circuit, reader, chunk_size, rng,
+    )
+}
+
+#[inline]
+pub fn create_proof_from_reader<E, C, P>(
+    circuit: C,
+    reader: P,
+    chunk_size: usize,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    P: Read + Send,
+{
+    self::generic::create_proof_from_reader::<E, C, GeneralEvaluationDomain<E::Fr>, P>(
+        circuit, reader, chunk_size, r, s,
+    )
+}
@@ -34,12 +34,18 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
 mod bls12_377 {
     use super::*;
     use crate::{
-        create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+        create_proof, create_proof_from_reader, create_proof_no_zk,
+        create_proof_with_prepared_parameters, create_random_proof, generate_random_parameters,
+        generate_random_parameters_with_tables, prepare_parameters, prepare_verifying_key,
+        verify_proof, GeneratorTables, PreparedParameters,
     };
 
-    use algebra::bls12_377::{Bls12_377, Fr};
-    use algebra_core::{test_rng, UniformRand};
+    use algebra::bls12_377::{Bls12_377, Fr, G1Projective, G2Projective};
+    use algebra_core::{
+        test_rng, CanonicalDeserialize, CanonicalSerialize, One, UniformRand, Zero,
+    };
     use core::ops::MulAssign;
+    use r1cs_core::SynthesisError;
 
     #[test]
     fn prove_and_verify() {
@@ -71,6 +77,164 @@ mod bls12_377 {
             assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
         }
     }
+
+    #[test]
+    fn prove_and_verify_with_prepared_parameters() {
+        let rng = &mut test_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+        let prepared_params = prepare_parameters(&params, 4);
+
+        let mut serialized = vec![0; prepared_params.serialized_size()];
+        prepared_params.serialize(&mut serialized[..]).unwrap();
+        let deserialized = PreparedParameters::deserialize(&serialized[..]).unwrap();
+        assert_eq!(deserialized, prepared_params);
+        let prepared_params = deserialized;
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+            let r = Fr::rand(rng);
+            let s = Fr::rand(rng);
+
+            let circuit = MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            };
+            let proof =
+                create_proof_with_prepared_parameters(circuit, &prepared_params, r, s).unwrap();
+
+            let circuit = MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            };
+            assert_eq!(proof, create_proof(circuit, &params, r, s).unwrap());
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+        }
+    }
+
+    #[test]
+    fn prove_and_verify_with_generator_tables() {
+        let rng = &mut test_rng();
+
+        let tables = GeneratorTables::<Bls12_377>::new(
+            G1Projective::rand(rng),
+            G2Projective::rand(rng),
+            6,
+            4,
+        );
+        let mut serialized = vec![0; tables.serialized_size()];
+        tables.serialize(&mut serialized[..]).unwrap();
+        let tables = GeneratorTables::deserialize(&serialized[..]).unwrap();
+
+        for _ in 0..2 {
+            let params = generate_random_parameters_with_tables::<Bls12_377, _, _>(
+                MySillyCircuit { a: None, b: None },
+                &tables,
+                rng,
+            )
+            .unwrap();
+
+            let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+
+            let proof = create_random_proof(
+                MySillyCircuit {
+                    a: Some(a),
+                    b: Some(b),
+                },
+                &params,
+                rng,
+            )
+            .unwrap();
+
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+        }
+    }
+
+    #[test]
+    fn prove_and_verify_from_reader() {
+        let rng = &mut test_rng();
+
+        let params =
+            generate_random_parameters::<Bls12_377, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+        let mut serialized = vec![0; params.uncompressed_size()];
+        params.serialize_uncompressed(&mut serialized[..]).unwrap();
+
+        let pvk = prepare_verifying_key::<Bls12_377>(&params.vk);
+
+        for &chunk_size in &[1, 2, 1000] {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+            let r = Fr::rand(rng);
+            let s = Fr::rand(rng);
+
+            let circuit = MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            };
+            let proof =
+                create_proof_from_reader(circuit, &serialized[..], chunk_size, r, s).unwrap();
+
+            let circuit = MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            };
+            assert_eq!(proof, create_proof(circuit, &params, r, s).unwrap());
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+
+            let circuit = MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            };
+            let proof = create_proof_from_reader(
+                circuit,
+                &serialized[..],
+                chunk_size,
+                Fr::zero(),
+                Fr::zero(),
+            )
+            .unwrap();
+
+            let circuit = MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            };
+            assert_eq!(proof, create_proof_no_zk(circuit, &params).unwrap());
+        }
+
+        let circuit = MySillyCircuit {
+            a: Some(Fr::one()),
+            b: Some(Fr::one()),
+        };
+        let truncated = &serialized[..serialized.len() - 1];
+        assert_eq!(
+            create_proof_from_reader::<Bls12_377, _, _>(
+                circuit,
+                truncated,
+                16,
+                Fr::zero(),
+                Fr::zero()
+            ),
+            Err(SynthesisError::MalformedProvingKey)
+        );
+    }
 }
 
 mod cp6_782 {
@@ -1,4 +1,4 @@
-use algebra_core::{AffineCurve, PairingEngine, PrimeField, ProjectiveCurve};
+use algebra_core::{AffineCurve, PairingEngine, ProjectiveCurve};
 
 use super::{PreparedVerifyingKey, Proof, VerifyingKey};
 
@@ -27,7 +27,7 @@ pub fn verify_proof<E: PairingEngine>(
 
     let mut g_ic = pvk.gamma_abc_g1[0].into_projective();
     for (i, b) in public_inputs.iter().zip(pvk.gamma_abc_g1.iter().skip(1)) {
-        g_ic.add_assign(&b.mul(i.into_repr()));
+        g_ic.add_assign(&b.mul_glv(i));
     }
 
     let qap = E::miller_loop(
@@ -21,6 +21,9 @@ pub enum SynthesisError {
     MalformedVerifyingKey,
     /// During CRS generation, we observed an unconstrained auxiliary variable
     UnconstrainedVariable,
+    /// During proof generation, the proving key was malformed or could not be
+    /// read.
+    MalformedProvingKey,
 }
 
 #[cfg(feature = "std")]
@@ -47,6 +50,7 @@ impl fmt::Display for SynthesisError {
             SynthesisError::UnconstrainedVariable => {
                 write!(f, "auxiliary variable was unconstrained")
             }
+            SynthesisError::MalformedProvingKey => write!(f, "malformed proving key"),
         }
     }
 }
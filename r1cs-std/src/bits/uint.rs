@@ -6,7 +6,7 @@ macro_rules! make_uint {
         #[doc = $native_doc_name]
         #[doc = "`type."]
         pub mod $mod_name {
-            use algebra::{Field, FpParameters, PrimeField};
+            use algebra::{Field, PrimeField};
             use core::borrow::Borrow;
             use core::convert::TryFrom;
 
@@ -181,10 +181,10 @@ macro_rules! make_uint {
                 {
                     // Make some arbitrary bounds for ourselves to avoid overflows
                     // in the scalar field
-                    assert!(F::Params::MODULUS_BITS >= 2 * $size);
+                    assert!(F::size_in_bits() >= 2 * $size);
 
                     assert!(operands.len() >= 1);
-                    assert!($size * operands.len() <= F::Params::MODULUS_BITS as usize);
+                    assert!($size * operands.len() <= F::size_in_bits());
 
                     if operands.len() == 1 {
                         return Ok(operands[0].clone());
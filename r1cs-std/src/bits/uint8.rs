@@ -1,5 +1,5 @@
 use algebra::Field;
-use algebra::{FpParameters, PrimeField, ToConstraintField};
+use algebra::{PrimeField, ToConstraintField};
 
 use r1cs_core::{ConstraintSystemRef, Namespace, SynthesisError};
 
@@ -152,7 +152,7 @@ impl<F: Field> UInt8<F> {
         let values_len = values.len();
         let field_elements: Vec<F> = ToConstraintField::<F>::to_field_elements(values).unwrap();
 
-        let max_size = 8 * (F::Params::CAPACITY / 8) as usize;
+        let max_size = 8 * (F::capacity() / 8);
         let mut allocated_bits = Vec::new();
         for field_element in field_elements.into_iter() {
             let fe = AllocatedFp::new_input(cs.clone(), || Ok(field_element))?;
@@ -1,4 +1,4 @@
-use algebra::{BigInteger, FpParameters, PrimeField};
+use algebra::{BigInteger, PrimeField};
 use r1cs_core::{lc, ConstraintSystemRef, LinearCombination, Namespace, SynthesisError, Variable};
 
 use core::borrow::Borrow;
@@ -425,10 +425,10 @@ impl<F: PrimeField> ToBitsGadget<F> for AllocatedFp<F> {
                 .skip_while(|(_, c)| !c)
                 .map(|(b, _)| Some(b))
                 .collect();
-            assert_eq!(bits.len(), F::Params::MODULUS_BITS as usize);
+            assert_eq!(bits.len(), F::size_in_bits());
             bits
         } else {
-            vec![None; F::Params::MODULUS_BITS as usize]
+            vec![None; F::size_in_bits()]
         };
 
         // Convert to little-endian
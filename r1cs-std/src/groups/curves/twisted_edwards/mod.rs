@@ -276,6 +276,39 @@ where
     }
 }
 
+impl<P, F> AffineVar<P, F>
+where
+    P: TEModelParameters,
+    F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>
+        + TwoBitLookupGadget<<P::BaseField as Field>::BasePrimeField, TableConstant = P::BaseField>,
+    for<'a> &'a F: FieldOpsBounds<'a, P::BaseField, F>,
+{
+    /// Computes `k1 * self + k2 * other`, where `k1` and `k2` are
+    /// *little-endian* representations of the scalars, sharing the doublings
+    /// between both multiplications.
+    #[tracing::instrument(target = "r1cs", skip(k1, k2))]
+    pub fn joint_scalar_mul_le(
+        &self,
+        k1: &[Boolean<<P::BaseField as Field>::BasePrimeField>],
+        other: &Self,
+        k2: &[Boolean<<P::BaseField as Field>::BasePrimeField>],
+    ) -> Result<Self, SynthesisError> {
+        let zero = Self::zero();
+        let sum = self + other;
+        let no = Boolean::constant(false);
+        let mut res = Self::zero();
+        for i in (0..core::cmp::max(k1.len(), k2.len())).rev() {
+            let b1 = k1.get(i).unwrap_or(&no);
+            let b2 = k2.get(i).unwrap_or(&no);
+            res.double_in_place()?;
+            let with_other = b1.select(&sum, other)?;
+            let without_other = b1.select(self, &zero)?;
+            res += &b2.select(&with_other, &without_other)?;
+        }
+        Ok(res)
+    }
+}
+
 impl<P: TEModelParameters, F: FieldVar<P::BaseField, <P::BaseField as Field>::BasePrimeField>>
     AffineVar<P, F>
 where
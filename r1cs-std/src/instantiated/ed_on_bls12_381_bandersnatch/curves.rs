@@ -0,0 +1,84 @@
+use crate::groups::curves::twisted_edwards::AffineVar;
+use algebra::ed_on_bls12_381_bandersnatch::*;
+use r1cs_core::SynthesisError;
+
+use crate::{ed_on_bls12_381_bandersnatch::FqVar, prelude::*, Vec};
+
+/// A variable that is the R1CS equivalent of `algebra::ed_on_bls12_381_bandersnatch::EdwardsAffine`.
+pub type EdwardsVar = AffineVar<EdwardsParameters, FqVar>;
+
+/// Evaluates the endomorphism of Bandersnatch that `EdwardsParameters::GLV`
+/// uses, `(x, y) -> (c * (1 - y^2) / (x * y), b * (y^2 + b) / (y^2 - b))`,
+/// on `p`, which must be in the prime order subgroup.
+#[tracing::instrument(target = "r1cs")]
+pub fn endomorphism(p: &EdwardsVar) -> Result<EdwardsVar, SynthesisError> {
+    // The identity is the only point of the subgroup with x * y = 0, and is
+    // mapped to itself.
+    let is_identity = p.x.is_zero()?;
+    let xy = is_identity.select(&FqVar::one(), &(&p.x * &p.y))?;
+    let y2 = p.y.square()?;
+    let x = ((FqVar::one() - &y2) * ENDO_C).mul_by_inverse(&xy)?;
+    let y = ((&y2 + ENDO_B) * ENDO_B).mul_by_inverse(&(&y2 - ENDO_B))?;
+    is_identity.select(&EdwardsVar::zero(), &EdwardsVar::new(x, y))
+}
+
+/// Computes `k * p` with the GLV method, for `p` in the prime order subgroup
+/// and a scalar given as the decomposition `k = k1 + k2 * lambda` that
+/// `GLVParameters::decompose` returns, with the sign of each half and its
+/// absolute value as *little-endian* bits. The halves have about half as many
+/// bits as `k`, and so does the double-and-add loop.
+#[tracing::instrument(target = "r1cs", skip(k))]
+pub fn mul_glv(
+    p: &EdwardsVar,
+    k: &[(Boolean<Fq>, Vec<Boolean<Fq>>); 2],
+) -> Result<EdwardsVar, SynthesisError> {
+    let [(neg1, k1), (neg2, k2)] = k;
+    let p1 = neg1.select(&p.negate()?, p)?;
+    let p2 = endomorphism(p)?;
+    let p2 = neg2.select(&p2.negate()?, &p2)?;
+    p1.joint_scalar_mul_le(k1, &p2, k2)
+}
+
+#[test]
+fn test() {
+    crate::groups::curves::twisted_edwards::test::<_, EdwardsVar>().unwrap();
+}
+
+#[test]
+fn test_mul_glv() {
+    use algebra::{test_rng, BitIteratorLE, One, PrimeField, ProjectiveCurve, UniformRand, Zero};
+    use r1cs_core::ConstraintSystem;
+
+    let mut rng = test_rng();
+    let cs = ConstraintSystem::<Fq>::new_ref();
+    let glv = <EdwardsParameters as algebra::TEModelParameters>::GLV.unwrap();
+    let half_bits = Fr::size_in_bits() / 2 + 1;
+
+    let points = [EdwardsProjective::zero(), EdwardsProjective::rand(&mut rng)];
+    let scalars = [
+        Fr::zero(),
+        Fr::one(),
+        -Fr::one(),
+        glv.lambda,
+        Fr::rand(&mut rng),
+    ];
+    for a in points.iter() {
+        for k in scalars.iter() {
+            let a_var = EdwardsVar::new_witness(cs.clone(), || Ok(*a)).unwrap();
+            let endo_var = endomorphism(&a_var).unwrap();
+            assert_eq!(endo_var.value().unwrap(), (glv.endomorphism)(a));
+
+            let mut k_var = Vec::new();
+            for (neg, half) in glv.decompose(k).iter() {
+                let neg = Boolean::new_witness(cs.clone(), || Ok(*neg)).unwrap();
+                let bits = BitIteratorLE::new(half).take(half_bits).collect::<Vec<_>>();
+                let bits = Vec::new_witness(cs.clone(), || Ok(bits)).unwrap();
+                k_var.push((neg, bits));
+            }
+            let k_var = [k_var[0].clone(), k_var[1].clone()];
+            let result = mul_glv(&a_var, &k_var).unwrap();
+            assert_eq!(result.value().unwrap(), a.mul(k.into_repr()));
+        }
+    }
+    assert!(cs.is_satisfied().unwrap());
+}
@@ -0,0 +1,12 @@
+use crate::groups::curves::short_weierstrass::ProjectiveVar;
+use algebra::grumpkin::*;
+
+use crate::grumpkin::FqVar;
+
+/// A variable that is the R1CS equivalent of `algebra::grumpkin::Projective`.
+pub type GVar = ProjectiveVar<GrumpkinParameters, FqVar>;
+
+#[test]
+fn test() {
+    crate::groups::curves::short_weierstrass::test::<GrumpkinParameters, GVar>().unwrap();
+}
@@ -0,0 +1,9 @@
+use crate::fields::fp::FpVar;
+
+/// A variable that is the R1CS equivalent of `algebra::grumpkin::Fq`.
+pub type FqVar = FpVar<algebra::grumpkin::Fq>;
+
+#[test]
+fn test() {
+    crate::fields::tests::field_test::<_, _, FqVar>().unwrap();
+}
@@ -0,0 +1,109 @@
+//! This module implements the R1CS equivalent of `algebra::grumpkin`.
+//!
+//! It implements field variables for `algebra::grumpkin::Fq`,
+//! and group variables for `algebra::grumpkin::Projective`.
+//!
+//! The field underlying these constraints is `algebra::grumpkin::Fq`, which is
+//! the scalar field of BN254, so these gadgets can be used in circuits over
+//! BN254, e.g. for Pedersen hashes or signature verification.
+//!
+//! # Examples
+//!
+//! One can perform standard algebraic operations on `FqVar`:
+//!
+//! ```
+//! # fn main() -> Result<(), r1cs_core::SynthesisError> {
+//! use algebra::{UniformRand, grumpkin::*};
+//! use r1cs_core::*;
+//! use r1cs_std::prelude::*;
+//! use r1cs_std::grumpkin::*;
+//!
+//! let cs = ConstraintSystem::<Fq>::new_ref();
+//! // This rng is just for test purposes; do not use it
+//! // in real applications.
+//! let mut rng = algebra::test_rng();
+//!
+//! // Generate some random `Fq` elements.
+//! let a_native = Fq::rand(&mut rng);
+//! let b_native = Fq::rand(&mut rng);
+//!
+//! // Allocate `a_native` and `b_native` as witness variables in `cs`.
+//! let a = FqVar::new_witness(r1cs_core::ns!(cs, "generate_a"), || Ok(a_native))?;
+//! let b = FqVar::new_witness(r1cs_core::ns!(cs, "generate_b"), || Ok(b_native))?;
+//!
+//! // Allocate `a_native` and `b_native` as constants in `cs`. This does not add any
+//! // constraints or variables.
+//! let a_const = FqVar::new_constant(r1cs_core::ns!(cs, "a_as_constant"), a_native)?;
+//! let b_const = FqVar::new_constant(r1cs_core::ns!(cs, "b_as_constant"), b_native)?;
+//!
+//! let one = FqVar::one();
+//! let zero = FqVar::zero();
+//!
+//! // Sanity check one + one = two
+//! let two = &one + &one + &zero;
+//! two.enforce_equal(&one.double()?)?;
+//!
+//! assert!(cs.is_satisfied()?);
+//!
+//! // Check that the value of &a + &b is correct.
+//! assert_eq!((&a + &b).value()?, a_native + &b_native);
+//!
+//! // Check that the value of &a * &b is correct.
+//! assert_eq!((&a * &b).value()?, a_native * &b_native);
+//!
+//! // Check that operations on variables and constants are equivalent.
+//! (&a + &b).enforce_equal(&(&a_const + &b_const))?;
+//! assert!(cs.is_satisfied()?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! One can also perform standard algebraic operations on `GVar`:
+//!
+//! ```
+//! # fn main() -> Result<(), r1cs_core::SynthesisError> {
+//! # use algebra::{UniformRand, grumpkin::*};
+//! # use r1cs_core::*;
+//! # use r1cs_std::prelude::*;
+//! # use r1cs_std::grumpkin::*;
+//!
+//! # let cs = ConstraintSystem::<Fq>::new_ref();
+//! # let mut rng = algebra::test_rng();
+//!
+//! // Generate some random `Grumpkin` elements.
+//! let a_native = Projective::rand(&mut rng);
+//! let b_native = Projective::rand(&mut rng);
+//!
+//! // Allocate `a_native` and `b_native` as witness variables in `cs`.
+//! let a = GVar::new_witness(r1cs_core::ns!(cs, "a"), || Ok(a_native))?;
+//! let b = GVar::new_witness(r1cs_core::ns!(cs, "b"), || Ok(b_native))?;
+//!
+//! // Allocate `a_native` and `b_native` as constants in `cs`. This does not add any
+//! // constraints or variables.
+//! let a_const = GVar::new_constant(r1cs_core::ns!(cs, "a_as_constant"), a_native)?;
+//! let b_const = GVar::new_constant(r1cs_core::ns!(cs, "b_as_constant"), b_native)?;
+//!
+//! // This returns the identity of `Grumpkin`.
+//! let zero = GVar::zero();
+//!
+//! // Sanity check one + one = two
+//! let two_a = &a + &a + &zero;
+//! two_a.enforce_equal(&a.double()?)?;
+//!
+//! assert!(cs.is_satisfied()?);
+//!
+//! // Check that the value of &a + &b is correct.
+//! assert_eq!((&a + &b).value()?, a_native + &b_native);
+//!
+//! // Check that operations on variables and constants are equivalent.
+//! (&a + &b).enforce_equal(&(&a_const + &b_const))?;
+//! assert!(cs.is_satisfied()?);
+//! # Ok(())
+//! # }
+//! ```
+
+mod curves;
+mod fields;
+
+pub use curves::*;
+pub use fields::*;
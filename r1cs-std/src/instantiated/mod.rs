@@ -16,9 +16,15 @@ pub mod ed_on_bw6_761;
 #[cfg(feature = "ed_on_bn254")]
 pub mod ed_on_bn254;
 
+#[cfg(feature = "grumpkin")]
+pub mod grumpkin;
+
 #[cfg(feature = "ed_on_bls12_381")]
 pub mod ed_on_bls12_381;
 
+#[cfg(feature = "ed_on_bls12_381_bandersnatch")]
+pub mod ed_on_bls12_381_bandersnatch;
+
 #[cfg(feature = "ed_on_mnt4_298")]
 pub mod ed_on_mnt4_298;
 
@@ -36,3 +42,9 @@ pub mod mnt6_298;
 
 #[cfg(feature = "mnt6_753")]
 pub mod mnt6_753;
+
+#[cfg(feature = "pallas")]
+pub mod pallas;
+
+#[cfg(feature = "vesta")]
+pub mod vesta;
@@ -0,0 +1,12 @@
+use crate::groups::curves::short_weierstrass::ProjectiveVar;
+use algebra::pallas::*;
+
+use crate::pallas::FqVar;
+
+/// A variable that is the R1CS equivalent of `algebra::pallas::Projective`.
+pub type GVar = ProjectiveVar<PallasParameters, FqVar>;
+
+#[test]
+fn test() {
+    crate::groups::curves::short_weierstrass::test::<PallasParameters, GVar>().unwrap();
+}
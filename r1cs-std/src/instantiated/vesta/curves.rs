@@ -0,0 +1,12 @@
+use crate::groups::curves::short_weierstrass::ProjectiveVar;
+use algebra::vesta::*;
+
+use crate::vesta::FqVar;
+
+/// A variable that is the R1CS equivalent of `algebra::vesta::Projective`.
+pub type GVar = ProjectiveVar<VestaParameters, FqVar>;
+
+#[test]
+fn test() {
+    crate::groups::curves::short_weierstrass::test::<VestaParameters, GVar>().unwrap();
+}
@@ -0,0 +1,9 @@
+use crate::fields::fp::FpVar;
+
+/// A variable that is the R1CS equivalent of `algebra::vesta::Fq`.
+pub type FqVar = FpVar<algebra::vesta::Fq>;
+
+#[test]
+fn test() {
+    crate::fields::tests::field_test::<_, _, FqVar>().unwrap();
+}
@@ -57,6 +57,9 @@ pub use instantiated::bls12_377;
 #[cfg(feature = "ed_on_bn254")]
 pub use instantiated::ed_on_bn254;
 
+#[cfg(feature = "grumpkin")]
+pub use instantiated::grumpkin;
+
 #[cfg(feature = "ed_on_bls12_377")]
 pub use instantiated::ed_on_bls12_377;
 
@@ -75,6 +78,9 @@ pub use instantiated::ed_on_bw6_761;
 #[cfg(feature = "ed_on_bls12_381")]
 pub use instantiated::ed_on_bls12_381;
 
+#[cfg(feature = "ed_on_bls12_381_bandersnatch")]
+pub use instantiated::ed_on_bls12_381_bandersnatch;
+
 #[cfg(feature = "mnt4_298")]
 pub use instantiated::mnt4_298;
 
@@ -87,6 +93,12 @@ pub use instantiated::mnt6_298;
 #[cfg(feature = "mnt6_753")]
 pub use instantiated::mnt6_753;
 
+#[cfg(feature = "pallas")]
+pub use instantiated::pallas;
+
+#[cfg(feature = "vesta")]
+pub use instantiated::vesta;
+
 /// This module implements gadgets related to computing pairings in bilinear groups.
 pub mod pairing;
 